@@ -0,0 +1,45 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use fn_num_types::core::ops::{self, apply_binary_batch, apply_unary_batch};
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+
+/// The values of an analysis, whose possibilities repeat
+fn values(len: usize) -> Vec<FnArgFloat> {
+    let kinds: Vec<FnArgFloat> = FloatPossibilities::enumerate(&[Possible::Yes, Possible::No])
+        .step_by(37)
+        .map(FnArgFloat::F64)
+        .collect();
+
+    (0..len).map(|i| kinds[(i * 7) % kinds.len()]).collect()
+}
+
+fn bench_batch(c: &mut Criterion) {
+    let lhs = values(100_000);
+    let rhs: Vec<FnArgFloat> = lhs.iter().rev().copied().collect();
+    let mut out = vec![FnArgFloat::f64_any(); lhs.len()];
+
+    c.bench_function("sqrt, one by one", |b| {
+        b.iter(|| {
+            for (x, out) in lhs.iter().zip(out.iter_mut()) {
+                *out = ops::sqrt(black_box(x));
+            }
+        });
+    });
+    c.bench_function("sqrt, batched", |b| {
+        b.iter(|| apply_unary_batch(ops::sqrt, black_box(&lhs), &mut out));
+    });
+
+    c.bench_function("mul, one by one", |b| {
+        b.iter(|| {
+            for ((x, y), out) in lhs.iter().zip(&rhs).zip(out.iter_mut()) {
+                *out = ops::mul(black_box(x), y);
+            }
+        });
+    });
+    c.bench_function("mul, batched", |b| {
+        b.iter(|| apply_binary_batch(ops::mul, black_box(&lhs), &rhs, &mut out));
+    });
+}
+
+criterion_group!(benches, bench_batch);
+criterion_main!(benches);
@@ -0,0 +1,99 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use fn_num_types::core::ops::{self, fp};
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+
+/// Every possibilities of `Yes` and `No`
+fn values() -> Vec<FloatPossibilities> {
+    FloatPossibilities::enumerate(&[Possible::Yes, Possible::No]).collect()
+}
+
+fn bench_unary(c: &mut Criterion) {
+    let fps = values();
+    let args: Vec<FnArgFloat> = fps.iter().copied().map(FnArgFloat::F64).collect();
+
+    macro_rules! unary {
+        ($($op:ident),*) => {
+            $(
+                let mut group = c.benchmark_group(stringify!($op));
+                group.bench_function("FnArgFloat", |b| {
+                    b.iter(|| args.iter().map(|x| ops::$op(black_box(x))).collect::<Vec<_>>())
+                });
+                group.bench_function("FloatPossibilities", |b| {
+                    b.iter(|| fps.iter().map(|x| fp::$op(black_box(x))).collect::<Vec<_>>())
+                });
+                group.finish();
+            )*
+        };
+    }
+
+    unary!(neg, abs, round, sqrt, exp, ln, sin, atan, tanh, recip);
+}
+
+fn bench_binary(c: &mut Criterion) {
+    // The pairs of a sample, not to take too long
+    let fps: Vec<FloatPossibilities> = values().into_iter().step_by(7).collect();
+    let args: Vec<FnArgFloat> = fps.iter().copied().map(FnArgFloat::F64).collect();
+
+    macro_rules! binary {
+        ($($op:ident),*) => {
+            $(
+                let mut group = c.benchmark_group(stringify!($op));
+                group.bench_function("FnArgFloat", |b| {
+                    b.iter(|| {
+                        for x in &args {
+                            for y in &args {
+                                black_box(ops::$op(black_box(x), y));
+                            }
+                        }
+                    })
+                });
+                group.bench_function("FloatPossibilities", |b| {
+                    b.iter(|| {
+                        for x in &fps {
+                            for y in &fps {
+                                black_box(fp::$op(black_box(x), y));
+                            }
+                        }
+                    })
+                });
+                group.finish();
+            )*
+        };
+    }
+
+    binary!(add, mul, div, rem, powf, hypot, atan2, max);
+}
+
+fn bench_ternary(c: &mut Criterion) {
+    let fps: Vec<FloatPossibilities> = values().into_iter().step_by(41).collect();
+    let args: Vec<FnArgFloat> = fps.iter().copied().map(FnArgFloat::F64).collect();
+
+    let mut group = c.benchmark_group("mul_add");
+    group.bench_function("FnArgFloat", |b| {
+        b.iter(|| {
+            for x in &args {
+                for y in &args {
+                    for z in &args {
+                        black_box(ops::mul_add(black_box(x), y, z));
+                    }
+                }
+            }
+        })
+    });
+    group.bench_function("FloatPossibilities", |b| {
+        b.iter(|| {
+            for x in &fps {
+                for y in &fps {
+                    for z in &fps {
+                        black_box(fp::mul_add(black_box(x), y, z));
+                    }
+                }
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_unary, bench_binary, bench_ternary);
+criterion_main!(benches);
@@ -0,0 +1,301 @@
+//! The attribute macros of `fn_num_types`, re-exported by its `macros` feature
+
+use std::collections::HashMap;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Literal, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, BinOp, Block, Error, Expr, FnArg, ItemFn, Lit, Pat, ReturnType, Stmt, Type,
+    UnOp,
+};
+
+/// Adds `<NAME>_POSSIBILITIES`, the possibilities of the result of the
+/// function for any arguments of its types, and `<name>_signature()`, its
+/// `FnSignature`, both computed from its body
+///
+/// The parameters and the result must be `f32` or `f64`. The body is made of
+/// `let` bindings and a final expression of the parameters, float literals,
+/// operators and methods of one or two arguments, like `x.sqrt()` or `x.powf(y)`.
+///
+/// The possibilities are a `const`, applying the `const fn` of `core::ops`.
+/// They are not an associated `const POSSIBILITIES: FnSignature`, which
+/// cannot be written:
+///
+/// - a function has no associated items, only a type does;
+/// - a `FnSignature` holds a `Vec`, which cannot be built in a constant:
+///   `<name>_signature()` builds it, with `expr::eval`.
+#[proc_macro_attribute]
+pub fn analyze(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return Error::new(Span::call_site(), "`analyze` takes no arguments")
+            .into_compile_error()
+            .into();
+    }
+
+    let item = parse_macro_input!(item as ItemFn);
+    match signature(&item) {
+        Ok(signature) => quote!(#item #signature).into(),
+        Err(err) => {
+            let err = err.into_compile_error();
+            quote!(#item #err).into()
+        }
+    }
+}
+
+/// The body of the function, of the parameters by index
+#[derive(Clone)]
+enum Node {
+    Var(usize),
+    Const(f64),
+    /// The method, like `sqrt`, or the operator, like `neg`
+    Unary(Ident, Box<Node>),
+    Binary(Ident, Box<Node>, Box<Node>),
+}
+
+/// The variant of `FnArgFloat` of a parameter, and whether it is `f32`
+type Param = (TokenStream2, bool);
+
+impl Node {
+    /// The `expr::Expr`
+    fn to_expr(&self) -> TokenStream2 {
+        let ops = quote!(::fn_num_types::expr);
+        match self {
+            Node::Var(index) => quote!(#ops::Expr::Var(#index)),
+            Node::Const(value) => quote!(#ops::Expr::Const(#value)),
+            Node::Unary(op, x) => {
+                let op = variant(op);
+                let x = x.to_expr();
+                quote!(#ops::Expr::Unary(#ops::UnaryOp::#op, ::std::boxed::Box::new(#x)))
+            }
+            Node::Binary(op, x, y) => {
+                let op = variant(op);
+                let (x, y) = (x.to_expr(), y.to_expr());
+                quote! {
+                    #ops::Expr::Binary(
+                        #ops::BinaryOp::#op,
+                        ::std::boxed::Box::new(#x),
+                        ::std::boxed::Box::new(#y),
+                    )
+                }
+            }
+        }
+    }
+
+    /// The possibilities for any arguments, with the `const fn` of `core::ops`
+    ///
+    /// Like with `expr::eval`, the constants are of the type of the first parameter.
+    fn to_const(&self, params: &[Param]) -> TokenStream2 {
+        let ops = quote!(::fn_num_types::core::ops);
+        let fp = quote!(::fn_num_types::FloatPossibilities);
+        match self {
+            Node::Var(index) => {
+                let (ty, _) = &params[*index];
+                quote!(#ty(#fp::any()))
+            }
+            Node::Const(value) => match params.first() {
+                Some((ty, true)) => {
+                    let value = *value as f32;
+                    // The literals are positive, `-` being `neg`
+                    let value = if value.is_infinite() {
+                        quote!(f32::INFINITY)
+                    } else {
+                        Literal::f32_suffixed(value).to_token_stream()
+                    };
+                    quote!(#ty(#fp::from_f32(#value)))
+                }
+                _ => {
+                    let value = if value.is_infinite() {
+                        quote!(f64::INFINITY)
+                    } else {
+                        Literal::f64_suffixed(*value).to_token_stream()
+                    };
+                    quote!(::fn_num_types::FnArgFloat::F64(#fp::from_f64(#value)))
+                }
+            },
+            Node::Unary(op, x) => {
+                let x = x.to_const(params);
+                quote!(#ops::#op(&#x))
+            }
+            // `x - y` is `x + (-y)`, like the operator
+            Node::Binary(op, x, y) if op == "sub" => {
+                let (x, y) = (x.to_const(params), y.to_const(params));
+                quote!(#ops::add(&#x, &#ops::neg(&#y)))
+            }
+            Node::Binary(op, x, y) => {
+                let (x, y) = (x.to_const(params), y.to_const(params));
+                quote!(#ops::#op(&#x, &#y))
+            }
+        }
+    }
+}
+
+/// `exp_m1` is `ExpM1`, like the variants of `UnaryOp` and `BinaryOp`
+fn variant(op: &Ident) -> Ident {
+    format_ident!("{}", camel_case(&op.to_string()), span = op.span())
+}
+
+fn signature(item: &ItemFn) -> syn::Result<TokenStream2> {
+    let mut vars = HashMap::new();
+    let mut params = Vec::new();
+    for (index, input) in item.sig.inputs.iter().enumerate() {
+        let FnArg::Typed(input) = input else {
+            return Err(Error::new(input.span(), "`self` is not supported"));
+        };
+        let Pat::Ident(pat) = &*input.pat else {
+            return Err(Error::new(input.pat.span(), "expected a name"));
+        };
+        vars.insert(pat.ident.to_string(), Node::Var(index));
+        params.push(float_type(&input.ty)?);
+    }
+
+    let ReturnType::Type(_, ret) = &item.sig.output else {
+        return Err(Error::new(item.sig.span(), "expected a float result"));
+    };
+    float_type(ret)?;
+
+    let node = body(&item.block, vars)?;
+    let expr = node.to_expr();
+    let possibilities = node.to_const(&params);
+    let args = params.iter().map(|(ty, _)| ty);
+
+    let vis = &item.vis;
+    let ident = &item.sig.ident;
+    let name = format_ident!("{}_signature", ident);
+    let doc = format!("The signature of `{ident}`, for any arguments");
+    let const_name = format_ident!("{}_POSSIBILITIES", ident.to_string().to_uppercase());
+    let const_doc = format!("The possibilities of the result of `{ident}`, for any arguments");
+
+    Ok(quote! {
+        #[doc = #const_doc]
+        #vis const #const_name: ::fn_num_types::FnArgFloat = #possibilities;
+
+        #[doc = #doc]
+        #vis fn #name() -> ::fn_num_types::FnSignature {
+            let args = ::std::vec![#(#args(::fn_num_types::FloatPossibilities::any())),*];
+            let ret = ::fn_num_types::expr::eval(&#expr, &args);
+            ::fn_num_types::FnSignature::new(args, ret)
+        }
+    })
+}
+
+/// The variant of `FnArgFloat` of `ty`
+fn float_type(ty: &Type) -> syn::Result<Param> {
+    match ty {
+        Type::Path(path) if path.path.is_ident("f32") => {
+            Ok((quote!(::fn_num_types::FnArgFloat::F32), true))
+        }
+        Type::Path(path) if path.path.is_ident("f64") => {
+            Ok((quote!(::fn_num_types::FnArgFloat::F64), false))
+        }
+        _ => Err(Error::new(ty.span(), "expected `f32` or `f64`")),
+    }
+}
+
+/// The node of the block, with the `let` bindings inlined
+fn body(block: &Block, mut vars: HashMap<String, Node>) -> syn::Result<Node> {
+    let Some((last, stmts)) = block.stmts.split_last() else {
+        return Err(Error::new(block.span(), "expected an expression"));
+    };
+
+    for stmt in stmts {
+        let Stmt::Local(local) = stmt else {
+            return Err(Error::new(stmt.span(), "expected a `let` binding"));
+        };
+        let pat = match &local.pat {
+            Pat::Type(pat) => &*pat.pat,
+            pat => pat,
+        };
+        let (Pat::Ident(pat), Some(init)) = (pat, &local.init) else {
+            return Err(Error::new(
+                local.span(),
+                "expected `let name = expression;`",
+            ));
+        };
+        let value = expr(&init.expr, &vars)?;
+        vars.insert(pat.ident.to_string(), value);
+    }
+
+    match last {
+        Stmt::Expr(last, None) => expr(last, &vars),
+        _ => Err(Error::new(last.span(), "expected an expression")),
+    }
+}
+
+fn expr(e: &Expr, vars: &HashMap<String, Node>) -> syn::Result<Node> {
+    match e {
+        Expr::Paren(e) => expr(&e.expr, vars),
+        Expr::Group(e) => expr(&e.expr, vars),
+        Expr::Return(ret) => match &ret.expr {
+            Some(e) => expr(e, vars),
+            None => Err(Error::new(ret.span(), "expected a float result")),
+        },
+        Expr::Path(path) => {
+            let var = path
+                .path
+                .get_ident()
+                .and_then(|ident| vars.get(&ident.to_string()));
+            var.cloned()
+                .ok_or_else(|| Error::new(path.span(), "unknown variable"))
+        }
+        Expr::Lit(lit) => {
+            let value = match &lit.lit {
+                Lit::Float(value) => value.base10_parse::<f64>()?,
+                Lit::Int(value) => value.base10_parse::<f64>()?,
+                _ => return Err(Error::new(lit.span(), "expected a number")),
+            };
+            Ok(Node::Const(value))
+        }
+        Expr::Unary(unary) => match unary.op {
+            UnOp::Neg(op) => {
+                let x = expr(&unary.expr, vars)?;
+                Ok(Node::Unary(Ident::new("neg", op.span), Box::new(x)))
+            }
+            _ => Err(Error::new(unary.span(), "unsupported operator")),
+        },
+        Expr::Binary(binary) => {
+            let op = match binary.op {
+                BinOp::Add(_) => "add",
+                BinOp::Sub(_) => "sub",
+                BinOp::Mul(_) => "mul",
+                BinOp::Div(_) => "div",
+                BinOp::Rem(_) => "rem",
+                _ => return Err(Error::new(binary.op.span(), "unsupported operator")),
+            };
+            let op = Ident::new(op, binary.op.span());
+            let x = expr(&binary.left, vars)?;
+            let y = expr(&binary.right, vars)?;
+            Ok(Node::Binary(op, Box::new(x), Box::new(y)))
+        }
+        Expr::MethodCall(call) => {
+            let op = call.method.clone();
+            let x = expr(&call.receiver, vars)?;
+            let mut args = call.args.iter();
+            match (args.next(), args.next()) {
+                (None, _) => Ok(Node::Unary(op, Box::new(x))),
+                (Some(y), None) => {
+                    let y = expr(y, vars)?;
+                    Ok(Node::Binary(op, Box::new(x), Box::new(y)))
+                }
+                _ => Err(Error::new(
+                    call.span(),
+                    "expected a method of one or two arguments",
+                )),
+            }
+        }
+        _ => Err(Error::new(e.span(), "unsupported expression")),
+    }
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -1,35 +1,95 @@
-use crate::{return_fp2, FnArgFloat, Possible, FP};
-
-pub fn add(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
-    return_fp2(a, b, |fp1, fp2| {
-        let overflow = FP {
-            nan: Possible::No,
-            zero: Possible::No,
-            infinite: Possible::ShouldNot,
-            positive: Possible::No,
-            negative: Possible::No,
-        };
-
-        let mut res = fp1.union(fp2);
-
-        // Negative overflow
-        if (fp1.negative & fp2.negative) != Possible::No {
-            res = res.union(&overflow);
-        }
-
-        // Positive overflow
-        if (fp1.positive & fp2.positive) != Possible::No {
-            res = res.union(&overflow);
-        }
-
-        // Opposit infinities
-        let both_inf = fp1.infinite & fp2.infinite;
-        let opposite = (fp1.positive & fp2.negative) | (fp1.negative & fp2.positive);
-        res.nan = res.nan | (both_inf & opposite);
-
-        // Zero
-        res.zero = res.zero | opposite;
-
-        res
-    })
+use crate::config::{round_overflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+pub const fn add(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, add_fp)
+}
+
+/// `add`, rounded in the direction `mode`
+pub const fn add_with(mode: RoundingMode, a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, |fp1, fp2| add_fp_with(mode, fp1, fp2))
+}
+
+/// `core::ops::add`
+#[inline]
+pub const fn add_fp(fp1: &FP, fp2: &FP) -> FP {
+    add_fp_with(RoundingMode::ToNearest, fp1, fp2)
+}
+
+/// `core::ops::add`, rounded in the direction `mode`
+#[inline]
+pub const fn add_fp_with(mode: RoundingMode, fp1: &FP, fp2: &FP) -> FP {
+    add_outcome(mode, fp1, fp2).fp
+}
+
+/// `core::ops::add`, telling the infinities from an overflow apart
+pub(crate) const fn add_outcome(mode: RoundingMode, fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let mut res = fp1.union(fp2);
+
+    // Like `0.75 + 0.75`, two values of the same sign may add up above one
+    cases = cases.fire("negative sum", all!(fp1.negative, fp2.negative));
+    cases = cases.fire("positive sum", all!(fp1.positive, fp2.positive));
+    res.large = any!(
+        res.large,
+        all!(fp1.negative, fp2.negative),
+        all!(fp1.positive, fp2.positive)
+    );
+
+    // Overflow, only from two large values of the same sign
+    let both_large = all!(fp1.large, fp2.large);
+    cases = cases.fire(
+        "negative overflow",
+        all!(both_large, fp1.negative, fp2.negative),
+    );
+    cases = cases.fire(
+        "positive overflow",
+        all!(both_large, fp1.positive, fp2.positive),
+    );
+    let overflow = all!(
+        both_large,
+        any!(
+            all!(fp1.negative, fp2.negative),
+            all!(fp1.positive, fp2.positive)
+        ),
+        Possible::ShouldNot
+    );
+    let rounded = round_overflow(res, overflow, mode);
+    res = rounded.fp;
+
+    // Opposit infinities
+    let both_inf = all!(fp1.infinite, fp2.infinite);
+    let opposite = any!(
+        all!(fp1.positive, fp2.negative),
+        all!(fp1.negative, fp2.positive)
+    );
+    cases = cases.fire("opposite infinities", all!(both_inf, opposite));
+    res.nan = any!(res.nan, all!(both_inf, opposite));
+
+    // Zero: -0.0 only as the sum of two -0.0, the cancellation gives +0.0,
+    // and the opposite when rounded downward
+    cases = cases.fire("cancellation", opposite);
+    if matches!(mode, RoundingMode::Downward) {
+        res.pos_zero = all!(fp1.pos_zero, fp2.pos_zero);
+        res.neg_zero = any!(
+            all!(fp1.neg_zero, fp2.zero()),
+            all!(fp1.zero(), fp2.neg_zero),
+            opposite
+        );
+    } else {
+        res.pos_zero = any!(
+            all!(fp1.pos_zero, fp2.zero()),
+            all!(fp1.zero(), fp2.pos_zero),
+            opposite
+        );
+        res.neg_zero = all!(fp1.neg_zero, fp2.neg_zero);
+    }
+    // Like `1.5 * MIN_POSITIVE - MIN_POSITIVE`, the integers stay integers
+    res.subnormal = any!(res.subnormal, all!(opposite, res.fractional));
+
+    Outcome {
+        fp: res,
+        overflow: rounded.overflow,
+        cases,
+    }
 }
@@ -0,0 +1,210 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::expr::{eval, parse};
+use crate::{Error, FnArgFloat};
+
+/// A guard on a variable, like `if x.is_finite()`, narrowing it in each branch
+///
+/// Each guard is the complement of another, for the `else` branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Guard {
+    /// `!x.is_nan()`
+    NotNan,
+    /// `x.is_nan()`
+    Nan,
+    /// `x.is_finite()`
+    Finite,
+    /// `!x.is_finite()`
+    NotFinite,
+    /// `x.is_sign_positive()`
+    Positive,
+    /// `x.is_sign_negative()`
+    Negative,
+    /// `x != 0.0`
+    Nonzero,
+    /// `x == 0.0`
+    Zero,
+}
+
+impl Guard {
+    /// The guard of the `else` branch
+    pub const fn complement(self) -> Self {
+        match self {
+            Guard::NotNan => Guard::Nan,
+            Guard::Nan => Guard::NotNan,
+            Guard::Finite => Guard::NotFinite,
+            Guard::NotFinite => Guard::Finite,
+            Guard::Positive => Guard::Negative,
+            Guard::Negative => Guard::Positive,
+            Guard::Nonzero => Guard::Zero,
+            Guard::Zero => Guard::Nonzero,
+        }
+    }
+
+    /// `x` narrowed by the guard, with the `assume_*` methods
+    pub fn narrow(self, x: &FnArgFloat) -> FnArgFloat {
+        match self {
+            Guard::NotNan => x.assume_not_nan(),
+            Guard::Nan => x.assume_nan(),
+            Guard::Finite => x.assume_finite(),
+            Guard::NotFinite => x.assume_not_finite(),
+            Guard::Positive => x.assume_positive(),
+            Guard::Negative => x.assume_negative(),
+            Guard::Nonzero => x.assume_nonzero(),
+            Guard::Zero => x.assume_zero(),
+        }
+    }
+}
+
+/// The possibilities of named variables along a float code, branches included
+///
+/// The variables are all of the same type, the one of the constants too
+/// (`f64` without variables). A branch is forked from the state before the
+/// guard, and merged back at the join point.
+///
+/// ```
+/// use fn_num_types::{Analyzer, FloatPossibilities, FnArgFloat, Guard, Possible};
+///
+/// let mut analyzer = Analyzer::new();
+/// analyzer.bind("x", FnArgFloat::F64(FloatPossibilities::finite()))?;
+///
+/// // let y = if x.is_sign_negative() { -x } else { x };
+/// let (mut then, mut otherwise) = analyzer.fork("x", Guard::Negative);
+/// then.assign("y", "-x")?;
+/// otherwise.assign("y", "x")?;
+/// let mut analyzer = then.merge(&otherwise)?;
+///
+/// analyzer.assign("z", "y.sqrt() + 1.0")?;
+/// assert_eq!(analyzer.get("y").unwrap().possibilities().negative, Possible::No);
+/// assert_eq!(analyzer.get("z").unwrap().possibilities().nan, Possible::No);
+/// # Ok::<(), fn_num_types::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Analyzer {
+    vars: BTreeMap<String, FnArgFloat>,
+}
+
+impl Analyzer {
+    /// Without variables
+    pub fn new() -> Self {
+        Analyzer::default()
+    }
+
+    /// Sets the possibilities of `name`, like an argument of the function
+    ///
+    /// # Errors
+    ///
+    /// `Error::DifferentTypes` if `value` is not of the type of the other variables
+    pub fn bind(&mut self, name: &str, value: FnArgFloat) -> Result<(), Error> {
+        let other = self.vars.iter().find(|(other, _)| *other != name);
+        if other.is_some_and(|(_, other)| !other.is_same_type(&value)) {
+            return Err(Error::DifferentTypes);
+        }
+
+        self.vars.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    /// `let name = expr;`, `expr` being written like in Rust, of the variables,
+    /// see `expr::parse`
+    ///
+    /// # Errors
+    ///
+    /// Like `expr::parse`, for an unknown variable too
+    pub fn assign(&mut self, name: &str, expr: &str) -> Result<(), Error> {
+        let names: Vec<&str> = self.vars.keys().map(String::as_str).collect();
+        let expr = parse(expr, &names)?;
+        let args: Vec<FnArgFloat> = self.vars.values().copied().collect();
+
+        let value = eval(&expr, &args);
+        self.vars.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    /// The possibilities of `name`, if defined
+    pub fn get(&self, name: &str) -> Option<&FnArgFloat> {
+        self.vars.get(name)
+    }
+
+    /// The variables and their possibilities, sorted by name
+    pub fn vars(&self) -> impl Iterator<Item = (&str, &FnArgFloat)> {
+        self.vars.iter().map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Returns true if the code may get there: no variable is without value
+    pub fn is_reachable(&self) -> bool {
+        self.vars
+            .values()
+            .all(|value| value.possibilities().is_satisfiable())
+    }
+
+    /// `name` narrowed by `guard`, like inside `if guard { ... }`
+    ///
+    /// # Panics
+    ///
+    /// If `name` is not defined
+    pub fn assume(&mut self, name: &str, guard: Guard) {
+        let value = self.vars.get_mut(name).expect("Unknown variable");
+        *value = guard.narrow(value);
+    }
+
+    /// The states of both branches of `if guard { ... } else { ... }` on `name`
+    ///
+    /// # Panics
+    ///
+    /// If `name` is not defined
+    pub fn fork(&self, name: &str, guard: Guard) -> (Self, Self) {
+        let mut then = self.clone();
+        let mut otherwise = self.clone();
+        then.assume(name, guard);
+        otherwise.assume(name, guard.complement());
+
+        (then, otherwise)
+    }
+
+    /// The state at the join point of two branches: the possibilities of
+    /// either, for the variables defined in both
+    ///
+    /// A branch that is not reachable is ignored.
+    ///
+    /// # Errors
+    ///
+    /// `Error::DifferentTypes` if a variable is not of the same type in both
+    pub fn merge(&self, other: &Self) -> Result<Self, Error> {
+        if !other.is_reachable() {
+            return Ok(self.clone());
+        }
+        if !self.is_reachable() {
+            return Ok(other.clone());
+        }
+
+        let mut vars = BTreeMap::new();
+        for (name, value) in &self.vars {
+            let Some(other) = other.vars.get(name) else {
+                continue;
+            };
+            if !value.is_same_type(other) {
+                return Err(Error::DifferentTypes);
+            }
+
+            let union = value.map(|fp| fp.union(other.possibilities()));
+            vars.insert(name.clone(), union);
+        }
+
+        Ok(Analyzer { vars })
+    }
+}
+
+/// A variable per line, like `x: f64 nan:N ...`
+impl fmt::Display for Analyzer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, value) in &self.vars {
+            writeln!(f, "{name}: {value}")?;
+        }
+        Ok(())
+    }
+}
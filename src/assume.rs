@@ -0,0 +1,140 @@
+//! Narrowing of the possibilities after a guard, like `if !x.is_nan() { ... }`.
+//!
+//! Each `assume_*` method has a complement for the `else` branch.
+
+use crate::{FloatPossibilities, FnArgFloat, Possible, FP};
+
+impl FloatPossibilities {
+    /// After `if !x.is_nan()`
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let x = FloatPossibilities {
+    ///     nan: Possible::Yes,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::No,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::No,
+    /// };
+    ///
+    /// assert_eq!(x.assume_not_nan().nan, Possible::No);
+    /// assert_eq!(x.assume_nan().zero(), Possible::No);
+    /// ```
+    pub const fn assume_not_nan(&self) -> Self {
+        FP {
+            nan: Possible::No,
+            ..*self
+        }
+    }
+
+    /// After `if x.is_nan()`, the complement of `assume_not_nan`
+    pub const fn assume_nan(&self) -> Self {
+        FP {
+            nan: self.nan,
+            pos_zero: Possible::No,
+            neg_zero: Possible::No,
+            subnormal: Possible::No,
+            fractional: Possible::No,
+            large: Possible::No,
+            infinite: Possible::No,
+            positive: Possible::No,
+            negative: Possible::No,
+        }
+    }
+
+    /// After `if x.is_finite()`
+    pub const fn assume_finite(&self) -> Self {
+        FP {
+            nan: Possible::No,
+            infinite: Possible::No,
+            ..*self
+        }
+    }
+
+    /// After `if !x.is_finite()`, the complement of `assume_finite`: NaN or infinite
+    pub const fn assume_not_finite(&self) -> Self {
+        FP {
+            nan: self.nan,
+            pos_zero: Possible::No,
+            neg_zero: Possible::No,
+            subnormal: Possible::No,
+            fractional: Possible::No,
+            large: Possible::No,
+            infinite: all!(self.infinite, any!(self.positive, self.negative)),
+            // The signs of the infinities
+            positive: all!(self.positive, self.infinite),
+            negative: all!(self.negative, self.infinite),
+        }
+    }
+
+    /// After `if x.is_sign_positive()`, which is true for `+0.0` and may be for NaN
+    ///
+    /// Combine it with `assume_not_nan` and `assume_nonzero` for `if x > 0.0`.
+    pub const fn assume_positive(&self) -> Self {
+        FP {
+            negative: Possible::No,
+            ..*self
+        }
+    }
+
+    /// After `if x.is_sign_negative()`, the complement of `assume_positive`
+    pub const fn assume_negative(&self) -> Self {
+        FP {
+            positive: Possible::No,
+            ..*self
+        }
+    }
+
+    /// After `if x != 0.0`, which is true for NaN
+    pub const fn assume_nonzero(&self) -> Self {
+        FP {
+            pos_zero: Possible::No,
+            neg_zero: Possible::No,
+            ..*self
+        }
+    }
+
+    /// After `if x == 0.0`, the complement of `assume_nonzero`
+    pub const fn assume_zero(&self) -> Self {
+        FP {
+            nan: Possible::No,
+            pos_zero: self.pos_zero,
+            neg_zero: self.neg_zero,
+            subnormal: Possible::No,
+            fractional: Possible::No,
+            large: Possible::No,
+            infinite: Possible::No,
+            positive: all!(self.pos_zero, self.positive),
+            negative: all!(self.neg_zero, self.negative),
+        }
+    }
+}
+
+macro_rules! assume {
+    ($($assume:ident),* $(,)?) => {
+        impl FnArgFloat {
+            $(
+                /// Like the method of `FloatPossibilities`, keeping the type
+                pub const fn $assume(&self) -> Self {
+                    self.with_possibilities(self.possibilities().$assume())
+                }
+            )*
+        }
+    };
+}
+
+assume!(
+    assume_not_nan,
+    assume_nan,
+    assume_finite,
+    assume_not_finite,
+    assume_positive,
+    assume_negative,
+    assume_nonzero,
+    assume_zero,
+);
@@ -0,0 +1,101 @@
+//! The operations applied to many arguments at once.
+//!
+//! An analysis applies the same operation to many values whose possibilities
+//! repeat: the results are memoized by the packed arguments for the batch.
+
+use crate::FnArgFloat;
+
+/// The memoized results, indexed by a hash of their packed arguments
+const SLOTS: usize = 64;
+
+/// The last results of an operation, one per slot
+struct Memo {
+    slots: [(u64, FnArgFloat); SLOTS],
+}
+
+impl Memo {
+    fn new() -> Self {
+        // No packed argument is `0`
+        Memo {
+            slots: [(0, FnArgFloat::f64_any()); SLOTS],
+        }
+    }
+
+    fn get_or(&mut self, key: u64, op: impl FnOnce() -> FnArgFloat) -> FnArgFloat {
+        // Fibonacci hashing
+        let index = (key.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 58) as usize;
+        let slot = &mut self.slots[index];
+
+        if slot.0 != key {
+            *slot = (key, op());
+        }
+        slot.1
+    }
+}
+
+/// Writes `op(input)` in `out` for each of `inputs`
+///
+/// # Panics
+///
+/// If `out` is not of the length of `inputs`
+///
+/// ```
+/// use fn_num_types::core::ops::{self, apply_unary_batch};
+/// use fn_num_types::{FloatPossibilities, FnArgFloat};
+///
+/// let inputs = [
+///     FnArgFloat::F64(FloatPossibilities::positive()),
+///     FnArgFloat::F32(FloatPossibilities::any()),
+///     FnArgFloat::F64(FloatPossibilities::positive()),
+/// ];
+/// let mut out = [FnArgFloat::f64_any(); 3];
+///
+/// apply_unary_batch(ops::sqrt, &inputs, &mut out);
+/// assert_eq!(out[1], ops::sqrt(&inputs[1]));
+/// assert_eq!(out[2], ops::sqrt(&inputs[2]));
+/// ```
+pub fn apply_unary_batch(
+    op: fn(&FnArgFloat) -> FnArgFloat,
+    inputs: &[FnArgFloat],
+    out: &mut [FnArgFloat],
+) {
+    assert_eq!(inputs.len(), out.len(), "Different lengths");
+
+    let mut memo = Memo::new();
+    for (input, out) in inputs.iter().zip(out.iter_mut()) {
+        *out = memo.get_or(u64::from(input.pack()), || op(input));
+    }
+}
+
+/// Writes `op(lhs, rhs)` in `out` for each pair of `lhs` and `rhs`
+///
+/// # Panics
+///
+/// If the slices are not of the same length, or if a pair is of different types
+///
+/// ```
+/// use fn_num_types::core::ops::{self, apply_binary_batch};
+/// use fn_num_types::{FloatPossibilities, FnArgFloat};
+///
+/// let x = FnArgFloat::F64(FloatPossibilities::finite());
+/// let y = FnArgFloat::F64(FloatPossibilities::positive());
+/// let mut out = [FnArgFloat::f64_any(); 2];
+///
+/// apply_binary_batch(ops::add, &[x, y], &[y, y], &mut out);
+/// assert_eq!(out, [ops::add(&x, &y), ops::add(&y, &y)]);
+/// ```
+pub fn apply_binary_batch(
+    op: fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat,
+    lhs: &[FnArgFloat],
+    rhs: &[FnArgFloat],
+    out: &mut [FnArgFloat],
+) {
+    assert_eq!(lhs.len(), rhs.len(), "Different lengths");
+    assert_eq!(lhs.len(), out.len(), "Different lengths");
+
+    let mut memo = Memo::new();
+    for ((a, b), out) in lhs.iter().zip(rhs).zip(out.iter_mut()) {
+        let key = u64::from(a.pack()) << 32 | u64::from(b.pack());
+        *out = memo.get_or(key, || op(a, b));
+    }
+}
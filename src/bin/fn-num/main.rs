@@ -0,0 +1,119 @@
+//! Prints the possibilities of the result of an operation.
+//!
+//! ```text
+//! $ fn-num sqrt "f64 +0:Y +:Y"
+//! f64 nan:N +0:Y -0:N sub:N frac:Y large:N inf:N +:Y -:N
+//! PositiveFinite<f64>
+//! ```
+//!
+//! Or of an expression, with the possibilities of each variable:
+//!
+//! ```text
+//! $ fn-num --expr "(x.abs() + y).sqrt()" "x=f64 nan:Y +:Y -:Y frac:Y large:Y" "y=f64 +:Y large:Y"
+//! f64 nan:Y zero:N sub:N frac:Y large:Y inf:SN +:Y -:N
+//! f64
+//! ```
+
+#[cfg(feature = "server")]
+mod server;
+
+use std::process::ExitCode;
+
+use fn_num_types::core::ops::apply;
+use fn_num_types::expr::{eval, parse};
+use fn_num_types::{Error, FloatType, FnArgFloat, ParseError};
+
+/// Name of the narrowest `typed_floats` type accepting all the possibilities
+fn typed_float_name(arg: &FnArgFloat) -> String {
+    match arg.possibilities().tightest_type() {
+        FloatType::Float => arg.type_name().to_owned(),
+        FloatType::Never => "!".to_owned(),
+        name => format!("{name}<{}>", arg.type_name()),
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("Usage: fn-num <op> <arg>...");
+    eprintln!("       fn-num --expr <expression> <name>=<arg>...");
+    #[cfg(feature = "server")]
+    eprintln!("       fn-num --stdio");
+    eprintln!("  <arg> is a type followed by the possible outcomes, like \"f64 zero:Y +:Y inf:N\"");
+    eprintln!("  <expression> is written like in Rust, like \"(x.abs() + y).sqrt()\"");
+    eprintln!("  Outcomes are nan, zero (or +0 and -0), sub, frac, inf, + and -, with levels Y, S, SN and N (when omitted)");
+    ExitCode::FAILURE
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let Some((name, args)) = args.split_first() else {
+        return usage();
+    };
+
+    #[cfg(feature = "server")]
+    if name == "--stdio" && args.is_empty() {
+        return server::run();
+    }
+
+    if name == "--expr" {
+        return match expression(args) {
+            Ok(res) => print(&res),
+            Err(err) => {
+                eprintln!("Error: {err}");
+                usage()
+            }
+        };
+    }
+
+    let args = match args
+        .iter()
+        .map(|arg| arg.parse::<FnArgFloat>())
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return usage();
+        }
+    };
+
+    let res = match apply(name, &args) {
+        Ok(res) => res,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return usage();
+        }
+    };
+
+    print(&res)
+}
+
+/// The result of `<expression> <name>=<arg>...`
+fn expression(args: &[String]) -> Result<FnArgFloat, Error> {
+    let Some((text, args)) = args.split_first() else {
+        return Err(Error::NoArguments);
+    };
+
+    let mut names = vec![];
+    let mut values = vec![];
+    for arg in args {
+        let (name, value) = arg.split_once('=').ok_or_else(|| ParseError {
+            token: arg.to_owned(),
+        })?;
+        names.push(name.trim());
+        values.push(value.parse::<FnArgFloat>()?);
+    }
+
+    let expr = parse(text, &names)?;
+    if !values.is_empty() {
+        FnArgFloat::check_same_type(&values)?;
+    }
+    Ok(eval(&expr, &values))
+}
+
+fn print(res: &FnArgFloat) -> ExitCode {
+    println!("{res}");
+    println!("{}", typed_float_name(res));
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,147 @@
+//! JSON-RPC 2.0 server, reading one request per line on stdin
+//! and writing one response per line on stdout.
+//!
+//! Methods:
+//! - `apply`, with `{"op": "add", "args": ["f64 +:Y", "f64 -:Y"]}`
+//! - `chain`, applying unary operations in sequence, with `{"arg": "f64 +:Y", "ops": ["abs", "sqrt"]}`
+//!
+//! Both return `{"value": "f64 nan:N zero:N sub:N frac:Y large:N inf:N +:Y -:N", "type": "StrictlyPositiveFinite<f64>"}`
+
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+use fn_num_types::FnArgFloat;
+use serde_json::{json, Value};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+struct Error {
+    code: i64,
+    message: String,
+}
+
+fn invalid_params(message: impl Into<String>) -> Error {
+    Error {
+        code: INVALID_PARAMS,
+        message: message.into(),
+    }
+}
+
+fn parse_arg(value: &Value) -> Result<FnArgFloat, Error> {
+    value
+        .as_str()
+        .ok_or_else(|| invalid_params("arguments must be strings"))?
+        .parse::<FnArgFloat>()
+        .map_err(|err| invalid_params(err.to_string()))
+}
+
+fn strings<'a>(params: &'a Value, name: &str) -> Result<&'a Vec<Value>, Error> {
+    params[name]
+        .as_array()
+        .ok_or_else(|| invalid_params(format!("`{name}` must be an array")))
+}
+
+fn result(value: &FnArgFloat) -> Value {
+    json!({
+        "value": value.to_string(),
+        "type": super::typed_float_name(value),
+    })
+}
+
+fn call(method: &str, params: &Value) -> Result<Value, Error> {
+    match method {
+        "apply" => {
+            let op = params["op"]
+                .as_str()
+                .ok_or_else(|| invalid_params("`op` must be a string"))?;
+
+            let args = strings(params, "args")?
+                .iter()
+                .map(parse_arg)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let value = super::apply(op, &args).map_err(|err| invalid_params(err.to_string()))?;
+
+            Ok(result(&value))
+        }
+        "chain" => {
+            let mut value = parse_arg(&params["arg"])?;
+
+            for op in strings(params, "ops")? {
+                let op = op
+                    .as_str()
+                    .ok_or_else(|| invalid_params("`ops` must be strings"))?;
+
+                value =
+                    super::apply(op, &[value]).map_err(|err| invalid_params(err.to_string()))?;
+            }
+
+            Ok(result(&value))
+        }
+        _ => Err(Error {
+            code: METHOD_NOT_FOUND,
+            message: format!("unknown method `{method}`"),
+        }),
+    }
+}
+
+/// Returns the response to a request, or `None` for notifications
+fn handle(line: &str) -> Option<Value> {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(err) => {
+            return Some(json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": { "code": PARSE_ERROR, "message": err.to_string() },
+            }))
+        }
+    };
+
+    let id = request.get("id").cloned();
+
+    let response = match request["method"].as_str() {
+        Some(method) => call(method, &request["params"]),
+        None => Err(Error {
+            code: INVALID_REQUEST,
+            message: "`method` must be a string".to_owned(),
+        }),
+    };
+
+    let id = id?;
+
+    Some(match response {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(err) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": err.code, "message": err.message },
+        }),
+    })
+}
+
+pub fn run() -> ExitCode {
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+
+    for line in stdin.lock().lines() {
+        let Ok(line) = line else {
+            return ExitCode::FAILURE;
+        };
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(response) = handle(&line) {
+            if writeln!(stdout, "{response}").is_err() || stdout.flush().is_err() {
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
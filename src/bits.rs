@@ -0,0 +1,88 @@
+//! The bits of the floats, as unsigned integers
+//!
+//! All the bits zero is `+0.0` only, and all the bits one is a NaN: the other
+//! integers are any float.
+
+use crate::{FnArgFloat, FnArgInt, IntKind, IntPossibilities, Possible, FP};
+
+/// `lhs.to_bits()`, of the unsigned integer of the width of the float
+///
+/// ```
+/// use fn_num_types::core::ops::to_bits;
+/// use fn_num_types::{FloatPossibilities, FnArgFloat, FnArgInt, Possible};
+///
+/// let x = FnArgFloat::F64(FloatPossibilities::non_nan());
+/// let bits = to_bits(&x);
+///
+/// assert!(matches!(bits, FnArgInt::U64(_)));
+/// assert_eq!(bits.possibilities().max_value, Possible::No);
+/// assert!(bits.possibilities().accept(1.5f64.to_bits()));
+/// ```
+pub const fn to_bits(lhs: &FnArgFloat) -> FnArgInt {
+    let fp = lhs.possibilities();
+    let kind = match lhs {
+        FnArgFloat::F32(_) => IntKind::U32,
+        FnArgFloat::F64(_) => IntKind::U64,
+        #[cfg(feature = "extra-widths")]
+        FnArgFloat::F16(_) | FnArgFloat::BF16(_) => IntKind::U16,
+        #[cfg(feature = "extra-widths")]
+        FnArgFloat::F128(_) => IntKind::U128,
+    };
+
+    // `1.0` has no flag of its own
+    let non_zero = any!(fp.nan, fp.neg_zero, fp.subnormal, fp.fractional, fp.large, fp.infinite, fp.positive, fp.negative);
+
+    let ip = IntPossibilities {
+        zero: fp.pos_zero,
+        positive: non_zero,
+        negative: Possible::No,
+        min_value: fp.pos_zero,
+        max_value: fp.nan,
+    };
+    FnArgInt::new(kind, ip)
+}
+
+/// `f64::from_bits(lhs)`, or of the float of the width of `lhs`
+///
+/// ```
+/// use fn_num_types::core::ops::from_bits;
+/// use fn_num_types::{FnArgFloat, FnArgInt, IntPossibilities, Possible};
+///
+/// let zero = from_bits(&FnArgInt::U64(IntPossibilities::from_value(0u64)));
+/// assert_eq!(zero, FnArgFloat::from(0.0));
+///
+/// let any = from_bits(&FnArgInt::U32(IntPossibilities::from_value(1u32)));
+/// assert_eq!(any.possibilities().nan, Possible::Yes);
+/// assert!(any.accept(f32::from_bits(1)));
+/// ```
+///
+/// # Panics
+///
+/// If `lhs` is not an unsigned integer of the width of a float:
+/// `u32`, `u64`, or with `extra-widths` `u16` for `f16` and `u128`
+pub const fn from_bits(lhs: &FnArgInt) -> FnArgFloat {
+    let ip = lhs.possibilities().fit(lhs.kind());
+    let any = ip.positive;
+
+    let fp = FP {
+        nan: any,
+        pos_zero: ip.zero,
+        neg_zero: any,
+        subnormal: any,
+        fractional: any,
+        large: any,
+        infinite: any,
+        positive: any!(ip.zero, any),
+        negative: any,
+    };
+
+    match lhs {
+        FnArgInt::U32(_) => FnArgFloat::F32(fp),
+        FnArgInt::U64(_) => FnArgFloat::F64(fp),
+        #[cfg(feature = "extra-widths")]
+        FnArgInt::U16(_) => FnArgFloat::F16(fp),
+        #[cfg(feature = "extra-widths")]
+        FnArgInt::U128(_) => FnArgFloat::F128(fp),
+        _ => panic!("Not the bits of a float"),
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use crate::core::ops::{apply, op_names};
+use crate::{Error, FnArgFloat};
+
+/// The arguments of the cached operations, at most three
+const MAX_ARGS: usize = 3;
+
+/// An operation and its arguments, packed
+type Key = (u16, [u32; MAX_ARGS]);
+
+/// The results of `core::ops::apply`, kept for the repeated queries of an analysis
+///
+/// The arguments are packed with `FloatPossibilities::to_bits` and their type.
+/// Only the built-in operations of at most three arguments are kept, the others
+/// are applied every time.
+///
+/// ```
+/// use fn_num_types::core::ops;
+/// use fn_num_types::{AnalysisCache, FloatPossibilities, FnArgFloat};
+///
+/// let mut cache = AnalysisCache::new();
+/// let x = FnArgFloat::F64(FloatPossibilities::positive());
+///
+/// assert_eq!(cache.cached_apply("sqrt", &[x]), Ok(ops::sqrt(&x)));
+/// assert_eq!(cache.cached_apply("sqrt", &[x]), Ok(ops::sqrt(&x)));
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct AnalysisCache {
+    ids: HashMap<&'static str, u16>,
+    results: HashMap<Key, FnArgFloat>,
+}
+
+impl Default for AnalysisCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnalysisCache {
+    /// An empty cache
+    pub fn new() -> Self {
+        AnalysisCache {
+            ids: op_names()
+                .zip(0..)
+                .map(|((name, _), id)| (name, id))
+                .collect(),
+            results: HashMap::new(),
+        }
+    }
+
+    /// `core::ops::apply`, computed once per operation and arguments
+    ///
+    /// # Errors
+    ///
+    /// Like `core::ops::apply`, the errors are not kept
+    pub fn cached_apply(&mut self, name: &str, args: &[FnArgFloat]) -> Result<FnArgFloat, Error> {
+        let Some(key) = self.key(name, args) else {
+            return apply(name, args);
+        };
+
+        if let Some(res) = self.results.get(&key) {
+            return Ok(*res);
+        }
+
+        let res = apply(name, args)?;
+        self.results.insert(key, res);
+        Ok(res)
+    }
+
+    /// The number of results kept
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Forgets all the results
+    pub fn clear(&mut self) {
+        self.results.clear();
+    }
+
+    fn key(&self, name: &str, args: &[FnArgFloat]) -> Option<Key> {
+        let id = *self.ids.get(name)?;
+        if args.len() > MAX_ARGS {
+            return None;
+        }
+
+        // `0` is no argument, so that the number of arguments is in the key
+        let mut packed = [0; MAX_ARGS];
+        for (packed, arg) in packed.iter_mut().zip(args) {
+            *packed = arg.pack();
+        }
+        Some((id, packed))
+    }
+}
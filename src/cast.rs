@@ -0,0 +1,271 @@
+use crate::config::{round_overflow, round_underflow, Cases, Outcome};
+use crate::{FnArgFloat, FnArgInt, IntKind, IntPossibilities, Possible, RoundingMode, FP};
+
+/// `lhs as T`, with `T` the integer type `target`
+///
+/// The cast rounds toward zero and saturates: NaN becomes `0`,
+/// values above `MAX` (like `inf`) become `MAX` and values below `MIN` (like `-inf`) become `MIN`.
+///
+/// ```
+/// use fn_num_types::core::ops::cast_to_int;
+/// use fn_num_types::{FnArgFloat, IntKind, Possible, FP};
+///
+/// let x = FnArgFloat::F64(FP {
+///     nan: Possible::Yes,
+///     pos_zero: Possible::No,
+///     neg_zero: Possible::No,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::No,
+///     negative: Possible::Yes,
+/// });
+///
+/// let ip = cast_to_int(&x, IntKind::U8);
+/// // NaN and the negative values saturate to zero
+/// assert_eq!(ip.zero, Possible::Yes);
+/// assert_eq!(ip.positive, Possible::No);
+/// ```
+pub const fn cast_to_int(lhs: &FnArgFloat, target: IntKind) -> IntPossibilities {
+    let fp = lhs.possibilities();
+
+    // Values in ]-1, 1[ are truncated to zero, and only the zeros are integers there
+    let truncated = any!(
+        fp.zero(),
+        all!(fp.fractional, any!(fp.positive, fp.negative))
+    );
+
+    // The negative values saturate to zero
+    let saturated = if target.is_signed() {
+        Possible::No
+    } else {
+        fp.negative
+    };
+
+    // `-MIN`, or `MAX + 1`, is `2^k`: the finite values may be below, like `f32::MAX < u128::MAX`.
+    // The finite values are below `2^(bias + 1)`, and their maximum is `2^k - 1` or above
+    // when `bias + 1 == k` only if the significand has `k` bits or more.
+    let k = target.bits() - target.is_signed() as u32;
+    let (exponent, precision) = lhs.widths();
+    let bias = (1 << (exponent - 1)) - 1;
+    let reachable_min = if bias >= k { fp.large } else { Possible::No };
+    let reachable_max = if bias >= k || (bias + 1 == k && k <= precision) {
+        fp.large
+    } else {
+        Possible::No
+    };
+
+    let max_value = all!(fp.positive, any!(fp.infinite, reachable_max));
+    let min_value = all!(fp.negative, any!(fp.infinite, reachable_min));
+
+    IntPossibilities {
+        zero: any!(fp.nan, truncated, saturated),
+        positive: fp.positive,
+        negative: fp.negative,
+        min_value,
+        max_value,
+    }
+    .fit(target)
+}
+
+/// Is `to_int_unchecked` safe for all the values of `lhs`?
+///
+/// It is undefined behavior for NaN, infinite values, and values out of range of the integer type
+/// once truncated. `FloatPossibilities` do not bound the magnitude of finite values,
+/// so only an argument without any possible value is provably safe.
+///
+/// ```
+/// use fn_num_types::core::ops::is_to_int_unchecked_safe;
+/// use fn_num_types::{FnArgFloat, Possible, FP};
+///
+/// let finite = FnArgFloat::F64(FP {
+///     nan: Possible::No,
+///     pos_zero: Possible::Yes,
+///     neg_zero: Possible::Yes,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::Yes,
+///     negative: Possible::No,
+/// });
+///
+/// // `1e300` may be out of range
+/// assert!(!is_to_int_unchecked_safe(&finite));
+/// ```
+pub const fn is_to_int_unchecked_safe(lhs: &FnArgFloat) -> bool {
+    let fp = lhs.possibilities();
+
+    matches!(
+        (fp.nan, fp.infinite, fp.positive, fp.negative),
+        (Possible::No, Possible::No, Possible::No, Possible::No)
+    )
+}
+
+const fn int_to_fp(lhs: &FnArgInt, overflow: Possible) -> FP {
+    let ip = lhs.possibilities();
+
+    let fp = FP {
+        nan: Possible::No,
+        pos_zero: ip.zero,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        large: any!(ip.positive, ip.negative),
+        infinite: Possible::No,
+        // `0 as f64` is `+0.0`
+        positive: any!(ip.positive, ip.zero),
+        negative: ip.negative,
+    };
+
+    round_overflow(fp, overflow, RoundingMode::ToNearest).fp
+}
+
+/// `lhs as f32`
+///
+/// ```
+/// use fn_num_types::core::ops::int_to_f32;
+/// use fn_num_types::{FnArgFloat, FnArgInt, IntPossibilities, Possible};
+///
+/// let x = FnArgInt::U128(IntPossibilities::from_value(u128::MAX));
+///
+/// // Rounded up to `2^128`, above `f32::MAX`
+/// match int_to_f32(&x) {
+///     FnArgFloat::F32(fp) => assert_eq!(fp.infinite, Possible::ShouldNot),
+///     _ => unreachable!(),
+/// }
+/// ```
+pub const fn int_to_f32(lhs: &FnArgInt) -> FnArgFloat {
+    // Only the values close to `u128::MAX` are rounded above `f32::MAX`
+    let overflow = match lhs {
+        FnArgInt::U128(ip) => all!(ip.positive, Possible::ShouldNot),
+        _ => Possible::No,
+    };
+
+    FnArgFloat::F32(int_to_fp(lhs, overflow))
+}
+
+/// `lhs as f64`
+///
+/// ```
+/// use fn_num_types::core::ops::int_to_f64;
+/// use fn_num_types::{FnArgFloat, FnArgInt, IntPossibilities, Possible};
+///
+/// let x = FnArgInt::I64(IntPossibilities::from_value(-3i64));
+///
+/// match int_to_f64(&x) {
+///     FnArgFloat::F64(fp) => {
+///         assert_eq!(fp.negative, Possible::Yes);
+///         assert_eq!(fp.zero(), Possible::No);
+///         assert_eq!(fp.nan, Possible::No);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+pub const fn int_to_f64(lhs: &FnArgInt) -> FnArgFloat {
+    FnArgFloat::F64(int_to_fp(lhs, Possible::No))
+}
+
+/// `lhs as f32`
+///
+/// ```
+/// use fn_num_types::core::ops::to_f32;
+/// use fn_num_types::{FnArgFloat, Possible, FP};
+///
+/// let x = FnArgFloat::F64(FP {
+///     nan: Possible::No,
+///     pos_zero: Possible::No,
+///     neg_zero: Possible::No,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::Yes,
+///     negative: Possible::No,
+/// });
+///
+/// // `1e300 as f32` is infinite, and `1e-300 as f32` is zero
+/// match to_f32(&x) {
+///     FnArgFloat::F32(fp) => {
+///         assert_eq!(fp.infinite, Possible::ShouldNot);
+///         assert_eq!(fp.zero(), Possible::ShouldNot);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+pub const fn to_f32(lhs: &FnArgFloat) -> FnArgFloat {
+    convert(lhs, FnArgFloat::F32(FP::any()))
+}
+
+/// `lhs as f64`, which is exact except from `f128`
+pub const fn to_f64(lhs: &FnArgFloat) -> FnArgFloat {
+    convert(lhs, FnArgFloat::F64(FP::any()))
+}
+
+/// `lhs as f16`
+#[cfg(feature = "extra-widths")]
+pub const fn to_f16(lhs: &FnArgFloat) -> FnArgFloat {
+    convert(lhs, FnArgFloat::F16(FP::any()))
+}
+
+/// `bf16::from_f64(lhs)`, rounded to nearest like `as`
+#[cfg(feature = "extra-widths")]
+pub const fn to_bf16(lhs: &FnArgFloat) -> FnArgFloat {
+    convert(lhs, FnArgFloat::BF16(FP::any()))
+}
+
+/// `lhs as f128`, which is exact
+#[cfg(feature = "extra-widths")]
+pub const fn to_f128(lhs: &FnArgFloat) -> FnArgFloat {
+    convert(lhs, FnArgFloat::F128(FP::any()))
+}
+
+/// Converts `lhs` to the type of `target`
+pub(crate) const fn convert(lhs: &FnArgFloat, target: FnArgFloat) -> FnArgFloat {
+    target.with_possibilities(convert_outcome(lhs, &target).fp)
+}
+
+/// `convert`, with the cases that fired
+pub(crate) const fn convert_outcome(lhs: &FnArgFloat, target: &FnArgFloat) -> Outcome {
+    let fp = lhs.possibilities();
+    let mut cases = Cases::NONE;
+
+    // A narrower range of exponents, on both sides: the large values overflow,
+    // and the tiny ones underflow. With the same exponents, a narrower significand
+    // rounds the largest values above the largest one of the target, like `f32` to `bf16`.
+    let (lhs_exponent, lhs_precision) = lhs.widths();
+    let (exponent, precision) = target.widths();
+    let narrower =
+        if lhs_exponent > exponent || (lhs_exponent == exponent && lhs_precision > precision) {
+            Possible::ShouldNot
+        } else {
+            Possible::No
+        };
+    let non_nan = any!(fp.positive, fp.negative);
+
+    let overflow = all!(non_nan, narrower);
+    cases = cases.fire("overflow", overflow);
+
+    let underflow = all!(non_nan, narrower);
+    cases = cases.fire("underflow", underflow);
+
+    // Tiny normal values become subnormal, and the subnormals of a narrower range
+    // of exponents become normal
+    let subnormal = if !matches!(narrower, Possible::No) {
+        any!(fp.subnormal, non_nan)
+    } else if lhs_exponent < exponent {
+        Possible::No
+    } else {
+        fp.subnormal
+    };
+
+    Outcome {
+        cases,
+        ..round_overflow(
+            round_underflow(FP { subnormal, ..*fp }, underflow, RoundingMode::ToNearest),
+            overflow,
+            RoundingMode::ToNearest,
+        )
+    }
+}
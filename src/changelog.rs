@@ -0,0 +1,34 @@
+use crate::{Field, Possible};
+
+/// A change of the verdict of an operation for one field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Revision {
+    /// Version of the crate introducing the change
+    pub version: &'static str,
+    /// Name of the operation, as in `core::ops`
+    pub op: &'static str,
+    /// Field of the result whose verdict changed
+    pub field: Field,
+    pub old: Possible,
+    pub new: Possible,
+    pub rationale: &'static str,
+}
+
+/// Revisions since the first release, from the oldest to the newest.
+///
+/// Operations added in a release have no revision for that release.
+const CHANGELOG: &[Revision] = &[];
+
+/// Returns all the revisions, from the oldest to the newest
+pub fn changelog() -> &'static [Revision] {
+    CHANGELOG
+}
+
+/// Returns the revisions of the operation `op`, from the oldest to the newest
+///
+/// ```
+/// assert_eq!(fn_num_types::revisions("add").count(), 0);
+/// ```
+pub fn revisions(op: &str) -> impl Iterator<Item = &'static Revision> + '_ {
+    CHANGELOG.iter().filter(move |revision| revision.op == op)
+}
@@ -0,0 +1,102 @@
+use core::fmt;
+
+use crate::{FloatPossibilities, Possible};
+
+/// The classifications of floats of `typed_floats`, from `FloatPossibilities::tightest_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FloatType {
+    /// The primitive type, NaN included
+    Float,
+    NonNaN,
+    NonNaNFinite,
+    NonZeroNonNaN,
+    NonZeroNonNaNFinite,
+    Positive,
+    PositiveFinite,
+    Negative,
+    NegativeFinite,
+    StrictlyPositive,
+    StrictlyPositiveFinite,
+    StrictlyNegative,
+    StrictlyNegativeFinite,
+    /// No possible value, like the result of a function that never returns
+    Never,
+}
+
+/// The name of the type, like `NonNaNFinite`, `!` for `Never` and `float` for `Float`
+impl fmt::Display for FloatType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FloatType::Float => f.write_str("float"),
+            FloatType::Never => f.write_str("!"),
+            _ => fmt::Debug::fmt(self, f),
+        }
+    }
+}
+
+impl FloatType {
+    /// All the types, from the widest
+    pub const ALL: [FloatType; 14] = [
+        FloatType::Float,
+        FloatType::NonNaN,
+        FloatType::NonNaNFinite,
+        FloatType::NonZeroNonNaN,
+        FloatType::NonZeroNonNaNFinite,
+        FloatType::Positive,
+        FloatType::PositiveFinite,
+        FloatType::Negative,
+        FloatType::NegativeFinite,
+        FloatType::StrictlyPositive,
+        FloatType::StrictlyPositiveFinite,
+        FloatType::StrictlyNegative,
+        FloatType::StrictlyNegativeFinite,
+        FloatType::Never,
+    ];
+
+    /// The possibilities of the values of the type
+    pub const fn possibilities(self) -> FloatPossibilities {
+        match self {
+            FloatType::Float => FloatPossibilities::any(),
+            FloatType::NonNaN => FloatPossibilities::non_nan(),
+            FloatType::NonNaNFinite => FloatPossibilities::finite(),
+            FloatType::NonZeroNonNaN => FloatPossibilities::non_zero_non_nan(),
+            FloatType::NonZeroNonNaNFinite => FloatPossibilities::non_zero_non_nan_finite(),
+            FloatType::Positive => FloatPossibilities::positive(),
+            FloatType::PositiveFinite => FloatPossibilities::positive_finite(),
+            FloatType::Negative => FloatPossibilities::negative(),
+            FloatType::NegativeFinite => FloatPossibilities::negative_finite(),
+            FloatType::StrictlyPositive => FloatPossibilities::strictly_positive(),
+            FloatType::StrictlyPositiveFinite => FloatPossibilities::strictly_positive_finite(),
+            FloatType::StrictlyNegative => FloatPossibilities::strictly_negative(),
+            FloatType::StrictlyNegativeFinite => FloatPossibilities::strictly_negative_finite(),
+            FloatType::Never => FloatPossibilities::nan().with_nan(Possible::No),
+        }
+    }
+}
+
+impl FloatPossibilities {
+    /// The narrowest classification accepting all the possibilities,
+    /// however unlikely
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, FloatType, Possible};
+    ///
+    /// let x = FloatPossibilities::strictly_positive_finite().with_pos_zero(Possible::ShouldNot);
+    ///
+    /// assert_eq!(x.tightest_type(), FloatType::PositiveFinite);
+    /// assert_eq!(FloatPossibilities::any().tightest_type(), FloatType::Float);
+    /// ```
+    pub fn tightest_type(&self) -> FloatType {
+        if !self.is_satisfiable() {
+            return FloatType::Never;
+        }
+
+        // From the narrowest: each type is after all its subsets
+        FloatType::ALL
+            .into_iter()
+            .rev()
+            .find(|ty| self.is_subset_of(&ty.possibilities()))
+            .unwrap_or(FloatType::Float)
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{BoolPossibility, FnArgFloat, Possible, FP};
+
+/// The possible results of a comparison
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrderingPossibilities {
+    pub less: Possible,
+    pub equal: Possible,
+    pub greater: Possible,
+}
+
+/// The possible results of `partial_cmp`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PartialOrderingPossibilities {
+    /// The result, when it is `Some`
+    pub ordering: OrderingPossibilities,
+    /// The result may be `None`, when an argument is NaN
+    pub none: Possible,
+}
+
+/// The non-NaN values, in increasing order: `-inf`, negative, `±0`, positive, `inf`,
+/// and whether each class contains several values
+const fn classes(fp: &FP) -> [(Possible, bool); 5] {
+    [
+        (all!(fp.infinite, fp.negative), false),
+        (fp.negative, true),
+        (
+            any!(all!(fp.pos_zero, fp.positive), all!(fp.neg_zero, fp.negative)),
+            false,
+        ),
+        (fp.positive, true),
+        (all!(fp.infinite, fp.positive), false),
+    ]
+}
+
+/// The values in the order of `total_cmp`: NaN with the sign bit, `-inf`, negative, `-0`, `+0`,
+/// positive, `inf` and NaN without the sign bit
+const fn total_classes(fp: &FP) -> [(Possible, bool); 8] {
+    [
+        (fp.nan, true),
+        (all!(fp.infinite, fp.negative), false),
+        (fp.negative, true),
+        (all!(fp.neg_zero, fp.negative), false),
+        (all!(fp.pos_zero, fp.positive), false),
+        (fp.positive, true),
+        (all!(fp.infinite, fp.positive), false),
+        (fp.nan, true),
+    ]
+}
+
+/// Compares values of ordered classes
+const fn ordering<const N: usize>(
+    classes1: [(Possible, bool); N],
+    classes2: [(Possible, bool); N],
+) -> OrderingPossibilities {
+    let mut res = OrderingPossibilities {
+        less: Possible::No,
+        equal: Possible::No,
+        greater: Possible::No,
+    };
+
+    let mut i = 0;
+    while i < N {
+        let (possible1, several) = classes1[i];
+        let mut j = 0;
+        while j < N {
+            let possible = all!(possible1, classes2[j].0);
+
+            if i < j || (i == j && several) {
+                res.less = any!(res.less, possible);
+            }
+            if i == j {
+                res.equal = any!(res.equal, possible);
+            }
+            if i > j || (i == j && several) {
+                res.greater = any!(res.greater, possible);
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    res
+}
+
+/// `lhs.partial_cmp(rhs)`
+///
+/// ```
+/// use fn_num_types::core::ops::cmp::partial_cmp;
+/// use fn_num_types::{FnArgFloat, Possible, FP};
+///
+/// let positive = FnArgFloat::F64(FP {
+///     nan: Possible::No,
+///     pos_zero: Possible::No,
+///     neg_zero: Possible::No,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::Yes,
+///     negative: Possible::No,
+/// });
+/// let nan = FnArgFloat::F64(FP {
+///     nan: Possible::Yes,
+///     pos_zero: Possible::No,
+///     neg_zero: Possible::No,
+///     subnormal: Possible::No,
+///     fractional: Possible::No,
+///     large: Possible::No,
+///     infinite: Possible::No,
+///     positive: Possible::No,
+///     negative: Possible::No,
+/// });
+///
+/// assert_eq!(partial_cmp(&positive, &positive).none, Possible::No);
+/// assert_eq!(partial_cmp(&positive, &nan).none, Possible::Yes);
+/// ```
+///
+/// # Panics
+///
+/// If the arguments are not of the same type
+pub const fn partial_cmp(lhs: &FnArgFloat, rhs: &FnArgFloat) -> PartialOrderingPossibilities {
+    assert!(lhs.is_same_type(rhs), "Different types");
+
+    let (fp1, fp2) = (lhs.possibilities(), rhs.possibilities());
+
+    PartialOrderingPossibilities {
+        ordering: ordering(classes(fp1), classes(fp2)),
+        none: any!(fp1.nan, fp2.nan),
+    }
+}
+
+/// Whether a comparison, true for the results `accepted`, may be true or false
+const fn compare(lhs: &FnArgFloat, rhs: &FnArgFloat, accepted: [bool; 3]) -> BoolPossibility {
+    let res = partial_cmp(lhs, rhs);
+    let results = [res.ordering.less, res.ordering.equal, res.ordering.greater];
+
+    let mut can_be_true = false;
+    // Comparisons with NaN are false
+    let mut can_be_false = !matches!(res.none, Possible::No);
+
+    let mut i = 0;
+    while i < results.len() {
+        if !matches!(results[i], Possible::No) {
+            if accepted[i] {
+                can_be_true = true;
+            } else {
+                can_be_false = true;
+            }
+        }
+        i += 1;
+    }
+
+    BoolPossibility::new(can_be_true, can_be_false)
+}
+
+/// `lhs == rhs`
+pub const fn eq(lhs: &FnArgFloat, rhs: &FnArgFloat) -> BoolPossibility {
+    compare(lhs, rhs, [false, true, false])
+}
+
+/// `lhs != rhs`, true when an argument is NaN
+pub const fn ne(lhs: &FnArgFloat, rhs: &FnArgFloat) -> BoolPossibility {
+    eq(lhs, rhs).negate()
+}
+
+/// `lhs < rhs`
+pub const fn lt(lhs: &FnArgFloat, rhs: &FnArgFloat) -> BoolPossibility {
+    compare(lhs, rhs, [true, false, false])
+}
+
+/// `lhs <= rhs`
+pub const fn le(lhs: &FnArgFloat, rhs: &FnArgFloat) -> BoolPossibility {
+    compare(lhs, rhs, [true, true, false])
+}
+
+/// `lhs > rhs`
+pub const fn gt(lhs: &FnArgFloat, rhs: &FnArgFloat) -> BoolPossibility {
+    compare(lhs, rhs, [false, false, true])
+}
+
+/// `lhs >= rhs`
+pub const fn ge(lhs: &FnArgFloat, rhs: &FnArgFloat) -> BoolPossibility {
+    compare(lhs, rhs, [false, true, true])
+}
+
+/// `lhs.total_cmp(rhs)`, which orders NaN and distinguishes `-0.0` from `+0.0`
+///
+/// ```
+/// use fn_num_types::core::ops::cmp::total_cmp;
+/// use fn_num_types::{FnArgFloat, Possible, FP};
+///
+/// let neg_zero = FnArgFloat::F64(FP {
+///     nan: Possible::No,
+///     pos_zero: Possible::Yes,
+///     neg_zero: Possible::Yes,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::No,
+///     negative: Possible::Yes,
+/// });
+/// let pos_zero = FnArgFloat::F64(FP {
+///     nan: Possible::No,
+///     pos_zero: Possible::Yes,
+///     neg_zero: Possible::Yes,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::Yes,
+///     negative: Possible::No,
+/// });
+///
+/// let ordering = total_cmp(&neg_zero, &pos_zero);
+/// assert_eq!(ordering.equal, Possible::No);
+/// assert_eq!(ordering.greater, Possible::No);
+/// ```
+///
+/// # Panics
+///
+/// If the arguments are not of the same type
+pub const fn total_cmp(lhs: &FnArgFloat, rhs: &FnArgFloat) -> OrderingPossibilities {
+    assert!(lhs.is_same_type(rhs), "Different types");
+
+    ordering(
+        total_classes(lhs.possibilities()),
+        total_classes(rhs.possibilities()),
+    )
+}
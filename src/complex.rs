@@ -0,0 +1,265 @@
+//! Complex numbers, like the `Complex` of `num-complex`
+//!
+//! The models follow the formulas of `num-complex`: the NaNs of a complex
+//! number often come from a finite one, like `mul` of infinite parts.
+
+use crate::core::ops;
+use crate::{FloatPossibilities, FloatValue, FnArgFloat, Possible, FP};
+use core::num::FpCategory;
+
+/// The possibilities of the real and the imaginary parts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComplexPossibilities {
+    pub re: FloatPossibilities,
+    pub im: FloatPossibilities,
+}
+
+/// The possibilities of a complex number of a given type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FnArgComplex {
+    /// `Complex<f32>`
+    C32(ComplexPossibilities),
+    /// `Complex<f64>`
+    C64(ComplexPossibilities),
+}
+
+impl FnArgComplex {
+    /// The complex number of the parts `re` and `im`
+    ///
+    /// # Panics
+    ///
+    /// If they are not both `f32` or both `f64`
+    pub const fn new(re: FnArgFloat, im: FnArgFloat) -> Self {
+        let c = ComplexPossibilities {
+            re: *re.possibilities(),
+            im: *im.possibilities(),
+        };
+
+        match (re, im) {
+            (FnArgFloat::F32(_), FnArgFloat::F32(_)) => FnArgComplex::C32(c),
+            (FnArgFloat::F64(_), FnArgFloat::F64(_)) => FnArgComplex::C64(c),
+            _ => panic!("Different types"),
+        }
+    }
+
+    pub const fn possibilities(&self) -> &ComplexPossibilities {
+        match self {
+            FnArgComplex::C32(c) | FnArgComplex::C64(c) => c,
+        }
+    }
+
+    const fn part(&self, fp: FP) -> FnArgFloat {
+        match self {
+            FnArgComplex::C32(_) => FnArgFloat::F32(fp),
+            FnArgComplex::C64(_) => FnArgFloat::F64(fp),
+        }
+    }
+
+    /// The real part
+    pub const fn re(&self) -> FnArgFloat {
+        self.part(self.possibilities().re)
+    }
+
+    /// The imaginary part
+    pub const fn im(&self) -> FnArgFloat {
+        self.part(self.possibilities().im)
+    }
+
+    /// Returns true if both parts of `value` are accepted
+    ///
+    /// ```
+    /// use fn_num_types::{ComplexPossibilities, FloatPossibilities, FnArgComplex};
+    /// use num_complex::Complex;
+    ///
+    /// let z = FnArgComplex::C64(ComplexPossibilities {
+    ///     re: FloatPossibilities::finite(),
+    ///     im: FloatPossibilities::any(),
+    /// });
+    ///
+    /// assert!(z.accept(Complex::new(1.0, f64::NAN)));
+    /// assert!(!z.accept(Complex::new(f64::INFINITY, 1.0)));
+    /// assert!(!z.accept(Complex::new(1.0f32, 1.0)));
+    /// ```
+    pub fn accept<T: FloatValue>(&self, value: num_complex::Complex<T>) -> bool {
+        self.re().accept(value.re) && self.im().accept(value.im)
+    }
+
+    /// The values of either
+    const fn union(&self, rhs: &Self) -> Self {
+        FnArgComplex::new(union(&self.re(), &rhs.re()), union(&self.im(), &rhs.im()))
+    }
+}
+
+const fn union(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    a.with_possibilities(a.possibilities().union(b.possibilities()))
+}
+
+/// `a - b`, like the operator
+const fn sub(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    ops::add(a, &ops::neg(b))
+}
+
+const fn satisfiable(x: &FnArgFloat) -> bool {
+    x.possibilities().is_satisfiable()
+}
+
+/// `a + b`
+///
+/// # Panics
+///
+/// If they are not of the same type
+pub const fn add(a: &FnArgComplex, b: &FnArgComplex) -> FnArgComplex {
+    FnArgComplex::new(ops::add(&a.re(), &b.re()), ops::add(&a.im(), &b.im()))
+}
+
+/// `a * b`, `(a.re * b.re - a.im * b.im) + i (a.re * b.im + a.im * b.re)`
+///
+/// ```
+/// use fn_num_types::core::ops::complex::mul;
+/// use fn_num_types::{ComplexPossibilities, FloatPossibilities, FnArgComplex, Possible};
+///
+/// // `(inf + 0i) * (1 + 1i)`: `0 * 1` and `inf * 1` are fine, `inf - inf` is not
+/// let z = FnArgComplex::C64(ComplexPossibilities {
+///     re: FloatPossibilities::from(f64::INFINITY),
+///     im: FloatPossibilities::from(0.0),
+/// });
+/// let w = FnArgComplex::C64(ComplexPossibilities {
+///     re: FloatPossibilities::from(1.0),
+///     im: FloatPossibilities::from(1.0),
+/// });
+///
+/// assert_eq!(mul(&z, &w).possibilities().re.nan, Possible::No);
+/// assert_eq!(mul(&z, &z).possibilities().im.nan, Possible::Yes);
+/// ```
+///
+/// # Panics
+///
+/// If they are not of the same type
+pub const fn mul(a: &FnArgComplex, b: &FnArgComplex) -> FnArgComplex {
+    let re = sub(&ops::mul(&a.re(), &b.re()), &ops::mul(&a.im(), &b.im()));
+    let im = ops::add(&ops::mul(&a.re(), &b.im()), &ops::mul(&a.im(), &b.re()));
+
+    FnArgComplex::new(re, im)
+}
+
+/// `z.norm()`, `re.hypot(im)`
+pub const fn norm(z: &FnArgComplex) -> FnArgFloat {
+    ops::hypot(&z.re(), &z.im())
+}
+
+/// `z.arg()`, `im.atan2(re)`
+pub const fn arg(z: &FnArgComplex) -> FnArgFloat {
+    ops::atan2(&z.im(), &z.re())
+}
+
+/// `z.sqrt()`, the principal square root
+///
+/// Like `num-complex`, the real and the imaginary axes are special cases,
+/// the others are computed in polar form.
+///
+/// ```
+/// use fn_num_types::core::ops::complex::sqrt;
+/// use fn_num_types::{ComplexPossibilities, FloatPossibilities, FnArgComplex, Possible};
+/// use num_complex::Complex;
+///
+/// // The roots of the finite reals are finite
+/// let z = FnArgComplex::C64(ComplexPossibilities {
+///     re: FloatPossibilities::negative_finite(),
+///     im: FloatPossibilities::from(0.0),
+/// });
+/// let root = sqrt(&z);
+/// let root = root.possibilities();
+///
+/// assert_eq!(root.re.infinite, Possible::No);
+/// assert_eq!(root.im.infinite, Possible::No);
+/// assert!(sqrt(&z).accept(Complex::new(-4.0f64, 0.0).sqrt()));
+/// ```
+///
+/// As `FloatPossibilities::from(0.0)` is also of the values like `1.0`, a part
+/// that is zero does not rule out the other branches. The real part may be
+/// negative: of `f32`, `theta / 2` may be past `pi / 2`.
+pub const fn sqrt(z: &FnArgComplex) -> FnArgComplex {
+    let (re, im) = (z.re(), z.im());
+    let zero = re.with_possibilities(FP::of_value(FpCategory::Zero, false, false, false));
+    let two = re.with_possibilities(FP::of_value(FpCategory::Normal, false, false, true));
+
+    let mut roots = [None, None, None, None];
+
+    let im_zero = im.assume_zero();
+    if satisfiable(&im_zero) {
+        // `sqrt(re) + i im`
+        let re_positive = re.assume_positive();
+        if satisfiable(&re_positive) {
+            roots[0] = Some(FnArgComplex::new(ops::sqrt(&re_positive), im_zero));
+        }
+
+        // `0 ± i sqrt(-re)`, of the sign of `im`
+        let re_negative = re.assume_negative();
+        if satisfiable(&re_negative) {
+            let root = ops::sqrt(&ops::neg(&re_negative));
+            let root = match (
+                im_zero.possibilities().pos_zero,
+                im_zero.possibilities().neg_zero,
+            ) {
+                (_, Possible::No) => root,
+                (Possible::No, _) => ops::neg(&root),
+                _ => union(&root, &ops::neg(&root)),
+            };
+            roots[1] = Some(FnArgComplex::new(zero, root));
+        }
+    }
+
+    let im_non_zero = im.assume_nonzero();
+    if !satisfiable(&im_non_zero) {
+        return union_all(z, &roots);
+    }
+
+    // `x ± i x`, of `x = sqrt(|im| / 2)`, of the sign of `im`
+    let re_zero = re.assume_zero();
+    if satisfiable(&re_zero) {
+        let x = ops::sqrt(&ops::div(&ops::abs(&im_non_zero), &two));
+        let root = match (
+            satisfiable(&im_non_zero.assume_positive()),
+            satisfiable(&im_non_zero.assume_negative()),
+        ) {
+            (true, true) => Some(union(&x, &ops::neg(&x))),
+            (true, false) => Some(x),
+            (false, true) => Some(ops::neg(&x)),
+            (false, false) => None,
+        };
+        if let Some(root) = root {
+            roots[2] = Some(FnArgComplex::new(x, root));
+        }
+    }
+
+    // `from_polar(sqrt(r), theta / 2)`
+    let re_non_zero = re.assume_nonzero();
+    if satisfiable(&re_non_zero) {
+        let r = ops::sqrt(&ops::hypot(&re_non_zero, &im_non_zero));
+        let theta = ops::div(&ops::atan2(&im_non_zero, &re_non_zero), &two);
+        roots[3] = Some(FnArgComplex::new(
+            ops::mul(&r, &ops::cos(&theta)),
+            ops::mul(&r, &ops::sin(&theta)),
+        ));
+    }
+
+    union_all(z, &roots)
+}
+
+/// The values of any of `roots`, none if there are none, of the type of `z`
+const fn union_all(z: &FnArgComplex, roots: &[Option<FnArgComplex>]) -> FnArgComplex {
+    let none = z.part(FP::splat(Possible::No));
+
+    let mut res = FnArgComplex::new(none, none);
+    let mut i = 0;
+    while i < roots.len() {
+        if let Some(root) = &roots[i] {
+            res = res.union(root);
+        }
+        i += 1;
+    }
+
+    res
+}
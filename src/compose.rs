@@ -0,0 +1,77 @@
+use crate::{Field, FnArgFloat, Possible};
+
+/// The analysis of `x`, the neutral element of `compose`
+pub const fn identity(lhs: &FnArgFloat) -> FnArgFloat {
+    *lhs
+}
+
+/// The analysis of `g(f(x))`: `f` then `g`
+///
+/// ```
+/// use fn_num_types::core::ops::{abs, compose, identity, sqrt};
+/// use fn_num_types::{FloatPossibilities, FnArgFloat};
+///
+/// let sqrt_abs = compose(abs, sqrt);
+/// let x = FnArgFloat::F64(FloatPossibilities::any());
+///
+/// assert_eq!(sqrt_abs(&x), sqrt(&abs(&x)));
+/// assert_eq!(compose(identity, sqrt_abs)(&x), sqrt(&abs(&x)));
+/// ```
+pub fn compose<F, G>(f: F, g: G) -> impl Fn(&FnArgFloat) -> FnArgFloat
+where
+    F: Fn(&FnArgFloat) -> FnArgFloat,
+    G: Fn(&FnArgFloat) -> FnArgFloat,
+{
+    move |lhs| g(&f(lhs))
+}
+
+/// The possibilities of `x` after any number of iterations of `x = op(x)`,
+/// from `initial`, for the analysis of a loop
+///
+/// The results of `op` are added until none is new. To terminate quickly,
+/// an outcome that keeps getting more possible is widened to `Yes`.
+///
+/// ```
+/// use fn_num_types::core::ops::{iterate_to_fixpoint, mul};
+/// use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+///
+/// // `x *= 3.0`, from `1.0`
+/// let three = FnArgFloat::F64(FloatPossibilities::from(3.0));
+/// let x = iterate_to_fixpoint(&FnArgFloat::F64(FloatPossibilities::from(1.0)), |x| {
+///     mul(x, &three)
+/// });
+///
+/// assert_eq!(x.possibilities().infinite, Possible::Yes);
+/// assert_eq!(x.possibilities().negative, Possible::No);
+/// ```
+///
+/// # Panics
+///
+/// If `op` changes the type
+pub fn iterate_to_fixpoint<F>(initial: &FnArgFloat, op: F) -> FnArgFloat
+where
+    F: Fn(&FnArgFloat) -> FnArgFloat,
+{
+    let mut x = *initial;
+
+    loop {
+        let next = op(&x);
+        assert!(next.is_same_type(&x), "Different types");
+
+        let fp = x.possibilities();
+        let joined = fp.union(next.possibilities());
+        if joined == *fp {
+            return x;
+        }
+
+        // Each field is widened at most once, so there are at most 10 iterations
+        let widened = Field::ALL.into_iter().fold(joined, |widened, field| {
+            if joined.get(field) > fp.get(field) {
+                widened.with(field, Possible::Yes)
+            } else {
+                widened
+            }
+        });
+        x = x.with_possibilities(widened);
+    }
+}
@@ -0,0 +1,471 @@
+use crate::{FnArgFloat, Possible, FP};
+
+/// Precision of the intermediate results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Precision {
+    /// Each result is rounded once, to the precision of its type
+    #[default]
+    Native,
+    /// Intermediate results are computed with the 80-bit format of the x87 FPU
+    /// and rounded a second time when stored (double rounding).
+    ///
+    /// The exact operations, like `neg`, are not affected: see `apply_exact`.
+    Extended,
+}
+
+/// Levels of the possibilities of the results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Verdicts {
+    /// The four levels of `Possible`
+    #[default]
+    Nuanced,
+    /// Only `Yes` and `No`, with `FloatPossibilities::to_strict`
+    Strict,
+    /// Only `Yes` and `No`, with `FloatPossibilities::to_relaxed`
+    Relaxed,
+}
+
+/// Rounding direction of the correctly rounded operations, like `fesetround`
+///
+/// The functions of `libm` are not guaranteed to follow it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Ties to even, an overflow gives an infinity
+    #[default]
+    ToNearest,
+    /// An overflow gives `MAX` or `MIN`
+    TowardZero,
+    /// A negative overflow gives `MIN`, and `x - x` is `+0.0`
+    Upward,
+    /// A positive overflow gives `MAX`, and `x - x` is `-0.0`
+    Downward,
+}
+
+/// The possibilities of the result of a model, with the possibility that it
+/// is an infinity from the overflow of finite values, and not from an
+/// infinite argument, and the special cases it handled
+#[cfg_attr(not(feature = "arith"), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Outcome {
+    pub(crate) fp: FP,
+    pub(crate) overflow: Possible,
+    pub(crate) cases: Cases,
+}
+
+#[cfg_attr(not(feature = "arith"), allow(dead_code))]
+impl Outcome {
+    /// `fp`, of a model that can't overflow
+    pub(crate) const fn without_overflow(fp: FP) -> Self {
+        Outcome {
+            fp,
+            overflow: Possible::No,
+            cases: Cases::NONE,
+        }
+    }
+}
+
+/// The most special cases a model handles, those of the models it applies included
+const MAX_CASES: usize = 16;
+
+/// The special cases handled by a model, like `opposite infinities` in `add`,
+/// by name and in the order they were first fired, with their possibility
+///
+/// The models return them instead of logging them, to stay `const`:
+/// `explain` and the tracing of `core::ops::apply` report them.
+#[cfg_attr(not(feature = "arith"), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Cases {
+    names: [&'static str; MAX_CASES],
+    possible: [Possible; MAX_CASES],
+    len: usize,
+}
+
+#[cfg_attr(not(feature = "arith"), allow(dead_code))]
+impl Cases {
+    pub(crate) const NONE: Cases = Cases {
+        names: [""; MAX_CASES],
+        possible: [Possible::No; MAX_CASES],
+        len: 0,
+    };
+
+    /// Adds the case `name` if it is possible, at the strongest of its possibilities
+    pub(crate) const fn fire(self, name: &'static str, possible: Possible) -> Self {
+        if matches!(possible, Possible::No) {
+            return self;
+        }
+
+        let mut cases = self;
+        let mut i = 0;
+        while i < cases.len {
+            if str_eq(cases.names[i], name) {
+                cases.possible[i] = Possible::any(cases.possible[i], possible);
+                return cases;
+            }
+            i += 1;
+        }
+
+        assert!(cases.len < MAX_CASES, "Too many cases");
+        cases.names[cases.len] = name;
+        cases.possible[cases.len] = possible;
+        cases.len += 1;
+        cases
+    }
+
+    /// Adds the cases of `other`, fired after those
+    pub(crate) const fn extend(self, other: Cases) -> Self {
+        let mut cases = self;
+        let mut i = 0;
+        while i < other.len {
+            cases = cases.fire(other.names[i], other.possible[i]);
+            i += 1;
+        }
+        cases
+    }
+
+    /// The names and the possibilities, in order
+    #[cfg(feature = "full")]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&'static str, Possible)> + '_ {
+        self.names[..self.len]
+            .iter()
+            .copied()
+            .zip(self.possible[..self.len].iter().copied())
+    }
+}
+
+/// `a == b`, in a `const fn`
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Adds the results of a possible `overflow` to `fp`: an infinity,
+/// or the largest finite value of the same sign with a directed rounding,
+/// telling the former apart in the outcome
+#[cfg_attr(not(feature = "arith"), allow(dead_code))]
+#[inline]
+pub(crate) const fn round_overflow(fp: FP, overflow: Possible, mode: RoundingMode) -> Outcome {
+    let (infinite, large) = match mode {
+        RoundingMode::ToNearest => (overflow, Possible::No),
+        RoundingMode::TowardZero => (Possible::No, overflow),
+        // We don't know the sign of the overflow
+        RoundingMode::Upward | RoundingMode::Downward => (overflow, overflow),
+    };
+
+    Outcome {
+        fp: FP {
+            infinite: any!(fp.infinite, infinite),
+            large: any!(fp.large, large),
+            ..fp
+        },
+        overflow: infinite,
+        cases: Cases::NONE,
+    }
+}
+
+/// Adds the results of a possible `underflow` to `fp`: a zero of the sign of
+/// the result, or the smallest subnormal with a directed rounding
+///
+/// Gradual underflow only loses precision, but the results below half of the
+/// smallest subnormal vanish: a rounding artifact, so `underflow` is `ShouldNot`
+/// at most, like an overflow.
+#[cfg_attr(not(feature = "arith"), allow(dead_code))]
+#[inline]
+pub(crate) const fn round_underflow(fp: FP, underflow: Possible, mode: RoundingMode) -> FP {
+    let subnormal = match mode {
+        RoundingMode::ToNearest | RoundingMode::TowardZero => Possible::No,
+        // Away from zero on one side, we don't know which
+        RoundingMode::Upward | RoundingMode::Downward => underflow,
+    };
+
+    FP {
+        pos_zero: any!(fp.pos_zero, all!(fp.positive, underflow)),
+        neg_zero: any!(fp.neg_zero, all!(fp.negative, underflow)),
+        subnormal: any!(fp.subnormal, subnormal),
+        ..fp
+    }
+}
+
+/// Floating point semantics of the target running the analysed code.
+///
+/// The default configuration describes an IEEE 754 target.
+///
+/// ```
+/// use fn_num_types::core::ops;
+/// use fn_num_types::{AnalysisConfig, FnArgFloat, Possible, Precision, FP};
+///
+/// let x87 = AnalysisConfig {
+///     precision: Precision::Extended,
+///     ..Default::default()
+/// };
+///
+/// let x = FnArgFloat::F64(FP {
+///     nan: Possible::No,
+///     pos_zero: Possible::No,
+///     neg_zero: Possible::No,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::Yes,
+///     negative: Possible::No,
+/// });
+///
+/// // Any rounded operation whose result may be `x`
+/// match x87.apply(|_, x| *x, &x) {
+///     FnArgFloat::F64(fp) => assert_eq!(fp.zero(), Possible::ShouldNot),
+///     _ => unreachable!(),
+/// }
+///
+/// // Never rounded
+/// assert_eq!(x87.apply_exact(|_, x| ops::neg(x), &x), ops::neg(&x));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnalysisConfig {
+    pub precision: Precision,
+    /// Subnormal results are replaced by a zero of the same sign (FTZ)
+    pub flush_to_zero: bool,
+    /// Subnormal arguments are read as a zero of the same sign (DAZ)
+    pub denormals_are_zero: bool,
+    /// NaN and infinities follow the GLSL/HLSL rules: NaN is not guaranteed
+    /// to be propagated and may be read or produced as any value,
+    /// and the handling of infinities is implementation-defined.
+    pub relaxed_specials: bool,
+    /// The compiler assumes that no argument nor result is NaN or infinite
+    /// (`-ffast-math`, `fadd_fast`...) and may reassociate operations.
+    ///
+    /// Those possibilities are removed, use `apply_checked` to know whether
+    /// the assumption may be violated.
+    pub fast_math: bool,
+    /// Rounding direction of the operations, which changes the result
+    /// of an overflow and the sign of an exact zero sum
+    ///
+    /// Given to the operation by `apply`: the `_with` variants of the
+    /// operations follow it, the others round to nearest.
+    pub rounding: RoundingMode,
+    /// Collapses `Should` and `ShouldNot`, in the direction of the consumer
+    pub verdicts: Verdicts,
+}
+
+impl AnalysisConfig {
+    /// GLSL/HLSL-like semantics of GPU shaders
+    pub fn gpu() -> Self {
+        AnalysisConfig {
+            precision: Precision::Native,
+            flush_to_zero: true,
+            denormals_are_zero: true,
+            relaxed_specials: true,
+            fast_math: false,
+            rounding: RoundingMode::ToNearest,
+            verdicts: Verdicts::Nuanced,
+        }
+    }
+
+    /// Applies the unary operation `op` with those semantics,
+    /// giving it the rounding mode, like `ops::recip_with`
+    pub fn apply<F>(&self, op: F, lhs: &FnArgFloat) -> FnArgFloat
+    where
+        F: FnOnce(RoundingMode, &FnArgFloat) -> FnArgFloat,
+    {
+        self.apply_checked(op, lhs).0
+    }
+
+    /// Applies the binary operation `op` with those semantics,
+    /// giving it the rounding mode, like `ops::add_with`
+    pub fn apply2<F>(&self, op: F, lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat
+    where
+        F: FnOnce(RoundingMode, &FnArgFloat, &FnArgFloat) -> FnArgFloat,
+    {
+        self.apply2_checked(op, lhs, rhs).0
+    }
+
+    /// Like `apply`, for an operation whose result is exact, never rounded,
+    /// like `neg`, `abs`, `trunc` or `copysign`: not affected by double rounding
+    pub fn apply_exact<F>(&self, op: F, lhs: &FnArgFloat) -> FnArgFloat
+    where
+        F: FnOnce(RoundingMode, &FnArgFloat) -> FnArgFloat,
+    {
+        self.apply_impl(op, lhs, false).0
+    }
+
+    /// Like `apply2`, for an operation whose result is exact,
+    /// like `min` or `copysign`
+    pub fn apply2_exact<F>(&self, op: F, lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat
+    where
+        F: FnOnce(RoundingMode, &FnArgFloat, &FnArgFloat) -> FnArgFloat,
+    {
+        self.apply2_impl(op, lhs, rhs, false).0
+    }
+
+    /// Like `apply`, also returning the possibility that the
+    /// `fast_math` assumption is violated, which is undefined behavior
+    pub fn apply_checked<F>(&self, op: F, lhs: &FnArgFloat) -> (FnArgFloat, Possible)
+    where
+        F: FnOnce(RoundingMode, &FnArgFloat) -> FnArgFloat,
+    {
+        self.apply_impl(op, lhs, true)
+    }
+
+    /// `apply_checked`, the result rounded by `op` if `rounded`
+    fn apply_impl<F>(&self, op: F, lhs: &FnArgFloat, rounded: bool) -> (FnArgFloat, Possible)
+    where
+        F: FnOnce(RoundingMode, &FnArgFloat) -> FnArgFloat,
+    {
+        let lhs = self.adjust_arg(lhs);
+        let res = self.adjust(&op(self.rounding, &self.assume(&lhs)), rounded);
+
+        (
+            self.assume(&res),
+            self.violation(&lhs) | self.violation(&res),
+        )
+    }
+
+    /// Like `apply2`, also returning the possibility that the
+    /// `fast_math` assumption is violated, which is undefined behavior
+    pub fn apply2_checked<F>(
+        &self,
+        op: F,
+        lhs: &FnArgFloat,
+        rhs: &FnArgFloat,
+    ) -> (FnArgFloat, Possible)
+    where
+        F: FnOnce(RoundingMode, &FnArgFloat, &FnArgFloat) -> FnArgFloat,
+    {
+        self.apply2_impl(op, lhs, rhs, true)
+    }
+
+    /// `apply2_checked`, the result rounded by `op` if `rounded`
+    fn apply2_impl<F>(
+        &self,
+        op: F,
+        lhs: &FnArgFloat,
+        rhs: &FnArgFloat,
+        rounded: bool,
+    ) -> (FnArgFloat, Possible)
+    where
+        F: FnOnce(RoundingMode, &FnArgFloat, &FnArgFloat) -> FnArgFloat,
+    {
+        let lhs = self.adjust_arg(lhs);
+        let rhs = self.adjust_arg(rhs);
+        let res = self.adjust(
+            &op(self.rounding, &self.assume(&lhs), &self.assume(&rhs)),
+            rounded,
+        );
+
+        (
+            self.assume(&res),
+            self.violation(&lhs) | self.violation(&rhs) | self.violation(&res),
+        )
+    }
+
+    /// Removes the possibilities excluded by the `fast_math` assumption
+    fn assume(&self, arg: &FnArgFloat) -> FnArgFloat {
+        return_fp!(arg, |fp| {
+            let mut fp = *fp;
+
+            if self.fast_math {
+                rule!(fp.nan | fp.infinite, "fast math");
+                fp.nan = Possible::No;
+                fp.infinite = Possible::No;
+            }
+
+            fp
+        })
+    }
+
+    fn violation(&self, arg: &FnArgFloat) -> Possible {
+        if self.fast_math {
+            let fp = arg.possibilities();
+            fp.nan | fp.infinite
+        } else {
+            Possible::No
+        }
+    }
+
+    fn adjust_arg(&self, arg: &FnArgFloat) -> FnArgFloat {
+        return_fp!(arg, |fp| {
+            let mut fp = *fp;
+
+            if self.denormals_are_zero {
+                rule!(fp.subnormal & finite_non_zero(&fp), "denormals are zero");
+                flush(&mut fp);
+            }
+
+            if self.relaxed_specials {
+                relax(&mut fp);
+            }
+
+            fp
+        })
+    }
+
+    fn adjust(&self, res: &FnArgFloat, rounded: bool) -> FnArgFloat {
+        return_fp!(res, |fp| {
+            let mut fp = *fp;
+
+            if rounded && self.precision == Precision::Extended {
+                // Double rounding may push a finite result over the overflow
+                // or under the underflow threshold, and a spilled intermediate
+                // may overflow when it didn't in the extended format.
+                let finite = finite_non_zero(&fp);
+                rule!(finite, "double rounding");
+                fp.pos_zero = fp.pos_zero | finite;
+                fp.neg_zero = fp.neg_zero | finite;
+                fp.subnormal = fp.subnormal | finite;
+                fp.infinite = fp.infinite | finite;
+            }
+
+            if self.flush_to_zero {
+                rule!(fp.subnormal & finite_non_zero(&fp), "flush to zero");
+                flush(&mut fp);
+            }
+
+            if self.relaxed_specials {
+                relax(&mut fp);
+            }
+
+            match self.verdicts {
+                Verdicts::Nuanced => fp,
+                Verdicts::Strict => fp.to_strict(),
+                Verdicts::Relaxed => fp.to_relaxed(),
+            }
+        })
+    }
+}
+
+/// Any positive or negative value may be finite and close enough to zero to be
+/// affected by the target semantics, which is a rounding artifact.
+fn finite_non_zero(fp: &FP) -> Possible {
+    (fp.positive | fp.negative) & Possible::ShouldNot
+}
+
+/// The subnormals are replaced by a zero of the same sign
+fn flush(fp: &mut FP) {
+    fp.pos_zero = fp.pos_zero | (fp.subnormal & fp.positive);
+    fp.neg_zero = fp.neg_zero | (fp.subnormal & fp.negative);
+    fp.subnormal = Possible::No;
+}
+
+/// An infinity may be handled as NaN, and NaN as any value
+fn relax(fp: &mut FP) {
+    rule!(fp.nan | fp.infinite, "relaxed specials");
+    fp.nan = fp.nan | fp.infinite;
+    fp.pos_zero = fp.pos_zero | fp.nan;
+    fp.neg_zero = fp.neg_zero | fp.nan;
+    fp.subnormal = fp.subnormal | fp.nan;
+    fp.fractional = fp.fractional | fp.nan;
+    fp.large = fp.large | fp.nan;
+    fp.infinite = fp.infinite | fp.nan;
+    fp.positive = fp.positive | fp.nan;
+    fp.negative = fp.negative | fp.nan;
+}
@@ -0,0 +1,68 @@
+use crate::{Field, FloatPossibilities, Possible};
+
+impl Field {
+    /// The name of the field of `FloatPossibilities`, like `neg_zero`
+    pub const fn name(self) -> &'static str {
+        match self {
+            Field::Nan => "nan",
+            Field::PosZero => "pos_zero",
+            Field::NegZero => "neg_zero",
+            Field::Subnormal => "subnormal",
+            Field::Fractional => "fractional",
+            Field::Large => "large",
+            Field::Infinite => "infinite",
+            Field::Positive => "positive",
+            Field::Negative => "negative",
+        }
+    }
+}
+
+impl FloatPossibilities {
+    /// The first field of the value that is `No`, `None` if the value is accepted
+    ///
+    /// ```
+    /// use fn_num_types::{Field, FloatPossibilities};
+    ///
+    /// let positive = FloatPossibilities::positive_finite();
+    ///
+    /// assert_eq!(positive.violation(1.5), None);
+    /// assert_eq!(positive.violation(-1.5), Some(Field::Negative));
+    /// assert_eq!(positive.violation(f64::NAN), Some(Field::Nan));
+    /// ```
+    pub fn violation(&self, value: impl Into<FloatPossibilities>) -> Option<Field> {
+        let value = value.into();
+        Field::ALL
+            .into_iter()
+            .find(|&field| value.get(field) == Possible::Yes && self.get(field) == Possible::No)
+    }
+}
+
+/// Wraps `f` so that, in debug builds, every result is checked against `contract`
+///
+/// The functions of several arguments take a tuple.
+///
+/// ```should_panic
+/// use fn_num_types::{checked, FloatPossibilities};
+///
+/// let sqrt = checked(FloatPossibilities::positive(), |x: f64| x.sqrt());
+/// assert_eq!(sqrt(4.0), 2.0);
+///
+/// // Panics with "Result NaN not accepted: `nan` is `No`"
+/// sqrt(-1.0);
+/// ```
+pub fn checked<A, R, F>(contract: FloatPossibilities, f: F) -> impl Fn(A) -> R
+where
+    R: Copy + core::fmt::Debug + Into<FloatPossibilities>,
+    F: Fn(A) -> R,
+{
+    move |args| {
+        let res = f(args);
+        debug_assert!(
+            contract.violation(res).is_none(),
+            "Result {:?} not accepted: `{}` is `No`",
+            res,
+            contract.violation(res).map_or("", Field::name),
+        );
+        res
+    }
+}
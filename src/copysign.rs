@@ -0,0 +1,38 @@
+use crate::config::{Cases, Outcome};
+use crate::{FnArgFloat, FP};
+
+/// Magnitude of `a` with the sign of `b`
+pub const fn copysign(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, copysign_fp)
+}
+
+/// `core::ops::copysign`
+pub const fn copysign_fp(fp1: &FP, fp2: &FP) -> FP {
+    copysign_outcome(fp1, fp2).fp
+}
+
+/// `core::ops::copysign`, with the special cases it handled
+pub(crate) const fn copysign_outcome(fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan1 = any!(fp1.positive, fp1.negative);
+
+    // The sign bit of a NaN may be set or not
+    cases = cases.fire("sign of NaN", all!(fp2.nan, non_nan1));
+
+    let res = FP {
+        nan: fp1.nan,
+        pos_zero: fp1.zero(),
+        neg_zero: fp1.zero(),
+        subnormal: fp1.subnormal,
+        fractional: fp1.fractional,
+        large: fp1.large,
+        infinite: fp1.infinite,
+        positive: all!(any!(fp2.positive, fp2.nan), non_nan1),
+        negative: all!(any!(fp2.negative, fp2.nan), non_nan1),
+    };
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(res)
+    }
+}
@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Field, FloatPossibilities};
+
+/// Change of each field between two `FloatPossibilities`, in levels of `Possible`.
+///
+/// A positive change means that the field widened, e.g. from `No` to `ShouldNot` is `1`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FieldDiffs {
+    pub nan: i8,
+    pub pos_zero: i8,
+    pub neg_zero: i8,
+    pub subnormal: i8,
+    pub fractional: i8,
+    pub large: i8,
+    pub infinite: i8,
+    pub positive: i8,
+    pub negative: i8,
+}
+
+impl FieldDiffs {
+    /// Returns the change of `field`
+    pub fn get(&self, field: Field) -> i8 {
+        match field {
+            Field::Nan => self.nan,
+            Field::PosZero => self.pos_zero,
+            Field::NegZero => self.neg_zero,
+            Field::Subnormal => self.subnormal,
+            Field::Fractional => self.fractional,
+            Field::Large => self.large,
+            Field::Infinite => self.infinite,
+            Field::Positive => self.positive,
+            Field::Negative => self.negative,
+        }
+    }
+
+    /// Returns the fields that widened
+    pub fn widened(&self) -> Vec<Field> {
+        Field::ALL
+            .into_iter()
+            .filter(|field| self.get(*field) > 0)
+            .collect()
+    }
+
+    /// Returns the fields that narrowed
+    pub fn narrowed(&self) -> Vec<Field> {
+        Field::ALL
+            .into_iter()
+            .filter(|field| self.get(*field) < 0)
+            .collect()
+    }
+
+    /// Returns true if no field changed
+    pub fn is_empty(&self) -> bool {
+        *self == FieldDiffs::default()
+    }
+}
+
+/// The changed fields only, like `infinite +1, negative -3`, or `unchanged`
+///
+/// ```
+/// use fn_num_types::{FloatPossibilities, Possible};
+///
+/// let before = FloatPossibilities::finite();
+/// let after = before
+///     .with_infinite(Possible::ShouldNot)
+///     .with_negative(Possible::No);
+///
+/// assert_eq!(before.diff(&after).to_string(), "infinite +1, negative -3");
+/// assert_eq!(before.diff(&before).to_string(), "unchanged");
+/// ```
+impl fmt::Display for FieldDiffs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("unchanged");
+        }
+
+        let changed = Field::ALL.into_iter().filter(|field| self.get(*field) != 0);
+        for (i, field) in changed.enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{} {:+}", field.name(), self.get(field))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FloatPossibilities {
+    /// Returns the changes from `self` to `other`
+    ///
+    /// ```
+    /// use fn_num_types::{Field, FloatPossibilities, Possible};
+    ///
+    /// let before = FloatPossibilities {
+    ///     nan: Possible::No,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::No,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::Yes,
+    /// };
+    ///
+    /// let after = FloatPossibilities {
+    ///     infinite: Possible::ShouldNot,
+    ///     negative: Possible::No,
+    ///     ..before
+    /// };
+    ///
+    /// let diffs = before.diff(&after);
+    ///
+    /// assert_eq!(diffs.widened(), [Field::Infinite]);
+    /// assert_eq!(diffs.narrowed(), [Field::Negative]);
+    /// assert_eq!(diffs.negative, -3);
+    /// ```
+    pub fn diff(&self, other: &Self) -> FieldDiffs {
+        let change = |field| other.get(field) as i8 - self.get(field) as i8;
+
+        FieldDiffs {
+            nan: change(Field::Nan),
+            pos_zero: change(Field::PosZero),
+            neg_zero: change(Field::NegZero),
+            subnormal: change(Field::Subnormal),
+            fractional: change(Field::Fractional),
+            large: change(Field::Large),
+            infinite: change(Field::Infinite),
+            positive: change(Field::Positive),
+            negative: change(Field::Negative),
+        }
+    }
+}
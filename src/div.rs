@@ -0,0 +1,75 @@
+use crate::config::{round_overflow, round_underflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+pub const fn div(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, div_fp)
+}
+
+/// `div`, rounded in the direction `mode`
+pub const fn div_with(mode: RoundingMode, a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, |fp1, fp2| div_fp_with(mode, fp1, fp2))
+}
+
+/// `core::ops::div`
+#[inline]
+pub const fn div_fp(fp1: &FP, fp2: &FP) -> FP {
+    div_fp_with(RoundingMode::ToNearest, fp1, fp2)
+}
+
+/// `core::ops::div`, rounded in the direction `mode`
+#[inline]
+pub const fn div_fp_with(mode: RoundingMode, fp1: &FP, fp2: &FP) -> FP {
+    div_outcome(mode, fp1, fp2).fp
+}
+
+/// `core::ops::div`, telling the infinities from an overflow apart
+pub(crate) const fn div_outcome(mode: RoundingMode, fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_zero1 = any!(fp1.positive, fp1.negative);
+    let non_zero2 = any!(fp2.positive, fp2.negative);
+
+    // Two finite values may underflow, and overflow if the dividend is large
+    // or the divisor subnormal
+    let rounding = all!(non_zero1, non_zero2, Possible::ShouldNot);
+    cases = cases.fire("underflow", rounding);
+    let overflow = all!(
+        non_zero1,
+        non_zero2,
+        any!(fp1.large, fp2.subnormal),
+        Possible::ShouldNot
+    );
+    cases = cases.fire("overflow", overflow);
+
+    // 0 / 0 and inf / inf
+    let zero_zero = all!(fp1.zero(), fp2.zero());
+    cases = cases.fire("zero divided by zero", zero_zero);
+    let inf_inf = all!(fp1.infinite, fp2.infinite);
+    cases = cases.fire("infinity divided by infinity", inf_inf);
+
+    let by_zero = all!(fp2.zero(), non_zero1);
+    cases = cases.fire("division by zero", by_zero);
+
+    let res = FP {
+        nan: any!(fp1.nan, fp2.nan, zero_zero, inf_inf),
+        pos_zero: any!(all!(fp1.zero(), non_zero2), all!(fp2.infinite, non_zero1)),
+        neg_zero: any!(all!(fp1.zero(), non_zero2), all!(fp2.infinite, non_zero1)),
+        // Like `MIN_POSITIVE / 2.0`
+        subnormal: all!(non_zero1, non_zero2),
+        fractional: all!(non_zero1, non_zero2),
+        large: all!(non_zero1, non_zero2),
+        infinite: any!(all!(fp1.infinite, non_zero2), by_zero),
+        positive: any!(
+            all!(fp1.positive, fp2.positive),
+            all!(fp1.negative, fp2.negative)
+        ),
+        negative: any!(
+            all!(fp1.positive, fp2.negative),
+            all!(fp1.negative, fp2.positive)
+        ),
+    };
+
+    Outcome {
+        cases,
+        ..round_overflow(round_underflow(res, rounding, mode), overflow, mode)
+    }
+}
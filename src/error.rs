@@ -0,0 +1,78 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::{FnArgFloat, ParseError};
+
+/// Errors of the fallible APIs
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The arguments are not all `f32` or all `f64`
+    DifferentTypes,
+    /// At least one argument is required
+    NoArguments,
+    /// No operation has this name
+    UnknownOp(String),
+    /// An operation already has this name
+    DuplicateOp(String),
+    /// The operation does not take this number of arguments
+    Arity { op: String, found: usize },
+    /// The textual representation is invalid
+    Parse(ParseError),
+    /// The function does not take this number of arguments
+    ArgumentCount { expected: usize, found: usize },
+    /// The argument at `index` has possibilities not accepted by the parameter
+    NotAccepted { index: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DifferentTypes => f.write_str("the arguments must be of the same type"),
+            Error::NoArguments => f.write_str("at least one argument is required"),
+            Error::UnknownOp(op) => write!(f, "unknown operation `{op}`"),
+            Error::DuplicateOp(op) => write!(f, "an operation is already named `{op}`"),
+            Error::Arity { op, found } => {
+                write!(f, "`{op}` does not take {found} argument(s)")
+            }
+            Error::Parse(err) => err.fmt(f),
+            Error::ArgumentCount { expected, found } => {
+                write!(f, "expected {expected} argument(s), found {found}")
+            }
+            Error::NotAccepted { index } => {
+                write!(f, "the argument {index} is not accepted by the parameter")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl FnArgFloat {
+    /// Checks that there is at least one argument and that they are all of the same type
+    pub fn check_same_type(args: &[FnArgFloat]) -> Result<(), Error> {
+        let first = args.first().ok_or(Error::NoArguments)?;
+
+        let same = args.iter().all(|arg| first.is_same_type(arg));
+
+        if same {
+            Ok(())
+        } else {
+            Err(Error::DifferentTypes)
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::config::{round_overflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+/// Euclidean division, like `f64::div_euclid`
+///
+/// The quotient is rounded toward zero, then moved away from it when the
+/// remainder is negative, keeping the sign of the division.
+pub const fn div_euclid(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, div_euclid_fp)
+}
+
+/// `div_euclid`, rounded in the direction `mode`
+pub const fn div_euclid_with(mode: RoundingMode, a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, |fp1, fp2| div_euclid_fp_with(mode, fp1, fp2))
+}
+
+/// `core::ops::div_euclid`
+#[inline]
+pub const fn div_euclid_fp(fp1: &FP, fp2: &FP) -> FP {
+    div_euclid_fp_with(RoundingMode::ToNearest, fp1, fp2)
+}
+
+/// `core::ops::div_euclid`, rounded in the direction `mode`
+#[inline]
+pub const fn div_euclid_fp_with(mode: RoundingMode, fp1: &FP, fp2: &FP) -> FP {
+    div_euclid_outcome(mode, fp1, fp2).fp
+}
+
+/// `core::ops::div_euclid`, telling the infinities from an overflow apart
+pub(crate) const fn div_euclid_outcome(mode: RoundingMode, fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan1 = any!(fp1.positive, fp1.negative);
+    let non_nan2 = any!(fp2.positive, fp2.negative);
+
+    // Two finite values may overflow if the dividend is large or the divisor subnormal
+    let overflow = all!(
+        non_nan1,
+        non_nan2,
+        any!(fp1.large, fp2.subnormal),
+        Possible::ShouldNot
+    );
+    cases = cases.fire("overflow", overflow);
+
+    // 0 / 0 and inf / inf
+    let zero_zero = all!(fp1.zero(), fp2.zero());
+    cases = cases.fire("zero divided by zero", zero_zero);
+    let inf_inf = all!(fp1.infinite, fp2.infinite);
+    cases = cases.fire("infinity divided by infinity", inf_inf);
+
+    let by_zero = all!(fp2.zero(), non_nan1);
+    cases = cases.fire("division by zero", by_zero);
+
+    // The quotient is truncated to zero when |a| < |b|
+    let truncated = all!(non_nan1, non_nan2);
+    cases = cases.fire("truncated to zero", truncated);
+
+    let res = FP {
+        nan: any!(fp1.nan, fp2.nan, zero_zero, inf_inf),
+        pos_zero: truncated,
+        neg_zero: truncated,
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        large: all!(non_nan1, non_nan2),
+        infinite: any!(all!(fp1.infinite, non_nan2), by_zero),
+        positive: any!(
+            all!(fp1.positive, fp2.positive),
+            all!(fp1.negative, fp2.negative)
+        ),
+        negative: any!(
+            all!(fp1.positive, fp2.negative),
+            all!(fp1.negative, fp2.positive)
+        ),
+    };
+
+    Outcome {
+        cases,
+        ..round_overflow(res, overflow, mode)
+    }
+}
+
+/// Euclidean remainder, like `f64::rem_euclid`
+///
+/// The remainder is in `[0, |b|)`, except a negative zero
+/// when the dividend is a negative multiple of the divisor.
+pub const fn rem_euclid(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, rem_euclid_fp)
+}
+
+/// `core::ops::rem_euclid`
+pub const fn rem_euclid_fp(fp1: &FP, fp2: &FP) -> FP {
+    rem_euclid_outcome(fp1, fp2).fp
+}
+
+/// `core::ops::rem_euclid`, with the special cases it handled
+pub(crate) const fn rem_euclid_outcome(fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan1 = any!(fp1.positive, fp1.negative);
+    let non_nan2 = any!(fp2.positive, fp2.negative);
+
+    // x % 0 and inf % y
+    cases = cases.fire("remainder by zero", fp2.zero());
+    cases = cases.fire("infinite dividend", fp1.infinite);
+
+    // The remainder is exact, and zero when the dividend is a multiple of the divisor
+    let multiple = all!(non_nan1, non_nan2);
+    cases = cases.fire("multiple of the divisor", multiple);
+
+    // A negative remainder is shifted by |b|, infinite if b is
+    let shifted_to_inf = all!(fp1.negative, fp2.infinite);
+    cases = cases.fire("negative remainder shifted by infinity", shifted_to_inf);
+
+    let res = FP {
+        nan: any!(fp1.nan, fp2.nan, fp2.zero(), fp1.infinite),
+        pos_zero: multiple,
+        neg_zero: multiple,
+        subnormal: multiple,
+        fractional: any!(fp1.fractional, fp2.fractional),
+        // Below |b|, and below |a| unless shifted
+        large: all!(any!(fp2.large, fp2.infinite), any!(fp1.large, fp1.negative)),
+        infinite: shifted_to_inf,
+        positive: multiple,
+        // Only the negative zero
+        negative: all!(fp1.negative, non_nan2),
+    };
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(res)
+    }
+}
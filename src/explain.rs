@@ -0,0 +1,232 @@
+//! Why a field of a result is possible.
+//!
+//! The models name the special cases they handle, like `opposite infinities`
+//! in `add`, and return them with their result. `explain` collects them, and finds the fields of the arguments
+//! the possible fields of the result come from, for the diagnostics of linters.
+//!
+//! ```
+//! use fn_num_types::explain::explain;
+//! use fn_num_types::{Field, FloatPossibilities, FnArgFloat, Possible};
+//!
+//! let x = FnArgFloat::F64(FloatPossibilities::non_nan());
+//! let explanation = explain("add", &[x, x]).unwrap();
+//!
+//! let nan = explanation.field(Field::Nan).unwrap();
+//! assert_eq!(nan.possible, Possible::Yes);
+//! assert_eq!(nan.rules[0].name, "opposite infinities");
+//! assert!(nan.inputs.contains(&(0, Field::Infinite)));
+//! assert_eq!(
+//!     nan.to_string(),
+//!     "nan is Yes: opposite infinities, from infinite of arguments 0 and 1"
+//! );
+//!
+//! // Not possible, nothing to explain
+//! assert!(explain("abs", &[x]).unwrap().field(Field::Negative).is_none());
+//! ```
+
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::core::ops;
+use crate::registry;
+use crate::{Error, Field, FnArgFloat, Possible};
+
+/// A special case handled by a model, fired when it is possible
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// The description of the case, like `opposite infinities`
+    pub name: &'static str,
+    /// Its possibility for the arguments
+    pub possible: Possible,
+}
+
+impl Rule {
+    /// The fields of the result the rule may make possible
+    pub fn fields(&self) -> &'static [Field] {
+        const ZEROS: &[Field] = &[Field::PosZero, Field::NegZero];
+        const SIGNS: &[Field] = &[Field::Positive, Field::Negative];
+
+        match self.name {
+            "zero times infinity"
+            | "zero divided by zero"
+            | "infinity divided by infinity"
+            | "opposite infinities"
+            | "remainder by zero"
+            | "infinite dividend"
+            | "negative argument"
+            | "negative base"
+            | "infinite logarithms"
+            | "log of one in base one" => &[Field::Nan],
+            "division by zero"
+            | "base one"
+            | "infinity over NaN"
+            | "negative remainder shifted by infinity" => &[Field::Infinite],
+            // `|x| > 1` or not
+            "infinite exponent" => &[Field::PosZero, Field::Infinite],
+            "negative pole" => &[Field::Nan, Field::Infinite],
+            "underflow" => &[Field::PosZero, Field::NegZero, Field::Subnormal],
+            "overflow" | "positive overflow" | "negative overflow" => {
+                &[Field::Infinite, Field::Large]
+            }
+            "overflow or underflow" => &[
+                Field::PosZero,
+                Field::NegZero,
+                Field::Subnormal,
+                Field::Large,
+                Field::Infinite,
+            ],
+            "cancellation"
+            | "multiple of the divisor"
+            | "truncated to zero"
+            | "positive x axis" => ZEROS,
+            "positive sum" | "negative sum" => &[Field::Large],
+            "power of zero" => &[Field::Positive],
+            "sign of NaN" => SIGNS,
+            // Not fired by the models
+            _ => &[],
+        }
+    }
+}
+
+/// Why a field of the result is possible
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldExplanation {
+    pub field: Field,
+    /// Its possibility in the result, never `No`
+    pub possible: Possible,
+    /// The rules fired that may make it possible, at their strongest
+    pub rules: Vec<Rule>,
+    /// The indexes and fields of the arguments without which it would be weaker
+    pub inputs: Vec<(usize, Field)>,
+}
+
+/// Like `nan is Yes: opposite infinities, from infinite of argument 0`
+impl fmt::Display for FieldExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is {:?}", self.field.name(), self.possible)?;
+
+        let names: Vec<&str> = self.rules.iter().map(|rule| rule.name).collect();
+        if !names.is_empty() {
+            write!(f, ": {}", names.join(", "))?;
+        }
+
+        let mut fields: Vec<&str> = Vec::new();
+        let mut args: Vec<String> = Vec::new();
+        for (arg, field) in &self.inputs {
+            if !fields.contains(&field.name()) {
+                fields.push(field.name());
+            }
+            let arg = arg.to_string();
+            if !args.contains(&arg) {
+                args.push(arg);
+            }
+        }
+        if !fields.is_empty() {
+            let plural = if args.len() > 1 { "s" } else { "" };
+            write!(
+                f,
+                ", from {} of argument{plural} {}",
+                join_and(&fields),
+                join_and(&args)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// `a, b and c`
+fn join_and<T: AsRef<str>>(items: &[T]) -> String {
+    let items: Vec<&str> = items.iter().map(AsRef::as_ref).collect();
+
+    match items.split_last() {
+        Some((last, [])) => (*last).to_owned(),
+        Some((last, rest)) => alloc::format!("{} and {last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
+/// The result of an operation, with why its fields are possible
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub result: FnArgFloat,
+    /// All the rules fired, in order
+    pub rules: Vec<Rule>,
+    fields: Vec<FieldExplanation>,
+}
+
+impl Explanation {
+    /// Why `field` of the result is possible, `None` if it is not
+    pub fn field(&self, field: Field) -> Option<&FieldExplanation> {
+        self.fields
+            .iter()
+            .find(|explanation| explanation.field == field)
+    }
+
+    /// Why each possible field of the result is possible
+    pub fn fields(&self) -> &[FieldExplanation] {
+        &self.fields
+    }
+}
+
+/// Applies the operation named `name`, like `core::ops::apply`, explaining its result
+///
+/// An argument field contributes to a field of the result if the result
+/// is weaker without it.
+pub fn explain(name: &str, args: &[FnArgFloat]) -> Result<Explanation, Error> {
+    let result = ops::apply(name, args)?;
+    let rules: Vec<Rule> = registry::cases(name, args)
+        .iter()
+        .map(|(name, possible)| Rule { name, possible })
+        .collect();
+
+    let mut fields = Vec::new();
+    for field in Field::ALL {
+        let possible = result.possibilities().get(field);
+        if possible == Possible::No {
+            continue;
+        }
+
+        let mut field_rules: Vec<Rule> = Vec::new();
+        for rule in rules.iter().filter(|rule| rule.fields().contains(&field)) {
+            match field_rules.iter_mut().find(|r| r.name == rule.name) {
+                Some(r) => r.possible = r.possible | rule.possible,
+                None => field_rules.push(*rule),
+            }
+        }
+
+        fields.push(FieldExplanation {
+            field,
+            possible,
+            rules: field_rules,
+            inputs: Vec::new(),
+        });
+    }
+
+    // Without each possible field of each argument
+    for (index, arg) in args.iter().enumerate() {
+        for input in Field::ALL {
+            if arg.possibilities().get(input) == Possible::No {
+                continue;
+            }
+
+            let mut ablated = args.to_vec();
+            ablated[index] = arg.map(|fp| fp.with(input, Possible::No));
+            let weaker = ops::apply(name, &ablated)?;
+
+            for explanation in fields.iter_mut() {
+                if weaker.possibilities().get(explanation.field) < explanation.possible {
+                    explanation.inputs.push((index, input));
+                }
+            }
+        }
+    }
+
+    Ok(Explanation {
+        result,
+        rules,
+        fields,
+    })
+}
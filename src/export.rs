@@ -0,0 +1,99 @@
+//! The models of the operations as data, for the tools not written in Rust
+//!
+//! With the `serde` feature, the table can be serialized, for example to JSON.
+//!
+//! ```
+//! use fn_num_types::core::ops;
+//! use fn_num_types::export::op_table;
+//! use fn_num_types::FnArgFloat;
+//!
+//! let table = op_table();
+//! let sqrt = table.iter().find(|op| op.name == "sqrt").unwrap();
+//!
+//! assert_eq!(sqrt.arity, 1);
+//! assert_eq!(sqrt.transfer.len(), 512);
+//! for transfer in &sqrt.transfer {
+//!     let res = ops::sqrt(&FnArgFloat::F64(transfer.args[0]));
+//!     assert_eq!(*res.possibilities(), transfer.result);
+//! }
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::core::ops::{apply, op_names};
+use crate::verify::{possibilities, ternary_possibilities};
+use crate::{FloatPossibilities, FnArgFloat};
+
+/// An operation and its model, sampled
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OpTable {
+    /// The name of `core::ops::apply`
+    pub name: &'static str,
+    /// The number of arguments
+    pub arity: usize,
+    /// The result for each combination of arguments
+    pub transfer: Vec<Transfer>,
+}
+
+/// The possibilities of the result of an operation for `args`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Transfer {
+    pub args: Vec<FloatPossibilities>,
+    pub result: FloatPossibilities,
+}
+
+/// The table of the operations of a fixed number of arguments, for `f64`
+///
+/// The arguments of the operations of one argument are all the possibilities
+/// of `verify::possibilities`. For more arguments, only those with
+/// `subnormal`, `fractional` and `large` set are combined, keeping the table
+/// tractable, as for the verification: they still cover every value.
+///
+/// The casts, like `to_f32`, give possibilities of their own type.
+pub fn op_table() -> Vec<OpTable> {
+    op_names()
+        .filter_map(|(name, arity)| {
+            let arity = arity?;
+            let samples = if arity == 1 {
+                possibilities()
+            } else {
+                ternary_possibilities()
+            };
+
+            let mut combinations: Vec<Vec<FloatPossibilities>> = alloc::vec![Vec::new()];
+            for _ in 0..arity {
+                combinations = combinations
+                    .iter()
+                    .flat_map(|args| {
+                        samples.iter().map(move |p| {
+                            let mut args = args.clone();
+                            args.push(*p);
+                            args
+                        })
+                    })
+                    .collect();
+            }
+
+            let transfer = combinations
+                .into_iter()
+                .map(|args| {
+                    let arg_types: Vec<FnArgFloat> =
+                        args.iter().map(|p| FnArgFloat::F64(*p)).collect();
+                    let result = apply(name, &arg_types).expect("Listed by `op_names`");
+                    Transfer {
+                        args,
+                        result: *result.possibilities(),
+                    }
+                })
+                .collect();
+
+            Some(OpTable {
+                name,
+                arity,
+                transfer,
+            })
+        })
+        .collect()
+}
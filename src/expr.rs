@@ -0,0 +1,371 @@
+//! Expressions, to analyze a whole formula
+//!
+//! ```
+//! use fn_num_types::expr::{eval, BinaryOp, Expr, UnaryOp};
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! // `(x.abs() + 1.0).sqrt()`
+//! let expr = Expr::Unary(
+//!     UnaryOp::Sqrt,
+//!     Box::new(Expr::Binary(
+//!         BinaryOp::Add,
+//!         Box::new(Expr::Unary(UnaryOp::Abs, Box::new(Expr::Var(0)))),
+//!         Box::new(Expr::Const(1.0)),
+//!     )),
+//! );
+//!
+//! let res = eval(&expr, &[FnArgFloat::F64(FloatPossibilities::finite())]);
+//! assert_eq!(res.possibilities().nan, Possible::No);
+//! assert_eq!(res.possibilities().negative, Possible::No);
+//! ```
+
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+
+use crate::cast::convert;
+use crate::core::ops;
+use crate::{Error, FnArgFloat, ParseError, FP};
+
+macro_rules! op_enum {
+    ($(#[$meta:meta])* $name:ident: $model:ty { $($variant:ident($op_name:literal) => $op:expr,)* }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            /// All the operations
+            pub const ALL: &'static [$name] = &[$($name::$variant,)*];
+
+            /// The name of the operation, like the method of `f64`
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $($name::$variant => $op_name,)*
+                }
+            }
+
+            /// The model of the operation, from `core::ops`
+            pub fn model(self) -> $model {
+                match self {
+                    $($name::$variant => $op,)*
+                }
+            }
+        }
+    };
+}
+
+op_enum!(
+    /// The operations of one argument, like the methods of `f64`
+    UnaryOp: fn(&FnArgFloat) -> FnArgFloat {
+        Neg("neg") => ops::neg,
+        Abs("abs") => ops::abs,
+        Ceil("ceil") => ops::ceil,
+        Floor("floor") => ops::floor,
+        Round("round") => ops::round,
+        RoundTiesEven("round_ties_even") => ops::round_ties_even,
+        Trunc("trunc") => ops::trunc,
+        Fract("fract") => ops::fract,
+        Signum("signum") => ops::signum,
+        Sqrt("sqrt") => ops::sqrt,
+        Exp("exp") => ops::exp,
+        Exp2("exp2") => ops::exp2,
+        Ln("ln") => ops::ln,
+        Log2("log2") => ops::log2,
+        Log10("log10") => ops::log10,
+        ToDegrees("to_degrees") => ops::to_degrees,
+        ToRadians("to_radians") => ops::to_radians,
+        Cbrt("cbrt") => ops::cbrt,
+        Sin("sin") => ops::sin,
+        Cos("cos") => ops::cos,
+        Tan("tan") => ops::tan,
+        Asin("asin") => ops::asin,
+        Acos("acos") => ops::acos,
+        Atan("atan") => ops::atan,
+        ExpM1("exp_m1") => ops::exp_m1,
+        Ln1p("ln_1p") => ops::ln_1p,
+        Sinh("sinh") => ops::sinh,
+        Cosh("cosh") => ops::cosh,
+        Tanh("tanh") => ops::tanh,
+        Asinh("asinh") => ops::asinh,
+        Acosh("acosh") => ops::acosh,
+        Atanh("atanh") => ops::atanh,
+        Recip("recip") => ops::recip,
+        NextUp("next_up") => ops::next_up,
+        NextDown("next_down") => ops::next_down,
+    }
+);
+
+op_enum!(
+    /// The operations of two arguments, like the operators and the methods of `f64`
+    BinaryOp: fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat {
+        Add("add") => ops::add,
+        Sub("sub") => |x: &FnArgFloat, y: &FnArgFloat| x - y,
+        Mul("mul") => ops::mul,
+        Div("div") => ops::div,
+        Rem("rem") => ops::rem,
+        DivEuclid("div_euclid") => ops::div_euclid,
+        RemEuclid("rem_euclid") => ops::rem_euclid,
+        Powf("powf") => ops::powf,
+        Hypot("hypot") => ops::hypot,
+        Atan2("atan2") => ops::atan2,
+        Copysign("copysign") => ops::copysign,
+        Max("max") => ops::max,
+        Min("min") => ops::min,
+        Maximum("maximum") => ops::maximum,
+        Minimum("minimum") => ops::minimum,
+        Log("log") => ops::log,
+        Midpoint("midpoint") => ops::midpoint,
+    }
+);
+
+/// A formula of floats
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expr {
+    /// The argument at this index
+    Var(usize),
+    /// A constant, of the type of the first argument
+    Const(f64),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+/// The possibilities of the result of `expr` for the arguments `args`
+///
+/// The constants are of the type of the first argument, `f64` without arguments.
+///
+/// # Panics
+///
+/// If a variable is out of `args`, or like the operations if their arguments are not of the same type
+pub fn eval(expr: &Expr, args: &[FnArgFloat]) -> FnArgFloat {
+    match expr {
+        Expr::Var(index) => args[*index],
+        Expr::Const(value) => constant(*value, args.first()),
+        Expr::Unary(op, x) => op.model()(&eval(x, args)),
+        Expr::Binary(op, x, y) => op.model()(&eval(x, args), &eval(y, args)),
+    }
+}
+
+/// The possibilities of `value` rounded to the type of `ty`
+pub(crate) fn constant(value: f64, ty: Option<&FnArgFloat>) -> FnArgFloat {
+    match ty {
+        None => FnArgFloat::F64(FP::from(value)),
+        Some(FnArgFloat::F32(_)) => FnArgFloat::F32(FP::from(value as f32)),
+        Some(ty) => convert(&FnArgFloat::F64(FP::from(value)), *ty),
+    }
+}
+
+/// Parses `text`, an expression written like in Rust, of the variables `vars`
+///
+/// The expression is made of the variables, numbers, the operators `+`, `-`,
+/// `*`, `/` and `%`, parentheses and the methods of `UnaryOp` and `BinaryOp`.
+///
+/// ```
+/// use fn_num_types::expr::{parse, BinaryOp, Expr, UnaryOp};
+///
+/// let expr = parse("-x.sqrt() * 2.0", &["x"]).unwrap();
+///
+/// assert_eq!(
+///     expr,
+///     Expr::Binary(
+///         BinaryOp::Mul,
+///         Box::new(Expr::Unary(
+///             UnaryOp::Neg,
+///             Box::new(Expr::Unary(UnaryOp::Sqrt, Box::new(Expr::Var(0)))),
+///         )),
+///         Box::new(Expr::Const(2.0)),
+///     )
+/// );
+/// ```
+///
+/// # Errors
+///
+/// `Error::UnknownOp` for an unknown method, `Error::Arity` if it takes
+/// another number of arguments and `Error::Parse` for the other errors
+pub fn parse(text: &str, vars: &[&str]) -> Result<Expr, Error> {
+    let mut parser = Parser { rest: text, vars };
+    let expr = parser.sum()?;
+
+    match parser.rest.trim_start() {
+        "" => Ok(expr),
+        rest => Err(parser.error(rest)),
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a str,
+    vars: &'a [&'a str],
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, token: &str) -> Error {
+        Error::Parse(ParseError {
+            token: token.to_owned(),
+        })
+    }
+
+    /// Consumes `token` if it is next
+    fn eat(&mut self, token: char) -> bool {
+        let rest = self.rest.trim_start();
+        match rest.strip_prefix(token) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, token: char) -> Result<(), Error> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            let rest = self.rest.trim_start();
+            Err(self.error(if rest.is_empty() { "end" } else { rest }))
+        }
+    }
+
+    /// Consumes the longest prefix of the chars matching `f`
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest.trim_start();
+        let end = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.rest = &rest[end..];
+        &rest[..end]
+    }
+
+    /// `x + y` and `x - y`
+    fn sum(&mut self) -> Result<Expr, Error> {
+        let mut expr = self.product()?;
+        loop {
+            let op = if self.eat('+') {
+                BinaryOp::Add
+            } else if self.eat('-') {
+                BinaryOp::Sub
+            } else {
+                return Ok(expr);
+            };
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.product()?));
+        }
+    }
+
+    /// `x * y`, `x / y` and `x % y`
+    fn product(&mut self) -> Result<Expr, Error> {
+        let mut expr = self.neg()?;
+        loop {
+            let op = if self.eat('*') {
+                BinaryOp::Mul
+            } else if self.eat('/') {
+                BinaryOp::Div
+            } else if self.eat('%') {
+                BinaryOp::Rem
+            } else {
+                return Ok(expr);
+            };
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.neg()?));
+        }
+    }
+
+    /// `-x`, below the methods: `-x.abs()` is `-(x.abs())`
+    fn neg(&mut self) -> Result<Expr, Error> {
+        if self.eat('-') {
+            Ok(Expr::Unary(UnaryOp::Neg, Box::new(self.neg()?)))
+        } else {
+            self.method()
+        }
+    }
+
+    /// `x.sqrt()` and `x.powf(y)`
+    fn method(&mut self) -> Result<Expr, Error> {
+        let mut expr = self.atom()?;
+        while self.eat('.') {
+            let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+            self.expect('(')?;
+
+            expr = if self.eat(')') {
+                match UnaryOp::ALL.iter().find(|op| op.name() == name) {
+                    Some(op) => Expr::Unary(*op, Box::new(expr)),
+                    None => return Err(self.op_error(name, 1)),
+                }
+            } else {
+                let arg = self.sum()?;
+                self.expect(')')?;
+                match BinaryOp::ALL.iter().find(|op| op.name() == name) {
+                    Some(op) => Expr::Binary(*op, Box::new(expr), Box::new(arg)),
+                    None => return Err(self.op_error(name, 2)),
+                }
+            };
+        }
+        Ok(expr)
+    }
+
+    /// The error of the method `name`, not of `found` arguments
+    fn op_error(&self, name: &str, found: usize) -> Error {
+        let known = UnaryOp::ALL.iter().any(|op| op.name() == name)
+            || BinaryOp::ALL.iter().any(|op| op.name() == name);
+
+        if known {
+            Error::Arity {
+                op: name.to_owned(),
+                found,
+            }
+        } else {
+            Error::UnknownOp(name.to_owned())
+        }
+    }
+
+    /// A variable, a number or an expression between parentheses
+    fn atom(&mut self) -> Result<Expr, Error> {
+        if self.eat('(') {
+            let expr = self.sum()?;
+            self.expect(')')?;
+            return Ok(expr);
+        }
+
+        let rest = self.rest.trim_start();
+        match rest.chars().next() {
+            Some(c) if c.is_ascii_digit() => self.number(),
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                let name = self.take_while(|c| c.is_alphanumeric() || c == '_');
+                match self.vars.iter().position(|var| *var == name) {
+                    Some(index) => Ok(Expr::Var(index)),
+                    None => Err(self.error(name)),
+                }
+            }
+            Some(_) => Err(self.error(rest)),
+            None => Err(self.error("end")),
+        }
+    }
+
+    /// Like `2`, `0.5` or `1e-3`, with the `.` of a method kept, like in `2.0.sqrt()`
+    fn number(&mut self) -> Result<Expr, Error> {
+        let rest = self.rest.trim_start();
+        let bytes = rest.as_bytes();
+        let digits = |mut i: usize| {
+            while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'_') {
+                i += 1;
+            }
+            i
+        };
+
+        let mut end = digits(0);
+        if bytes.get(end) == Some(&b'.') && bytes.get(end + 1).map_or(true, u8::is_ascii_digit) {
+            end = digits(end + 1);
+        }
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+            if bytes.get(end + 1 + sign).is_some_and(u8::is_ascii_digit) {
+                end = digits(end + 1 + sign);
+            }
+        }
+
+        let (number, rest) = rest.split_at(end);
+        let value = number
+            .replace('_', "")
+            .parse::<f64>()
+            .map_err(|_| self.error(number))?;
+        self.rest = rest;
+        Ok(Expr::Const(value))
+    }
+}
@@ -0,0 +1,180 @@
+//! Properties of the values defined outside of the crate, on top of the possibilities.
+//!
+//! An `Extended` float carries an `Extension` along the possibilities, like
+//! "is a probability, in `[0, 1]`". Each operation computes the extension of its
+//! result with the hooks of the trait, which know nothing by default.
+//!
+//! ```
+//! use fn_num_types::extension::{Extended, Extension};
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! /// The value is in `[0, 1]`
+//! #[derive(Debug, Clone, Copy, Default, PartialEq)]
+//! struct Probability(bool);
+//!
+//! impl Extension for Probability {
+//!     fn unary(op: &'static str, x: &Extended<Self>, _: &FnArgFloat) -> Self {
+//!         Probability(x.ext().0 && matches!(op, "sqrt" | "abs" | "cbrt"))
+//!     }
+//!
+//!     fn binary(op: &'static str, x: &Extended<Self>, y: &Extended<Self>, _: &FnArgFloat) -> Self {
+//!         Probability(x.ext().0 && y.ext().0 && matches!(op, "mul" | "min" | "max" | "midpoint"))
+//!     }
+//!
+//!     fn accept(&self, value: f64) -> bool {
+//!         !self.0 || (0.0..=1.0).contains(&value)
+//!     }
+//! }
+//!
+//! let p = Extended::new(FnArgFloat::F64(FloatPossibilities::positive_finite()), Probability(true));
+//!
+//! assert_eq!(p.mul(&p.sqrt()).ext(), &Probability(true));
+//! assert_eq!(p.add(&p).ext(), &Probability(false));
+//! assert!(p.accept(0.5));
+//! assert!(!p.accept(1.5));
+//!
+//! // The possibilities are the usual ones
+//! assert_eq!(p.sqrt().arg().possibilities().negative, Possible::No);
+//! ```
+
+use crate::core::ops;
+use crate::FnArgFloat;
+
+/// A property of the values, computed by the hooks of each operation
+///
+/// `Default` is knowing nothing: the hooks return it unless overridden.
+pub trait Extension: Copy + Default {
+    /// The extension of the result `res` of the operation of one argument named `op`
+    fn unary(op: &'static str, x: &Extended<Self>, res: &FnArgFloat) -> Self {
+        let _ = (op, x, res);
+        Self::default()
+    }
+
+    /// The extension of the result `res` of the operation of two arguments named `op`
+    fn binary(op: &'static str, x: &Extended<Self>, y: &Extended<Self>, res: &FnArgFloat) -> Self {
+        let _ = (op, x, y, res);
+        Self::default()
+    }
+
+    /// Returns true if `value` may have the property
+    fn accept(&self, value: f64) -> bool {
+        let _ = value;
+        true
+    }
+}
+
+/// Nothing more
+impl Extension for () {}
+
+/// The possibilities of a float, with an extension
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Extended<E> {
+    arg: FnArgFloat,
+    ext: E,
+}
+
+impl<E: Extension> Extended<E> {
+    pub fn new(arg: FnArgFloat, ext: E) -> Self {
+        Extended { arg, ext }
+    }
+
+    /// The possibilities, whatever the extension
+    pub fn arg(&self) -> &FnArgFloat {
+        &self.arg
+    }
+
+    pub fn ext(&self) -> &E {
+        &self.ext
+    }
+
+    /// Returns true if the `f64` value is accepted by both the possibilities
+    /// and the extension
+    pub fn accept(&self, value: f64) -> bool {
+        self.arg.accept(value) && self.ext.accept(value)
+    }
+
+    fn apply_unary(&self, op: &'static str, res: FnArgFloat) -> Self {
+        Extended::new(res, E::unary(op, self, &res))
+    }
+
+    fn apply_binary(&self, op: &'static str, rhs: &Self, res: FnArgFloat) -> Self {
+        Extended::new(res, E::binary(op, self, rhs, &res))
+    }
+
+    /// `x - y`
+    pub fn sub(&self, rhs: &Self) -> Self {
+        self.apply_binary("sub", rhs, self.arg - rhs.arg)
+    }
+}
+
+/// Knowing nothing more
+impl<E: Extension> From<FnArgFloat> for Extended<E> {
+    fn from(arg: FnArgFloat) -> Self {
+        Extended::new(arg, E::default())
+    }
+}
+
+macro_rules! unary {
+    ($($op:ident),* $(,)?) => {
+        impl<E: Extension> Extended<E> {
+            $(
+                pub fn $op(&self) -> Self {
+                    self.apply_unary(stringify!($op), ops::$op(&self.arg))
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident),* $(,)?) => {
+        impl<E: Extension> Extended<E> {
+            $(
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    self.apply_binary(stringify!($op), rhs, ops::$op(&self.arg, &rhs.arg))
+                }
+            )*
+        }
+    };
+}
+
+unary!(
+    neg,
+    abs,
+    ceil,
+    floor,
+    round,
+    round_ties_even,
+    trunc,
+    fract,
+    signum,
+    sqrt,
+    exp,
+    exp2,
+    ln,
+    log2,
+    log10,
+    to_degrees,
+    to_radians,
+    cbrt,
+    sin,
+    cos,
+    tan,
+    asin,
+    acos,
+    atan,
+    exp_m1,
+    ln_1p,
+    sinh,
+    cosh,
+    tanh,
+    asinh,
+    acosh,
+    atanh,
+    recip,
+);
+
+binary!(
+    add, mul, div, rem, div_euclid, rem_euclid, powf, hypot, atan2, copysign, max, min, maximum,
+    minimum, midpoint,
+);
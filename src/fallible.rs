@@ -0,0 +1,117 @@
+//! Variants of the operations returning an `Error` instead of panicking
+//! when the arguments are not of the same type.
+
+#[cfg(feature = "arith")]
+use alloc::vec::Vec;
+
+#[allow(unused_imports)]
+use crate::core::ops;
+#[allow(unused_imports)]
+use crate::{Error, FnArgFloat};
+
+/// `FnArgFloat::check_same_type(args)?`: an `Error` can't be dropped in a `const fn`
+#[allow(unused_macros)]
+macro_rules! check_same_type {
+    ($args:expr) => {
+        let args: &[FnArgFloat] = $args;
+        let Some(first) = args.first() else {
+            return Err(Error::NoArguments);
+        };
+
+        let mut i = 1;
+        while i < args.len() {
+            if !first.is_same_type(&args[i]) {
+                return Err(Error::DifferentTypes);
+            }
+            i += 1;
+        }
+    };
+}
+
+macro_rules! binary {
+    ($feature:literal => $($op:ident => $try_op:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("`", stringify!($op), "`, or `Error::DifferentTypes`")]
+            #[cfg(feature = $feature)]
+            pub const fn $try_op(lhs: &FnArgFloat, rhs: &FnArgFloat) -> Result<FnArgFloat, Error> {
+                check_same_type!(&[*lhs, *rhs]);
+
+                Ok(ops::$op(lhs, rhs))
+            }
+        )*
+    };
+}
+
+macro_rules! ternary {
+    ($feature:literal => $($op:ident => $try_op:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("`", stringify!($op), "`, or `Error::DifferentTypes`")]
+            #[cfg(feature = $feature)]
+            pub const fn $try_op(
+                a: &FnArgFloat,
+                b: &FnArgFloat,
+                c: &FnArgFloat,
+            ) -> Result<FnArgFloat, Error> {
+                check_same_type!(&[*a, *b, *c]);
+
+                Ok(ops::$op(a, b, c))
+            }
+        )*
+    };
+}
+
+macro_rules! n_ary {
+    ($feature:literal => $($op:ident => $try_op:ident),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "`", stringify!($op), "`, or `Error::NoArguments` or `Error::DifferentTypes`"
+            )]
+            #[cfg(feature = $feature)]
+            pub const fn $try_op(args: &[FnArgFloat]) -> Result<FnArgFloat, Error> {
+                check_same_type!(args);
+
+                Ok(ops::$op(args))
+            }
+        )*
+    };
+}
+
+binary!("arith" =>
+    add => try_add,
+    mul => try_mul,
+    div => try_div,
+    rem => try_rem,
+    div_euclid => try_div_euclid,
+    rem_euclid => try_rem_euclid,
+    powf => try_powf,
+    hypot => try_hypot,
+    copysign => try_copysign,
+    max => try_max,
+    min => try_min,
+    maximum => try_maximum,
+    minimum => try_minimum,
+    midpoint => try_midpoint,
+);
+binary!("exp-log" => log => try_log);
+binary!("trig" => atan2 => try_atan2);
+binary!("nightly-ops" => abs_sub => try_abs_sub);
+
+ternary!("arith" => mul_add => try_mul_add, clamp => try_clamp);
+
+n_ary!("arith" =>
+    sum => try_sum,
+    product => try_product,
+    sum_kahan => try_sum_kahan,
+    mean => try_mean,
+    variance => try_variance,
+    stddev => try_stddev,
+    norm => try_norm,
+);
+
+/// `normalize`, or `Error::NoArguments` or `Error::DifferentTypes`
+#[cfg(feature = "arith")]
+pub fn try_normalize(args: &[FnArgFloat]) -> Result<Vec<FnArgFloat>, Error> {
+    FnArgFloat::check_same_type(args)?;
+
+    Ok(ops::normalize(args))
+}
@@ -0,0 +1,156 @@
+//! C ABI of the operations.
+//!
+//! `Possible`, `FloatPossibilities` and `FnArgFloat` are `#[repr(C)]`
+//! and can be passed by value. Each operation `op` of `core::ops`
+//! is exported as `fnt_op`.
+//!
+//! `FloatPossibilities` can also be packed in an `uint32_t`, with
+//! `fnt_pack` and `fnt_unpack`: its 9 fields of 4 levels need 18 bits.
+//!
+//! To link it from C, build the crate as a static or dynamic library:
+//! `cargo rustc --release --features ffi --crate-type staticlib`
+
+pub mod plugin;
+
+use crate::core::ops;
+use crate::{FloatPossibilities, FloatValue, FnArgFloat};
+
+macro_rules! unary {
+    ($($ffi:ident => $op:ident),* $(,)?) => {
+        $(
+            #[no_mangle]
+            pub extern "C" fn $ffi(lhs: FnArgFloat) -> FnArgFloat {
+                ops::$op(&lhs)
+            }
+        )*
+    };
+}
+
+/// Binary operations write their result in `res` and return `false`,
+/// without writing it, if `lhs` and `rhs` are of different types.
+macro_rules! binary {
+    ($($ffi:ident => $op:ident),* $(,)?) => {
+        $(
+            #[no_mangle]
+            pub extern "C" fn $ffi(lhs: FnArgFloat, rhs: FnArgFloat, res: &mut FnArgFloat) -> bool {
+                if FnArgFloat::check_same_type(&[lhs, rhs]).is_err() {
+                    return false;
+                }
+
+                *res = ops::$op(&lhs, &rhs);
+                true
+            }
+        )*
+    };
+}
+
+macro_rules! ternary {
+    ($($ffi:ident => $op:ident),* $(,)?) => {
+        $(
+            #[no_mangle]
+            pub extern "C" fn $ffi(a: FnArgFloat, b: FnArgFloat, c: FnArgFloat, res: &mut FnArgFloat) -> bool {
+                if FnArgFloat::check_same_type(&[a, b, c]).is_err() {
+                    return false;
+                }
+
+                *res = ops::$op(&a, &b, &c);
+                true
+            }
+        )*
+    };
+}
+
+unary!(
+    fnt_neg => neg,
+    fnt_abs => abs,
+    fnt_ceil => ceil,
+    fnt_floor => floor,
+    fnt_round => round,
+    fnt_round_ties_even => round_ties_even,
+    fnt_trunc => trunc,
+    fnt_fract => fract,
+    fnt_signum => signum,
+    fnt_sqrt => sqrt,
+    fnt_exp => exp,
+    fnt_exp2 => exp2,
+    fnt_ln => ln,
+    fnt_log2 => log2,
+    fnt_log10 => log10,
+    fnt_to_degrees => to_degrees,
+    fnt_to_radians => to_radians,
+    fnt_cbrt => cbrt,
+    fnt_sin => sin,
+    fnt_cos => cos,
+    fnt_tan => tan,
+    fnt_asin => asin,
+    fnt_acos => acos,
+    fnt_atan => atan,
+    fnt_exp_m1 => exp_m1,
+    fnt_ln_1p => ln_1p,
+    fnt_sinh => sinh,
+    fnt_cosh => cosh,
+    fnt_tanh => tanh,
+    fnt_asinh => asinh,
+    fnt_acosh => acosh,
+    fnt_atanh => atanh,
+    fnt_recip => recip,
+    fnt_powi => powi,
+    fnt_next_up => next_up,
+    fnt_next_down => next_down,
+    fnt_to_f32 => to_f32,
+    fnt_to_f64 => to_f64,
+);
+
+binary!(
+    fnt_add => add,
+    fnt_mul => mul,
+    fnt_div => div,
+    fnt_rem => rem,
+    fnt_div_euclid => div_euclid,
+    fnt_rem_euclid => rem_euclid,
+    fnt_powf => powf,
+    fnt_hypot => hypot,
+    fnt_atan2 => atan2,
+    fnt_copysign => copysign,
+    fnt_max => max,
+    fnt_min => min,
+    fnt_maximum => maximum,
+    fnt_minimum => minimum,
+    fnt_log => log,
+    fnt_midpoint => midpoint,
+);
+
+ternary!(
+    fnt_mul_add => mul_add,
+    fnt_clamp => clamp,
+);
+
+/// Returns true if `arg` accepts `value`, converted to the type of `arg`
+#[no_mangle]
+pub extern "C" fn fnt_accept(arg: FnArgFloat, value: f64) -> bool {
+    match arg {
+        FnArgFloat::F32(_) => (value as f32).accepted_by(&arg),
+        FnArgFloat::F64(_) => value.accepted_by(&arg),
+        #[cfg(feature = "extra-widths")]
+        _ => arg.possibilities().accept(value),
+    }
+}
+
+/// Two bits per field, see `FloatPossibilities::to_bits`
+#[no_mangle]
+pub extern "C" fn fnt_pack(fp: FloatPossibilities) -> u32 {
+    fp.to_bits()
+}
+
+/// Writes in `res` the possibilities packed by `fnt_pack`, and returns `false`,
+/// without writing them, if a bit above the 18 lowest is set
+#[no_mangle]
+pub extern "C" fn fnt_unpack(bits: u32, res: &mut FloatPossibilities) -> bool {
+    match FloatPossibilities::from_bits(bits) {
+        Some(fp) => {
+            *res = fp;
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,130 @@
+//! Stable ABI to provide operations from other languages.
+//!
+//! A shared library registers its operations by calling `fnt_register_op`,
+//! usually from its initialization function, and they can then be applied
+//! by name with `fnt_apply_op` or `apply`.
+
+use std::ffi::{c_char, CStr};
+use std::sync::RwLock;
+
+use crate::FnArgFloat;
+
+/// Version of the plugin ABI, increased on every incompatible change
+pub const FNT_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// An operation provided by a plugin
+#[repr(C)]
+pub struct FntOp {
+    /// Must be `FNT_PLUGIN_ABI_VERSION`
+    pub abi_version: u32,
+    /// Nul-terminated UTF-8 name, copied on registration
+    pub name: *const c_char,
+    /// Number of arguments
+    pub arity: u32,
+    /// Writes in `res` the possibilities of the result for the `arity` arguments
+    /// pointed by `args`, or returns `false` if the arguments are not supported.
+    pub apply: unsafe extern "C" fn(args: *const FnArgFloat, res: *mut FnArgFloat) -> bool,
+}
+
+struct RegisteredOp {
+    name: String,
+    arity: usize,
+    apply: unsafe extern "C" fn(*const FnArgFloat, *mut FnArgFloat) -> bool,
+}
+
+static REGISTRY: RwLock<Vec<RegisteredOp>> = RwLock::new(Vec::new());
+
+/// Registers an operation.
+///
+/// Returns `false` if `op` is null, of another ABI version, has an invalid name,
+/// or if an operation with the same name is already registered.
+///
+/// # Safety
+///
+/// `op` must be null or point to a valid `FntOp` whose `name` is a valid
+/// nul-terminated string. `apply` must stay callable for the lifetime of the process.
+#[no_mangle]
+pub unsafe extern "C" fn fnt_register_op(op: *const FntOp) -> bool {
+    let Some(op) = op.as_ref() else {
+        return false;
+    };
+
+    if op.abi_version != FNT_PLUGIN_ABI_VERSION || op.name.is_null() {
+        return false;
+    }
+
+    let Ok(name) = CStr::from_ptr(op.name).to_str() else {
+        return false;
+    };
+
+    let mut registry = REGISTRY.write().unwrap();
+
+    if registry.iter().any(|registered| registered.name == name) {
+        return false;
+    }
+
+    registry.push(RegisteredOp {
+        name: name.to_owned(),
+        arity: op.arity as usize,
+        apply: op.apply,
+    });
+
+    true
+}
+
+/// Applies the registered operation `name` to the `nargs` arguments pointed by `args`
+/// and writes the result in `res`.
+///
+/// Returns `false` if there is no such operation, if `nargs` is not its arity,
+/// or if the operation does not support the arguments.
+///
+/// # Safety
+///
+/// `name` must be a valid nul-terminated string, `args` must point to `nargs`
+/// arguments and `res` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fnt_apply_op(
+    name: *const c_char,
+    args: *const FnArgFloat,
+    nargs: usize,
+    res: *mut FnArgFloat,
+) -> bool {
+    if name.is_null() || args.is_null() || res.is_null() {
+        return false;
+    }
+
+    let Ok(name) = CStr::from_ptr(name).to_str() else {
+        return false;
+    };
+
+    match apply(name, std::slice::from_raw_parts(args, nargs)) {
+        Some(value) => {
+            res.write(value);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Applies the registered operation `name` to `args`.
+///
+/// Returns `None` if there is no such operation, if the number of arguments
+/// is not its arity, or if the operation does not support the arguments.
+pub fn apply(name: &str, args: &[FnArgFloat]) -> Option<FnArgFloat> {
+    let registry = REGISTRY.read().unwrap();
+
+    let op = registry.iter().find(|op| op.name == name)?;
+
+    if op.arity != args.len() {
+        return None;
+    }
+
+    let mut res = std::mem::MaybeUninit::<FnArgFloat>::uninit();
+
+    // SAFETY: the registered function was promised to be valid,
+    // `args` holds `arity` arguments and `res` is valid for writes.
+    let supported = unsafe { (op.apply)(args.as_ptr(), res.as_mut_ptr()) };
+
+    // SAFETY: the result is initialized when the operation succeeds
+    supported.then(|| unsafe { res.assume_init() })
+}
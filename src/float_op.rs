@@ -0,0 +1,310 @@
+//! Operations as values, so that other crates can add their own
+//!
+//! A registered operation can be applied by name with `core::ops::apply`, and
+//! its model verified with `verify::verify_unary_op`.
+//!
+//! ```
+//! use fn_num_types::core::ops::{abs, apply, mul};
+//! use fn_num_types::float_op::{register_unary, UnaryFloatOp};
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! /// `x * x`
+//! struct Square;
+//!
+//! impl UnaryFloatOp for Square {
+//!     fn name(&self) -> &str {
+//!         "square"
+//!     }
+//!
+//!     fn apply_concrete(&self, x: f64) -> f64 {
+//!         x * x
+//!     }
+//!
+//!     fn apply_abstract(&self, x: &FnArgFloat) -> FnArgFloat {
+//!         mul(&abs(x), &abs(x))
+//!     }
+//! }
+//!
+//! register_unary(Square).unwrap();
+//!
+//! let x = FnArgFloat::F64(FloatPossibilities::any());
+//! assert_eq!(apply("square", &[x]).unwrap().possibilities().negative, Possible::No);
+//! assert!(fn_num_types::verify::verify_unary_op(&Square).is_empty());
+//! ```
+
+use alloc::borrow::ToOwned;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use std::sync::RwLock;
+
+use crate::expr::{BinaryOp, UnaryOp};
+use crate::{Error, FnArgFloat};
+
+/// An operation of one argument, with its model
+pub trait UnaryFloatOp: Send + Sync {
+    /// The name, to apply it with `core::ops::apply`
+    fn name(&self) -> &str;
+
+    /// The result for `x`
+    fn apply_concrete(&self, x: f64) -> f64;
+
+    /// The possibilities of the result for the possibilities of `x`
+    fn apply_abstract(&self, x: &FnArgFloat) -> FnArgFloat;
+}
+
+/// An operation of two arguments, with its model
+pub trait BinaryFloatOp: Send + Sync {
+    /// The name, to apply it with `core::ops::apply`
+    fn name(&self) -> &str;
+
+    /// The result for `x` and `y`
+    fn apply_concrete(&self, x: f64, y: f64) -> f64;
+
+    /// The possibilities of the result for the possibilities of `x` and `y`
+    fn apply_abstract(&self, x: &FnArgFloat, y: &FnArgFloat) -> FnArgFloat;
+}
+
+impl UnaryFloatOp for UnaryOp {
+    fn name(&self) -> &str {
+        UnaryOp::name(*self)
+    }
+
+    fn apply_concrete(&self, x: f64) -> f64 {
+        match self {
+            UnaryOp::Neg => -x,
+            UnaryOp::Abs => x.abs(),
+            UnaryOp::Ceil => x.ceil(),
+            UnaryOp::Floor => x.floor(),
+            UnaryOp::Round => x.round(),
+            UnaryOp::RoundTiesEven => round_ties_even(x),
+            UnaryOp::Trunc => x.trunc(),
+            UnaryOp::Fract => x.fract(),
+            UnaryOp::Signum => x.signum(),
+            UnaryOp::Sqrt => x.sqrt(),
+            UnaryOp::Exp => x.exp(),
+            UnaryOp::Exp2 => x.exp2(),
+            UnaryOp::Ln => x.ln(),
+            UnaryOp::Log2 => x.log2(),
+            UnaryOp::Log10 => x.log10(),
+            UnaryOp::ToDegrees => x.to_degrees(),
+            UnaryOp::ToRadians => x.to_radians(),
+            UnaryOp::Cbrt => x.cbrt(),
+            UnaryOp::Sin => x.sin(),
+            UnaryOp::Cos => x.cos(),
+            UnaryOp::Tan => x.tan(),
+            UnaryOp::Asin => x.asin(),
+            UnaryOp::Acos => x.acos(),
+            UnaryOp::Atan => x.atan(),
+            UnaryOp::ExpM1 => x.exp_m1(),
+            UnaryOp::Ln1p => x.ln_1p(),
+            UnaryOp::Sinh => x.sinh(),
+            UnaryOp::Cosh => x.cosh(),
+            UnaryOp::Tanh => x.tanh(),
+            UnaryOp::Asinh => x.asinh(),
+            UnaryOp::Acosh => x.acosh(),
+            UnaryOp::Atanh => x.atanh(),
+            UnaryOp::Recip => x.recip(),
+            UnaryOp::NextUp => next_up(x),
+            UnaryOp::NextDown => -next_up(-x),
+        }
+    }
+
+    fn apply_abstract(&self, x: &FnArgFloat) -> FnArgFloat {
+        self.model()(x)
+    }
+}
+
+impl BinaryFloatOp for BinaryOp {
+    fn name(&self) -> &str {
+        BinaryOp::name(*self)
+    }
+
+    fn apply_concrete(&self, x: f64, y: f64) -> f64 {
+        match self {
+            BinaryOp::Add => x + y,
+            BinaryOp::Sub => x - y,
+            BinaryOp::Mul => x * y,
+            BinaryOp::Div => x / y,
+            BinaryOp::Rem => x % y,
+            BinaryOp::DivEuclid => x.div_euclid(y),
+            BinaryOp::RemEuclid => x.rem_euclid(y),
+            BinaryOp::Powf => x.powf(y),
+            BinaryOp::Hypot => x.hypot(y),
+            BinaryOp::Atan2 => x.atan2(y),
+            BinaryOp::Copysign => x.copysign(y),
+            BinaryOp::Max => x.max(y),
+            BinaryOp::Min => x.min(y),
+            BinaryOp::Maximum => maximum(x, y),
+            BinaryOp::Minimum => -maximum(-x, -y),
+            BinaryOp::Log => x.log(y),
+            BinaryOp::Midpoint => midpoint(x, y),
+        }
+    }
+
+    fn apply_abstract(&self, x: &FnArgFloat, y: &FnArgFloat) -> FnArgFloat {
+        self.model()(x, y)
+    }
+}
+
+// `f64::next_up`, stable since Rust 1.86
+fn next_up(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let abs = bits & !(-0.0f64).to_bits();
+
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if abs == 0 {
+        // The smallest positive subnormal
+        f64::from_bits(1)
+    } else if bits == abs {
+        f64::from_bits(bits + 1)
+    } else {
+        f64::from_bits(bits - 1)
+    }
+}
+
+// `f64::round_ties_even`, stable since Rust 1.77
+pub(crate) fn round_ties_even(x: f64) -> f64 {
+    let rounded = x.round();
+
+    // The ties rounded away from zero to an odd integer go toward zero instead
+    if (x - x.trunc()).abs() == 0.5 && rounded % 2.0 != 0.0 {
+        x.trunc()
+    } else {
+        rounded
+    }
+}
+
+// IEEE 754-2019 `maximum`: NaN if any is, `-0.0` below `+0.0`
+fn maximum(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        f64::NAN
+    } else if x == y {
+        if x.is_sign_positive() {
+            x
+        } else {
+            y
+        }
+    } else {
+        x.max(y)
+    }
+}
+
+// `f64::midpoint`, stable since Rust 1.85
+fn midpoint(a: f64, b: f64) -> f64 {
+    const LO: f64 = f64::MIN_POSITIVE * 2.0;
+    const HI: f64 = f64::MAX / 2.0;
+
+    let (abs_a, abs_b) = (a.abs(), b.abs());
+
+    if abs_a <= HI && abs_b <= HI {
+        (a + b) / 2.0
+    } else if abs_a < LO {
+        a + b / 2.0
+    } else if abs_b < LO {
+        a / 2.0 + b
+    } else {
+        a / 2.0 + b / 2.0
+    }
+}
+
+/// The registered operations
+struct Registry {
+    unary: Vec<Arc<dyn UnaryFloatOp>>,
+    binary: Vec<Arc<dyn BinaryFloatOp>>,
+}
+
+impl Registry {
+    fn is_taken(&self, name: &str) -> bool {
+        crate::core::ops::op_names().any(|(op, _)| op == name)
+            || self.unary.iter().any(|op| op.name() == name)
+            || self.binary.iter().any(|op| op.name() == name)
+    }
+}
+
+static REGISTRY: RwLock<Registry> = RwLock::new(Registry {
+    unary: Vec::new(),
+    binary: Vec::new(),
+});
+
+fn registered_unary(name: &str) -> Option<Arc<dyn UnaryFloatOp>> {
+    let registry = REGISTRY.read().unwrap_or_else(|err| err.into_inner());
+    registry.unary.iter().find(|op| op.name() == name).cloned()
+}
+
+fn registered_binary(name: &str) -> Option<Arc<dyn BinaryFloatOp>> {
+    let registry = REGISTRY.read().unwrap_or_else(|err| err.into_inner());
+    registry.binary.iter().find(|op| op.name() == name).cloned()
+}
+
+/// Registers `op`, for `core::ops::apply` and `unary_op`
+///
+/// # Errors
+///
+/// `Error::DuplicateOp` if an operation already has its name
+pub fn register_unary(op: impl UnaryFloatOp + 'static) -> Result<(), Error> {
+    let mut registry = REGISTRY.write().unwrap_or_else(|err| err.into_inner());
+    if registry.is_taken(op.name()) {
+        return Err(Error::DuplicateOp(op.name().to_owned()));
+    }
+
+    registry.unary.push(Arc::new(op));
+    Ok(())
+}
+
+/// Registers `op`, for `core::ops::apply` and `binary_op`
+///
+/// # Errors
+///
+/// `Error::DuplicateOp` if an operation already has its name
+pub fn register_binary(op: impl BinaryFloatOp + 'static) -> Result<(), Error> {
+    let mut registry = REGISTRY.write().unwrap_or_else(|err| err.into_inner());
+    if registry.is_taken(op.name()) {
+        return Err(Error::DuplicateOp(op.name().to_owned()));
+    }
+
+    registry.binary.push(Arc::new(op));
+    Ok(())
+}
+
+/// The operation of one argument named `name`, built-in or registered
+///
+/// ```
+/// use fn_num_types::float_op::unary_op;
+///
+/// let sqrt = unary_op("sqrt").unwrap();
+/// assert_eq!(sqrt.apply_concrete(4.0), 2.0);
+/// assert!(unary_op("add").is_none());
+/// ```
+pub fn unary_op(name: &str) -> Option<Arc<dyn UnaryFloatOp>> {
+    match UnaryOp::ALL.iter().find(|op| op.name() == name) {
+        Some(op) => Some(Arc::new(*op)),
+        None => registered_unary(name),
+    }
+}
+
+/// The operation of two arguments named `name`, built-in or registered
+pub fn binary_op(name: &str) -> Option<Arc<dyn BinaryFloatOp>> {
+    match BinaryOp::ALL.iter().find(|op| op.name() == name) {
+        Some(op) => Some(Arc::new(*op)),
+        None => registered_binary(name),
+    }
+}
+
+/// `apply` for the registered operations, `None` if none is named `name`
+pub(crate) fn apply_registered(
+    name: &str,
+    args: &[FnArgFloat],
+) -> Option<Result<FnArgFloat, Error>> {
+    match (args, registered_unary(name), registered_binary(name)) {
+        ([x], Some(op), _) => Some(Ok(op.apply_abstract(x))),
+        ([x, y], _, Some(op)) => {
+            Some(FnArgFloat::check_same_type(args).map(|()| op.apply_abstract(x, y)))
+        }
+        (_, None, None) => None,
+        _ => Some(Err(Error::Arity {
+            op: name.to_owned(),
+            found: args.len(),
+        })),
+    }
+}
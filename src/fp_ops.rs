@@ -0,0 +1,739 @@
+//! The models of the operations, on the possibilities themselves, without a
+//! `FnArgFloat`, for the analyses applying them millions of times.
+//!
+//! The functions of `core::ops` apply them to the possibilities of any
+//! `FnArgFloat`: `fp::sqrt(&x)` is the possibilities of `ops::sqrt` for `F32`
+//! and `F64`, and `F16`, `BF16` and `F128` with `extra-widths`. The
+//! conversions, which depend on the width, are not provided here.
+//!
+//! Like those of `core::ops`, the models are `const fn`: the rounding mode is
+//! an argument, and the special cases they handle are returned, not recorded.
+//!
+//! ```
+//! use fn_num_types::core::ops::{self, fp};
+//! use fn_num_types::{FloatPossibilities, FnArgFloat};
+//!
+//! let x = FloatPossibilities::positive();
+//! let y = FloatPossibilities::finite();
+//!
+//! assert_eq!(fp::sqrt(&x), *ops::sqrt(&FnArgFloat::F32(x)).possibilities());
+//! assert_eq!(fp::sub(&x, &y), *(FnArgFloat::F64(x) - FnArgFloat::F64(y)).possibilities());
+//!
+//! const ROOT: FloatPossibilities = fp::sqrt(&FloatPossibilities::positive());
+//! const SUM: FnArgFloat = ops::add(&FnArgFloat::F64(ROOT), &FnArgFloat::F64(ROOT));
+//! assert_eq!(ROOT, fp::sqrt(&x));
+//! assert_eq!(*SUM.possibilities(), fp::add(&ROOT, &ROOT));
+//! ```
+
+#[allow(unused_imports)]
+use crate::config::{Cases, Outcome};
+#[allow(unused_imports)]
+use crate::{Possible, RoundingMode, FP};
+
+#[cfg(feature = "arith")]
+pub use crate::add::{add_fp as add, add_fp_with as add_with};
+#[cfg(feature = "arith")]
+pub use crate::copysign::copysign_fp as copysign;
+#[cfg(feature = "arith")]
+pub use crate::div::{div_fp as div, div_fp_with as div_with};
+#[cfg(feature = "arith")]
+pub use crate::euclid::{
+    div_euclid_fp as div_euclid, div_euclid_fp_with as div_euclid_with, rem_euclid_fp as rem_euclid,
+};
+#[cfg(feature = "arith")]
+pub use crate::hypot::{hypot_fp as hypot, hypot_fp_with as hypot_with};
+#[cfg(feature = "exp-log")]
+pub use crate::log::log_fp as log;
+#[cfg(feature = "arith")]
+pub use crate::midpoint::{midpoint_fp as midpoint, midpoint_fp_with as midpoint_with};
+#[cfg(feature = "arith")]
+pub use crate::mul::{mul_fp as mul, mul_fp_with as mul_with};
+#[cfg(feature = "arith")]
+pub use crate::mul_add::{mul_add_fp as mul_add, mul_add_fp_with as mul_add_with};
+#[cfg(feature = "arith")]
+pub use crate::powf::{powf_fp as powf, powf_fp_with as powf_with};
+#[cfg(feature = "arith")]
+pub use crate::rem::rem_fp as rem;
+#[cfg(feature = "trig")]
+pub use crate::trig::{atan2_fp as atan2, atan2_fp_with as atan2_with};
+
+#[cfg(feature = "arith")]
+use crate::minmax::{clamp_fp_with, max_fp_with, min_fp_with, NanPolicy};
+
+/// `x - y` is exactly `x + (-y)`, including the sign of the zeros
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn sub(x: &FP, y: &FP) -> FP {
+    add(x, &neg(y))
+}
+
+/// `sub`, rounded in the direction `mode`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn sub_with(mode: RoundingMode, x: &FP, y: &FP) -> FP {
+    add_with(mode, x, &neg(y))
+}
+
+/// `core::ops::max`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn max(x: &FP, y: &FP) -> FP {
+    max_fp_with(NanPolicy::Std, x, y)
+}
+
+/// `core::ops::min`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn min(x: &FP, y: &FP) -> FP {
+    min_fp_with(NanPolicy::Std, x, y)
+}
+
+/// `core::ops::maximum`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn maximum(x: &FP, y: &FP) -> FP {
+    max_fp_with(NanPolicy::Propagate, x, y)
+}
+
+/// `core::ops::minimum`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn minimum(x: &FP, y: &FP) -> FP {
+    min_fp_with(NanPolicy::Propagate, x, y)
+}
+
+/// `core::ops::clamp`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn clamp(x: &FP, min: &FP, max: &FP) -> FP {
+    clamp_fp_with(NanPolicy::Std, x, min, max)
+}
+
+/// `core::ops::neg`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn neg(fp: &FP) -> FP {
+    FP {
+        pos_zero: fp.neg_zero,
+        neg_zero: fp.pos_zero,
+        positive: fp.negative,
+        negative: fp.positive,
+        ..*fp
+    }
+}
+
+/// `core::ops::abs`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn abs(fp: &FP) -> FP {
+    FP {
+        pos_zero: fp.zero(),
+        neg_zero: Possible::No,
+        positive: any!(fp.positive, fp.negative),
+        negative: Possible::No,
+        ..*fp
+    }
+}
+
+/// `core::ops::ceil`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn ceil(fp: &FP) -> FP {
+    FP {
+        // Values in ]-1, 0[ go to -0.0
+        neg_zero: any!(fp.neg_zero, fp.negative),
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        ..*fp
+    }
+}
+
+/// `core::ops::floor`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn floor(fp: &FP) -> FP {
+    FP {
+        // Values in ]0, 1[ go to +0.0
+        pos_zero: any!(fp.pos_zero, fp.positive),
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        ..*fp
+    }
+}
+
+/// `core::ops::round`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn round(fp: &FP) -> FP {
+    FP {
+        // Small values keep their sign
+        pos_zero: any!(fp.pos_zero, fp.positive),
+        neg_zero: any!(fp.neg_zero, fp.negative),
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        ..*fp
+    }
+}
+
+/// `core::ops::round_ties_even`: only the ties differ from `round`, not the possibilities
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn round_ties_even(fp: &FP) -> FP {
+    round(fp)
+}
+
+/// `core::ops::trunc`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn trunc(fp: &FP) -> FP {
+    FP {
+        // Small values keep their sign
+        pos_zero: any!(fp.pos_zero, fp.positive),
+        neg_zero: any!(fp.neg_zero, fp.negative),
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        ..*fp
+    }
+}
+
+/// `core::ops::fract`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn fract(fp: &FP) -> FP {
+    FP {
+        // Return POSITIVE zero if the factional part is zero, even for -0.0
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::No,
+        // Exact, and a multiple of the ulp of `lhs` if `|lhs| >= 1`
+        subnormal: fp.subnormal,
+        // Zero for the integers
+        fractional: fp.fractional,
+        large: Possible::No,
+        nan: any!(fp.nan, fp.infinite),
+        positive: any!(fp.positive, fp.negative),
+        negative: fp.negative,
+        infinite: fp.infinite,
+    }
+}
+
+/// `core::ops::signum`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn signum(fp: &FP) -> FP {
+    FP {
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        large: Possible::No,
+        infinite: Possible::No,
+        ..*fp
+    }
+}
+
+/// `core::ops::sqrt`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn sqrt(fp: &FP) -> FP {
+    FP {
+        nan: any!(fp.nan, fp.negative),
+        // Even the square root of the smallest subnormal is normal
+        subnormal: Possible::No,
+        fractional: fp.positive,
+        ..*fp
+    }
+}
+
+/// `core::ops::exp`
+#[cfg(feature = "exp-log")]
+#[inline]
+pub const fn exp(fp: &FP) -> FP {
+    exp_with(RoundingMode::ToNearest, fp)
+}
+
+/// `exp`, rounded in the direction `mode`
+#[cfg(feature = "exp-log")]
+#[inline]
+pub const fn exp_with(mode: RoundingMode, fp: &FP) -> FP {
+    exp_outcome(mode, fp).fp
+}
+
+/// `exp`, with the special cases it handled
+#[cfg(feature = "exp-log")]
+pub(crate) const fn exp_outcome(mode: RoundingMode, fp: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    // Below about -745 (or -104, ...)
+    let underflow = all!(fp.negative, fp.large, Possible::ShouldNot);
+    cases = cases.fire("underflow", underflow);
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(crate::config::round_underflow(
+            FP {
+                positive: Possible::Yes,
+                negative: Possible::No,
+                // exp(-inf) is +0.0
+                pos_zero: all!(fp.negative, fp.infinite),
+                neg_zero: Possible::No,
+                subnormal: fp.negative,
+                fractional: any!(fp.positive, fp.negative),
+                large: fp.positive,
+                infinite: fp.positive,
+                nan: fp.nan,
+            },
+            underflow,
+            mode,
+        ))
+    }
+}
+
+/// `core::ops::exp2`
+#[cfg(feature = "exp-log")]
+#[inline]
+pub const fn exp2(fp: &FP) -> FP {
+    exp2_with(RoundingMode::ToNearest, fp)
+}
+
+/// `exp2`, rounded in the direction `mode`
+#[cfg(feature = "exp-log")]
+#[inline]
+pub const fn exp2_with(mode: RoundingMode, fp: &FP) -> FP {
+    exp2_outcome(mode, fp).fp
+}
+
+/// `exp2`, with the special cases it handled
+#[cfg(feature = "exp-log")]
+pub(crate) const fn exp2_outcome(mode: RoundingMode, fp: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    // Only below -1022 (or -126, ...) does it leave the normals
+    let underflow = all!(fp.negative, fp.large, Possible::ShouldNot);
+    cases = cases.fire("underflow", underflow);
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(crate::config::round_underflow(
+            FP {
+                positive: any!(fp.positive, fp.negative),
+                negative: Possible::No,
+                // exp2(-inf) is +0.0
+                pos_zero: all!(fp.negative, fp.infinite),
+                neg_zero: Possible::No,
+                subnormal: all!(fp.negative, fp.large),
+                // The other integers give powers of two, and the subnormals round to 1.0
+                fractional: any!(fp.fractional, fp.negative),
+                // exp2(1.0) is 2.0
+                large: fp.positive,
+                // Only from 1024 (or 128, ...)
+                infinite: all!(fp.positive, any!(fp.large, fp.infinite)),
+                nan: fp.nan,
+            },
+            underflow,
+            mode,
+        ))
+    }
+}
+
+/// `core::ops::to_degrees`
+#[cfg(feature = "trig")]
+#[inline]
+pub const fn to_degrees(fp: &FP) -> FP {
+    FP {
+        // May reach Infinity with large values
+        infinite: Possible::Yes,
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.positive, fp.negative),
+        ..*fp
+    }
+}
+
+/// The logarithms in any base
+///
+/// Only the base changes which arguments give integers, like the powers of
+/// two for `log2`, and none of them overflow nor underflow: the possibilities
+/// cannot tell the bases apart, `ln`, `log2` and `log10` share this model.
+#[cfg(feature = "exp-log")]
+#[inline]
+const fn logarithm(fp: &FP) -> FP {
+    FP {
+        // From 1.0
+        positive: fp.positive,
+        // Below 1.0
+        negative: any!(fp.zero(), all!(fp.positive, fp.fractional)),
+        // log(1.0) is +0.0
+        pos_zero: fp.positive,
+        neg_zero: Possible::No,
+        // log(1 + EPSILON) is normal
+        subnormal: Possible::No,
+        fractional: all!(fp.positive, any!(fp.fractional, fp.large)),
+        // Far from 1.0
+        large: all!(fp.positive, any!(fp.fractional, fp.large)),
+        // Even the logarithm of the largest float is far from overflowing
+        infinite: any!(fp.zero(), all!(fp.positive, fp.infinite)),
+        nan: any!(fp.nan, fp.negative),
+    }
+}
+
+/// `core::ops::ln`
+#[cfg(feature = "exp-log")]
+#[inline]
+pub const fn ln(fp: &FP) -> FP {
+    logarithm(fp)
+}
+
+/// `core::ops::log2`, the model of `ln`
+///
+/// `log2(8.0)` is exactly `3.0`, but so is the rounding of `ln` near `e^3`.
+#[cfg(feature = "exp-log")]
+#[inline]
+pub const fn log2(fp: &FP) -> FP {
+    logarithm(fp)
+}
+
+/// `core::ops::log10`, the model of `ln`
+#[cfg(feature = "exp-log")]
+#[inline]
+pub const fn log10(fp: &FP) -> FP {
+    logarithm(fp)
+}
+
+/// `core::ops::to_radians`
+#[cfg(feature = "trig")]
+#[inline]
+pub const fn to_radians(fp: &FP) -> FP {
+    to_radians_with(RoundingMode::ToNearest, fp)
+}
+
+/// `to_radians`, rounded in the direction `mode`
+#[cfg(feature = "trig")]
+#[inline]
+pub const fn to_radians_with(mode: RoundingMode, fp: &FP) -> FP {
+    to_radians_outcome(mode, fp).fp
+}
+
+/// `to_radians`, with the special cases it handled
+#[cfg(feature = "trig")]
+pub(crate) const fn to_radians_outcome(mode: RoundingMode, fp: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    // Small normal values become subnormal, and the smallest subnormals underflow
+    let underflow = all!(fp.subnormal, Possible::ShouldNot);
+    cases = cases.fire("underflow", underflow);
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(crate::config::round_underflow(
+            FP {
+                subnormal: any!(fp.positive, fp.negative),
+                fractional: any!(fp.positive, fp.negative),
+                ..*fp
+            },
+            underflow,
+            mode,
+        ))
+    }
+}
+
+/// `core::ops::cbrt`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn cbrt(fp: &FP) -> FP {
+    FP {
+        // Even the cube root of the smallest subnormal is normal
+        subnormal: Possible::No,
+        fractional: any!(fp.positive, fp.negative),
+        ..*fp
+    }
+}
+
+/// `core::ops::sin`
+#[cfg(feature = "trig")]
+#[inline]
+pub const fn sin(fp: &FP) -> FP {
+    FP {
+        positive: Possible::Yes,
+        negative: Possible::Yes,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        // sin(x) == x for the subnormals, and no other float is that close to a multiple of π
+        subnormal: fp.subnormal,
+        fractional: any!(fp.positive, fp.negative),
+        large: Possible::No,
+        infinite: Possible::No,
+        nan: any!(fp.nan, fp.infinite),
+    }
+}
+
+/// `core::ops::cos`
+#[cfg(feature = "trig")]
+#[inline]
+pub const fn cos(fp: &FP) -> FP {
+    FP {
+        positive: any!(fp.positive, fp.negative),
+        // Only beyond π/2
+        negative: fp.large,
+        // No float is close enough to an odd multiple of π/2
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        // Even cos(1.0), but cos(x) == 1.0 for the zeros and the subnormals
+        fractional: any!(fp.positive, fp.negative),
+        large: Possible::No,
+        infinite: Possible::No,
+        nan: any!(fp.nan, fp.infinite),
+    }
+}
+
+/// `core::ops::tan`
+#[cfg(feature = "trig")]
+#[inline]
+pub const fn tan(fp: &FP) -> FP {
+    FP {
+        positive: Possible::Yes,
+        negative: Possible::Yes,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: fp.subnormal,
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.positive, fp.negative),
+        infinite: Possible::Yes,
+        nan: any!(fp.nan, fp.infinite),
+    }
+}
+
+/// `core::ops::asin`
+#[cfg(feature = "trig")]
+#[inline]
+pub const fn asin(fp: &FP) -> FP {
+    FP {
+        infinite: Possible::No,
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.positive, fp.negative),
+        nan: Possible::Yes,
+        ..*fp
+    }
+}
+
+/// `core::ops::acos`
+#[cfg(feature = "trig")]
+#[inline]
+pub const fn acos(_: &FP) -> FP {
+    FP {
+        positive: Possible::Yes,
+        negative: Possible::No,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::No,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::No,
+        nan: Possible::Yes,
+    }
+}
+
+/// `core::ops::atan`
+#[cfg(feature = "trig")]
+#[inline]
+pub const fn atan(fp: &FP) -> FP {
+    FP {
+        infinite: Possible::No,
+        fractional: any!(fp.positive, fp.negative),
+        // atan(inf) is π/2
+        large: any!(fp.large, fp.infinite),
+        ..*fp
+    }
+}
+
+/// `core::ops::exp_m1`
+#[cfg(feature = "exp-log")]
+#[inline]
+pub const fn exp_m1(fp: &FP) -> FP {
+    FP {
+        infinite: fp.positive,
+        fractional: any!(fp.positive, fp.negative),
+        large: fp.positive,
+        ..*fp
+    }
+}
+
+/// `core::ops::ln_1p`
+#[cfg(feature = "exp-log")]
+#[inline]
+pub const fn ln_1p(fp: &FP) -> FP {
+    FP {
+        nan: any!(fp.nan, fp.negative),
+        infinite: any!(fp.infinite, fp.negative),
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.large, fp.negative),
+        ..*fp
+    }
+}
+
+/// `core::ops::sinh`
+#[cfg(feature = "hyperbolic")]
+#[inline]
+pub const fn sinh(fp: &FP) -> FP {
+    FP {
+        infinite: Possible::Yes,
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.positive, fp.negative),
+        ..*fp
+    }
+}
+
+/// `core::ops::cosh`
+#[cfg(feature = "hyperbolic")]
+#[inline]
+pub const fn cosh(fp: &FP) -> FP {
+    FP {
+        positive: Possible::Yes,
+        negative: Possible::No,
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.positive, fp.negative),
+        infinite: Possible::Yes,
+        nan: fp.nan,
+    }
+}
+
+/// `core::ops::tanh`
+#[cfg(feature = "hyperbolic")]
+#[inline]
+pub const fn tanh(fp: &FP) -> FP {
+    FP {
+        infinite: Possible::No,
+        fractional: any!(fp.positive, fp.negative),
+        large: Possible::No,
+        ..*fp
+    }
+}
+
+/// `core::ops::asinh`
+#[cfg(feature = "hyperbolic")]
+#[inline]
+pub const fn asinh(fp: &FP) -> FP {
+    FP {
+        infinite: Possible::Yes,
+        fractional: any!(fp.positive, fp.negative),
+        large: fp.large,
+        ..*fp
+    }
+}
+
+/// `core::ops::acosh`
+#[cfg(feature = "hyperbolic")]
+#[inline]
+pub const fn acosh(_: &FP) -> FP {
+    FP {
+        positive: Possible::Yes,
+        negative: Possible::No,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::No,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::Yes,
+        nan: Possible::Yes,
+    }
+}
+
+/// `core::ops::atanh`
+#[cfg(feature = "hyperbolic")]
+#[inline]
+pub const fn atanh(fp: &FP) -> FP {
+    FP {
+        infinite: Possible::Yes,
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.positive, fp.negative),
+        nan: Possible::Yes,
+        ..*fp
+    }
+}
+
+/// `core::ops::recip`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn recip(fp: &FP) -> FP {
+    recip_with(RoundingMode::ToNearest, fp)
+}
+
+/// `recip`, rounded in the direction `mode`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn recip_with(mode: RoundingMode, fp: &FP) -> FP {
+    recip_outcome(mode, fp).fp
+}
+
+/// `recip`, telling the infinities from an overflow apart
+#[cfg(feature = "arith")]
+#[inline]
+pub(crate) const fn recip_outcome(mode: RoundingMode, fp: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    // The reciprocal of the smallest subnormals
+    let overflow = all!(fp.subnormal, Possible::ShouldNot);
+    cases = cases.fire("overflow", overflow);
+
+    let res = FP {
+        pos_zero: fp.infinite,
+        neg_zero: fp.infinite,
+        infinite: fp.zero(),
+        // The reciprocal of the largest normal values
+        subnormal: any!(fp.positive, fp.negative),
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.positive, fp.negative),
+        ..*fp
+    };
+
+    Outcome {
+        cases,
+        ..crate::config::round_overflow(res, overflow, mode)
+    }
+}
+
+/// `core::ops::powi`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn powi(fp: &FP) -> FP {
+    FP {
+        positive: Possible::Yes,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: any!(fp.positive, fp.negative),
+        // Negative exponents
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.positive, fp.negative),
+        infinite: Possible::Yes,
+        ..*fp
+    }
+}
+
+/// `core::ops::next_up`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn next_up(fp: &FP) -> FP {
+    FP {
+        nan: fp.nan,
+        // The greatest negative subnormal goes to -0.0
+        pos_zero: Possible::No,
+        neg_zero: all!(fp.subnormal, fp.negative),
+        // -MIN_POSITIVE goes to the least negative subnormal
+        subnormal: any!(fp.subnormal, fp.zero(), fp.negative),
+        // The integers below `2^53` are followed by fractional values
+        fractional: any!(fp.zero(), fp.positive, fp.negative),
+        // 1.0 goes to `1.0 + EPSILON`, and -inf to MIN
+        large: any!(fp.large, fp.positive, fp.infinite),
+        // MAX goes to infinity, and infinity stays
+        infinite: fp.positive,
+        // Zeros go to the smallest positive subnormal
+        positive: any!(fp.positive, fp.zero()),
+        // -inf goes to MIN
+        negative: fp.negative,
+    }
+}
+
+/// `core::ops::next_down`
+#[cfg(feature = "arith")]
+#[inline]
+pub const fn next_down(fp: &FP) -> FP {
+    neg(&next_up(&neg(fp)))
+}
@@ -0,0 +1,397 @@
+//! Seedable generator of random programs, to fuzz consumers of the crate.
+//!
+//! A `Program` is a DAG of `f64` operations. Its possibilities are computed
+//! with the models of `core::ops`, and it can be evaluated on concrete inputs
+//! to compare both.
+//!
+//! ```
+//! use fn_num_types::generator::Generator;
+//!
+//! let mut generator = Generator::new(42);
+//! let program = generator.program(2, 8);
+//! let inputs = generator.inputs(&program);
+//!
+//! let result = program.evaluate(&inputs);
+//!
+//! match program.possibilities() {
+//!     fn_num_types::FnArgFloat::F64(fp) => assert!(fp.accept(result)),
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+use crate::core::ops;
+use crate::float_op::round_ties_even;
+use crate::{Error, Field, FloatPossibilities, FnArgFloat, Possible};
+
+type Unary = (&'static str, fn(&FnArgFloat) -> FnArgFloat, fn(f64) -> f64);
+type Binary = (
+    &'static str,
+    fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat,
+    fn(f64, f64) -> f64,
+);
+
+const UNARY: &[Unary] = &[
+    ("neg", ops::neg, |x| -x),
+    ("abs", ops::abs, f64::abs),
+    ("ceil", ops::ceil, f64::ceil),
+    ("floor", ops::floor, f64::floor),
+    ("round", ops::round, f64::round),
+    ("round_ties_even", ops::round_ties_even, round_ties_even),
+    ("trunc", ops::trunc, f64::trunc),
+    ("fract", ops::fract, f64::fract),
+    ("signum", ops::signum, f64::signum),
+    ("sqrt", ops::sqrt, f64::sqrt),
+    ("exp", ops::exp, f64::exp),
+    ("exp2", ops::exp2, f64::exp2),
+    ("ln", ops::ln, f64::ln),
+    ("log2", ops::log2, f64::log2),
+    ("log10", ops::log10, f64::log10),
+    ("to_degrees", ops::to_degrees, f64::to_degrees),
+    ("to_radians", ops::to_radians, f64::to_radians),
+    ("cbrt", ops::cbrt, f64::cbrt),
+    ("sin", ops::sin, f64::sin),
+    ("cos", ops::cos, f64::cos),
+    ("tan", ops::tan, f64::tan),
+    ("asin", ops::asin, f64::asin),
+    ("acos", ops::acos, f64::acos),
+    ("atan", ops::atan, f64::atan),
+    ("exp_m1", ops::exp_m1, f64::exp_m1),
+    ("ln_1p", ops::ln_1p, f64::ln_1p),
+    ("sinh", ops::sinh, f64::sinh),
+    ("cosh", ops::cosh, f64::cosh),
+    ("tanh", ops::tanh, f64::tanh),
+    ("asinh", ops::asinh, f64::asinh),
+    ("acosh", ops::acosh, f64::acosh),
+    ("atanh", ops::atanh, f64::atanh),
+    ("recip", ops::recip, f64::recip),
+    ("powi", ops::powi, |x| x.powi(2)),
+];
+
+const BINARY: &[Binary] = &[
+    ("add", ops::add, |x, y| x + y),
+    ("mul", ops::mul, |x, y| x * y),
+    ("div", ops::div, |x, y| x / y),
+    ("rem", ops::rem, |x, y| x % y),
+    ("div_euclid", ops::div_euclid, f64::div_euclid),
+    ("rem_euclid", ops::rem_euclid, f64::rem_euclid),
+    ("powf", ops::powf, f64::powf),
+    ("hypot", ops::hypot, f64::hypot),
+    ("atan2", ops::atan2, f64::atan2),
+    ("copysign", ops::copysign, f64::copysign),
+    ("max", ops::max, f64::max),
+    ("min", ops::min, f64::min),
+    ("log", ops::log, f64::log),
+];
+
+/// Values tried first when generating inputs
+const VALUES: &[f64] = &[
+    f64::NAN,
+    f64::NEG_INFINITY,
+    f64::MIN,
+    -core::f64::consts::PI,
+    -1.0,
+    -f64::MIN_POSITIVE,
+    -1.0e-308,
+    -0.0,
+    0.0,
+    1.0e-308,
+    f64::MIN_POSITIVE,
+    1.0,
+    core::f64::consts::PI,
+    f64::MAX,
+    f64::INFINITY,
+];
+
+/// A node of a `Program`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Node {
+    /// The input at this index
+    Input(usize),
+    /// A unary operation applied to a previous node
+    Unary(&'static str, usize),
+    /// A binary operation applied to two previous nodes
+    Binary(&'static str, usize, usize),
+}
+
+/// A DAG of operations, whose result is the last node
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    /// Possibilities of each input
+    pub inputs: Vec<FloatPossibilities>,
+    pub nodes: Vec<Node>,
+}
+
+fn unary(name: &str) -> &'static Unary {
+    UNARY
+        .iter()
+        .find(|op| op.0 == name)
+        .expect("Unknown operation")
+}
+
+fn binary(name: &str) -> &'static Binary {
+    BINARY
+        .iter()
+        .find(|op| op.0 == name)
+        .expect("Unknown operation")
+}
+
+impl Program {
+    /// Possibilities of the result, computed by the models
+    pub fn possibilities(&self) -> FnArgFloat {
+        let mut values: Vec<FnArgFloat> = vec![];
+
+        for node in &self.nodes {
+            let value = match *node {
+                Node::Input(i) => FnArgFloat::F64(self.inputs[i]),
+                Node::Unary(name, a) => (unary(name).1)(&values[a]),
+                Node::Binary(name, a, b) => (binary(name).1)(&values[a], &values[b]),
+            };
+            values.push(value);
+        }
+
+        *values.last().expect("Empty program")
+    }
+
+    /// Concrete result for those inputs
+    pub fn evaluate(&self, inputs: &[f64]) -> f64 {
+        let mut values: Vec<f64> = vec![];
+
+        for node in &self.nodes {
+            let value = match *node {
+                Node::Input(i) => inputs[i],
+                Node::Unary(name, a) => (unary(name).2)(values[a]),
+                Node::Binary(name, a, b) => (binary(name).2)(values[a], values[b]),
+            };
+            values.push(value);
+        }
+
+        *values.last().expect("Empty program")
+    }
+}
+
+/// Seedable pseudo-random generator (SplitMix64)
+#[derive(Debug, Clone)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        Generator { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn possible(&mut self) -> Possible {
+        if self.next() & 1 == 0 {
+            Possible::No
+        } else {
+            Possible::Yes
+        }
+    }
+
+    fn possibilities(&mut self) -> FloatPossibilities {
+        loop {
+            let fp = FloatPossibilities {
+                nan: self.possible(),
+                pos_zero: self.possible(),
+                neg_zero: self.possible(),
+                subnormal: self.possible(),
+                fractional: self.possible(),
+                large: self.possible(),
+                infinite: self.possible(),
+                positive: self.possible(),
+                negative: self.possible(),
+            };
+
+            if VALUES.iter().any(|value| fp.accept(*value)) {
+                return fp;
+            }
+        }
+    }
+
+    /// Generates a program with `inputs` inputs followed by `len` operations
+    ///
+    /// # Panics
+    ///
+    /// If `inputs` is zero
+    pub fn program(&mut self, inputs: usize, len: usize) -> Program {
+        assert!(inputs > 0, "No inputs");
+
+        let mut nodes: Vec<Node> = (0..inputs).map(Node::Input).collect();
+
+        for _ in 0..len {
+            let a = self.below(nodes.len());
+
+            let node = if self.below(4) == 0 {
+                let b = self.below(nodes.len());
+                Node::Binary(BINARY[self.below(BINARY.len())].0, a, b)
+            } else {
+                Node::Unary(UNARY[self.below(UNARY.len())].0, a)
+            };
+
+            nodes.push(node);
+        }
+
+        Program {
+            inputs: (0..inputs).map(|_| self.possibilities()).collect(),
+            nodes,
+        }
+    }
+
+    /// Generates a concrete value accepted by `fp`
+    fn value(&mut self, fp: &FloatPossibilities) -> f64 {
+        loop {
+            let value = if self.next() & 1 == 0 {
+                VALUES[self.below(VALUES.len())]
+            } else {
+                f64::from_bits(self.next())
+            };
+
+            if fp.accept(value) {
+                return value;
+            }
+        }
+    }
+
+    /// Generates concrete inputs accepted by the possibilities of the inputs of `program`
+    pub fn inputs(&mut self, program: &Program) -> Vec<f64> {
+        program.inputs.iter().map(|fp| self.value(fp)).collect()
+    }
+
+    /// Searches concrete arguments, accepted by `args`, for which the result
+    /// of the operation `op` is in `target`.
+    ///
+    /// All the combinations of special values are tried first,
+    /// then `samples` random arguments.
+    ///
+    /// ```
+    /// use fn_num_types::generator::{Generator, Witness};
+    /// use fn_num_types::{Field, FloatPossibilities, Possible};
+    ///
+    /// let negative = FloatPossibilities {
+    ///     nan: Possible::No,
+    ///     pos_zero: Possible::No,
+    ///     neg_zero: Possible::No,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::No,
+    ///     positive: Possible::No,
+    ///     negative: Possible::Yes,
+    /// };
+    ///
+    /// let mut generator = Generator::new(0);
+    /// let witness = generator.find_witness("sqrt", &[negative], Field::Nan, 100);
+    ///
+    /// assert!(matches!(witness, Ok(Witness::Found { .. })));
+    /// ```
+    pub fn find_witness(
+        &mut self,
+        op: &str,
+        args: &[FloatPossibilities],
+        target: Field,
+        samples: usize,
+    ) -> Result<Witness, Error> {
+        let arity = |found| Error::Arity {
+            op: op.to_owned(),
+            found,
+        };
+
+        let unary = UNARY.iter().find(|u| u.0 == op);
+        let binary = BINARY.iter().find(|b| b.0 == op);
+
+        match (args.len(), unary, binary) {
+            (1, Some(_), _) | (2, _, Some(_)) => {}
+            (_, None, None) => return Err(Error::UnknownOp(op.to_owned())),
+            _ => return Err(arity(args.len())),
+        }
+
+        let apply = |x: &[f64]| match (x, unary, binary) {
+            ([a], Some(unary), _) => (unary.2)(*a),
+            ([a, b], _, Some(binary)) => (binary.2)(*a, *b),
+            _ => unreachable!(),
+        };
+
+        let found = |inputs: Vec<f64>| {
+            let result = apply(&inputs);
+            in_field(result, target).then_some(Witness::Found { inputs, result })
+        };
+
+        // Special values
+        let specials: Vec<Vec<f64>> = args
+            .iter()
+            .map(|fp| VALUES.iter().copied().filter(|v| fp.accept(*v)).collect())
+            .collect();
+
+        let mut tried = 0;
+        let mut combination = vec![0; args.len()];
+
+        if specials.iter().all(|values| !values.is_empty()) {
+            loop {
+                let inputs = combination
+                    .iter()
+                    .zip(&specials)
+                    .map(|(i, values)| values[*i])
+                    .collect();
+
+                tried += 1;
+                if let Some(witness) = found(inputs) {
+                    return Ok(witness);
+                }
+
+                // Next combination
+                let Some(i) = (0..args.len()).find(|i| combination[*i] + 1 < specials[*i].len())
+                else {
+                    break;
+                };
+                combination[i] += 1;
+                combination[..i].iter_mut().for_each(|c| *c = 0);
+            }
+        }
+
+        for _ in 0..samples {
+            let inputs = args.iter().map(|fp| self.value(fp)).collect();
+
+            tried += 1;
+            if let Some(witness) = found(inputs) {
+                return Ok(witness);
+            }
+        }
+
+        Ok(Witness::NotFound { tried })
+    }
+}
+
+/// Result of `Generator::find_witness`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Witness {
+    /// Those arguments give this result
+    Found { inputs: Vec<f64>, result: f64 },
+    /// None of the `tried` arguments reached the target
+    NotFound { tried: usize },
+}
+
+fn in_field(value: f64, field: Field) -> bool {
+    match field {
+        Field::Nan => value.is_nan(),
+        Field::PosZero => value == 0.0 && value.is_sign_positive(),
+        Field::NegZero => value == 0.0 && value.is_sign_negative(),
+        Field::Subnormal => value.is_subnormal(),
+        Field::Fractional => value.is_finite() && value.fract() != 0.0,
+        Field::Large => value.is_finite() && value.abs() > 1.0,
+        Field::Infinite => value.is_infinite(),
+        Field::Positive => !value.is_nan() && value.is_sign_positive(),
+        Field::Negative => !value.is_nan() && value.is_sign_negative(),
+    }
+}
@@ -0,0 +1,58 @@
+use crate::config::{round_overflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+pub const fn hypot(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, hypot_fp)
+}
+
+/// `hypot`, rounded in the direction `mode`
+pub const fn hypot_with(mode: RoundingMode, a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, |fp1, fp2| hypot_fp_with(mode, fp1, fp2))
+}
+
+/// `core::ops::hypot`
+#[inline]
+pub const fn hypot_fp(fp1: &FP, fp2: &FP) -> FP {
+    hypot_fp_with(RoundingMode::ToNearest, fp1, fp2)
+}
+
+/// `core::ops::hypot`, rounded in the direction `mode`
+#[inline]
+pub const fn hypot_fp_with(mode: RoundingMode, fp1: &FP, fp2: &FP) -> FP {
+    hypot_outcome(mode, fp1, fp2).fp
+}
+
+/// `core::ops::hypot`, telling the infinities from an overflow apart
+pub(crate) const fn hypot_outcome(mode: RoundingMode, fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan1 = any!(fp1.positive, fp1.negative);
+    let non_nan2 = any!(fp2.positive, fp2.negative);
+
+    // Two large values may overflow
+    let overflow = all!(fp1.large, fp2.large, Possible::ShouldNot);
+    cases = cases.fire("overflow", overflow);
+
+    // hypot(inf, NaN) == inf
+    let infinite = any!(fp1.infinite, fp2.infinite);
+    cases = cases.fire("infinity over NaN", all!(infinite, any!(fp1.nan, fp2.nan)));
+
+    let res = FP {
+        nan: any!(fp1.nan, fp2.nan),
+        pos_zero: all!(fp1.zero(), fp2.zero()),
+        neg_zero: Possible::No,
+        // Not less than the absolute value of each argument
+        subnormal: any!(fp1.subnormal, fp2.subnormal),
+        // Like `hypot(1.0, 1.0)`
+        fractional: all!(non_nan1, non_nan2),
+        // Like `hypot(0.75, 0.75)`
+        large: all!(non_nan1, non_nan2),
+        infinite,
+        positive: any!(infinite, all!(non_nan1, non_nan2)),
+        negative: Possible::No,
+    };
+
+    Outcome {
+        cases,
+        ..round_overflow(res, overflow, mode)
+    }
+}
@@ -0,0 +1,272 @@
+use crate::Possible;
+
+/// Width and signedness of an integer type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IntKind {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+}
+
+impl IntKind {
+    pub const fn is_signed(self) -> bool {
+        matches!(
+            self,
+            IntKind::I8 | IntKind::I16 | IntKind::I32 | IntKind::I64 | IntKind::I128
+        )
+    }
+
+    pub const fn bits(self) -> u32 {
+        match self {
+            IntKind::I8 | IntKind::U8 => 8,
+            IntKind::I16 | IntKind::U16 => 16,
+            IntKind::I32 | IntKind::U32 => 32,
+            IntKind::I64 | IntKind::U64 => 64,
+            IntKind::I128 | IntKind::U128 => 128,
+        }
+    }
+}
+
+/// Is that value possible to reach, for an integer?
+///
+/// `positive` and `negative` exclude zero, and include `MAX` and `MIN`.
+/// For unsigned integers, `MIN` is zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntPossibilities {
+    pub zero: Possible,
+    pub positive: Possible,
+    pub negative: Possible,
+    /// The value is `MIN`
+    pub min_value: Possible,
+    /// The value is `MAX`
+    pub max_value: Possible,
+}
+
+pub type IP = IntPossibilities;
+
+/// A primitive integer, whose values can be checked against `IntPossibilities`
+pub trait IntValue: Copy + Ord {
+    const KIND: IntKind;
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+}
+
+macro_rules! int_value {
+    ($($ty:ident => $kind:ident),* $(,)?) => {
+        $(
+            impl IntValue for $ty {
+                const KIND: IntKind = IntKind::$kind;
+                const MIN: Self = $ty::MIN;
+                const MAX: Self = $ty::MAX;
+                const ZERO: Self = 0;
+            }
+        )*
+    };
+}
+
+int_value!(
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+);
+
+impl IntPossibilities {
+    /// Returns true if the value is accepted
+    ///
+    /// ```
+    /// use fn_num_types::{IntPossibilities, Possible};
+    ///
+    /// let non_negative = IntPossibilities {
+    ///     zero: Possible::Yes,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::No,
+    ///     min_value: Possible::No,
+    ///     max_value: Possible::Yes,
+    /// };
+    ///
+    /// assert!(non_negative.accept(0i8));
+    /// assert!(non_negative.accept(i8::MAX));
+    /// assert!(!non_negative.accept(-1i8));
+    /// // MIN is zero
+    /// assert!(!non_negative.accept(0u8));
+    /// ```
+    pub fn accept<T: IntValue>(&self, value: T) -> bool {
+        let checks = [
+            (value == T::ZERO, self.zero),
+            (value > T::ZERO, self.positive),
+            (value < T::ZERO, self.negative),
+            (value == T::MIN, self.min_value),
+            (value == T::MAX, self.max_value),
+        ];
+
+        checks
+            .iter()
+            .all(|(applies, possible)| !applies || *possible != Possible::No)
+    }
+
+    /// The possibilities of exactly `value`
+    ///
+    /// ```
+    /// use fn_num_types::{IntPossibilities, Possible};
+    ///
+    /// let ip = IntPossibilities::from_value(i64::MAX);
+    /// assert_eq!(ip.positive, Possible::Yes);
+    /// assert_eq!(ip.max_value, Possible::Yes);
+    /// assert_eq!(ip.zero, Possible::No);
+    /// ```
+    pub fn from_value<T: IntValue>(value: T) -> Self {
+        let possible = |b: bool| if b { Possible::Yes } else { Possible::No };
+
+        IntPossibilities {
+            zero: possible(value == T::ZERO),
+            positive: possible(value > T::ZERO),
+            negative: possible(value < T::ZERO),
+            min_value: possible(value == T::MIN),
+            max_value: possible(value == T::MAX),
+        }
+    }
+
+    pub const fn union(&self, rhs: &Self) -> Self {
+        IntPossibilities {
+            zero: Possible::any(self.zero, rhs.zero),
+            positive: Possible::any(self.positive, rhs.positive),
+            negative: Possible::any(self.negative, rhs.negative),
+            min_value: Possible::any(self.min_value, rhs.min_value),
+            max_value: Possible::any(self.max_value, rhs.max_value),
+        }
+    }
+
+    /// Keeps the possibilities consistent with the kind:
+    /// unsigned integers are never negative, and their `MIN` is zero
+    pub(crate) const fn fit(&self, kind: IntKind) -> Self {
+        if kind.is_signed() {
+            *self
+        } else {
+            IntPossibilities {
+                negative: Possible::No,
+                min_value: self.zero,
+                ..*self
+            }
+        }
+    }
+}
+
+/// The possibilities of an integer of a given type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FnArgInt {
+    I8(IntPossibilities),
+    I16(IntPossibilities),
+    I32(IntPossibilities),
+    I64(IntPossibilities),
+    I128(IntPossibilities),
+    U8(IntPossibilities),
+    U16(IntPossibilities),
+    U32(IntPossibilities),
+    U64(IntPossibilities),
+    U128(IntPossibilities),
+}
+
+impl FnArgInt {
+    pub const fn new(kind: IntKind, possibilities: IntPossibilities) -> Self {
+        match kind {
+            IntKind::I8 => FnArgInt::I8(possibilities),
+            IntKind::I16 => FnArgInt::I16(possibilities),
+            IntKind::I32 => FnArgInt::I32(possibilities),
+            IntKind::I64 => FnArgInt::I64(possibilities),
+            IntKind::I128 => FnArgInt::I128(possibilities),
+            IntKind::U8 => FnArgInt::U8(possibilities),
+            IntKind::U16 => FnArgInt::U16(possibilities),
+            IntKind::U32 => FnArgInt::U32(possibilities),
+            IntKind::U64 => FnArgInt::U64(possibilities),
+            IntKind::U128 => FnArgInt::U128(possibilities),
+        }
+    }
+
+    pub const fn kind(&self) -> IntKind {
+        match self {
+            FnArgInt::I8(_) => IntKind::I8,
+            FnArgInt::I16(_) => IntKind::I16,
+            FnArgInt::I32(_) => IntKind::I32,
+            FnArgInt::I64(_) => IntKind::I64,
+            FnArgInt::I128(_) => IntKind::I128,
+            FnArgInt::U8(_) => IntKind::U8,
+            FnArgInt::U16(_) => IntKind::U16,
+            FnArgInt::U32(_) => IntKind::U32,
+            FnArgInt::U64(_) => IntKind::U64,
+            FnArgInt::U128(_) => IntKind::U128,
+        }
+    }
+
+    pub const fn possibilities(&self) -> &IntPossibilities {
+        match self {
+            FnArgInt::I8(ip)
+            | FnArgInt::I16(ip)
+            | FnArgInt::I32(ip)
+            | FnArgInt::I64(ip)
+            | FnArgInt::I128(ip)
+            | FnArgInt::U8(ip)
+            | FnArgInt::U16(ip)
+            | FnArgInt::U32(ip)
+            | FnArgInt::U64(ip)
+            | FnArgInt::U128(ip) => ip,
+        }
+    }
+}
+
+/// Possibilities of the result of an integer operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntOutcome {
+    /// The result, when the operation neither overflows nor panics
+    pub value: FnArgInt,
+    /// The operation may overflow: it panics with overflow checks (debug builds),
+    /// and wraps around without them (release builds)
+    pub overflow: Possible,
+    /// The operation may panic even without overflow checks, like a division by zero
+    pub panic: Possible,
+}
+
+/// Possibilities of the result of a `checked_*` operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntChecked {
+    /// The result, when it is `Some`
+    pub value: FnArgInt,
+    /// The result may be `None`
+    pub none: Possible,
+}
+
+/// Possibilities of the result of an `overflowing_*` operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntOverflowing {
+    /// The wrapped result
+    pub value: FnArgInt,
+    /// The returned flag may be `true`
+    pub overflow: Possible,
+}
+
+/// # Panics
+///
+/// If the arguments are not of the same type
+pub(crate) const fn same_kind(lhs: &FnArgInt, rhs: &FnArgInt) -> IntKind {
+    let kind = lhs.kind();
+    assert!(kind as u8 == rhs.kind() as u8, "Different types");
+
+    kind
+}
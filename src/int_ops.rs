@@ -0,0 +1,373 @@
+use crate::int::{same_kind, FnArgInt, IntChecked, IntKind, IntOutcome, IntOverflowing, IP};
+use crate::Possible;
+
+// The results are computed as for signed integers: `IP::fit` removes the negative values
+// of the unsigned ones, which are only reached when they overflow.
+
+/// No value, `IP::default()`
+const NONE: IP = IP {
+    zero: Possible::No,
+    positive: Possible::No,
+    negative: Possible::No,
+    min_value: Possible::No,
+    max_value: Possible::No,
+};
+
+/// How an operation overflows
+struct Overflow {
+    /// The result, without overflow
+    value: IP,
+    /// The exact result may be above `MAX`
+    up: Possible,
+    /// The exact result may be below `MIN`
+    down: Possible,
+    /// The result, wrapped around after an overflow
+    wrapped: IP,
+}
+
+/// The operations that may overflow
+#[derive(Clone, Copy)]
+enum OverflowOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+const fn add_overflow(a: &IP, b: &IP, signed: bool) -> Overflow {
+    let up = all!(a.positive, b.positive);
+    let down = if signed {
+        all!(a.negative, b.negative)
+    } else {
+        Possible::No
+    };
+
+    let wrapped = if signed {
+        IP {
+            // In [MIN, -2]
+            negative: up,
+            min_value: up,
+            // In [0, MAX]: MIN + MIN == 0 and MIN + -1 == MAX
+            zero: down,
+            positive: down,
+            max_value: down,
+        }
+    } else {
+        // In [0, MAX - 1]
+        IP {
+            zero: up,
+            positive: up,
+            ..NONE
+        }
+    };
+
+    Overflow {
+        value: IP {
+            zero: any!(
+                all!(a.zero, b.zero),
+                all!(a.positive, b.negative),
+                all!(a.negative, b.positive)
+            ),
+            positive: any!(a.positive, b.positive),
+            negative: any!(a.negative, b.negative),
+            min_value: any!(
+                all!(a.min_value, b.zero),
+                all!(a.zero, b.min_value),
+                all!(a.negative, b.negative)
+            ),
+            max_value: any!(
+                all!(a.max_value, b.zero),
+                all!(a.zero, b.max_value),
+                all!(a.positive, b.positive)
+            ),
+        },
+        up,
+        down,
+        wrapped,
+    }
+}
+
+const fn sub_overflow(a: &IP, b: &IP, signed: bool) -> Overflow {
+    let (up, down) = if signed {
+        // 0 - MIN, 1 - MIN and -2 - MAX
+        (
+            any!(all!(a.zero, b.min_value), all!(a.positive, b.negative)),
+            all!(a.negative, b.positive),
+        )
+    } else {
+        (Possible::No, b.positive)
+    };
+
+    let wrapped = IP {
+        // In [MIN, -1]
+        negative: up,
+        min_value: up,
+        // In [1, MAX]
+        positive: down,
+        max_value: down,
+        ..NONE
+    };
+
+    Overflow {
+        value: IP {
+            zero: any!(
+                all!(a.zero, b.zero),
+                all!(a.positive, b.positive),
+                all!(a.negative, b.negative)
+            ),
+            positive: any!(a.positive, b.negative),
+            negative: any!(a.negative, b.positive),
+            // -1 - MIN == MAX and -1 - MAX == MIN
+            min_value: any!(all!(a.min_value, b.zero), all!(a.negative, b.positive)),
+            max_value: any!(all!(a.max_value, b.zero), b.negative),
+        },
+        up,
+        down,
+        wrapped,
+    }
+}
+
+const fn mul_overflow(a: &IP, b: &IP, signed: bool) -> Overflow {
+    let positive = any!(all!(a.positive, b.positive), all!(a.negative, b.negative));
+    let negative = any!(all!(a.positive, b.negative), all!(a.negative, b.positive));
+
+    let (up, down) = if signed {
+        (positive, negative)
+    } else {
+        (all!(a.positive, b.positive), Possible::No)
+    };
+
+    // Any value, like 16 * 16 == 0 for `u8`
+    let overflow = any!(up, down);
+    let wrapped = IP {
+        zero: overflow,
+        positive: overflow,
+        negative: overflow,
+        min_value: overflow,
+        max_value: overflow,
+    };
+
+    Overflow {
+        value: IP {
+            zero: any!(a.zero, b.zero),
+            positive,
+            negative,
+            min_value: negative,
+            max_value: positive,
+        },
+        up,
+        down,
+        wrapped,
+    }
+}
+
+const fn overflow2(lhs: &FnArgInt, rhs: &FnArgInt, op: OverflowOp) -> (IntKind, Overflow) {
+    let kind = same_kind(lhs, rhs);
+    let (a, b, signed) = (lhs.possibilities(), rhs.possibilities(), kind.is_signed());
+    let overflow = match op {
+        OverflowOp::Add => add_overflow(a, b, signed),
+        OverflowOp::Sub => sub_overflow(a, b, signed),
+        OverflowOp::Mul => mul_overflow(a, b, signed),
+    };
+
+    (kind, overflow)
+}
+
+const fn outcome(lhs: &FnArgInt, rhs: &FnArgInt, op: OverflowOp) -> IntOutcome {
+    let (kind, overflow) = overflow2(lhs, rhs, op);
+
+    IntOutcome {
+        value: FnArgInt::new(kind, overflow.value.fit(kind)),
+        overflow: any!(overflow.up, overflow.down),
+        panic: Possible::No,
+    }
+}
+
+const fn checked(lhs: &FnArgInt, rhs: &FnArgInt, op: OverflowOp) -> IntChecked {
+    let (kind, overflow) = overflow2(lhs, rhs, op);
+
+    IntChecked {
+        value: FnArgInt::new(kind, overflow.value.fit(kind)),
+        none: any!(overflow.up, overflow.down),
+    }
+}
+
+const fn wrapping(lhs: &FnArgInt, rhs: &FnArgInt, op: OverflowOp) -> FnArgInt {
+    let (kind, overflow) = overflow2(lhs, rhs, op);
+
+    FnArgInt::new(kind, overflow.value.union(&overflow.wrapped).fit(kind))
+}
+
+const fn saturating(lhs: &FnArgInt, rhs: &FnArgInt, op: OverflowOp) -> FnArgInt {
+    let (kind, overflow) = overflow2(lhs, rhs, op);
+
+    let saturated = IP {
+        positive: overflow.up,
+        max_value: overflow.up,
+        // MIN is zero for unsigned integers
+        zero: if kind.is_signed() {
+            Possible::No
+        } else {
+            overflow.down
+        },
+        negative: overflow.down,
+        min_value: overflow.down,
+    };
+
+    FnArgInt::new(kind, overflow.value.union(&saturated).fit(kind))
+}
+
+const fn overflowing(lhs: &FnArgInt, rhs: &FnArgInt, op: OverflowOp) -> IntOverflowing {
+    let (kind, overflow) = overflow2(lhs, rhs, op);
+
+    IntOverflowing {
+        value: FnArgInt::new(kind, overflow.value.union(&overflow.wrapped).fit(kind)),
+        overflow: any!(overflow.up, overflow.down),
+    }
+}
+
+/// `lhs + rhs`
+pub const fn add(lhs: &FnArgInt, rhs: &FnArgInt) -> IntOutcome {
+    outcome(lhs, rhs, OverflowOp::Add)
+}
+
+/// `lhs.checked_add(rhs)`
+pub const fn checked_add(lhs: &FnArgInt, rhs: &FnArgInt) -> IntChecked {
+    checked(lhs, rhs, OverflowOp::Add)
+}
+
+/// `lhs.wrapping_add(rhs)`
+pub const fn wrapping_add(lhs: &FnArgInt, rhs: &FnArgInt) -> FnArgInt {
+    wrapping(lhs, rhs, OverflowOp::Add)
+}
+
+/// `lhs.saturating_add(rhs)`
+pub const fn saturating_add(lhs: &FnArgInt, rhs: &FnArgInt) -> FnArgInt {
+    saturating(lhs, rhs, OverflowOp::Add)
+}
+
+/// `lhs.overflowing_add(rhs)`
+pub const fn overflowing_add(lhs: &FnArgInt, rhs: &FnArgInt) -> IntOverflowing {
+    overflowing(lhs, rhs, OverflowOp::Add)
+}
+
+/// `lhs - rhs`
+pub const fn sub(lhs: &FnArgInt, rhs: &FnArgInt) -> IntOutcome {
+    outcome(lhs, rhs, OverflowOp::Sub)
+}
+
+/// `lhs.checked_sub(rhs)`
+pub const fn checked_sub(lhs: &FnArgInt, rhs: &FnArgInt) -> IntChecked {
+    checked(lhs, rhs, OverflowOp::Sub)
+}
+
+/// `lhs.wrapping_sub(rhs)`
+pub const fn wrapping_sub(lhs: &FnArgInt, rhs: &FnArgInt) -> FnArgInt {
+    wrapping(lhs, rhs, OverflowOp::Sub)
+}
+
+/// `lhs.saturating_sub(rhs)`
+pub const fn saturating_sub(lhs: &FnArgInt, rhs: &FnArgInt) -> FnArgInt {
+    saturating(lhs, rhs, OverflowOp::Sub)
+}
+
+/// `lhs.overflowing_sub(rhs)`
+pub const fn overflowing_sub(lhs: &FnArgInt, rhs: &FnArgInt) -> IntOverflowing {
+    overflowing(lhs, rhs, OverflowOp::Sub)
+}
+
+/// `lhs * rhs`
+pub const fn mul(lhs: &FnArgInt, rhs: &FnArgInt) -> IntOutcome {
+    outcome(lhs, rhs, OverflowOp::Mul)
+}
+
+/// `lhs.checked_mul(rhs)`
+pub const fn checked_mul(lhs: &FnArgInt, rhs: &FnArgInt) -> IntChecked {
+    checked(lhs, rhs, OverflowOp::Mul)
+}
+
+/// `lhs.wrapping_mul(rhs)`
+pub const fn wrapping_mul(lhs: &FnArgInt, rhs: &FnArgInt) -> FnArgInt {
+    wrapping(lhs, rhs, OverflowOp::Mul)
+}
+
+/// `lhs.saturating_mul(rhs)`
+pub const fn saturating_mul(lhs: &FnArgInt, rhs: &FnArgInt) -> FnArgInt {
+    saturating(lhs, rhs, OverflowOp::Mul)
+}
+
+/// `lhs.overflowing_mul(rhs)`
+pub const fn overflowing_mul(lhs: &FnArgInt, rhs: &FnArgInt) -> IntOverflowing {
+    overflowing(lhs, rhs, OverflowOp::Mul)
+}
+
+/// `lhs / rhs`, rounded toward zero
+pub const fn div(lhs: &FnArgInt, rhs: &FnArgInt) -> IntOutcome {
+    let kind = same_kind(lhs, rhs);
+    let signed = kind.is_signed();
+    let (a, b) = (lhs.possibilities(), rhs.possibilities());
+
+    let non_zero_b = any!(b.positive, b.negative);
+
+    // Division by zero, and MIN / -1, always panic
+    let panic = any!(
+        b.zero,
+        if signed {
+            all!(a.min_value, b.negative)
+        } else {
+            Possible::No
+        }
+    );
+
+    let value = IP {
+        // |a| < |b|
+        zero: all!(any!(a.zero, a.positive, a.negative), non_zero_b),
+        positive: any!(all!(a.positive, b.positive), all!(a.negative, b.negative)),
+        negative: any!(all!(a.positive, b.negative), all!(a.negative, b.positive)),
+        // MIN / 1
+        min_value: all!(a.min_value, b.positive),
+        // MAX / 1 and -MAX / -1
+        max_value: any!(all!(a.max_value, b.positive), all!(a.negative, b.negative)),
+    };
+
+    IntOutcome {
+        value: FnArgInt::new(kind, value.fit(kind)),
+        overflow: Possible::No,
+        panic,
+    }
+}
+
+/// `lhs % rhs`, with the sign of `lhs`
+pub const fn rem(lhs: &FnArgInt, rhs: &FnArgInt) -> IntOutcome {
+    let kind = same_kind(lhs, rhs);
+    let signed = kind.is_signed();
+    let (a, b) = (lhs.possibilities(), rhs.possibilities());
+
+    let non_zero_b = any!(b.positive, b.negative);
+
+    // Division by zero, and MIN % -1, always panic
+    let panic = any!(
+        b.zero,
+        if signed {
+            all!(a.min_value, b.negative)
+        } else {
+            Possible::No
+        }
+    );
+
+    let value = IP {
+        // `a` is a multiple of `b`
+        zero: all!(any!(a.zero, a.positive, a.negative), non_zero_b),
+        positive: all!(a.positive, non_zero_b),
+        negative: all!(a.negative, non_zero_b),
+        // |a % b| < |b| <= -MIN
+        min_value: Possible::No,
+        // MAX % MIN
+        max_value: all!(a.max_value, b.min_value),
+    };
+
+    IntOutcome {
+        value: FnArgInt::new(kind, value.fit(kind)),
+        overflow: Possible::No,
+        panic,
+    }
+}
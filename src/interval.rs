@@ -0,0 +1,763 @@
+//! Ranges of the values, on top of the possibilities.
+//!
+//! The possibilities only tell the classes of the values apart, like `x > 1.0`.
+//! A `RangedFloat` also carries an `Interval` containing the values, computed
+//! for each operation and narrowed by the possibilities of the result.
+//!
+//! The bounds are rounded outward, like the operations they come from.
+//! `F16`, `BF16` and `F128` only get the bounds implied by their possibilities.
+//!
+//! ```
+//! use fn_num_types::interval::{Interval, RangedFloat};
+//! use fn_num_types::{FnArgFloat, Possible, FP};
+//!
+//! let any = FnArgFloat::F64(FP {
+//!     nan: Possible::No,
+//!     pos_zero: Possible::Yes,
+//!     neg_zero: Possible::Yes,
+//!     subnormal: Possible::Yes,
+//!     fractional: Possible::Yes,
+//!     large: Possible::Yes,
+//!     infinite: Possible::Yes,
+//!     positive: Possible::Yes,
+//!     negative: Possible::Yes,
+//! });
+//!
+//! let x = RangedFloat::new(any, Interval::new(1.0, 2.0));
+//! let y = RangedFloat::new(any, Interval::new(-4.0, -3.0));
+//!
+//! let sum = x.add(&y);
+//! assert_eq!(*sum.range(), Interval::new(-3.0, -1.0));
+//! assert_eq!(sum.arg().possibilities().positive, Possible::No);
+//! assert!(!sum.accept(-0.5));
+//! ```
+
+#[cfg(feature = "full")]
+use crate::core::ops;
+use crate::{FnArgFloat, Possible, FP};
+
+/// Closed range `[min, max]` containing the values other than NaN, the infinities included
+///
+/// Like `<=`, it doesn't tell `-0.0` and `+0.0` apart: the possibilities do.
+/// An empty interval, with `min > max`, contains no value: only NaN may be possible.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Interval {
+    /// Every value
+    pub const ALL: Interval = Interval {
+        min: f64::NEG_INFINITY,
+        max: f64::INFINITY,
+    };
+
+    /// No value
+    pub const EMPTY: Interval = Interval {
+        min: f64::INFINITY,
+        max: f64::NEG_INFINITY,
+    };
+
+    /// # Panics
+    ///
+    /// If a bound is NaN
+    pub fn new(min: f64, max: f64) -> Self {
+        assert!(!min.is_nan() && !max.is_nan(), "NaN bound");
+
+        Interval { min, max }
+    }
+
+    /// The interval containing only `value`
+    pub fn point(value: f64) -> Self {
+        Interval::new(value, value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min > self.max
+    }
+
+    /// Returns true if `value` is in the interval, never for NaN
+    pub fn contains(&self, value: f64) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// The smallest interval containing both
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// The values in both
+    pub fn intersection(&self, other: &Self) -> Self {
+        let res = Interval {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        };
+
+        if res.is_empty() {
+            Interval::EMPTY
+        } else {
+            res
+        }
+    }
+
+    /// The bounds implied by the possibilities of `arg`
+    ///
+    /// ```
+    /// use fn_num_types::interval::Interval;
+    /// use fn_num_types::{FnArgFloat, Possible, FP};
+    ///
+    /// // Like `x.sin()`
+    /// let bounded = FnArgFloat::F32(FP {
+    ///     nan: Possible::Yes,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::No,
+    ///     infinite: Possible::No,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::Yes,
+    /// });
+    ///
+    /// assert_eq!(Interval::of(&bounded), Interval::new(-1.0, 1.0));
+    /// ```
+    pub fn of(arg: &FnArgFloat) -> Self {
+        let fp = arg.possibilities().canonicalize();
+
+        Interval {
+            // Not `-0.0`
+            min: 0.0 - upper_bound(&mirror(&fp), arg.max_value()),
+            max: upper_bound(&fp, arg.max_value()),
+        }
+    }
+
+    /// The possibilities of the values of the type of `arg` in the interval
+    ///
+    /// NaN is always possible: the interval doesn't tell.
+    ///
+    /// ```
+    /// use fn_num_types::interval::Interval;
+    /// use fn_num_types::{FnArgFloat, Possible, FP};
+    ///
+    /// let fp = Interval::new(0.25, 0.5).possibilities(&FnArgFloat::F64(FP::default()));
+    ///
+    /// assert_eq!(fp.fractional, Possible::Yes);
+    /// assert_eq!(fp.large, Possible::No);
+    /// assert_eq!(fp.zero(), Possible::No);
+    /// assert_eq!(fp.negative, Possible::No);
+    /// ```
+    pub fn possibilities(&self, arg: &FnArgFloat) -> FP {
+        let possible = |cond: bool| if cond { Possible::Yes } else { Possible::No };
+
+        if self.is_empty() {
+            return FP {
+                nan: Possible::Yes,
+                pos_zero: Possible::No,
+                neg_zero: Possible::No,
+                subnormal: Possible::No,
+                fractional: Possible::No,
+                large: Possible::No,
+                infinite: Possible::No,
+                positive: Possible::No,
+                negative: Possible::No,
+            };
+        }
+
+        let min_positive = arg.min_positive();
+        let max_value = arg.max_value();
+        let integral = integral_above(arg);
+        let zero = self.contains(0.0);
+
+        FP {
+            nan: Possible::Yes,
+            pos_zero: possible(zero),
+            neg_zero: possible(zero),
+            subnormal: possible(
+                self.min < min_positive
+                    && self.max > -min_positive
+                    && !(self.min == 0.0 && self.max == 0.0),
+            ),
+            // All the values are integers from `integral` on
+            fractional: possible(
+                self.min < integral
+                    && self.max > -integral
+                    && !(self.min == self.max && self.min.fract() == 0.0),
+            ),
+            large: possible(
+                (self.max > 1.0 && self.min <= max_value)
+                    || (self.min < -1.0 && self.max >= -max_value),
+            ),
+            infinite: possible(self.min == f64::NEG_INFINITY || self.max == f64::INFINITY),
+            positive: possible(self.max >= 0.0),
+            negative: possible(self.min <= 0.0),
+        }
+    }
+}
+
+/// The possibilities with the signs swapped, like `-x`
+fn mirror(fp: &FP) -> FP {
+    FP {
+        pos_zero: fp.neg_zero,
+        neg_zero: fp.pos_zero,
+        positive: fp.negative,
+        negative: fp.positive,
+        ..*fp
+    }
+}
+
+/// The greatest value accepted by canonical possibilities,
+/// knowing that `1.0` is accepted as soon as `positive` is
+fn upper_bound(fp: &FP, max_value: f64) -> f64 {
+    if fp.positive != Possible::No {
+        if fp.infinite != Possible::No {
+            f64::INFINITY
+        } else if fp.large != Possible::No {
+            max_value
+        } else {
+            1.0
+        }
+    } else if fp.negative != Possible::No {
+        // The values in ]-1, 0]
+        if (fp.zero() | fp.subnormal | fp.fractional) != Possible::No {
+            0.0
+        } else {
+            -1.0
+        }
+    } else {
+        // Only NaN
+        f64::NEG_INFINITY
+    }
+}
+
+/// The magnitude from which all the values of the type are integers
+fn integral_above(arg: &FnArgFloat) -> f64 {
+    match arg {
+        FnArgFloat::F32(_) => 8_388_608.0,
+        FnArgFloat::F64(_) => 4_503_599_627_370_496.0,
+        #[cfg(feature = "extra-widths")]
+        FnArgFloat::F16(_) => 1024.0,
+        #[cfg(feature = "extra-widths")]
+        FnArgFloat::BF16(_) => 128.0,
+        #[cfg(feature = "extra-widths")]
+        FnArgFloat::F128(_) => 5_192_296_858_534_827_628_530_496_329_220_096.0,
+    }
+}
+
+/// The possibilities of a float, with the range of its values
+///
+/// Both are kept consistent: each one narrows the other.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangedFloat {
+    arg: FnArgFloat,
+    range: Interval,
+}
+
+impl RangedFloat {
+    /// The possibilities of `arg` with values in `range`
+    ///
+    /// The bounds of a `F32` are rounded inward to `f32` values.
+    pub fn new(arg: FnArgFloat, range: Interval) -> Self {
+        let range = round_in(&arg, &range.intersection(&Interval::of(&arg)));
+        let arg = arg.with_possibilities(
+            arg.possibilities()
+                .intersection(&range.possibilities(&arg))
+                .canonicalize(),
+        );
+
+        RangedFloat {
+            range: range.intersection(&Interval::of(&arg)),
+            arg,
+        }
+    }
+
+    /// The possibilities, whatever the type
+    pub fn arg(&self) -> &FnArgFloat {
+        &self.arg
+    }
+
+    pub fn range(&self) -> &Interval {
+        &self.range
+    }
+
+    /// Returns true if the value is accepted by both the possibilities and the range
+    pub fn accept(&self, value: f64) -> bool {
+        self.arg.possibilities().accept(value) && (value.is_nan() || self.range.contains(value))
+    }
+}
+
+/// Without range, only the bounds implied by the possibilities
+impl From<FnArgFloat> for RangedFloat {
+    fn from(arg: FnArgFloat) -> Self {
+        RangedFloat::new(arg, Interval::ALL)
+    }
+}
+
+/// Error of the functions of `std` that are not correctly rounded, in ulps
+#[cfg(feature = "full")]
+const LIBM_ULPS: u32 = 2;
+
+fn next_up_f64(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if x == 0.0 {
+        f64::from_bits(1)
+    } else if x > 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+fn next_down_f64(x: f64) -> f64 {
+    -next_up_f64(-x)
+}
+
+fn next_up_f32(x: f32) -> f32 {
+    if x.is_nan() || x == f32::INFINITY {
+        x
+    } else if x == 0.0 {
+        f32::from_bits(1)
+    } else if x > 0.0 {
+        f32::from_bits(x.to_bits() + 1)
+    } else {
+        f32::from_bits(x.to_bits() - 1)
+    }
+}
+
+fn next_down_f32(x: f32) -> f32 {
+    -next_up_f32(-x)
+}
+
+/// The bounds of the values of the type of `arg` in `range`
+fn round_in(arg: &FnArgFloat, range: &Interval) -> Interval {
+    match arg {
+        FnArgFloat::F32(_) if !range.is_empty() => {
+            let (min, max) = (range.min as f32, range.max as f32);
+            let min = if f64::from(min) < range.min {
+                next_up_f32(min)
+            } else {
+                min
+            };
+            let max = if f64::from(max) > range.max {
+                next_down_f32(max)
+            } else {
+                max
+            };
+
+            Interval {
+                min: f64::from(min),
+                max: f64::from(max),
+            }
+            .intersection(range)
+        }
+        _ => *range,
+    }
+}
+
+/// Rounds the bounds computed with `f64` outward to the type of `arg`, then widens
+/// them by `ulps` for the functions that are not correctly rounded
+#[cfg_attr(not(feature = "full"), allow(dead_code))]
+fn round_out(arg: &FnArgFloat, range: Interval, ulps: u32) -> Interval {
+    if range.is_empty() {
+        return Interval::EMPTY;
+    }
+
+    match arg {
+        FnArgFloat::F64(_) => {
+            let (mut min, mut max) = (range.min, range.max);
+            for _ in 0..ulps {
+                min = next_down_f64(min);
+                max = next_up_f64(max);
+            }
+
+            Interval { min, max }
+        }
+        FnArgFloat::F32(_) => {
+            let (mut min, mut max) = (range.min as f32, range.max as f32);
+            if f64::from(min) > range.min {
+                min = next_down_f32(min);
+            }
+            if f64::from(max) < range.max {
+                max = next_up_f32(max);
+            }
+            for _ in 0..ulps {
+                min = next_down_f32(min);
+                max = next_up_f32(max);
+            }
+
+            Interval {
+                min: f64::from(min),
+                max: f64::from(max),
+            }
+        }
+        #[cfg(feature = "extra-widths")]
+        _ => Interval::ALL,
+    }
+}
+
+/// The bounds of a non-decreasing function on `domain`, NaN outside of it
+#[cfg(feature = "full")]
+fn increasing(x: &RangedFloat, f: fn(f64) -> f64, domain: Interval, ulps: u32) -> Interval {
+    let range = x.range.intersection(&domain);
+    if range.is_empty() {
+        return Interval::EMPTY;
+    }
+
+    let (min, max) = (f(range.min), f(range.max));
+    if min.is_nan() || max.is_nan() {
+        return Interval::ALL;
+    }
+
+    round_out(&x.arg, Interval { min, max }, ulps)
+}
+
+/// The bounds of a non-increasing function on `domain`, NaN outside of it
+#[cfg(feature = "full")]
+fn decreasing(x: &RangedFloat, f: fn(f64) -> f64, domain: Interval, ulps: u32) -> Interval {
+    let range = x.range.intersection(&domain);
+    if range.is_empty() {
+        return Interval::EMPTY;
+    }
+
+    let (min, max) = (f(range.max), f(range.min));
+    if min.is_nan() || max.is_nan() {
+        return Interval::ALL;
+    }
+
+    round_out(&x.arg, Interval { min, max }, ulps)
+}
+
+/// The bounds of `f` at the corners, if it is monotonic in each argument
+#[cfg(feature = "full")]
+fn corners(a: &RangedFloat, b: &RangedFloat, f: fn(f64, f64) -> f64) -> Interval {
+    if a.range.is_empty() || b.range.is_empty() {
+        return Interval::EMPTY;
+    }
+
+    let values = [
+        f(a.range.min, b.range.min),
+        f(a.range.min, b.range.max),
+        f(a.range.max, b.range.min),
+        f(a.range.max, b.range.max),
+    ];
+
+    // Like `0.0 * inf`: any value may be reached close to the corner
+    if values.iter().any(|value| value.is_nan()) {
+        return Interval::ALL;
+    }
+
+    let range = values.iter().fold(Interval::EMPTY, |range, value| {
+        range.hull(&Interval::point(*value))
+    });
+
+    round_out(&a.arg, range, 0)
+}
+
+#[cfg(feature = "full")]
+mod rules {
+    use super::*;
+
+    pub(super) fn all(_: &RangedFloat) -> Interval {
+        Interval::ALL
+    }
+
+    pub(super) fn neg(x: &RangedFloat) -> Interval {
+        Interval {
+            min: -x.range.max,
+            max: -x.range.min,
+        }
+    }
+
+    pub(super) fn abs(x: &RangedFloat) -> Interval {
+        let range = x.range;
+
+        if range.is_empty() || range.min >= 0.0 {
+            range
+        } else if range.max <= 0.0 {
+            neg(x)
+        } else {
+            Interval {
+                min: 0.0,
+                max: range.max.max(-range.min),
+            }
+        }
+    }
+
+    pub(super) fn exact(f: fn(f64) -> f64) -> impl Fn(&RangedFloat) -> Interval {
+        move |x| increasing(x, f, Interval::ALL, 0)
+    }
+
+    pub(super) fn libm(f: fn(f64) -> f64) -> impl Fn(&RangedFloat) -> Interval {
+        move |x| increasing(x, f, Interval::ALL, LIBM_ULPS)
+    }
+
+    pub(super) fn libm_on(
+        f: fn(f64) -> f64,
+        min: f64,
+        max: f64,
+    ) -> impl Fn(&RangedFloat) -> Interval {
+        move |x| increasing(x, f, Interval::new(min, max), LIBM_ULPS)
+    }
+
+    pub(super) fn sqrt(x: &RangedFloat) -> Interval {
+        increasing(x, f64::sqrt, Interval::new(0.0, f64::INFINITY), 0)
+    }
+
+    pub(super) fn acos(x: &RangedFloat) -> Interval {
+        decreasing(x, f64::acos, Interval::new(-1.0, 1.0), LIBM_ULPS)
+    }
+
+    pub(super) fn recip(x: &RangedFloat) -> Interval {
+        // Decreasing on each side of zero
+        if x.range.min > 0.0 || x.range.max < 0.0 {
+            decreasing(x, f64::recip, Interval::ALL, 0)
+        } else {
+            Interval::ALL
+        }
+    }
+
+    pub(super) fn next_up(x: &RangedFloat) -> Interval {
+        increasing(x, next_up_f64, Interval::ALL, 0)
+    }
+
+    pub(super) fn next_down(x: &RangedFloat) -> Interval {
+        increasing(x, next_down_f64, Interval::ALL, 0)
+    }
+
+    pub(super) fn all2(_: &RangedFloat, _: &RangedFloat) -> Interval {
+        Interval::ALL
+    }
+
+    pub(super) fn add(a: &RangedFloat, b: &RangedFloat) -> Interval {
+        if a.range.is_empty() || b.range.is_empty() {
+            return Interval::EMPTY;
+        }
+
+        // `-inf + inf` is NaN, the other values are reached
+        let min = a.range.min + b.range.min;
+        let max = a.range.max + b.range.max;
+
+        round_out(
+            &a.arg,
+            Interval {
+                min: if min.is_nan() { f64::NEG_INFINITY } else { min },
+                max: if max.is_nan() { f64::INFINITY } else { max },
+            },
+            0,
+        )
+    }
+
+    pub(super) fn mul(a: &RangedFloat, b: &RangedFloat) -> Interval {
+        corners(a, b, |x, y| x * y)
+    }
+
+    pub(super) fn div(a: &RangedFloat, b: &RangedFloat) -> Interval {
+        // Like the reciprocal, only monotonic on each side of zero
+        if b.range.contains(0.0) {
+            Interval::ALL
+        } else {
+            corners(a, b, |x, y| x / y)
+        }
+    }
+
+    /// `min` and `max` ignore a NaN argument, returning the other one
+    fn ignoring_nan(a: &RangedFloat, b: &RangedFloat, range: Interval) -> Interval {
+        let mut range = range;
+
+        if a.arg.possibilities().nan != Possible::No {
+            range = range.hull(&b.range);
+        }
+        if b.arg.possibilities().nan != Possible::No {
+            range = range.hull(&a.range);
+        }
+
+        range
+    }
+
+    pub(super) fn maximum(a: &RangedFloat, b: &RangedFloat) -> Interval {
+        if a.range.is_empty() || b.range.is_empty() {
+            return Interval::EMPTY;
+        }
+
+        Interval {
+            min: a.range.min.max(b.range.min),
+            max: a.range.max.max(b.range.max),
+        }
+    }
+
+    pub(super) fn minimum(a: &RangedFloat, b: &RangedFloat) -> Interval {
+        if a.range.is_empty() || b.range.is_empty() {
+            return Interval::EMPTY;
+        }
+
+        Interval {
+            min: a.range.min.min(b.range.min),
+            max: a.range.max.min(b.range.max),
+        }
+    }
+
+    pub(super) fn max(a: &RangedFloat, b: &RangedFloat) -> Interval {
+        ignoring_nan(a, b, maximum(a, b))
+    }
+
+    pub(super) fn min(a: &RangedFloat, b: &RangedFloat) -> Interval {
+        ignoring_nan(a, b, minimum(a, b))
+    }
+
+    pub(super) fn mul_add(a: &RangedFloat, b: &RangedFloat, c: &RangedFloat) -> Interval {
+        if a.range.is_empty() || b.range.is_empty() || c.range.is_empty() {
+            return Interval::EMPTY;
+        }
+
+        // Rounded once, so the extremes are at the corners, with the extreme addends
+        let corners = [
+            (a.range.min, b.range.min),
+            (a.range.min, b.range.max),
+            (a.range.max, b.range.min),
+            (a.range.max, b.range.max),
+        ];
+        let min = corners
+            .iter()
+            .map(|(x, y)| x.mul_add(*y, c.range.min))
+            .fold(f64::INFINITY, f64::min);
+        let max = corners
+            .iter()
+            .map(|(x, y)| x.mul_add(*y, c.range.max))
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        // `f64::min` ignores NaN, like `0.0 * inf` at a corner
+        let nan = corners.iter().any(|(x, y)| {
+            x.mul_add(*y, c.range.min).is_nan() || x.mul_add(*y, c.range.max).is_nan()
+        });
+        if nan {
+            return Interval::ALL;
+        }
+
+        // The `f32` products are not rounded like the `f64` ones
+        round_out(&a.arg, Interval { min, max }, 1)
+    }
+
+    pub(super) fn clamp(x: &RangedFloat, min: &RangedFloat, max: &RangedFloat) -> Interval {
+        if x.range.is_empty() {
+            return Interval::EMPTY;
+        }
+
+        // Non-decreasing in each argument
+        Interval {
+            min: x.range.min.max(min.range.min).min(max.range.min),
+            max: x.range.max.max(min.range.max).min(max.range.max),
+        }
+    }
+}
+
+macro_rules! unary {
+    ($($op:ident => $rule:expr),* $(,)?) => {
+        #[cfg(feature = "full")]
+        impl RangedFloat {
+            $(
+                pub fn $op(&self) -> Self {
+                    let range = ($rule)(self);
+
+                    RangedFloat::new(ops::$op(&self.arg), range)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident => $rule:expr),* $(,)?) => {
+        #[cfg(feature = "full")]
+        impl RangedFloat {
+            $(
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    let range = ($rule)(self, rhs);
+
+                    RangedFloat::new(ops::$op(&self.arg, &rhs.arg), range)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! ternary {
+    ($($op:ident => $rule:expr),* $(,)?) => {
+        #[cfg(feature = "full")]
+        impl RangedFloat {
+            $(
+                pub fn $op(&self, b: &Self, c: &Self) -> Self {
+                    let range = ($rule)(self, b, c);
+
+                    RangedFloat::new(ops::$op(&self.arg, &b.arg, &c.arg), range)
+                }
+            )*
+        }
+    };
+}
+
+unary!(
+    neg => rules::neg,
+    abs => rules::abs,
+    ceil => rules::exact(f64::ceil),
+    floor => rules::exact(f64::floor),
+    round => rules::exact(f64::round),
+    round_ties_even => rules::exact(crate::float_op::round_ties_even),
+    trunc => rules::exact(f64::trunc),
+    fract => rules::all,
+    signum => rules::all,
+    sqrt => rules::sqrt,
+    exp => rules::libm(f64::exp),
+    exp2 => rules::libm(f64::exp2),
+    ln => rules::libm_on(f64::ln, 0.0, f64::INFINITY),
+    log2 => rules::libm_on(f64::log2, 0.0, f64::INFINITY),
+    log10 => rules::libm_on(f64::log10, 0.0, f64::INFINITY),
+    to_degrees => rules::libm(f64::to_degrees),
+    to_radians => rules::libm(f64::to_radians),
+    cbrt => rules::libm(f64::cbrt),
+    sin => rules::all,
+    cos => rules::all,
+    tan => rules::all,
+    asin => rules::libm_on(f64::asin, -1.0, 1.0),
+    acos => rules::acos,
+    atan => rules::libm(f64::atan),
+    exp_m1 => rules::libm(f64::exp_m1),
+    ln_1p => rules::libm_on(f64::ln_1p, -1.0, f64::INFINITY),
+    sinh => rules::libm(f64::sinh),
+    cosh => rules::all,
+    tanh => rules::libm(f64::tanh),
+    asinh => rules::libm(f64::asinh),
+    acosh => rules::libm_on(f64::acosh, 1.0, f64::INFINITY),
+    atanh => rules::libm_on(f64::atanh, -1.0, 1.0),
+    recip => rules::recip,
+    powi => rules::all,
+    next_up => rules::next_up,
+    next_down => rules::next_down,
+);
+
+binary!(
+    add => rules::add,
+    mul => rules::mul,
+    div => rules::div,
+    rem => rules::all2,
+    div_euclid => rules::all2,
+    rem_euclid => rules::all2,
+    powf => rules::all2,
+    hypot => rules::all2,
+    atan2 => rules::all2,
+    copysign => rules::all2,
+    max => rules::max,
+    min => rules::min,
+    maximum => rules::maximum,
+    minimum => rules::minimum,
+    log => rules::all2,
+    midpoint => rules::all2,
+);
+
+ternary!(
+    mul_add => rules::mul_add,
+    clamp => rules::clamp,
+);
@@ -0,0 +1,253 @@
+//! Float types as a trait, so that other crates can add their own.
+//!
+//! Unlike `FnArgFloat`, the arguments of an operation are checked to be of the same type
+//! at compile time.
+//!
+#![cfg_attr(feature = "full", doc = "```")]
+#![cfg_attr(not(feature = "full"), doc = "```ignore")]
+//! use fn_num_types::kind::{TypedFloat, F64};
+//! use fn_num_types::{Possible, FP};
+//!
+//! let x = TypedFloat::<F64>::new(FP {
+//!     nan: Possible::No,
+//!     pos_zero: Possible::No,
+//!     neg_zero: Possible::No,
+//!     subnormal: Possible::Yes,
+//!     fractional: Possible::Yes,
+//!     large: Possible::Yes,
+//!     infinite: Possible::No,
+//!     positive: Possible::Yes,
+//!     negative: Possible::No,
+//! });
+//!
+//! assert_eq!(x.sqrt().possibilities().nan, Possible::No);
+//! assert!(x.add(&x).accept(2.0));
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::num::FpCategory;
+
+#[cfg(feature = "full")]
+use crate::core::ops;
+#[cfg(feature = "full")]
+use crate::FnArgFloat;
+use crate::FP;
+
+/// A float type
+pub trait FloatKind: Debug + Clone + Copy + PartialEq + Eq {
+    /// The values of the type
+    type Value: Copy + Debug;
+
+    /// The name of the type, like `f64`
+    const NAME: &'static str;
+    /// Width in bits
+    const BITS: u32;
+
+    /// The category and the sign (true if negative) of `value`
+    fn classify(value: Self::Value) -> (FpCategory, bool);
+
+    /// Returns true if `value` is finite and not an integer
+    ///
+    /// `false` by default, which accepts more values than `fp.accept` would.
+    fn is_fractional(_value: Self::Value) -> bool {
+        false
+    }
+
+    /// Returns true if `value` is finite and greater than one in magnitude
+    ///
+    /// `false` by default, like `is_fractional`.
+    fn is_large(_value: Self::Value) -> bool {
+        false
+    }
+
+    /// Returns true if `value` is accepted by `fp`
+    fn accept(fp: &FP, value: Self::Value) -> bool {
+        let (category, sign_negative) = Self::classify(value);
+
+        fp.accept_category(category, sign_negative)
+            && fp.accept_fraction(Self::is_fractional(value))
+            && fp.accept_magnitude(Self::is_large(value))
+    }
+
+    /// Values covering all the categories and signs, to test the operations
+    fn test_values() -> Vec<Self::Value>;
+}
+
+/// `f32`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct F32;
+
+/// `f64`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct F64;
+
+macro_rules! float_kind {
+    ($kind:ident, $float:ident, $bits:literal) => {
+        impl FloatKind for $kind {
+            type Value = $float;
+
+            const NAME: &'static str = stringify!($float);
+            const BITS: u32 = $bits;
+
+            fn classify(value: $float) -> (FpCategory, bool) {
+                (value.classify(), value.is_sign_negative())
+            }
+
+            fn is_fractional(value: $float) -> bool {
+                crate::utils::is_fractional(value)
+            }
+
+            fn is_large(value: $float) -> bool {
+                crate::utils::is_large(value)
+            }
+
+            fn test_values() -> Vec<$float> {
+                let subnormal = $float::from_bits(1);
+
+                alloc::vec![
+                    $float::NAN,
+                    $float::NEG_INFINITY,
+                    $float::MIN,
+                    -1.0,
+                    -$float::MIN_POSITIVE,
+                    -subnormal,
+                    -0.0,
+                    0.0,
+                    subnormal,
+                    $float::MIN_POSITIVE,
+                    1.0,
+                    1.5,
+                    $float::MAX,
+                    $float::INFINITY,
+                ]
+            }
+        }
+    };
+}
+
+float_kind!(F32, f32, 32);
+float_kind!(F64, f64, 64);
+
+/// The possibilities of a float of the type `K`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypedFloat<K: FloatKind> {
+    possibilities: FP,
+    kind: PhantomData<K>,
+}
+
+impl<K: FloatKind> TypedFloat<K> {
+    pub fn new(possibilities: FP) -> Self {
+        TypedFloat {
+            possibilities,
+            kind: PhantomData,
+        }
+    }
+
+    pub fn possibilities(&self) -> &FP {
+        &self.possibilities
+    }
+
+    /// Returns true if the value is accepted
+    pub fn accept(&self, value: K::Value) -> bool {
+        K::accept(&self.possibilities, value)
+    }
+
+    /// The models of the operations do not depend on the width,
+    /// except for the conversions, which are not provided here
+    #[cfg(feature = "full")]
+    fn erased(&self) -> FnArgFloat {
+        FnArgFloat::F64(self.possibilities)
+    }
+
+    #[cfg(feature = "full")]
+    fn typed(result: FnArgFloat) -> Self {
+        TypedFloat::new(*result.possibilities())
+    }
+}
+
+macro_rules! unary {
+    ($($op:ident),* $(,)?) => {
+        #[cfg(feature = "full")]
+        impl<K: FloatKind> TypedFloat<K> {
+            $(
+                pub fn $op(&self) -> Self {
+                    Self::typed(ops::$op(&self.erased()))
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident),* $(,)?) => {
+        #[cfg(feature = "full")]
+        impl<K: FloatKind> TypedFloat<K> {
+            $(
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    Self::typed(ops::$op(&self.erased(), &rhs.erased()))
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! ternary {
+    ($($op:ident),* $(,)?) => {
+        #[cfg(feature = "full")]
+        impl<K: FloatKind> TypedFloat<K> {
+            $(
+                pub fn $op(&self, b: &Self, c: &Self) -> Self {
+                    Self::typed(ops::$op(&self.erased(), &b.erased(), &c.erased()))
+                }
+            )*
+        }
+    };
+}
+
+unary!(
+    neg,
+    abs,
+    ceil,
+    floor,
+    round,
+    round_ties_even,
+    trunc,
+    fract,
+    signum,
+    sqrt,
+    exp,
+    exp2,
+    ln,
+    log2,
+    log10,
+    to_degrees,
+    to_radians,
+    cbrt,
+    sin,
+    cos,
+    tan,
+    asin,
+    acos,
+    atan,
+    exp_m1,
+    ln_1p,
+    sinh,
+    cosh,
+    tanh,
+    asinh,
+    acosh,
+    atanh,
+    recip,
+    powi,
+    next_up,
+    next_down,
+);
+
+binary!(
+    add, mul, div, rem, div_euclid, rem_euclid, powf, hypot, atan2, copysign, max, min, maximum,
+    minimum, log, midpoint,
+);
+
+ternary!(mul_add, clamp);
@@ -0,0 +1,163 @@
+//! The flags of `FloatPossibilities` in another domain than `Possible`.
+//!
+//! An analysis may attach more to each flag, like the bounds of its probability.
+//! The models are written for the four levels of `Possible`: the flags of another
+//! lattice are mapped to `Possible` to apply them, and the results mapped back.
+//!
+//! They are not generic over `PossibilityLattice`:
+//!
+//! - they are `const fn`, which cannot call the methods of a trait;
+//! - they tell `ShouldNot` and `Should` apart, which `join`, `meet`, `top`
+//!   and `bottom` cannot express.
+//!
+//! ```
+//! use fn_num_types::core::ops;
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible, PossibilityLattice};
+//!
+//! // Only whether each flag may happen at all
+//! let x: FloatPossibilities<bool> = FloatPossibilities::positive().map(|p| p != Possible::No);
+//!
+//! let fp = x.map(|b| if b { Possible::Yes } else { Possible::No });
+//! let res = ops::sqrt(&FnArgFloat::F64(fp));
+//! let res: FloatPossibilities<bool> = res.possibilities().map(|p| p != Possible::No);
+//!
+//! assert!(!res.negative);
+//! assert_eq!(res.join(x), x);
+//! ```
+
+use crate::{FloatPossibilities, Possible};
+
+/// The domain of a flag: `bottom` is impossible, `top` may happen
+///
+/// `join` keeps what either may make possible, like `|`,
+/// and `meet` what both do, like `&`.
+pub trait PossibilityLattice: Copy + Eq {
+    fn top() -> Self;
+    fn bottom() -> Self;
+    fn join(self, rhs: Self) -> Self;
+    fn meet(self, rhs: Self) -> Self;
+
+    /// `self` is below `rhs`
+    ///
+    /// ```
+    /// use fn_num_types::{Possible, PossibilityLattice};
+    ///
+    /// assert!(Possible::ShouldNot.is_below(Possible::Should));
+    /// assert!(!Possible::Yes.is_below(Possible::No));
+    /// ```
+    fn is_below(self, rhs: Self) -> bool {
+        self.join(rhs) == rhs
+    }
+}
+
+impl PossibilityLattice for Possible {
+    fn top() -> Self {
+        Possible::Yes
+    }
+
+    fn bottom() -> Self {
+        Possible::No
+    }
+
+    fn join(self, rhs: Self) -> Self {
+        self | rhs
+    }
+
+    fn meet(self, rhs: Self) -> Self {
+        self & rhs
+    }
+}
+
+/// Whether the flag may happen at all
+impl PossibilityLattice for bool {
+    fn top() -> Self {
+        true
+    }
+
+    fn bottom() -> Self {
+        false
+    }
+
+    fn join(self, rhs: Self) -> Self {
+        self || rhs
+    }
+
+    fn meet(self, rhs: Self) -> Self {
+        self && rhs
+    }
+}
+
+/// Field by field
+impl<L: PossibilityLattice> PossibilityLattice for FloatPossibilities<L> {
+    fn top() -> Self {
+        FloatPossibilities::splat(L::top())
+    }
+
+    fn bottom() -> Self {
+        FloatPossibilities::splat(L::bottom())
+    }
+
+    fn join(self, rhs: Self) -> Self {
+        self.zip(rhs, L::join)
+    }
+
+    fn meet(self, rhs: Self) -> Self {
+        self.zip(rhs, L::meet)
+    }
+}
+
+impl<L: Copy> FloatPossibilities<L> {
+    /// All the fields to `flag`
+    pub(crate) const fn splat(flag: L) -> Self {
+        FloatPossibilities {
+            nan: flag,
+            pos_zero: flag,
+            neg_zero: flag,
+            subnormal: flag,
+            fractional: flag,
+            large: flag,
+            infinite: flag,
+            positive: flag,
+            negative: flag,
+        }
+    }
+
+    fn zip(self, rhs: Self, f: fn(L, L) -> L) -> Self {
+        FloatPossibilities {
+            nan: f(self.nan, rhs.nan),
+            pos_zero: f(self.pos_zero, rhs.pos_zero),
+            neg_zero: f(self.neg_zero, rhs.neg_zero),
+            subnormal: f(self.subnormal, rhs.subnormal),
+            fractional: f(self.fractional, rhs.fractional),
+            large: f(self.large, rhs.large),
+            infinite: f(self.infinite, rhs.infinite),
+            positive: f(self.positive, rhs.positive),
+            negative: f(self.negative, rhs.negative),
+        }
+    }
+
+    /// Applies `f` to each field, in the order of `Field::ALL`
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let fp = FloatPossibilities::finite().map(Possible::to_strict);
+    /// assert_eq!(fp.infinite, Possible::No);
+    ///
+    /// let may: FloatPossibilities<bool> = fp.map(|p| p != Possible::No);
+    /// assert!(may.large);
+    /// ```
+    pub fn map<M>(&self, mut f: impl FnMut(L) -> M) -> FloatPossibilities<M> {
+        FloatPossibilities {
+            nan: f(self.nan),
+            pos_zero: f(self.pos_zero),
+            neg_zero: f(self.neg_zero),
+            subnormal: f(self.subnormal),
+            fractional: f(self.fractional),
+            large: f(self.large),
+            infinite: f(self.infinite),
+            positive: f(self.positive),
+            negative: f(self.negative),
+        }
+    }
+}
@@ -1,262 +1,647 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+/// Traces that the rule `name` applies when `cond` is possible, in the code
+/// that is not `const`: the models return their rules in `Cases`
+#[allow(unused_macros)]
+macro_rules! rule {
+    ($cond:expr, $name:literal) => {
+        #[cfg(feature = "tracing")]
+        if !matches!($cond, $crate::Possible::No) {
+            tracing::trace!(rule = $name);
+        }
+    };
+}
+
+/// `a | b | ...` of `Possible`, the operators not being `const`
+#[allow(unused_macros)]
+macro_rules! any {
+    ($a:expr $(,)?) => {
+        $a
+    };
+    ($a:expr, $($rest:expr),+ $(,)?) => {
+        $crate::Possible::any($a, any!($($rest),+))
+    };
+}
+
+/// `a & b & ...` of `Possible`
+#[allow(unused_macros)]
+macro_rules! all {
+    ($a:expr $(,)?) => {
+        $a
+    };
+    ($a:expr, $($rest:expr),+ $(,)?) => {
+        $crate::Possible::all($a, all!($($rest),+))
+    };
+}
+
+/// `lhs` with the possibilities given by the model `f` of its own
+#[allow(unused_macros)]
+macro_rules! return_fp {
+    ($lhs:expr, |$fp:pat_param| $body:expr) => {{
+        let lhs: &$crate::FnArgFloat = $lhs;
+        let $fp = lhs.possibilities();
+        lhs.with_possibilities($body)
+    }};
+    ($lhs:expr, $f:path) => {
+        return_fp!($lhs, |fp| $f(fp))
+    };
+}
+
+/// Like `return_fp!`, of two arguments of the same type
+///
+/// # Panics
+///
+/// If the arguments are not of the same type
+#[allow(unused_macros)]
+macro_rules! return_fp2 {
+    ($lhs:expr, $rhs:expr, |$fp1:pat_param, $fp2:pat_param| $body:expr) => {{
+        let (lhs, rhs): (&$crate::FnArgFloat, &$crate::FnArgFloat) = ($lhs, $rhs);
+        assert!(lhs.is_same_type(rhs), "Different types");
+        let ($fp1, $fp2) = (lhs.possibilities(), rhs.possibilities());
+        lhs.with_possibilities($body)
+    }};
+    ($lhs:expr, $rhs:expr, $f:path) => {
+        return_fp2!($lhs, $rhs, |fp1, fp2| $f(fp1, fp2))
+    };
+}
+
+/// Like `return_fp!`, of three arguments of the same type
+///
+/// # Panics
+///
+/// If the arguments are not of the same type
+#[allow(unused_macros)]
+macro_rules! return_fp3 {
+    ($a:expr, $b:expr, $c:expr, |$fp1:pat_param, $fp2:pat_param, $fp3:pat_param| $body:expr) => {{
+        let (a, b, c): (&$crate::FnArgFloat, &$crate::FnArgFloat, &$crate::FnArgFloat) =
+            ($a, $b, $c);
+        assert!(a.is_same_type(b) && a.is_same_type(c), "Different types");
+        let ($fp1, $fp2, $fp3) = (a.possibilities(), b.possibilities(), c.possibilities());
+        a.with_possibilities($body)
+    }};
+    ($a:expr, $b:expr, $c:expr, $f:path) => {
+        return_fp3!($a, $b, $c, |fp1, fp2, fp3| $f(fp1, fp2, fp3))
+    };
+}
+
+#[cfg(feature = "arith")]
 mod add;
+#[cfg(feature = "full")]
+mod analyzer;
+mod assume;
+#[cfg(feature = "arith")]
+mod batch;
+#[cfg(feature = "integer")]
+mod bits;
+#[cfg(all(feature = "std", feature = "full"))]
+mod cache;
+#[cfg(feature = "casts")]
+mod cast;
+mod changelog;
+mod class;
+mod cmp;
+#[cfg(feature = "complex")]
+mod complex;
+mod compose;
+mod config;
+mod contract;
+#[cfg(feature = "arith")]
+mod copysign;
+mod diff;
+#[cfg(feature = "arith")]
+mod div;
+mod error;
+#[cfg(feature = "arith")]
+mod euclid;
+mod fallible;
+#[cfg(feature = "arith")]
+mod hypot;
+#[cfg(feature = "integer")]
+mod int;
+#[cfg(feature = "integer")]
+mod int_ops;
+mod lattice;
+#[cfg(feature = "exp-log")]
+mod log;
+#[cfg(feature = "full")]
+mod methods;
+#[cfg(feature = "arith")]
+mod midpoint;
+#[cfg(feature = "arith")]
+mod minmax;
+#[cfg(feature = "arith")]
+mod mul;
+#[cfg(feature = "arith")]
+mod mul_add;
+#[cfg(feature = "arith")]
+mod mul_pow2;
+#[cfg(feature = "nightly-ops")]
+mod nightly;
+#[cfg(feature = "arith")]
+mod norm;
+#[cfg(feature = "arith")]
+mod operators;
+#[cfg(feature = "arith")]
+mod powf;
+mod predicate;
+#[cfg(all(feature = "casts", feature = "arith"))]
+mod promote;
+#[cfg(feature = "full")]
+mod registry;
+#[cfg(feature = "arith")]
+mod rem;
+mod ret;
+#[cfg(feature = "rand")]
+mod sample;
+mod signature;
+#[cfg(feature = "arith")]
+mod stats;
+#[cfg(feature = "proptest")]
+mod strategy;
+mod text;
+#[cfg(feature = "trig")]
+mod trig;
+#[cfg(feature = "typed-floats")]
+mod typed;
 mod utils;
 
+#[cfg(all(kani, feature = "kani"))]
+mod verification;
+
+#[cfg(all(feature = "std", feature = "full"))]
+pub mod explain;
+#[cfg(feature = "full")]
+pub mod export;
+#[cfg(feature = "full")]
+pub mod expr;
+#[cfg(feature = "full")]
+pub mod extension;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(all(feature = "std", feature = "full"))]
+pub mod float_op;
+mod fp_ops;
+#[cfg(feature = "generator")]
+pub mod generator;
+#[cfg(feature = "std")]
+pub mod interval;
+pub mod kind;
+#[cfg(feature = "libm-ops")]
+pub mod libm_ops;
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+#[cfg(feature = "node")]
+pub mod node;
+#[cfg(feature = "num-traits")]
+pub mod num_float;
+#[cfg(feature = "oracle")]
+pub mod oracle;
+#[cfg(feature = "arith")]
+pub mod overflow;
+#[cfg(feature = "probabilistic")]
+pub mod probability;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "full")]
+pub mod simd;
+#[cfg(feature = "smt")]
+pub mod smt;
+#[cfg(feature = "snan")]
+pub mod snan;
+#[cfg(feature = "syn")]
+pub mod source;
+#[cfg(all(feature = "std", feature = "full"))]
+pub mod values;
+pub mod verify;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+#[cfg(feature = "full")]
+pub use analyzer::{Analyzer, Guard};
+#[cfg(all(feature = "std", feature = "full"))]
+pub use cache::AnalysisCache;
+pub use changelog::{changelog, revisions, Revision};
+pub use class::FloatType;
+pub use cmp::{OrderingPossibilities, PartialOrderingPossibilities};
+#[cfg(feature = "complex")]
+pub use complex::{ComplexPossibilities, FnArgComplex};
+pub use config::*;
+pub use contract::checked;
+pub use diff::FieldDiffs;
+pub use error::Error;
+#[cfg(feature = "macros")]
+pub use fn_num_types_macros::analyze;
+#[cfg(feature = "integer")]
+pub use int::{
+    FnArgInt, IntChecked, IntKind, IntOutcome, IntOverflowing, IntPossibilities, IntValue, IP,
+};
+pub use kind::FloatKind;
+pub use lattice::PossibilityLattice;
+#[cfg(feature = "arith")]
+pub use minmax::NanPolicy;
+pub use predicate::BoolPossibility;
+pub use ret::FnRet;
+pub use signature::FnSignature;
+pub use text::*;
+#[cfg(feature = "trig")]
+pub use trig::AngleDomain;
+#[cfg(feature = "typed-floats")]
+pub use typed::TypedFloatsType;
 pub use utils::*;
 
 pub mod core {
     pub mod ops {
 
+        #[allow(unused_imports)]
         use crate::*;
 
-        pub use add::add;
+        /// Comparisons
+        pub mod cmp {
+            pub use crate::cmp::{eq, ge, gt, le, lt, ne, partial_cmp, total_cmp};
+        }
+
+        /// Operations on complex numbers
+        #[cfg(feature = "complex")]
+        pub mod complex {
+            pub use crate::complex::{add, arg, mul, norm, sqrt};
+        }
+
+        /// Operations on the possibilities, of any width
+        pub mod fp {
+            #[allow(unused_imports)]
+            pub use crate::fp_ops::*;
+        }
+
+        /// Operations on integers
+        #[cfg(feature = "integer")]
+        pub mod int {
+            pub use crate::int_ops::{
+                add, checked_add, checked_mul, checked_sub, div, mul, overflowing_add,
+                overflowing_mul, overflowing_sub, rem, saturating_add, saturating_mul,
+                saturating_sub, sub, wrapping_add, wrapping_mul, wrapping_sub,
+            };
+        }
+
+        #[cfg(feature = "arith")]
+        pub use add::{add, add_with};
+        #[cfg(feature = "arith")]
+        pub use batch::{apply_binary_batch, apply_unary_batch};
+        #[cfg(feature = "integer")]
+        pub use bits::{from_bits, to_bits};
+        #[cfg(feature = "casts")]
+        pub use cast::{
+            cast_to_int, int_to_f32, int_to_f64, is_to_int_unchecked_safe, to_f32, to_f64,
+        };
+        #[cfg(all(feature = "casts", feature = "extra-widths"))]
+        pub use cast::{to_bf16, to_f128, to_f16};
+        pub use compose::{compose, identity, iterate_to_fixpoint};
+        #[cfg(feature = "arith")]
+        pub use copysign::copysign;
+        #[cfg(feature = "arith")]
+        pub use div::{div, div_with};
+        #[cfg(feature = "arith")]
+        pub use euclid::{div_euclid, div_euclid_with, rem_euclid};
+        #[allow(unused_imports)]
+        pub use fallible::*;
+        #[cfg(feature = "arith")]
+        pub use hypot::{hypot, hypot_with};
+        #[cfg(feature = "exp-log")]
+        pub use log::log;
+        #[cfg(feature = "arith")]
+        pub use midpoint::{midpoint, midpoint_with};
+        #[cfg(feature = "arith")]
+        pub use minmax::{clamp, clamp_with, max, max_with, maximum, min, min_with, minimum};
+        #[cfg(feature = "arith")]
+        pub use mul::{mul, mul_with};
+        #[cfg(feature = "arith")]
+        pub use mul_add::{mul_add, mul_add_with};
+        #[cfg(feature = "arith")]
+        pub use mul_pow2::{mul_pow2, mul_pow2_with};
+        #[cfg(feature = "nightly-ops")]
+        pub use nightly::{abs_sub, exp10, gamma, ln_gamma};
+        #[cfg(feature = "arith")]
+        pub use norm::{norm, normalize};
+        #[cfg(feature = "arith")]
+        pub use powf::{powf, powf_with};
+        pub use predicate::{
+            is_finite, is_infinite, is_nan, is_normal, is_sign_negative, is_sign_positive,
+            is_subnormal, select,
+        };
+        #[cfg(all(feature = "casts", feature = "arith"))]
+        pub use promote::*;
+        #[cfg(feature = "full")]
+        pub use registry::{apply, op_metadata, op_names, Monotonicity, OpMetadata, Parity};
+        #[cfg(feature = "arith")]
+        pub use rem::rem;
+        #[cfg(feature = "arith")]
+        pub use stats::{
+            iter_product, iter_sum, mean, product, product_unbounded, stddev, sum, sum_kahan,
+            sum_unbounded, variance,
+        };
+        #[cfg(feature = "trig")]
+        pub use trig::{atan2, atan2_with, cos_in, sin_in, tan_in};
 
-        pub fn neg(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                positive: fp.negative,
-                negative: fp.positive,
-                ..*fp
-            })
+        #[cfg(feature = "arith")]
+        pub const fn neg(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::neg)
         }
 
-        pub fn abs(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                positive: fp.positive | fp.negative,
-                negative: Possible::No,
-                ..*fp
-            })
+        #[cfg(feature = "arith")]
+        pub const fn abs(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::abs)
         }
 
-        pub fn ceil(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                zero: fp.zero | fp.negative,
-                ..*fp
-            })
+        #[cfg(feature = "arith")]
+        pub const fn ceil(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::ceil)
         }
 
-        pub fn floor(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                zero: fp.zero | fp.positive,
-                ..*fp
-            })
+        #[cfg(feature = "arith")]
+        pub const fn floor(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::floor)
         }
 
-        pub fn round(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                zero: Possible::Yes,
-                ..*fp
-            })
+        #[cfg(feature = "arith")]
+        pub const fn round(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::round)
         }
 
-        pub fn trunc(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                zero: Possible::Yes,
-                ..*fp
-            })
+        /// `f64::round_ties_even`: only the ties differ from `round`, not the possibilities
+        #[cfg(feature = "arith")]
+        pub const fn round_ties_even(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::round_ties_even)
         }
 
-        pub fn fract(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                zero: Possible::Yes,
-                nan: fp.nan | fp.infinite,
-                // Return POSITIVE zero if the factional part is zero
-                positive: fp.positive | fp.negative,
-                negative: fp.negative,
-                infinite: fp.infinite,
-            })
+        #[cfg(feature = "arith")]
+        pub const fn trunc(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::trunc)
         }
 
-        pub fn signum(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                zero: Possible::No,
-                infinite: Possible::No,
-                ..*fp
-            })
+        #[cfg(feature = "arith")]
+        pub const fn fract(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::fract)
         }
 
-        pub fn sqrt(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                nan: fp.nan | fp.negative,
-                ..*fp
-            })
+        #[cfg(feature = "arith")]
+        pub const fn signum(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::signum)
         }
 
-        pub fn exp(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                positive: Possible::Yes,
-                negative: Possible::No,
-                zero: fp.negative,
-                infinite: fp.positive,
-                nan: fp.nan,
-            })
+        #[cfg(feature = "arith")]
+        pub const fn sqrt(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::sqrt)
         }
 
-        pub fn exp2(lhs: &FnArgFloat) -> FnArgFloat {
-            exp(lhs)
+        #[cfg(feature = "exp-log")]
+        pub const fn exp(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::exp)
         }
 
-        pub fn ln(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                positive: Possible::Yes,
-                negative: Possible::Yes,
-                zero: fp.positive,
-                infinite: fp.infinite | fp.zero,
-                nan: fp.nan | fp.negative,
-            })
+        /// `exp`, rounded in the direction `mode`
+        #[cfg(feature = "exp-log")]
+        pub const fn exp_with(mode: RoundingMode, lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, |fp| fp::exp_with(mode, fp))
         }
 
-        pub fn log2(lhs: &FnArgFloat) -> FnArgFloat {
-            ln(lhs)
+        #[cfg(feature = "exp-log")]
+        pub const fn exp2(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::exp2)
         }
 
-        pub fn log10(lhs: &FnArgFloat) -> FnArgFloat {
-            ln(lhs)
+        /// `exp2`, rounded in the direction `mode`
+        #[cfg(feature = "exp-log")]
+        pub const fn exp2_with(mode: RoundingMode, lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, |fp| fp::exp2_with(mode, fp))
         }
 
-        pub fn to_degrees(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                // May reach Infinity with large values
-                infinite: Possible::Yes,
-                ..*fp
-            })
+        #[cfg(feature = "exp-log")]
+        pub const fn ln(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::ln)
         }
 
-        pub fn to_radians(lhs: &FnArgFloat) -> FnArgFloat {
-            *lhs
+        #[cfg(feature = "exp-log")]
+        pub const fn log2(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::log2)
         }
 
-        pub fn cbrt(lhs: &FnArgFloat) -> FnArgFloat {
-            *lhs
+        #[cfg(feature = "exp-log")]
+        pub const fn log10(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::log10)
         }
 
-        pub fn sin(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                positive: Possible::Yes,
-                negative: Possible::Yes,
-                zero: Possible::Yes,
-                infinite: Possible::No,
-                nan: fp.nan | fp.infinite,
-            })
+        #[cfg(feature = "trig")]
+        pub const fn to_degrees(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::to_degrees)
         }
 
-        pub fn cos(lhs: &FnArgFloat) -> FnArgFloat {
-            sin(lhs)
+        #[cfg(feature = "trig")]
+        pub const fn to_radians(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::to_radians)
         }
 
-        pub fn tan(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                positive: Possible::Yes,
-                negative: Possible::Yes,
-                zero: Possible::Yes,
-                infinite: Possible::Yes,
-                nan: fp.nan | fp.infinite,
-            })
+        /// `to_radians`, rounded in the direction `mode`
+        #[cfg(feature = "trig")]
+        pub const fn to_radians_with(mode: RoundingMode, lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, |fp| fp::to_radians_with(mode, fp))
         }
 
-        pub fn asin(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                zero: fp.zero,
-                infinite: Possible::No,
-                nan: Possible::Yes,
-                ..*fp
-            })
+        #[cfg(feature = "arith")]
+        pub const fn cbrt(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::cbrt)
         }
 
-        pub fn acos(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |_| FP {
-                positive: Possible::Yes,
-                negative: Possible::No,
-                zero: Possible::Yes,
-                infinite: Possible::No,
-                nan: Possible::Yes,
-            })
+        #[cfg(feature = "trig")]
+        pub const fn sin(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::sin)
         }
 
-        pub fn atan(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                infinite: Possible::No,
-                ..*fp
-            })
+        #[cfg(feature = "trig")]
+        pub const fn cos(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::cos)
         }
 
-        pub fn exp_m1(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                infinite: fp.positive,
-                ..*fp
-            })
+        /// The sine and the cosine, like `f64::sin_cos`
+        #[cfg(feature = "trig")]
+        pub const fn sin_cos(lhs: &FnArgFloat) -> (FnArgFloat, FnArgFloat) {
+            (sin(lhs), cos(lhs))
         }
 
-        pub fn ln_1p(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                nan: fp.nan | fp.negative,
-                infinite: fp.infinite | fp.negative,
-                ..*fp
-            })
+        #[cfg(feature = "trig")]
+        pub const fn tan(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::tan)
         }
 
-        pub fn sinh(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                infinite: Possible::Yes,
-                ..*fp
-            })
+        #[cfg(feature = "trig")]
+        pub const fn asin(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::asin)
         }
 
-        pub fn cosh(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                positive: Possible::Yes,
-                negative: Possible::No,
-                zero: Possible::No,
-                infinite: Possible::Yes,
-                nan: fp.nan,
-            })
+        #[cfg(feature = "trig")]
+        pub const fn acos(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::acos)
         }
 
-        pub fn tanh(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                infinite: Possible::No,
-                ..*fp
-            })
+        #[cfg(feature = "trig")]
+        pub const fn atan(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::atan)
         }
 
-        pub fn asinh(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                infinite: Possible::Yes,
-                ..*fp
-            })
+        #[cfg(feature = "exp-log")]
+        pub const fn exp_m1(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::exp_m1)
         }
 
-        pub fn acosh(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |_| FP {
-                positive: Possible::Yes,
-                negative: Possible::No,
-                zero: Possible::Yes,
-                infinite: Possible::Yes,
-                nan: Possible::Yes,
-            })
+        #[cfg(feature = "exp-log")]
+        pub const fn ln_1p(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::ln_1p)
         }
 
-        pub fn atanh(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                infinite: Possible::Yes,
-                nan: Possible::Yes,
-                ..*fp
-            })
+        #[cfg(feature = "hyperbolic")]
+        pub const fn sinh(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::sinh)
         }
 
-        pub fn recip(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                zero: fp.infinite,
-                infinite: fp.zero,
-                ..*fp
-            })
+        #[cfg(feature = "hyperbolic")]
+        pub const fn cosh(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::cosh)
         }
 
-        pub fn powi(lhs: &FnArgFloat) -> FnArgFloat {
-            return_fp(lhs, |fp| FP {
-                positive: Possible::Yes,
-                zero: Possible::Yes,
-                infinite: Possible::Yes,
-                ..*fp
-            })
+        #[cfg(feature = "hyperbolic")]
+        pub const fn tanh(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::tanh)
+        }
+
+        #[cfg(feature = "hyperbolic")]
+        pub const fn asinh(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::asinh)
+        }
+
+        #[cfg(feature = "hyperbolic")]
+        pub const fn acosh(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::acosh)
+        }
+
+        #[cfg(feature = "hyperbolic")]
+        pub const fn atanh(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::atanh)
+        }
+
+        #[cfg(feature = "arith")]
+        pub const fn recip(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::recip)
+        }
+
+        /// `recip`, rounded in the direction `mode`
+        #[cfg(feature = "arith")]
+        pub const fn recip_with(mode: RoundingMode, lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, |fp| fp::recip_with(mode, fp))
+        }
+
+        #[cfg(feature = "arith")]
+        pub const fn powi(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::powi)
+        }
+
+        /// `lhs.powi(n)`, using the sign and the parity of the exponent
+        #[cfg(feature = "arith")]
+        pub const fn powi_n(lhs: &FnArgFloat, n: i32) -> FnArgFloat {
+            return_fp!(lhs, |fp| powi_n_outcome(RoundingMode::ToNearest, fp, n).fp)
+        }
+
+        /// `powi_n`, rounded in the direction `mode`
+        #[cfg(feature = "arith")]
+        pub const fn powi_n_with(mode: RoundingMode, lhs: &FnArgFloat, n: i32) -> FnArgFloat {
+            return_fp!(lhs, |fp| powi_n_outcome(mode, fp, n).fp)
+        }
+
+        /// `powi_n`, telling the infinities from an overflow apart
+        #[cfg(feature = "arith")]
+        pub(crate) const fn powi_n_outcome(
+            mode: RoundingMode,
+            fp: &FP,
+            n: i32,
+        ) -> crate::config::Outcome {
+            if n == 0 {
+                // Exactly one, even for NaN
+                let one = FP {
+                    nan: Possible::No,
+                    pos_zero: Possible::No,
+                    neg_zero: Possible::No,
+                    subnormal: Possible::No,
+                    fractional: Possible::No,
+                    large: Possible::No,
+                    infinite: Possible::No,
+                    positive: Possible::Yes,
+                    negative: Possible::No,
+                };
+
+                return crate::config::Outcome::without_overflow(one);
+            }
+
+            if n == 1 {
+                return crate::config::Outcome::without_overflow(*fp);
+            }
+
+            let mut cases = crate::config::Cases::NONE;
+            let non_nan = any!(fp.positive, fp.negative);
+
+            // The repeated multiplications may underflow, and overflow if large
+            let rounding = all!(non_nan, Possible::ShouldNot);
+            cases = cases.fire("underflow", rounding);
+            let overflow = all!(if n > 0 { fp.large } else { non_nan }, Possible::ShouldNot);
+            cases = cases.fire("overflow", overflow);
+
+            let (positive, negative) = if n % 2 == 0 {
+                (non_nan, Possible::No)
+            } else {
+                (fp.positive, fp.negative)
+            };
+
+            // A negative exponent swaps zero and infinite, like `recip`,
+            // and the integers stay integers, and `[-1, 1]` stays, with a positive one
+            let (zero, infinite, fractional, large) = if n > 0 {
+                (fp.zero(), fp.infinite, fp.fractional, fp.large)
+            } else {
+                (fp.infinite, fp.zero(), non_nan, non_nan)
+            };
+
+            let res = FP {
+                nan: fp.nan,
+                pos_zero: zero,
+                neg_zero: zero,
+                subnormal: non_nan,
+                fractional,
+                large,
+                infinite,
+                positive,
+                negative,
+            };
+
+            crate::config::Outcome {
+                cases,
+                ..crate::config::round_overflow(
+                    crate::config::round_underflow(res, rounding, mode),
+                    overflow,
+                    mode,
+                )
+            }
+        }
+
+        /// The least value greater than `lhs`, like `f64::next_up`
+        #[cfg(feature = "arith")]
+        pub const fn next_up(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::next_up)
+        }
+
+        /// The greatest value less than `lhs`, like `f64::next_down`
+        #[cfg(feature = "arith")]
+        pub const fn next_down(lhs: &FnArgFloat) -> FnArgFloat {
+            return_fp!(lhs, fp::next_down)
         }
     }
 }
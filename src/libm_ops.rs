@@ -0,0 +1,229 @@
+//! Models of the functions of the [`libm`](https://crates.io/crates/libm) crate,
+//! named after them.
+//!
+//! The type of the argument selects the function: `F32` for `erff`, `F64` for `erf`.
+//!
+//! Functions returning an integer or several values
+//! (`frexp`, `ilogb`, `lgamma_r`, `modf`, `remquo` and `sincos`) are not modeled.
+//!
+//! ```
+//! use fn_num_types::{FnArgFloat, Possible, FP};
+//!
+//! let x = FnArgFloat::F64(FP {
+//!     nan: Possible::No,
+//!     pos_zero: Possible::No,
+//!     neg_zero: Possible::No,
+//!     subnormal: Possible::Yes,
+//!     fractional: Possible::Yes,
+//!     large: Possible::Yes,
+//!     infinite: Possible::Yes,
+//!     positive: Possible::Yes,
+//!     negative: Possible::No,
+//! });
+//!
+//! // erfc(inf) == 0
+//! match fn_num_types::libm_ops::erfc(&x) {
+//!     FnArgFloat::F64(fp) => assert_eq!(fp.zero(), Possible::Yes),
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+use crate::config::round_underflow;
+use crate::core::ops::{max_with, min_with, next_down, next_up};
+use crate::{FnArgFloat, NanPolicy, Possible, RoundingMode, FP};
+
+pub use crate::core::ops::{
+    abs as fabs, abs_sub as fdim, acos, acosh, asin, asinh, atan, atan2, atanh, cbrt, ceil,
+    copysign, cos, cosh, exp, exp10, exp2, exp_m1 as expm1, floor, gamma as tgamma, hypot,
+    ln as log, ln_1p as log1p, ln_gamma as lgamma, log10, log2, max as fmax, maximum as fmaximum,
+    min as fmin, minimum as fminimum, mul_add as fma, mul_pow2 as ldexp, mul_pow2 as scalbn,
+    powf as pow, rem as fmod, round, round_ties_even as rint, round_ties_even as roundeven, sin,
+    sinh, sqrt, tan, tanh, trunc,
+};
+
+/// The error function
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn erf(lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| FP {
+        // In [-1, 1]
+        infinite: Possible::No,
+        fractional: fp.positive | fp.negative,
+        large: Possible::No,
+        ..*fp
+    })
+}
+
+/// The complementary error function, `1 - erf(x)`
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn erfc(lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| {
+        // Large arguments underflow
+        let underflow = fp.positive & Possible::ShouldNot;
+        rule!(underflow, "underflow");
+
+        let res = FP {
+            nan: fp.nan,
+            // erfc(inf) == 0
+            pos_zero: fp.infinite & fp.positive,
+            neg_zero: Possible::No,
+            // In [0, 2]
+            subnormal: fp.positive,
+            fractional: fp.positive | fp.negative,
+            large: fp.negative,
+            infinite: Possible::No,
+            positive: fp.positive | fp.negative,
+            negative: Possible::No,
+        };
+
+        round_underflow(res, underflow, RoundingMode::ToNearest)
+    })
+}
+
+/// Bessel function of the first kind, of order `n`
+fn jn_fp(fp: &FP) -> FP {
+    let non_nan = fp.positive | fp.negative;
+
+    FP {
+        nan: fp.nan,
+        // jn(n, 0) == 0 except for the order zero, jn(n, ±inf) == 0,
+        // and the roots are not representable
+        pos_zero: fp.zero() | fp.infinite | (non_nan & Possible::Should),
+        neg_zero: fp.zero() | fp.infinite | (non_nan & Possible::Should),
+        subnormal: non_nan,
+        fractional: non_nan,
+        // |jn(n, x)| <= 1
+        large: Possible::No,
+        infinite: Possible::No,
+        positive: non_nan,
+        negative: non_nan,
+    }
+}
+
+/// Bessel function of the second kind, of order `n`
+fn yn_fp(fp: &FP, n: i32) -> FP {
+    // Tiny arguments overflow, except for the order zero
+    let overflow = if n == 0 {
+        Possible::No
+    } else {
+        fp.positive & Possible::ShouldNot
+    };
+    rule!(overflow, "overflow");
+
+    // Defined for positive arguments only
+    rule!(fp.negative, "negative argument");
+
+    FP {
+        nan: fp.nan | fp.negative,
+        // yn(n, inf) == 0, and the roots are not representable
+        pos_zero: (fp.infinite & fp.positive) | (fp.positive & Possible::Should),
+        neg_zero: (fp.infinite & fp.positive) | (fp.positive & Possible::Should),
+        // yn(n, ±0) == -inf, or inf for a negative odd order
+        subnormal: fp.positive,
+        fractional: fp.positive,
+        large: fp.positive,
+        infinite: fp.zero() | overflow,
+        positive: fp.positive
+            | if n < 0 && n % 2 != 0 {
+                fp.zero()
+            } else {
+                Possible::No
+            },
+        negative: fp.positive | fp.zero(),
+    }
+}
+
+/// Bessel function of the first kind, of order 0
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn j0(lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, jn_fp)
+}
+
+/// Bessel function of the first kind, of order 1
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn j1(lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, jn_fp)
+}
+
+/// Bessel function of the first kind, of order `n`
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn jn(_n: i32, lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, jn_fp)
+}
+
+/// Bessel function of the second kind, of order 0
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn y0(lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| yn_fp(fp, 0))
+}
+
+/// Bessel function of the second kind, of order 1
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn y1(lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| yn_fp(fp, 1))
+}
+
+/// Bessel function of the second kind, of order `n`
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn yn(n: i32, lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| yn_fp(fp, n))
+}
+
+/// IEEE 754 remainder: `a - n * b`, with `n` the nearest integer to `a / b`
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn remainder(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, |fp1, fp2| {
+        let non_nan1 = fp1.positive | fp1.negative;
+        let non_nan2 = fp2.positive | fp2.negative;
+
+        rule!(fp2.zero(), "remainder by zero");
+        rule!(fp1.infinite, "infinite dividend");
+
+        // The remainder is exact, and zero when the dividend is a multiple of the divisor
+        let multiple = non_nan1 & non_nan2;
+        rule!(multiple, "multiple of the divisor");
+
+        FP {
+            nan: fp1.nan | fp2.nan | fp2.zero() | fp1.infinite,
+            pos_zero: multiple,
+            neg_zero: multiple,
+            subnormal: multiple,
+            fractional: fp1.fractional | fp2.fractional,
+            large: fp1.large & (fp2.large | fp2.infinite),
+            infinite: Possible::No,
+            // The quotient is rounded to nearest, so the sign may differ from the dividend
+            positive: multiple,
+            negative: multiple,
+        }
+    })
+}
+
+/// The next value after `x` toward `y`
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn nextafter(x: &FnArgFloat, y: &FnArgFloat) -> FnArgFloat {
+    let up = next_up(x);
+    let down = next_down(x);
+
+    return_fp2!(x, y, |fp1, fp2| {
+        // `y` when equal to `x`, which differs for the zeros
+        let res = fp1.union(up.possibilities()).union(down.possibilities());
+
+        FP {
+            nan: fp1.nan | fp2.nan,
+            pos_zero: res.pos_zero | (fp1.zero() & fp2.pos_zero),
+            neg_zero: res.neg_zero | (fp1.zero() & fp2.neg_zero),
+            ..res
+        }
+    })
+}
+
+/// IEEE 754-2019 `maximumNumber`
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn fmaximum_num(x: &FnArgFloat, y: &FnArgFloat) -> FnArgFloat {
+    max_with(NanPolicy::MaximumNumber, x, y)
+}
+
+/// IEEE 754-2019 `minimumNumber`
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+pub fn fminimum_num(x: &FnArgFloat, y: &FnArgFloat) -> FnArgFloat {
+    min_with(NanPolicy::MaximumNumber, x, y)
+}
@@ -0,0 +1,70 @@
+use crate::config::{Cases, Outcome};
+use crate::{FnArgFloat, Possible, FP};
+
+/// Logarithm of `x` in the base `base`, computed as `x.ln() / base.ln()`
+pub const fn log(x: &FnArgFloat, base: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(x, base, log_fp)
+}
+
+/// `core::ops::log`
+pub const fn log_fp(fp_x: &FP, fp_b: &FP) -> FP {
+    log_outcome(fp_x, fp_b).fp
+}
+
+/// `core::ops::log`, with the special cases it handled
+pub(crate) const fn log_outcome(fp_x: &FP, fp_b: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    // Only the negative zero may have a logarithm
+    let valid_x = any!(fp_x.positive, fp_x.zero());
+    let valid_b = any!(fp_b.positive, fp_b.zero());
+
+    cases = cases.fire("negative argument", any!(fp_x.negative, fp_b.negative));
+
+    // inf / inf, when both logarithms are infinite
+    let both_inf = all!(
+        any!(fp_x.zero(), fp_x.infinite),
+        any!(fp_b.zero(), fp_b.infinite)
+    );
+    cases = cases.fire("infinite logarithms", both_inf);
+
+    // 0 / 0, when both are one
+    let both_one = all!(fp_x.positive, fp_b.positive);
+    cases = cases.fire("log of one in base one", both_one);
+
+    // Base one: its logarithm is zero
+    let base_one = all!(valid_x, fp_b.positive);
+    cases = cases.fire("base one", base_one);
+
+    let res = FP {
+        nan: any!(
+            fp_x.nan,
+            fp_b.nan,
+            fp_x.negative,
+            fp_b.negative,
+            both_inf,
+            both_one
+        ),
+        // `x` is one, or the logarithm of the base is infinite
+        pos_zero: any!(
+            all!(fp_x.positive, valid_b),
+            all!(valid_x, any!(fp_b.zero(), fp_b.infinite))
+        ),
+        neg_zero: any!(
+            all!(fp_x.positive, valid_b),
+            all!(valid_x, any!(fp_b.zero(), fp_b.infinite))
+        ),
+        // The logarithms are far from the subnormals
+        subnormal: Possible::No,
+        fractional: all!(valid_x, valid_b),
+        large: all!(valid_x, valid_b),
+        infinite: base_one,
+        // Negative when `x` and `base` are on both sides of one
+        positive: all!(valid_x, valid_b),
+        negative: all!(valid_x, valid_b),
+    };
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(res)
+    }
+}
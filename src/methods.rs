@@ -0,0 +1,124 @@
+//! The functions of `core::ops` as methods of `FnArgFloat`, named like those of `f64`
+//!
+//! ```
+//! use fn_num_types::{BoolPossibility, FloatPossibilities, FnArgFloat, Possible};
+//!
+//! let x = FnArgFloat::F64(FloatPossibilities::any());
+//! assert_eq!(x.abs().sqrt().ln().possibilities().nan, Possible::Yes);
+//!
+//! let y = FnArgFloat::F64(FloatPossibilities::positive_finite());
+//! assert_eq!(y.sqrt().is_nan(), BoolPossibility::False);
+//! assert_eq!(y.sqrt().ln().is_infinite(), BoolPossibility::Maybe);
+//! ```
+//!
+//! The arithmetic operators are implemented with the traits of `core::ops`.
+
+use crate::core::ops;
+use crate::{BoolPossibility, FnArgFloat};
+
+macro_rules! unary {
+    ($($op:ident),* $(,)?) => {
+        impl FnArgFloat {
+            $(
+                #[doc = concat!("`core::ops::", stringify!($op), "`")]
+                pub fn $op(&self) -> Self {
+                    ops::$op(self)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident),* $(,)?) => {
+        impl FnArgFloat {
+            $(
+                #[doc = concat!("`core::ops::", stringify!($op), "`")]
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    ops::$op(self, rhs)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! ternary {
+    ($($op:ident),* $(,)?) => {
+        impl FnArgFloat {
+            $(
+                #[doc = concat!("`core::ops::", stringify!($op), "`")]
+                pub fn $op(&self, b: &Self, c: &Self) -> Self {
+                    ops::$op(self, b, c)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! predicate {
+    ($($op:ident),* $(,)?) => {
+        impl FnArgFloat {
+            $(
+                #[doc = concat!("`core::ops::", stringify!($op), "`")]
+                pub fn $op(&self) -> BoolPossibility {
+                    ops::$op(self)
+                }
+            )*
+        }
+    };
+}
+
+unary!(
+    abs,
+    ceil,
+    floor,
+    round,
+    round_ties_even,
+    trunc,
+    fract,
+    signum,
+    sqrt,
+    exp,
+    exp2,
+    ln,
+    log2,
+    log10,
+    to_degrees,
+    to_radians,
+    cbrt,
+    sin,
+    cos,
+    tan,
+    asin,
+    acos,
+    atan,
+    exp_m1,
+    ln_1p,
+    sinh,
+    cosh,
+    tanh,
+    asinh,
+    acosh,
+    atanh,
+    recip,
+    powi,
+    next_up,
+    next_down,
+);
+
+binary!(
+    div_euclid, rem_euclid, powf, hypot, atan2, copysign, max, min, maximum, minimum, log,
+    midpoint,
+);
+
+ternary!(mul_add, clamp);
+
+predicate!(
+    is_nan,
+    is_infinite,
+    is_finite,
+    is_sign_positive,
+    is_sign_negative,
+    is_normal,
+    is_subnormal,
+);
@@ -0,0 +1,70 @@
+use crate::config::{round_underflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+/// `f64::midpoint`: `(a + b) / 2`, without the intermediate overflow
+pub const fn midpoint(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, midpoint_fp)
+}
+
+/// `midpoint`, rounded in the direction `mode`
+pub const fn midpoint_with(mode: RoundingMode, a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, |fp1, fp2| midpoint_fp_with(mode, fp1, fp2))
+}
+
+/// `core::ops::midpoint`
+#[inline]
+pub const fn midpoint_fp(fp1: &FP, fp2: &FP) -> FP {
+    midpoint_fp_with(RoundingMode::ToNearest, fp1, fp2)
+}
+
+/// `core::ops::midpoint`, rounded in the direction `mode`
+pub const fn midpoint_fp_with(mode: RoundingMode, fp1: &FP, fp2: &FP) -> FP {
+    midpoint_outcome(mode, fp1, fp2).fp
+}
+
+/// `core::ops::midpoint`, with the special cases it handled
+pub(crate) const fn midpoint_outcome(mode: RoundingMode, fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan1 = any!(fp1.positive, fp1.negative);
+    let non_nan2 = any!(fp2.positive, fp2.negative);
+
+    // Half of a subnormal may underflow
+    let underflow = all!(non_nan1, non_nan2, Possible::ShouldNot);
+    cases = cases.fire("underflow", underflow);
+
+    // Opposit infinities
+    let both_inf = all!(fp1.infinite, fp2.infinite);
+    let opposite = any!(
+        all!(fp1.positive, fp2.negative),
+        all!(fp1.negative, fp2.positive)
+    );
+    cases = cases.fire("opposite infinities", all!(both_inf, opposite));
+
+    // Zero
+    cases = cases.fire("cancellation", opposite);
+
+    let res = FP {
+        nan: any!(fp1.nan, fp2.nan, all!(both_inf, opposite)),
+        pos_zero: any!(
+            all!(fp1.pos_zero, fp2.zero()),
+            all!(fp1.zero(), fp2.pos_zero),
+            opposite
+        ),
+        neg_zero: all!(fp1.neg_zero, fp2.neg_zero),
+        // Like the midpoint of `MIN_POSITIVE` and `-0.0`
+        subnormal: all!(non_nan1, non_nan2),
+        // Like the midpoint of `1.0` and `0.0`
+        fractional: all!(non_nan1, non_nan2),
+        // Between the arguments
+        large: any!(fp1.large, fp2.large),
+        // Finite values can't overflow
+        infinite: any!(fp1.infinite, fp2.infinite),
+        positive: any!(fp1.positive, fp2.positive),
+        negative: any!(fp1.negative, fp2.negative),
+    };
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(round_underflow(res, underflow, mode))
+    }
+}
@@ -0,0 +1,195 @@
+use crate::fp_ops as fp;
+use crate::{FnArgFloat, Possible, FP};
+
+/// How `min`, `max` and `clamp` handle NaN and signed zeros
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// `f64::max`, `f64::min` and `f64::clamp`:
+    /// a NaN argument is ignored (but `clamp` returns NaN if `self` is NaN),
+    /// and `-0.0` and `+0.0` are considered equal.
+    #[default]
+    Std,
+    /// IEEE 754-2019 `maximumNumber` and `minimumNumber`:
+    /// a NaN argument is ignored and `-0.0 < +0.0`.
+    MaximumNumber,
+    /// IEEE 754-2019 `maximum` and `minimum`:
+    /// NaN is always propagated and `-0.0 < +0.0`.
+    Propagate,
+}
+
+/// The NaN of `fp`, if ignored
+const fn ignored(fp: &FP, ignore_nan: bool) -> Possible {
+    if ignore_nan {
+        fp.nan
+    } else {
+        Possible::No
+    }
+}
+
+/// The other argument may be returned
+const fn through(fp: &FP, ignore_nan: bool) -> Possible {
+    any!(fp.positive, fp.negative, ignored(fp, ignore_nan))
+}
+
+/// A zero is returned when the other argument is not greater
+const fn below_zero(fp: &FP, ignore_nan: bool) -> Possible {
+    any!(fp.negative, fp.zero(), ignored(fp, ignore_nan))
+}
+
+const fn max_fp(a: &FP, b: &FP, ignore_nan: bool, ordered_zeros: bool) -> FP {
+    let nan = if ignore_nan {
+        all!(a.nan, b.nan)
+    } else {
+        any!(a.nan, b.nan)
+    };
+
+    let positive = any!(
+        all!(a.positive, through(b, ignore_nan)),
+        all!(b.positive, through(a, ignore_nan))
+    );
+
+    let mut negative = any!(
+        all!(a.negative, any!(b.negative, ignored(b, ignore_nan))),
+        all!(b.negative, any!(a.negative, ignored(a, ignore_nan)))
+    );
+
+    if !ordered_zeros {
+        // max(-0.0, +0.0) may be -0.0
+        negative = any!(
+            negative,
+            all!(
+                a.zero(),
+                b.zero(),
+                any!(all!(a.negative, b.positive), all!(a.positive, b.negative))
+            )
+        );
+    }
+
+    let pos_zero = any!(
+        all!(a.pos_zero, below_zero(b, ignore_nan)),
+        all!(b.pos_zero, below_zero(a, ignore_nan))
+    );
+    let neg_zero = any!(
+        all!(a.neg_zero, below_zero(b, ignore_nan)),
+        all!(b.neg_zero, below_zero(a, ignore_nan))
+    );
+
+    let positive_infinite = any!(
+        all!(a.infinite, a.positive, through(b, ignore_nan)),
+        all!(b.infinite, b.positive, through(a, ignore_nan))
+    );
+    let negative_infinite = any!(
+        all!(
+            a.infinite,
+            a.negative,
+            any!(all!(b.infinite, b.negative), ignored(b, ignore_nan))
+        ),
+        all!(
+            b.infinite,
+            b.negative,
+            any!(all!(a.infinite, a.negative), ignored(a, ignore_nan))
+        )
+    );
+
+    FP {
+        nan,
+        pos_zero,
+        neg_zero,
+        subnormal: any!(a.subnormal, b.subnormal),
+        fractional: any!(a.fractional, b.fractional),
+        large: any!(a.large, b.large),
+        infinite: any!(positive_infinite, negative_infinite),
+        positive,
+        negative,
+    }
+}
+
+const fn neg_fp(fp: &FP) -> FP {
+    FP {
+        pos_zero: fp.neg_zero,
+        neg_zero: fp.pos_zero,
+        positive: fp.negative,
+        negative: fp.positive,
+        ..*fp
+    }
+}
+
+const fn min_fp(a: &FP, b: &FP, ignore_nan: bool, ordered_zeros: bool) -> FP {
+    neg_fp(&max_fp(&neg_fp(a), &neg_fp(b), ignore_nan, ordered_zeros))
+}
+
+pub(crate) const fn max_fp_with(policy: NanPolicy, a: &FP, b: &FP) -> FP {
+    match policy {
+        NanPolicy::Std => max_fp(a, b, true, false),
+        NanPolicy::MaximumNumber => max_fp(a, b, true, true),
+        NanPolicy::Propagate => max_fp(a, b, false, true),
+    }
+}
+
+pub(crate) const fn min_fp_with(policy: NanPolicy, a: &FP, b: &FP) -> FP {
+    match policy {
+        NanPolicy::Std => min_fp(a, b, true, false),
+        NanPolicy::MaximumNumber => min_fp(a, b, true, true),
+        NanPolicy::Propagate => min_fp(a, b, false, true),
+    }
+}
+
+pub(crate) const fn clamp_fp_with(policy: NanPolicy, x: &FP, min: &FP, max: &FP) -> FP {
+    match policy {
+        NanPolicy::Std => {
+            let lower = max_fp(x, &min.with_nan(Possible::No), false, false);
+            min_fp(&lower, &max.with_nan(Possible::No), false, false)
+        }
+        NanPolicy::MaximumNumber | NanPolicy::Propagate => {
+            min_fp_with(policy, &max_fp_with(policy, x, min), max)
+        }
+    }
+}
+
+pub const fn max_with(policy: NanPolicy, lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(lhs, rhs, |fp1, fp2| max_fp_with(policy, fp1, fp2))
+}
+
+pub const fn min_with(policy: NanPolicy, lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(lhs, rhs, |fp1, fp2| min_fp_with(policy, fp1, fp2))
+}
+
+/// `min(max(x, min), max)`
+///
+/// With `NanPolicy::Std`, `f64::clamp` panics if a bound is NaN,
+/// so only a NaN `x` may result in NaN.
+pub const fn clamp_with(
+    policy: NanPolicy,
+    x: &FnArgFloat,
+    min: &FnArgFloat,
+    max: &FnArgFloat,
+) -> FnArgFloat {
+    return_fp3!(x, min, max, |fp, min, max| {
+        clamp_fp_with(policy, fp, min, max)
+    })
+}
+
+/// `f64::max`: a NaN argument is ignored
+pub const fn max(lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(lhs, rhs, fp::max)
+}
+
+/// `f64::min`: a NaN argument is ignored
+pub const fn min(lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(lhs, rhs, fp::min)
+}
+
+/// `f64::maximum`: NaN is propagated and `-0.0 < +0.0`
+pub const fn maximum(lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(lhs, rhs, fp::maximum)
+}
+
+/// `f64::minimum`: NaN is propagated and `-0.0 < +0.0`
+pub const fn minimum(lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(lhs, rhs, fp::minimum)
+}
+
+/// `f64::clamp`: only a NaN `x` may result in NaN
+pub const fn clamp(x: &FnArgFloat, min: &FnArgFloat, max: &FnArgFloat) -> FnArgFloat {
+    return_fp3!(x, min, max, fp::clamp)
+}
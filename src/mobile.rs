@@ -0,0 +1,174 @@
+//! UniFFI bindings, to generate Kotlin and Swift bindings with `uniffi-bindgen`.
+
+use crate::core::ops;
+use crate::{FloatPossibilities, FnArgFloat, Possible};
+
+/// The flags of `FloatPossibilities`, whose record can't be derived on the
+/// generic struct
+#[derive(Debug, Clone, Copy, uniffi::Record)]
+pub struct Possibilities {
+    pub nan: Possible,
+    pub pos_zero: Possible,
+    pub neg_zero: Possible,
+    pub subnormal: Possible,
+    pub fractional: Possible,
+    pub large: Possible,
+    pub infinite: Possible,
+    pub positive: Possible,
+    pub negative: Possible,
+}
+
+uniffi::custom_type!(FloatPossibilities, Possibilities);
+
+impl crate::UniffiCustomTypeConverter for FloatPossibilities {
+    type Builtin = Possibilities;
+
+    fn into_custom(fp: Possibilities) -> uniffi::Result<Self> {
+        Ok(FloatPossibilities {
+            nan: fp.nan,
+            pos_zero: fp.pos_zero,
+            neg_zero: fp.neg_zero,
+            subnormal: fp.subnormal,
+            fractional: fp.fractional,
+            large: fp.large,
+            infinite: fp.infinite,
+            positive: fp.positive,
+            negative: fp.negative,
+        })
+    }
+
+    fn from_custom(fp: Self) -> Possibilities {
+        Possibilities {
+            nan: fp.nan,
+            pos_zero: fp.pos_zero,
+            neg_zero: fp.neg_zero,
+            subnormal: fp.subnormal,
+            fractional: fp.fractional,
+            large: fp.large,
+            infinite: fp.infinite,
+            positive: fp.positive,
+            negative: fp.negative,
+        }
+    }
+}
+
+#[derive(Debug, uniffi::Error)]
+pub enum MobileError {
+    DifferentTypes,
+}
+
+impl std::fmt::Display for MobileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MobileError::DifferentTypes => f.write_str("Different types"),
+        }
+    }
+}
+
+impl std::error::Error for MobileError {}
+
+macro_rules! unary {
+    ($($op:ident),* $(,)?) => {
+        $(
+            #[uniffi::export]
+            pub fn $op(lhs: FnArgFloat) -> FnArgFloat {
+                ops::$op(&lhs)
+            }
+        )*
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident),* $(,)?) => {
+        $(
+            #[uniffi::export]
+            pub fn $op(lhs: FnArgFloat, rhs: FnArgFloat) -> Result<FnArgFloat, MobileError> {
+                FnArgFloat::check_same_type(&[lhs, rhs])
+                    .map_err(|_| MobileError::DifferentTypes)?;
+
+                Ok(ops::$op(&lhs, &rhs))
+            }
+        )*
+    };
+}
+
+macro_rules! ternary {
+    ($($op:ident),* $(,)?) => {
+        $(
+            #[uniffi::export]
+            pub fn $op(a: FnArgFloat, b: FnArgFloat, c: FnArgFloat) -> Result<FnArgFloat, MobileError> {
+                FnArgFloat::check_same_type(&[a, b, c])
+                    .map_err(|_| MobileError::DifferentTypes)?;
+
+                Ok(ops::$op(&a, &b, &c))
+            }
+        )*
+    };
+}
+
+/// Returns true if the value is accepted
+#[uniffi::export]
+pub fn accept(arg: FnArgFloat, value: f64) -> bool {
+    arg.possibilities().accept(value)
+}
+
+unary!(
+    neg,
+    abs,
+    ceil,
+    floor,
+    round,
+    round_ties_even,
+    trunc,
+    fract,
+    signum,
+    sqrt,
+    exp,
+    exp2,
+    ln,
+    log2,
+    log10,
+    to_degrees,
+    to_radians,
+    cbrt,
+    sin,
+    cos,
+    tan,
+    asin,
+    acos,
+    atan,
+    exp_m1,
+    ln_1p,
+    sinh,
+    cosh,
+    tanh,
+    asinh,
+    acosh,
+    atanh,
+    recip,
+    powi,
+    next_up,
+    next_down,
+    to_f32,
+    to_f64,
+);
+
+binary!(add);
+binary!(mul);
+binary!(div);
+binary!(rem);
+binary!(div_euclid);
+binary!(rem_euclid);
+binary!(powf);
+binary!(hypot);
+binary!(atan2);
+binary!(copysign);
+binary!(max);
+binary!(min);
+binary!(maximum);
+binary!(minimum);
+binary!(log);
+binary!(midpoint);
+
+ternary!(mul_add);
+ternary!(clamp);
@@ -0,0 +1,86 @@
+use crate::config::{round_overflow, round_underflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+pub const fn mul(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, mul_fp)
+}
+
+/// `mul`, rounded in the direction `mode`
+pub const fn mul_with(mode: RoundingMode, a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, |fp1, fp2| mul_fp_with(mode, fp1, fp2))
+}
+
+/// `core::ops::mul`
+#[inline]
+pub const fn mul_fp(fp1: &FP, fp2: &FP) -> FP {
+    mul_fp_with(RoundingMode::ToNearest, fp1, fp2)
+}
+
+/// `core::ops::mul`, rounded in the direction `mode`
+#[inline]
+pub const fn mul_fp_with(mode: RoundingMode, fp1: &FP, fp2: &FP) -> FP {
+    mul_outcome(mode, fp1, fp2).fp
+}
+
+/// `core::ops::mul`, telling the infinities from an overflow apart
+pub(crate) const fn mul_outcome(mode: RoundingMode, fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_zero1 = any!(fp1.positive, fp1.negative);
+    let non_zero2 = any!(fp2.positive, fp2.negative);
+
+    // Two finite values may underflow, and overflow if both are large
+    let rounding = all!(non_zero1, non_zero2, Possible::ShouldNot);
+    cases = cases.fire("underflow", rounding);
+    let overflow = all!(fp1.large, fp2.large, Possible::ShouldNot);
+    cases = cases.fire("overflow", overflow);
+
+    let product = product_outcome(fp1, fp2);
+
+    Outcome {
+        cases: cases.extend(product.cases),
+        ..round_overflow(round_underflow(product.fp, rounding, mode), overflow, mode)
+    }
+}
+
+/// The exact product, before rounding
+pub(crate) const fn product_outcome(fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_zero1 = any!(fp1.positive, fp1.negative);
+    let non_zero2 = any!(fp2.positive, fp2.negative);
+
+    // 0 * inf
+    let zero_inf = any!(
+        all!(fp1.zero(), fp2.infinite),
+        all!(fp1.infinite, fp2.zero())
+    );
+    cases = cases.fire("zero times infinity", zero_inf);
+
+    let res = FP {
+        nan: any!(fp1.nan, fp2.nan, zero_inf),
+        pos_zero: any!(all!(fp1.zero(), non_zero2), all!(fp2.zero(), non_zero1)),
+        neg_zero: any!(all!(fp1.zero(), non_zero2), all!(fp2.zero(), non_zero1)),
+        // Like `MIN_POSITIVE * 0.5`
+        subnormal: all!(non_zero1, non_zero2),
+        // The product of two integers is an integer, even rounded
+        fractional: any!(
+            all!(fp1.fractional, non_zero2),
+            all!(fp2.fractional, non_zero1)
+        ),
+        // Values in `[-1, 1]` stay in it
+        large: any!(all!(fp1.large, non_zero2), all!(fp2.large, non_zero1)),
+        infinite: any!(all!(fp1.infinite, non_zero2), all!(fp2.infinite, non_zero1)),
+        positive: any!(
+            all!(fp1.positive, fp2.positive),
+            all!(fp1.negative, fp2.negative)
+        ),
+        negative: any!(
+            all!(fp1.positive, fp2.negative),
+            all!(fp1.negative, fp2.positive)
+        ),
+    };
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(res)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::add::add_outcome;
+use crate::config::{round_overflow, round_underflow, Cases, Outcome};
+use crate::mul::product_outcome;
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+/// `a * b + c`, rounded once
+///
+/// The product is not rounded, so it can't overflow before the addition:
+/// only an infinite argument may make it infinite.
+pub const fn mul_add(a: &FnArgFloat, b: &FnArgFloat, c: &FnArgFloat) -> FnArgFloat {
+    return_fp3!(a, b, c, mul_add_fp)
+}
+
+/// `mul_add`, rounded in the direction `mode`
+pub const fn mul_add_with(
+    mode: RoundingMode,
+    a: &FnArgFloat,
+    b: &FnArgFloat,
+    c: &FnArgFloat,
+) -> FnArgFloat {
+    return_fp3!(a, b, c, |fp1, fp2, fp3| {
+        mul_add_fp_with(mode, fp1, fp2, fp3)
+    })
+}
+
+/// `core::ops::mul_add`
+#[inline]
+pub const fn mul_add_fp(fp1: &FP, fp2: &FP, fp3: &FP) -> FP {
+    mul_add_fp_with(RoundingMode::ToNearest, fp1, fp2, fp3)
+}
+
+/// `core::ops::mul_add`, rounded in the direction `mode`
+#[inline]
+pub const fn mul_add_fp_with(mode: RoundingMode, fp1: &FP, fp2: &FP, fp3: &FP) -> FP {
+    mul_add_outcome(mode, fp1, fp2, fp3).fp
+}
+
+/// `core::ops::mul_add`, telling the infinities from an overflow apart
+pub(crate) const fn mul_add_outcome(mode: RoundingMode, fp1: &FP, fp2: &FP, fp3: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_zero1 = any!(fp1.positive, fp1.negative);
+    let non_zero2 = any!(fp2.positive, fp2.negative);
+
+    // The exact product, outside of the range of the floats, may underflow,
+    // or overflow if both factors are large
+    let rounding = all!(non_zero1, non_zero2, Possible::ShouldNot);
+    cases = cases.fire("underflow", rounding);
+    let overflow = all!(fp1.large, fp2.large, Possible::ShouldNot);
+    cases = cases.fire("overflow", overflow);
+
+    let product = product_outcome(fp1, fp2);
+    let sum = add_outcome(mode, &product.fp, fp3);
+    let res = round_overflow(round_underflow(sum.fp, rounding, mode), overflow, mode);
+
+    Outcome {
+        overflow: any!(sum.overflow, res.overflow),
+        cases: cases.extend(product.cases).extend(sum.cases),
+        ..res
+    }
+}
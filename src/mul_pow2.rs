@@ -0,0 +1,108 @@
+use crate::config::{round_overflow, round_underflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+/// `lhs * 2^exp`, exact unless the result leaves the normal range
+///
+/// The exponents of the type bound the reach of the scaling: `0.5 * 2^100`
+/// overflows an `f16`, not an `f64`.
+pub const fn mul_pow2(lhs: &FnArgFloat, exp: i32) -> FnArgFloat {
+    mul_pow2_with(RoundingMode::ToNearest, lhs, exp)
+}
+
+/// `mul_pow2`, rounded in the direction `mode`
+pub const fn mul_pow2_with(mode: RoundingMode, lhs: &FnArgFloat, exp: i32) -> FnArgFloat {
+    lhs.with_possibilities(mul_pow2_outcome(mode, lhs, exp).fp)
+}
+
+/// `possible` if the binades `[low, high]` reach `[from, to]`
+const fn reaches(possible: Possible, (low, high): (i64, i64), from: i64, to: i64) -> Possible {
+    if low <= to && high >= from {
+        possible
+    } else {
+        Possible::No
+    }
+}
+
+/// `mul_pow2_with`, with the special cases it handled
+pub(crate) const fn mul_pow2_outcome(mode: RoundingMode, lhs: &FnArgFloat, exp: i32) -> Outcome {
+    let fp = lhs.possibilities();
+    if exp == 0 {
+        return Outcome::without_overflow(*fp);
+    }
+    let mut cases = Cases::NONE;
+
+    let (exponent, significand) = lhs.widths();
+    let n = exp as i64;
+    let p = significand as i64;
+    // The binades of the normal values, and of the least subnormal
+    let max_binade = (1i64 << (exponent - 1)) - 1;
+    let min_binade = 1 - max_binade;
+    let least_binade = min_binade - (p - 1);
+
+    // 1.0 has no flag of its own
+    let one = any!(fp.positive, fp.negative);
+
+    // Above one, the non-integers are large too
+    let fractional_binade = if matches!(fp.large, Possible::No) {
+        -1
+    } else {
+        p - 2
+    };
+
+    // The binades of each kind of value, and whether they have non-integers
+    let kinds = [
+        (fp.subnormal, least_binade, min_binade - 1, true),
+        (fp.fractional, least_binade, fractional_binade, true),
+        (fp.large, 0, max_binade, false),
+        (one, 0, 0, false),
+    ];
+
+    let mut res = FP {
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        large: Possible::No,
+        ..*fp
+    };
+    let mut overflow = Possible::No;
+    let mut underflow = Possible::No;
+
+    let mut i = 0;
+    while i < kinds.len() {
+        let (possible, low, high, non_integer) = kinds[i];
+        let binades = (low + n, high + n);
+
+        overflow = any!(
+            overflow,
+            reaches(possible, binades, max_binade + 1, i64::MAX)
+        );
+        // Half of the least subnormal is rounded to zero, above to it
+        underflow = any!(
+            underflow,
+            reaches(possible, binades, i64::MIN, least_binade - 1)
+        );
+        res.subnormal = any!(
+            res.subnormal,
+            reaches(possible, binades, least_binade - 1, min_binade - 1)
+        );
+        res.large = any!(res.large, reaches(possible, binades, 0, max_binade));
+        // Below one, or above without reaching the integers only
+        res.fractional = any!(
+            res.fractional,
+            reaches(possible, binades, least_binade - 1, -1)
+        );
+        if non_integer || n < 0 {
+            res.fractional = any!(res.fractional, reaches(possible, binades, 0, p - 2));
+        }
+        i += 1;
+    }
+
+    let overflow = all!(overflow, Possible::ShouldNot);
+    cases = cases.fire("overflow", overflow);
+    let underflow = all!(underflow, Possible::ShouldNot);
+    cases = cases.fire("underflow", underflow);
+
+    Outcome {
+        cases,
+        ..round_overflow(round_underflow(res, underflow, mode), overflow, mode)
+    }
+}
@@ -0,0 +1,151 @@
+//! Functions not (yet) stable in `std`, or deprecated
+
+use crate::config::{round_overflow, round_underflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+/// `10^lhs`
+pub const fn exp10(lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| exp10_outcome(fp).fp)
+}
+
+/// `exp10`, with the special cases it handled
+pub(crate) const fn exp10_outcome(fp: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    // Below about -323 (or -45, ...)
+    let underflow = all!(fp.negative, fp.large, Possible::ShouldNot);
+    cases = cases.fire("underflow", underflow);
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(round_underflow(
+            FP {
+                positive: Possible::Yes,
+                negative: Possible::No,
+                // exp10(-inf) is +0.0
+                pos_zero: all!(fp.negative, fp.infinite),
+                neg_zero: Possible::No,
+                subnormal: fp.negative,
+                fractional: any!(fp.positive, fp.negative),
+                large: fp.positive,
+                infinite: fp.positive,
+                nan: fp.nan,
+            },
+            underflow,
+            RoundingMode::ToNearest,
+        ))
+    }
+}
+
+/// The gamma function
+pub const fn gamma(lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| gamma_outcome(fp).fp)
+}
+
+/// `gamma`, with the special cases it handled
+pub(crate) const fn gamma_outcome(fp: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan = any!(fp.positive, fp.negative);
+
+    // Large arguments, and the reciprocal of tiny ones, overflow
+    let overflow = all!(non_nan, Possible::ShouldNot);
+    cases = cases.fire("overflow", overflow);
+
+    // Large negative non-integers underflow
+    let underflow = all!(fp.negative, fp.large, Possible::ShouldNot);
+    cases = cases.fire("underflow", underflow);
+
+    // Negative integers and -inf are poles
+    cases = cases.fire("negative pole", fp.negative);
+
+    let res = FP {
+        nan: any!(fp.nan, fp.negative),
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: fp.negative,
+        fractional: non_nan,
+        large: non_nan,
+        // gamma(±0) == ±inf and gamma(inf) == inf
+        infinite: any!(fp.zero(), all!(fp.infinite, fp.positive), overflow),
+        // The sign alternates between the negative poles
+        positive: non_nan,
+        negative: fp.negative,
+    };
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(round_underflow(res, underflow, RoundingMode::ToNearest))
+    }
+}
+
+/// The natural logarithm of the absolute value of the gamma function
+pub const fn ln_gamma(lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| ln_gamma_outcome(fp).fp)
+}
+
+/// `ln_gamma`, with the special cases it handled
+pub(crate) const fn ln_gamma_outcome(fp: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan = any!(fp.positive, fp.negative);
+
+    // Huge arguments overflow
+    let overflow = all!(non_nan, Possible::ShouldNot);
+    cases = cases.fire("overflow", overflow);
+
+    let res = FP {
+        nan: fp.nan,
+        // ln_gamma(1) == ln_gamma(2) == 0
+        pos_zero: non_nan,
+        neg_zero: non_nan,
+        // ln_gamma(1 + EPSILON) is normal
+        subnormal: Possible::No,
+        fractional: non_nan,
+        large: non_nan,
+        // Zero, the negative integers and the infinities are poles
+        infinite: any!(fp.zero(), fp.infinite, fp.negative, overflow),
+        positive: non_nan,
+        // gamma(x) < 1 between 1 and 2, and between some negative poles
+        negative: non_nan,
+    };
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(res)
+    }
+}
+
+/// The positive difference, `max(a - b, 0)`
+pub const fn abs_sub(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, |fp1, fp2| abs_sub_outcome(fp1, fp2).fp)
+}
+
+/// `abs_sub`, with the special cases it handled
+pub(crate) const fn abs_sub_outcome(fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan1 = any!(fp1.positive, fp1.negative);
+    let non_nan2 = any!(fp2.positive, fp2.negative);
+
+    // A positive minus a negative may overflow
+    let overflow = all!(fp1.positive, fp2.negative, Possible::ShouldNot);
+    cases = cases.fire("overflow", overflow);
+
+    let res = FP {
+        nan: any!(fp1.nan, fp2.nan),
+        // When a <= b
+        pos_zero: all!(non_nan1, non_nan2),
+        neg_zero: all!(non_nan1, non_nan2),
+        subnormal: all!(non_nan1, non_nan2),
+        fractional: any!(fp1.fractional, fp2.fractional),
+        large: any!(fp1.large, fp2.large, all!(fp1.positive, fp2.negative)),
+        infinite: any!(
+            all!(fp1.infinite, fp1.positive, non_nan2),
+            all!(fp2.infinite, fp2.negative, non_nan1)
+        ),
+        positive: all!(non_nan1, non_nan2),
+        negative: Possible::No,
+    };
+
+    Outcome {
+        cases,
+        ..round_overflow(res, overflow, RoundingMode::ToNearest)
+    }
+}
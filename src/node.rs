@@ -0,0 +1,108 @@
+//! Node.js bindings.
+//!
+//! Possibilities are exchanged in the compact textual format:
+//!
+//! ```js
+//! const { apply } = require('fn_num_types');
+//!
+//! apply('sqrt', ['f64 zero:Y +:Y']); // 'f64 nan:N zero:Y sub:N frac:Y large:N inf:N +:Y -:N'
+//! ```
+
+use napi::{Error, Result};
+use napi_derive::napi;
+
+use crate::core::ops;
+use crate::FnArgFloat;
+
+fn error(err: crate::Error) -> Error {
+    Error::from_reason(err.to_string())
+}
+
+/// An operation and its number of arguments
+#[napi(object)]
+pub struct OpInfo {
+    pub name: String,
+    pub arity: u32,
+}
+
+macro_rules! ops {
+    (unary: $($op:ident),* ; binary: $($op2:ident),* ; ternary: $($op3:ident),* $(,)?) => {
+        fn infos() -> Vec<OpInfo> {
+            vec![
+                $(OpInfo { name: stringify!($op).to_owned(), arity: 1 },)*
+                $(OpInfo { name: stringify!($op2).to_owned(), arity: 2 },)*
+                $(OpInfo { name: stringify!($op3).to_owned(), arity: 3 },)*
+            ]
+        }
+
+        fn apply_op(name: &str, args: &[FnArgFloat]) -> Result<FnArgFloat> {
+            $(
+                if name == stringify!($op) {
+                    return match args {
+                        [lhs] => Ok(ops::$op(lhs)),
+                        _ => Err(error(crate::Error::Arity { op: name.to_owned(), found: args.len() })),
+                    };
+                }
+            )*
+            $(
+                if name == stringify!($op2) {
+                    return match args {
+                        [lhs, rhs] => {
+                            FnArgFloat::check_same_type(args).map_err(error)?;
+                            Ok(ops::$op2(lhs, rhs))
+                        }
+                        _ => Err(error(crate::Error::Arity { op: name.to_owned(), found: args.len() })),
+                    };
+                }
+            )*
+            $(
+                if name == stringify!($op3) {
+                    return match args {
+                        [a, b, c] => {
+                            FnArgFloat::check_same_type(args).map_err(error)?;
+                            Ok(ops::$op3(a, b, c))
+                        }
+                        _ => Err(error(crate::Error::Arity { op: name.to_owned(), found: args.len() })),
+                    };
+                }
+            )*
+            Err(error(crate::Error::UnknownOp(name.to_owned())))
+        }
+    };
+}
+
+ops!(
+    unary: neg, abs, ceil, floor, round, round_ties_even, trunc, fract, signum, sqrt, exp, exp2, ln, log2, log10,
+    to_degrees, to_radians, cbrt, sin, cos, tan, asin, acos, atan, exp_m1, ln_1p, sinh, cosh, tanh,
+    asinh, acosh, atanh, recip, powi, next_up, next_down, to_f32, to_f64;
+    binary: add, mul, div, rem, div_euclid, rem_euclid, powf, hypot, atan2, copysign, max, min,
+    maximum, minimum, log, midpoint;
+    ternary: mul_add, clamp,
+);
+
+fn parse(spec: &str) -> Result<FnArgFloat> {
+    spec.parse().map_err(|err| error(crate::Error::Parse(err)))
+}
+
+/// Lists the available operations
+#[napi]
+pub fn list_ops() -> Vec<OpInfo> {
+    infos()
+}
+
+/// Returns the possibilities of the result of the operation `op`
+#[napi]
+pub fn apply(op: String, args: Vec<String>) -> Result<String> {
+    let args = args
+        .iter()
+        .map(|arg| parse(arg))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(apply_op(&op, &args)?.to_string())
+}
+
+/// Returns true if `value` is accepted by the possibilities
+#[napi]
+pub fn accept(spec: String, value: f64) -> Result<bool> {
+    Ok(parse(&spec)?.possibilities().accept(value))
+}
@@ -0,0 +1,85 @@
+use alloc::vec::Vec;
+
+use crate::stats::split_first;
+use crate::{FnArgFloat, Possible, FP};
+
+/// Euclidean norm, `sqrt(sum(x²))`
+///
+/// # Panics
+///
+/// If `args` is empty or its elements are not of the same type
+pub const fn norm(args: &[FnArgFloat]) -> FnArgFloat {
+    let (first, rest) = split_first(args);
+
+    let mut nan = Possible::No;
+    let mut infinite = Possible::No;
+    let mut zero = Possible::Yes;
+    let mut subnormal = Possible::No;
+    let mut all_numbers = Possible::Yes;
+    let mut large = Possible::No;
+    let mut i = 0;
+    while i < args.len() {
+        let fp = args[i].possibilities();
+        let non_nan = any!(fp.positive, fp.negative);
+
+        nan = any!(nan, fp.nan);
+        // Overflow of a square or of the sum
+        infinite = any!(infinite, fp.infinite, all!(non_nan, Possible::ShouldNot));
+        // Underflow of the squares
+        zero = all!(zero, any!(fp.zero(), all!(non_nan, Possible::ShouldNot)));
+        subnormal = any!(subnormal, fp.subnormal);
+        all_numbers = all!(all_numbers, non_nan);
+        large = any!(large, fp.large);
+        i += 1;
+    }
+
+    first.with_possibilities(FP {
+        nan,
+        infinite,
+        pos_zero: zero,
+        neg_zero: zero,
+        // Not less than the absolute value of each component
+        subnormal,
+        // Like `norm(&[1.0, 1.0])`
+        fractional: all_numbers,
+        // Like `norm(&[0.75, 0.75])`, but a single component keeps its magnitude
+        large: if rest.is_empty() { large } else { all_numbers },
+        positive: all_numbers,
+        negative: Possible::No,
+    })
+}
+
+/// Divides each component by the norm
+///
+/// The components are NaN if the norm can be zero,
+/// or infinite if the squares of non-zero components can underflow.
+///
+/// # Panics
+///
+/// If `args` is empty or its elements are not of the same type
+pub fn normalize(args: &[FnArgFloat]) -> Vec<FnArgFloat> {
+    let norm = norm(args);
+
+    args.iter()
+        .map(|arg| {
+            return_fp2!(arg, &norm, |x, norm| {
+                let non_zero = any!(x.positive, x.negative);
+
+                FP {
+                    // 0 / 0, inf / inf and NaN from any component
+                    nan: any!(x.nan, x.infinite, norm.nan, all!(norm.zero(), x.zero())),
+                    infinite: all!(norm.zero(), non_zero, Possible::ShouldNot),
+                    // Finite / inf, and underflow
+                    pos_zero: any!(x.zero(), norm.infinite, all!(non_zero, Possible::ShouldNot)),
+                    neg_zero: any!(x.zero(), norm.infinite, all!(non_zero, Possible::ShouldNot)),
+                    subnormal: non_zero,
+                    fractional: non_zero,
+                    // In `[-1, 1]`, give or take the rounding
+                    large: all!(non_zero, Possible::ShouldNot),
+                    positive: x.positive,
+                    negative: x.negative,
+                }
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,161 @@
+//! The methods of `num_traits::Float`, for the generic code written against it.
+//!
+//! `analyze::<T>` applies the model of a method by its name, whatever the type
+//! `T` the code is instantiated at.
+//!
+//! ```
+//! use fn_num_types::num_float::{analyze, FloatAnalysis};
+//! use fn_num_types::{BoolPossibility, FloatPossibilities};
+//!
+//! fn sqrt_is_nan<T: num_traits::Float + fn_num_types::verify::Float>() -> FloatAnalysis {
+//!     let x = FloatPossibilities::positive();
+//!     let sqrt = analyze::<T>("sqrt", &[x]).unwrap();
+//!     let FloatAnalysis::Float(sqrt) = sqrt else { unreachable!() };
+//!
+//!     analyze::<T>("is_nan", &[*sqrt.possibilities()]).unwrap()
+//! }
+//!
+//! assert_eq!(sqrt_is_nan::<f32>(), FloatAnalysis::Bool(BoolPossibility::False));
+//! assert_eq!(sqrt_is_nan::<f64>(), FloatAnalysis::Bool(BoolPossibility::False));
+//!
+//! let FloatAnalysis::Float(eps) = analyze::<f32>("epsilon", &[]).unwrap() else { panic!() };
+//! assert!(eps.accept(f32::EPSILON));
+//! ```
+
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
+
+use crate::core::ops;
+use crate::verify::Float;
+use crate::{BoolPossibility, Error, FloatPossibilities, FnArgFloat};
+
+/// The analysis of a method: the possibilities of a float, or of a `bool`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatAnalysis {
+    Float(FnArgFloat),
+    Bool(BoolPossibility),
+}
+
+/// The methods of `num_traits::Float` with an analysis, and `neg`,
+/// with their number of arguments, `self` included
+///
+/// Not the ones returning something else than a float or a `bool`, like `sin_cos`
+/// and `integer_decode`. `powi` is of any exponent.
+pub const METHODS: &[(&str, usize)] = &[
+    // The constants
+    ("nan", 0),
+    ("infinity", 0),
+    ("neg_infinity", 0),
+    ("neg_zero", 0),
+    ("min_value", 0),
+    ("min_positive_value", 0),
+    ("max_value", 0),
+    ("epsilon", 0),
+    // The predicates
+    ("is_nan", 1),
+    ("is_infinite", 1),
+    ("is_finite", 1),
+    ("is_normal", 1),
+    ("is_subnormal", 1),
+    ("is_sign_positive", 1),
+    ("is_sign_negative", 1),
+    // Of one argument
+    ("neg", 1),
+    ("floor", 1),
+    ("ceil", 1),
+    ("round", 1),
+    ("trunc", 1),
+    ("fract", 1),
+    ("abs", 1),
+    ("signum", 1),
+    ("recip", 1),
+    ("powi", 1),
+    ("sqrt", 1),
+    ("exp", 1),
+    ("exp2", 1),
+    ("ln", 1),
+    ("log2", 1),
+    ("log10", 1),
+    ("to_degrees", 1),
+    ("to_radians", 1),
+    ("cbrt", 1),
+    ("sin", 1),
+    ("cos", 1),
+    ("tan", 1),
+    ("asin", 1),
+    ("acos", 1),
+    ("atan", 1),
+    ("exp_m1", 1),
+    ("ln_1p", 1),
+    ("sinh", 1),
+    ("cosh", 1),
+    ("tanh", 1),
+    ("asinh", 1),
+    ("acosh", 1),
+    ("atanh", 1),
+    // Of two
+    ("powf", 2),
+    ("log", 2),
+    ("max", 2),
+    ("min", 2),
+    ("abs_sub", 2),
+    ("hypot", 2),
+    ("atan2", 2),
+    ("copysign", 2),
+    // Of three
+    ("mul_add", 3),
+];
+
+/// The analysis of the method `method` of `num_traits::Float`, at the type `T`,
+/// for the possibilities of `self` and of the other arguments
+///
+/// # Errors
+///
+/// `Error::UnknownOp` if it is not one of `METHODS`, and `Error::Arity`
+/// if `args` are not one per argument of the method.
+pub fn analyze<T>(method: &str, args: &[FloatPossibilities]) -> Result<FloatAnalysis, Error>
+where
+    T: num_traits::Float + Float,
+{
+    let Some((_, arity)) = METHODS.iter().find(|(m, _)| *m == method) else {
+        return Err(Error::UnknownOp(method.to_owned()));
+    };
+
+    let constant = |value: T| Ok(FloatAnalysis::Float(T::arg(value.into())));
+
+    match (method, args) {
+        ("nan", []) => constant(T::nan()),
+        ("infinity", []) => constant(T::infinity()),
+        ("neg_infinity", []) => constant(T::neg_infinity()),
+        ("neg_zero", []) => constant(T::neg_zero()),
+        ("min_value", []) => constant(T::min_value()),
+        ("min_positive_value", []) => constant(T::min_positive_value()),
+        ("max_value", []) => constant(T::max_value()),
+        ("epsilon", []) => constant(T::epsilon()),
+        ("is_nan", [x]) => Ok(FloatAnalysis::Bool(ops::is_nan(&T::arg(*x)))),
+        ("is_infinite", [x]) => Ok(FloatAnalysis::Bool(ops::is_infinite(&T::arg(*x)))),
+        ("is_finite", [x]) => Ok(FloatAnalysis::Bool(ops::is_finite(&T::arg(*x)))),
+        ("is_normal", [x]) => Ok(FloatAnalysis::Bool(ops::is_normal(&T::arg(*x)))),
+        ("is_subnormal", [x]) => Ok(FloatAnalysis::Bool(ops::is_subnormal(&T::arg(*x)))),
+        ("is_sign_positive", [x]) => Ok(FloatAnalysis::Bool(ops::is_sign_positive(&T::arg(*x)))),
+        ("is_sign_negative", [x]) => Ok(FloatAnalysis::Bool(ops::is_sign_negative(&T::arg(*x)))),
+        // `0` if `x <= y`, else `x - y`, NaN included
+        ("abs_sub", [x, y]) => {
+            let (x, y) = (T::arg(*x), T::arg(*y));
+            let zero = T::arg(FloatPossibilities::from(0.0));
+            Ok(FloatAnalysis::Float(ops::select(
+                ops::cmp::le(&x, &y),
+                &zero,
+                &(x - y),
+            )))
+        }
+        (_, [_, ..]) if args.len() == *arity => {
+            let args: Vec<FnArgFloat> = args.iter().map(|fp| T::arg(*fp)).collect();
+            ops::apply(method, &args).map(FloatAnalysis::Float)
+        }
+        _ => Err(Error::Arity {
+            op: method.to_owned(),
+            found: args.len(),
+        }),
+    }
+}
@@ -0,0 +1,89 @@
+//! The operators of `core::ops`, on owned and borrowed `FnArgFloat`
+//!
+//! ```
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! let a = FnArgFloat::F64(FloatPossibilities::positive_finite());
+//! let b = FnArgFloat::F64(FloatPossibilities::positive_finite());
+//! let c = FnArgFloat::F64(FloatPossibilities::strictly_positive_finite());
+//!
+//! let res = -(&a + &b) * &c;
+//! assert_eq!(res.possibilities().nan, Possible::No);
+//! assert_eq!(res.possibilities().positive, Possible::No);
+//! ```
+//!
+//! # Panics
+//!
+//! Like the functions of `core::ops`, if the arguments are not of the same type.
+
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::core::ops;
+use crate::FnArgFloat;
+
+/// `a - b` is exactly `a + (-b)`, including the sign of the zeros
+fn sub(lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat {
+    ops::add(lhs, &ops::neg(rhs))
+}
+
+macro_rules! binary {
+    ($($trait:ident, $method:ident => $op:path;)*) => {
+        $(
+            impl $trait<&FnArgFloat> for &FnArgFloat {
+                type Output = FnArgFloat;
+
+                fn $method(self, rhs: &FnArgFloat) -> FnArgFloat {
+                    $op(self, rhs)
+                }
+            }
+
+            impl $trait<FnArgFloat> for &FnArgFloat {
+                type Output = FnArgFloat;
+
+                fn $method(self, rhs: FnArgFloat) -> FnArgFloat {
+                    $op(self, &rhs)
+                }
+            }
+
+            impl $trait<&FnArgFloat> for FnArgFloat {
+                type Output = FnArgFloat;
+
+                fn $method(self, rhs: &FnArgFloat) -> FnArgFloat {
+                    $op(&self, rhs)
+                }
+            }
+
+            impl $trait<FnArgFloat> for FnArgFloat {
+                type Output = FnArgFloat;
+
+                fn $method(self, rhs: FnArgFloat) -> FnArgFloat {
+                    $op(&self, &rhs)
+                }
+            }
+        )*
+    };
+}
+
+binary! {
+    Add, add => ops::add;
+    Sub, sub => sub;
+    Mul, mul => ops::mul;
+    Div, div => ops::div;
+    Rem, rem => ops::rem;
+}
+
+impl Neg for &FnArgFloat {
+    type Output = FnArgFloat;
+
+    fn neg(self) -> FnArgFloat {
+        ops::neg(self)
+    }
+}
+
+impl Neg for FnArgFloat {
+    type Output = FnArgFloat;
+
+    fn neg(self) -> FnArgFloat {
+        ops::neg(&self)
+    }
+}
@@ -0,0 +1,224 @@
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+use crate::{Field, FloatPossibilities, Possible};
+
+/// The exact, infinitely precise, result of an operation.
+///
+/// Used to decide if an outcome is mathematically possible,
+/// or only reached because of the rounding error.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Exact {
+    NaN,
+    Infinity {
+        negative: bool,
+    },
+    Zero {
+        negative: bool,
+    },
+    /// A finite non-zero value
+    Value(BigRational),
+}
+
+impl Exact {
+    /// Returns the exact value of a float
+    ///
+    /// ```
+    /// use fn_num_types::oracle::Exact;
+    ///
+    /// assert_eq!(Exact::from_f64(-0.0), Exact::Zero { negative: true });
+    /// assert_eq!(Exact::from_f64(f64::NEG_INFINITY), Exact::Infinity { negative: true });
+    /// ```
+    pub fn from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            Exact::NaN
+        } else if value.is_infinite() {
+            Exact::Infinity {
+                negative: value.is_sign_negative(),
+            }
+        } else if value == 0.0 {
+            Exact::Zero {
+                negative: value.is_sign_negative(),
+            }
+        } else {
+            Exact::Value(BigRational::from_float(value).unwrap())
+        }
+    }
+
+    fn is_negative(&self) -> bool {
+        match self {
+            Exact::NaN => false,
+            Exact::Infinity { negative } | Exact::Zero { negative } => *negative,
+            Exact::Value(value) => value.is_negative(),
+        }
+    }
+
+    /// Returns the possibilities reached by this exact value
+    pub fn possibilities(&self) -> FloatPossibilities {
+        let sign = |negative: bool| {
+            if negative {
+                (Possible::No, Possible::Yes)
+            } else {
+                (Possible::Yes, Possible::No)
+            }
+        };
+
+        let mut fp = FloatPossibilities {
+            nan: Possible::No,
+            pos_zero: Possible::No,
+            neg_zero: Possible::No,
+            subnormal: Possible::No,
+            fractional: Possible::No,
+            large: Possible::No,
+            infinite: Possible::No,
+            positive: Possible::No,
+            negative: Possible::No,
+        };
+
+        match self {
+            Exact::NaN => fp.nan = Possible::Yes,
+            Exact::Infinity { negative } => {
+                fp.infinite = Possible::Yes;
+                (fp.positive, fp.negative) = sign(*negative);
+            }
+            Exact::Zero { negative } => {
+                if *negative {
+                    fp.neg_zero = Possible::Yes;
+                } else {
+                    fp.pos_zero = Possible::Yes;
+                }
+                (fp.positive, fp.negative) = sign(*negative);
+            }
+            Exact::Value(value) => {
+                (fp.positive, fp.negative) = sign(value.is_negative());
+
+                // Below the normal range of `f64`
+                let min_positive = BigRational::from_float(f64::MIN_POSITIVE).unwrap();
+                if value.abs() < min_positive {
+                    fp.subnormal = Possible::Yes;
+                }
+
+                if !value.is_integer() {
+                    fp.fractional = Possible::Yes;
+                }
+
+                if value.abs() > BigRational::one() {
+                    fp.large = Possible::Yes;
+                }
+            }
+        }
+
+        fp
+    }
+
+    /// Returns true if every outcome reached by this exact value
+    /// is at least `Possible::Should` in `model`.
+    ///
+    /// An outcome marked `Possible::ShouldNot` (or `Possible::No`)
+    /// must never be mathematically possible.
+    pub fn is_allowed_by(&self, model: &FloatPossibilities) -> bool {
+        allowed_by(&self.possibilities(), model)
+    }
+}
+
+/// `Exact::is_allowed_by`, with the possibilities of the exact value computed once
+pub fn allowed_by(exact: &FloatPossibilities, model: &FloatPossibilities) -> bool {
+    Field::ALL
+        .into_iter()
+        .all(|field| exact.get(field) == Possible::No || model.get(field) >= Possible::Should)
+}
+
+pub fn add(lhs: &Exact, rhs: &Exact) -> Exact {
+    match (lhs, rhs) {
+        (Exact::NaN, _) | (_, Exact::NaN) => Exact::NaN,
+        (Exact::Infinity { negative: a }, Exact::Infinity { negative: b }) => {
+            if a == b {
+                Exact::Infinity { negative: *a }
+            } else {
+                Exact::NaN
+            }
+        }
+        (Exact::Infinity { negative }, _) | (_, Exact::Infinity { negative }) => Exact::Infinity {
+            negative: *negative,
+        },
+        (Exact::Zero { negative: a }, Exact::Zero { negative: b }) => {
+            Exact::Zero { negative: *a && *b }
+        }
+        (Exact::Zero { .. }, value) | (value, Exact::Zero { .. }) => value.clone(),
+        (Exact::Value(a), Exact::Value(b)) => {
+            let sum = a + b;
+            if sum.is_zero() {
+                // The sum of two opposite values is positive zero
+                Exact::Zero { negative: false }
+            } else {
+                Exact::Value(sum)
+            }
+        }
+    }
+}
+
+pub fn neg(lhs: &Exact) -> Exact {
+    match lhs {
+        Exact::NaN => Exact::NaN,
+        Exact::Infinity { negative } => Exact::Infinity {
+            negative: !negative,
+        },
+        Exact::Zero { negative } => Exact::Zero {
+            negative: !negative,
+        },
+        Exact::Value(value) => Exact::Value(-value),
+    }
+}
+
+pub fn sub(lhs: &Exact, rhs: &Exact) -> Exact {
+    add(lhs, &neg(rhs))
+}
+
+pub fn mul(lhs: &Exact, rhs: &Exact) -> Exact {
+    let negative = lhs.is_negative() != rhs.is_negative();
+
+    match (lhs, rhs) {
+        (Exact::NaN, _) | (_, Exact::NaN) => Exact::NaN,
+        (Exact::Infinity { .. }, Exact::Zero { .. })
+        | (Exact::Zero { .. }, Exact::Infinity { .. }) => Exact::NaN,
+        (Exact::Infinity { .. }, _) | (_, Exact::Infinity { .. }) => Exact::Infinity { negative },
+        (Exact::Zero { .. }, _) | (_, Exact::Zero { .. }) => Exact::Zero { negative },
+        (Exact::Value(a), Exact::Value(b)) => Exact::Value(a * b),
+    }
+}
+
+pub fn recip(lhs: &Exact) -> Exact {
+    match lhs {
+        Exact::NaN => Exact::NaN,
+        Exact::Infinity { negative } => Exact::Zero {
+            negative: *negative,
+        },
+        Exact::Zero { negative } => Exact::Infinity {
+            negative: *negative,
+        },
+        Exact::Value(value) => Exact::Value(value.recip()),
+    }
+}
+
+pub fn div(lhs: &Exact, rhs: &Exact) -> Exact {
+    mul(lhs, &recip(rhs))
+}
+
+pub fn rem(lhs: &Exact, rhs: &Exact) -> Exact {
+    match (lhs, rhs) {
+        (Exact::NaN, _) | (_, Exact::NaN) => Exact::NaN,
+        (Exact::Infinity { .. }, _) | (_, Exact::Zero { .. }) => Exact::NaN,
+        (value, Exact::Infinity { .. }) | (value @ Exact::Zero { .. }, _) => value.clone(),
+        (Exact::Value(a), Exact::Value(b)) => {
+            let rem = a - (a / b).trunc() * b;
+            if rem.is_zero() {
+                // The sign follows the dividend
+                Exact::Zero {
+                    negative: a.is_negative(),
+                }
+            } else {
+                Exact::Value(rem)
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Where the infinities come from, on top of the possibilities.
+//!
+//! An infinite result may propagate an infinite argument, or come from the
+//! overflow of finite values. An `OverflowFloat` also carries the possibility
+//! of the latter, so that a diagnostic can tell "may overflow" apart from
+//! "may be infinite".
+//!
+//! ```
+//! use fn_num_types::overflow::OverflowFloat;
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! let finite = OverflowFloat::from(FnArgFloat::F64(FloatPossibilities::finite()));
+//! let sum = finite.add(&finite);
+//! assert_eq!(sum.infinite_from_overflow(), Possible::ShouldNot);
+//!
+//! // Only the infinities of the arguments
+//! let any = OverflowFloat::from(FnArgFloat::F64(FloatPossibilities::any()));
+//! let sign = any.mul(&finite.signum());
+//! assert_eq!(sign.arg().possibilities().infinite, Possible::Yes);
+//! assert_eq!(sign.infinite_from_overflow(), Possible::No);
+//!
+//! // Which is remembered
+//! assert_eq!(sum.mul(&finite.signum()).infinite_from_overflow(), Possible::ShouldNot);
+//! ```
+
+use crate::config::Outcome;
+use crate::core::ops::fp;
+use crate::{FnArgFloat, Possible, RoundingMode};
+
+/// The possibilities of a float, with the possibility that it is an infinity
+/// from an overflow
+///
+/// The overflows are those of the models: the arithmetic operations of this
+/// type tell them apart from their other infinities.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverflowFloat {
+    arg: FnArgFloat,
+    infinite_from_overflow: Possible,
+}
+
+impl OverflowFloat {
+    /// `arg`, whose infinities may come from an overflow as told by `infinite_from_overflow`
+    ///
+    /// It is bounded by the possibility of the infinities.
+    pub fn new(arg: FnArgFloat, infinite_from_overflow: Possible) -> Self {
+        OverflowFloat {
+            arg,
+            infinite_from_overflow: infinite_from_overflow & arg.possibilities().infinite,
+        }
+    }
+
+    /// The possibilities, wherever the infinities come from
+    pub fn arg(&self) -> &FnArgFloat {
+        &self.arg
+    }
+
+    /// The possibility that `self` is an infinity from an overflow,
+    /// in this operation or an earlier one
+    pub fn infinite_from_overflow(&self) -> Possible {
+        self.infinite_from_overflow
+    }
+
+    /// The result of a model, whose infinities come from an overflow if it
+    /// overflows, or if it propagates the infinities of `args` that do
+    fn apply(args: &[&Self], outcome: Outcome) -> Self {
+        let first = args[0].arg;
+        assert!(
+            args.iter().all(|x| first.is_same_type(&x.arg)),
+            "Different types"
+        );
+
+        let propagated = args
+            .iter()
+            .fold(Possible::No, |acc, x| acc | x.infinite_from_overflow);
+
+        OverflowFloat::new(
+            first.with_possibilities(outcome.fp),
+            outcome.overflow | propagated,
+        )
+    }
+
+    /// `x - y`, exactly `x + (-y)`
+    pub fn sub(&self, rhs: &Self) -> Self {
+        let outcome = crate::add::add_outcome(
+            RoundingMode::ToNearest,
+            self.arg.possibilities(),
+            &fp::neg(rhs.arg.possibilities()),
+        );
+
+        OverflowFloat::apply(&[self, rhs], outcome)
+    }
+
+    /// `x * y + z`, with a single rounding
+    pub fn mul_add(&self, y: &Self, z: &Self) -> Self {
+        let outcome = crate::mul_add::mul_add_outcome(
+            RoundingMode::ToNearest,
+            self.arg.possibilities(),
+            y.arg.possibilities(),
+            z.arg.possibilities(),
+        );
+
+        OverflowFloat::apply(&[self, y, z], outcome)
+    }
+
+    /// `x^n`
+    pub fn powi(&self, n: i32) -> Self {
+        let outcome =
+            crate::core::ops::powi_n_outcome(RoundingMode::ToNearest, self.arg.possibilities(), n);
+
+        OverflowFloat::apply(&[self], outcome)
+    }
+
+    /// `1 / x`
+    pub fn recip(&self) -> Self {
+        let outcome =
+            crate::fp_ops::recip_outcome(RoundingMode::ToNearest, self.arg.possibilities());
+
+        OverflowFloat::apply(&[self], outcome)
+    }
+}
+
+/// From the arguments: not from an overflow
+impl From<FnArgFloat> for OverflowFloat {
+    fn from(arg: FnArgFloat) -> Self {
+        OverflowFloat::new(arg, Possible::No)
+    }
+}
+
+/// The models that can't overflow
+macro_rules! unary {
+    ($($op:ident),* $(,)?) => {
+        impl OverflowFloat {
+            $(
+                pub fn $op(&self) -> Self {
+                    let outcome = Outcome::without_overflow(fp::$op(self.arg.possibilities()));
+
+                    OverflowFloat::apply(&[self], outcome)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident),* $(,)?) => {
+        impl OverflowFloat {
+            $(
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    let outcome = Outcome::without_overflow(fp::$op(
+                        self.arg.possibilities(),
+                        rhs.arg.possibilities(),
+                    ));
+
+                    OverflowFloat::apply(&[self, rhs], outcome)
+                }
+            )*
+        }
+    };
+}
+
+/// The models telling their overflows apart
+macro_rules! overflowing {
+    ($($op:ident => $model:path),* $(,)?) => {
+        impl OverflowFloat {
+            $(
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    let outcome = $model(
+                        RoundingMode::ToNearest,
+                        self.arg.possibilities(),
+                        rhs.arg.possibilities(),
+                    );
+
+                    OverflowFloat::apply(&[self, rhs], outcome)
+                }
+            )*
+        }
+    };
+}
+
+unary!(
+    neg,
+    abs,
+    ceil,
+    floor,
+    round,
+    round_ties_even,
+    trunc,
+    fract,
+    signum,
+    sqrt,
+    cbrt
+);
+
+binary!(rem, rem_euclid, midpoint);
+
+overflowing!(
+    add => crate::add::add_outcome,
+    mul => crate::mul::mul_outcome,
+    div => crate::div::div_outcome,
+    div_euclid => crate::euclid::div_euclid_outcome,
+    powf => crate::powf::powf_outcome,
+    hypot => crate::hypot::hypot_outcome,
+);
@@ -0,0 +1,76 @@
+use crate::config::{round_overflow, round_underflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+pub const fn powf(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, powf_fp)
+}
+
+/// `powf`, rounded in the direction `mode`
+pub const fn powf_with(mode: RoundingMode, a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, |fp1, fp2| powf_fp_with(mode, fp1, fp2))
+}
+
+/// `core::ops::powf`
+#[inline]
+pub const fn powf_fp(fp1: &FP, fp2: &FP) -> FP {
+    powf_fp_with(RoundingMode::ToNearest, fp1, fp2)
+}
+
+/// `core::ops::powf`, rounded in the direction `mode`
+#[inline]
+pub const fn powf_fp_with(mode: RoundingMode, fp1: &FP, fp2: &FP) -> FP {
+    powf_outcome(mode, fp1, fp2).fp
+}
+
+/// `core::ops::powf`, telling the infinities from an overflow apart
+pub(crate) const fn powf_outcome(mode: RoundingMode, fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan1 = any!(fp1.positive, fp1.negative);
+    let non_nan2 = any!(fp2.positive, fp2.negative);
+
+    // Two finite values may overflow or underflow
+    let rounding = all!(non_nan1, non_nan2, Possible::ShouldNot);
+    cases = cases.fire("overflow or underflow", rounding);
+
+    // x^0 == 1, even for NaN
+    cases = cases.fire("power of zero", all!(fp1.nan, fp2.zero()));
+
+    // Negative base with a non-integer exponent
+    let negative_base = all!(fp1.negative, non_nan2);
+    cases = cases.fire("negative base", negative_base);
+
+    // |x| != 1 with an infinite exponent
+    let infinite_exponent = all!(non_nan1, fp2.infinite);
+    cases = cases.fire("infinite exponent", infinite_exponent);
+
+    let res = FP {
+        nan: any!(fp1.nan, fp2.nan, negative_base),
+        pos_zero: any!(
+            all!(fp1.zero(), fp2.positive),
+            all!(fp1.infinite, fp2.negative),
+            infinite_exponent
+        ),
+        // (-0.0)^y and (-inf)^-y for an odd integer y
+        neg_zero: any!(
+            all!(fp1.neg_zero, fp2.positive),
+            all!(fp1.infinite, fp2.negative)
+        ),
+        subnormal: all!(non_nan1, non_nan2),
+        fractional: all!(non_nan1, non_nan2),
+        large: all!(non_nan1, non_nan2),
+        infinite: any!(
+            all!(fp1.zero(), fp2.negative),
+            all!(fp1.infinite, fp2.positive),
+            infinite_exponent
+        ),
+        // 1^y == 1, even for NaN
+        positive: any!(non_nan1, all!(fp1.nan, fp2.zero())),
+        // Negative base with an odd integer exponent
+        negative: negative_base,
+    };
+
+    Outcome {
+        cases,
+        ..round_overflow(round_underflow(res, rounding, mode), rounding, mode)
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{FnArgFloat, Possible, FP};
+
+/// The possible results of a predicate, in Kleene's three-valued logic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoolPossibility {
+    /// Always `true`
+    True,
+    /// Always `false`
+    False,
+    /// May be `true` or `false`
+    Maybe,
+}
+
+impl BoolPossibility {
+    /// From whether the result may be `true`, and may be `false`
+    ///
+    /// ```
+    /// use fn_num_types::BoolPossibility;
+    ///
+    /// assert_eq!(BoolPossibility::new(true, false), BoolPossibility::True);
+    /// assert_eq!(BoolPossibility::new(true, true), BoolPossibility::Maybe);
+    /// // Without any possible argument
+    /// assert_eq!(BoolPossibility::new(false, false), BoolPossibility::False);
+    /// ```
+    pub const fn new(can_be_true: bool, can_be_false: bool) -> Self {
+        match (can_be_true, can_be_false) {
+            (true, true) => BoolPossibility::Maybe,
+            (true, false) => BoolPossibility::True,
+            (false, _) => BoolPossibility::False,
+        }
+    }
+
+    /// Returns true if `value` is a possible result
+    pub const fn accept(&self, value: bool) -> bool {
+        match self {
+            BoolPossibility::True => value,
+            BoolPossibility::False => !value,
+            BoolPossibility::Maybe => true,
+        }
+    }
+
+    /// `!self`, in a `const fn`
+    pub(crate) const fn negate(self) -> Self {
+        match self {
+            BoolPossibility::True => BoolPossibility::False,
+            BoolPossibility::False => BoolPossibility::True,
+            BoolPossibility::Maybe => BoolPossibility::Maybe,
+        }
+    }
+}
+
+impl core::ops::Not for BoolPossibility {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.negate()
+    }
+}
+
+/// ```
+/// use fn_num_types::BoolPossibility;
+///
+/// assert_eq!(BoolPossibility::Maybe & BoolPossibility::False, BoolPossibility::False);
+/// assert_eq!(BoolPossibility::Maybe & BoolPossibility::True, BoolPossibility::Maybe);
+/// ```
+impl core::ops::BitAnd for BoolPossibility {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (BoolPossibility::False, _) | (_, BoolPossibility::False) => BoolPossibility::False,
+            (BoolPossibility::True, BoolPossibility::True) => BoolPossibility::True,
+            _ => BoolPossibility::Maybe,
+        }
+    }
+}
+
+/// ```
+/// use fn_num_types::BoolPossibility;
+///
+/// assert_eq!(BoolPossibility::Maybe | BoolPossibility::True, BoolPossibility::True);
+/// assert_eq!(BoolPossibility::Maybe | BoolPossibility::False, BoolPossibility::Maybe);
+/// ```
+impl core::ops::BitOr for BoolPossibility {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        !(!self & !rhs)
+    }
+}
+
+const fn possible(p: Possible) -> bool {
+    !matches!(p, Possible::No)
+}
+
+/// A value that is not NaN is possible
+const fn non_nan(fp: &FP) -> bool {
+    possible(any!(fp.positive, fp.negative))
+}
+
+/// `lhs.is_nan()`
+///
+/// ```
+/// use fn_num_types::core::ops::is_nan;
+/// use fn_num_types::{BoolPossibility, FnArgFloat, Possible, FP};
+///
+/// let x = FnArgFloat::F64(FP {
+///     nan: Possible::No,
+///     pos_zero: Possible::Yes,
+///     neg_zero: Possible::Yes,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::Yes,
+///     positive: Possible::Yes,
+///     negative: Possible::Yes,
+/// });
+///
+/// assert_eq!(is_nan(&x), BoolPossibility::False);
+/// ```
+pub const fn is_nan(lhs: &FnArgFloat) -> BoolPossibility {
+    let fp = lhs.possibilities();
+
+    BoolPossibility::new(possible(fp.nan), non_nan(fp))
+}
+
+/// `lhs.is_infinite()`
+pub const fn is_infinite(lhs: &FnArgFloat) -> BoolPossibility {
+    let fp = lhs.possibilities();
+
+    // Finite values are possible as soon as a sign is
+    BoolPossibility::new(
+        possible(fp.infinite) && non_nan(fp),
+        possible(fp.nan) || non_nan(fp),
+    )
+}
+
+/// `lhs.is_finite()`
+pub const fn is_finite(lhs: &FnArgFloat) -> BoolPossibility {
+    let fp = lhs.possibilities();
+
+    BoolPossibility::new(
+        non_nan(fp),
+        possible(fp.nan) || (possible(fp.infinite) && non_nan(fp)),
+    )
+}
+
+/// `lhs.is_sign_positive()`, whose result is unknown for NaN
+pub const fn is_sign_positive(lhs: &FnArgFloat) -> BoolPossibility {
+    let fp = lhs.possibilities();
+
+    BoolPossibility::new(
+        possible(any!(fp.positive, fp.nan)),
+        possible(any!(fp.negative, fp.nan)),
+    )
+}
+
+/// `lhs.is_sign_negative()`, whose result is unknown for NaN
+pub const fn is_sign_negative(lhs: &FnArgFloat) -> BoolPossibility {
+    let fp = lhs.possibilities();
+
+    BoolPossibility::new(
+        possible(any!(fp.negative, fp.nan)),
+        possible(any!(fp.positive, fp.nan)),
+    )
+}
+
+/// `lhs.is_normal()`
+pub const fn is_normal(lhs: &FnArgFloat) -> BoolPossibility {
+    let fp = lhs.possibilities();
+    let special = any!(fp.nan, fp.zero(), fp.subnormal, fp.infinite);
+
+    BoolPossibility::new(non_nan(fp), possible(special))
+}
+
+/// `lhs.is_subnormal()`
+pub const fn is_subnormal(lhs: &FnArgFloat) -> BoolPossibility {
+    let fp = lhs.possibilities();
+
+    BoolPossibility::new(
+        non_nan(fp) && possible(fp.subnormal),
+        possible(fp.nan) || non_nan(fp),
+    )
+}
+
+/// `if cond { then_fp } else { else_fp }`
+///
+/// ```
+/// use fn_num_types::core::ops::{is_nan, select};
+/// use fn_num_types::{FnArgFloat, Possible, FP};
+///
+/// let x = FnArgFloat::F64(FP {
+///     nan: Possible::No,
+///     pos_zero: Possible::Yes,
+///     neg_zero: Possible::Yes,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::Yes,
+///     negative: Possible::No,
+/// });
+/// let zero = FnArgFloat::F64(FP {
+///     nan: Possible::No,
+///     pos_zero: Possible::Yes,
+///     neg_zero: Possible::Yes,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::Yes,
+///     negative: Possible::No,
+/// });
+///
+/// // `if x.is_nan() { 0.0 } else { x }`, where `x` is never NaN
+/// assert_eq!(select(is_nan(&x), &zero, &x), x);
+/// ```
+///
+/// # Panics
+///
+/// If the branches are not of the same type
+pub const fn select(cond: BoolPossibility, then_fp: &FnArgFloat, else_fp: &FnArgFloat) -> FnArgFloat {
+    assert!(then_fp.is_same_type(else_fp), "Different types");
+
+    match cond {
+        BoolPossibility::True => *then_fp,
+        BoolPossibility::False => *else_fp,
+        BoolPossibility::Maybe => {
+            then_fp.with_possibilities(then_fp.possibilities().union(else_fp.possibilities()))
+        }
+    }
+}
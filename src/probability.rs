@@ -0,0 +1,396 @@
+//! Likelihoods of the flags, on top of the possibilities.
+//!
+//! The possibilities tell whether a NaN may happen, not how often. A
+//! `ProbableFloat` also carries a distribution over the kinds of values, like
+//! `+0.0` or the negative subnormals, seeded by the distribution of the inputs
+//! and propagated through the operations:
+//!
+//! - the arguments of an operation are independent,
+//! - the results of a kind of value are spread over the kinds its model
+//!   reaches, evenly, except for the `ShouldNot` ones, only reached by a
+//!   rounding error, weighted by `RARE`.
+//!
+//! The likelihoods are rough estimates, to prioritize the warnings: the
+//! possibilities, kept alongside, are the sound answer. They are as precise as
+//! the models, whose flags are shared by both signs: the square root of a
+//! negative value is NaN, but its model also lets it be negative, so only a
+//! part of the negative values end up NaN.
+//!
+//! ```
+//! use fn_num_types::probability::ProbableFloat;
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! // Mostly positive, rarely zero
+//! let x = ProbableFloat::from_values(
+//!     FnArgFloat::F64(FloatPossibilities::finite()),
+//!     &[1.5, 2.0, 0.5, 3.0, 7.0, -1.0, 4.5, 0.25, 9.0, 0.0],
+//! );
+//! assert_eq!(x.likelihoods().negative, 0.1);
+//!
+//! let root = x.sqrt();
+//! assert_ne!(root.arg().possibilities().nan, Possible::No);
+//! assert!(root.likelihoods().nan <= 0.1);
+//! assert!(root.likelihoods().positive > 0.8);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::expr::{BinaryOp, UnaryOp};
+use crate::{Field, FloatPossibilities, FnArgFloat, Possible, FP};
+
+/// The probability of each flag, of a value having it
+pub type Likelihoods = FloatPossibilities<f64>;
+
+/// The weight of a kind of value only reached by a rounding error, `ShouldNot`,
+/// relative to the other kinds reached
+pub const RARE: f64 = 1e-3;
+
+/// The rounds of fitting of `ProbableFloat::new`
+const FITTING_ROUNDS: usize = 100;
+
+/// A value of each kind of values the flags tell apart
+const KINDS: [f64; 15] = [
+    f64::NAN,
+    0.0,
+    -0.0,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    1e-310,
+    -1e-310,
+    // Normal, below one
+    0.5,
+    -0.5,
+    // Normal, integers up to one
+    1.0,
+    -1.0,
+    // Normal, from one, with a fractional part
+    1.5,
+    -1.5,
+    // Normal, from one, integers
+    2.0,
+    -2.0,
+];
+
+/// The possibilities of each kind
+fn kinds() -> [FP; 15] {
+    KINDS.map(FP::from)
+}
+
+/// The weight of a kind reached at that level
+fn weight(level: Possible) -> f64 {
+    match level {
+        Possible::No => 0.0,
+        Possible::ShouldNot => RARE,
+        Possible::Should | Possible::Yes => 1.0,
+    }
+}
+
+/// The level at which `fp` reaches `kind`: the weakest of the flags of the kind
+fn level(fp: &FP, kind: &FP) -> Possible {
+    Field::ALL
+        .into_iter()
+        .filter(|field| kind.get(*field) == Possible::Yes)
+        .fold(Possible::Yes, |level, field| {
+            Possible::all(level, fp.get(field))
+        })
+}
+
+fn likelihood(likelihoods: &Likelihoods, field: Field) -> f64 {
+    match field {
+        Field::Nan => likelihoods.nan,
+        Field::PosZero => likelihoods.pos_zero,
+        Field::NegZero => likelihoods.neg_zero,
+        Field::Subnormal => likelihoods.subnormal,
+        Field::Fractional => likelihoods.fractional,
+        Field::Large => likelihoods.large,
+        Field::Infinite => likelihoods.infinite,
+        Field::Positive => likelihoods.positive,
+        Field::Negative => likelihoods.negative,
+    }
+}
+
+/// The possibilities of a float, with the probability of each kind of value
+///
+/// The probabilities are of the kinds accepted by the possibilities only.
+/// They add up to one, or to zero if no value is possible.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProbableFloat {
+    arg: FnArgFloat,
+    /// The probabilities of the `KINDS`
+    weights: [f64; 15],
+}
+
+impl ProbableFloat {
+    /// The kinds of `arg`, in proportion to `weights`, normalized
+    fn from_weights(arg: FnArgFloat, mut weights: [f64; 15]) -> Self {
+        for (weight, kind) in weights.iter_mut().zip(kinds()) {
+            if level(arg.possibilities(), &kind) == Possible::No {
+                *weight = 0.0;
+            }
+        }
+
+        let total: f64 = weights.iter().sum();
+        if total > 0.0 {
+            weights.iter_mut().for_each(|weight| *weight /= total);
+        }
+
+        ProbableFloat { arg, weights }
+    }
+
+    /// The possibilities of `arg`, its flags of the probabilities `likelihoods`
+    ///
+    /// The kinds of values are as even as the likelihoods allow, fitted one
+    /// flag after the other until they match. The likelihoods of the result
+    /// are only close to `likelihoods` if they contradict each other, or `arg`.
+    ///
+    /// ```
+    /// use fn_num_types::probability::{Likelihoods, ProbableFloat};
+    /// use fn_num_types::{FloatPossibilities, FnArgFloat};
+    ///
+    /// let likelihoods = Likelihoods {
+    ///     nan: 0.01,
+    ///     pos_zero: 0.1,
+    ///     neg_zero: 0.0,
+    ///     subnormal: 0.0,
+    ///     fractional: 0.5,
+    ///     large: 0.5,
+    ///     infinite: 0.0,
+    ///     positive: 0.99,
+    ///     negative: 0.0,
+    /// };
+    /// let x = ProbableFloat::new(FnArgFloat::F64(FloatPossibilities::any()), &likelihoods);
+    ///
+    /// assert!((x.likelihoods().nan - 0.01).abs() < 1e-6);
+    /// assert!((x.likelihoods().large - 0.5).abs() < 1e-6);
+    /// assert_eq!(x.likelihoods().negative, 0.0);
+    /// ```
+    pub fn new(arg: FnArgFloat, likelihoods: &Likelihoods) -> Self {
+        let kinds = kinds();
+        let mut weights = [1.0; 15];
+
+        // Iterative proportional fitting
+        for _ in 0..FITTING_ROUNDS {
+            for field in Field::ALL {
+                let p = likelihood(likelihoods, field).clamp(0.0, 1.0);
+                let with: f64 = kinds
+                    .iter()
+                    .zip(weights)
+                    .filter(|(kind, _)| kind.get(field) == Possible::Yes)
+                    .map(|(_, weight)| weight)
+                    .sum();
+                let without = weights.iter().sum::<f64>() - with;
+
+                for (weight, kind) in weights.iter_mut().zip(&kinds) {
+                    let (target, sum) = if kind.get(field) == Possible::Yes {
+                        (p, with)
+                    } else {
+                        (1.0 - p, without)
+                    };
+                    if sum > 0.0 {
+                        *weight *= target / sum;
+                    }
+                }
+            }
+        }
+
+        ProbableFloat::from_weights(arg, weights)
+    }
+
+    /// The possibilities of `arg`, the values being as likely as in `values`,
+    /// like the inputs seen in production
+    ///
+    /// The values not accepted by `arg` are ignored. Without any other, all
+    /// the kinds of `arg` are as likely, like for `From<FnArgFloat>`.
+    pub fn from_values(arg: FnArgFloat, values: &[f64]) -> Self {
+        let kinds = kinds();
+        let mut weights = [0.0; 15];
+
+        for value in values.iter().filter(|value| arg.accept(**value)) {
+            let fp = FP::from(*value);
+            if let Some(index) = kinds.iter().position(|kind| *kind == fp) {
+                weights[index] += 1.0;
+            }
+        }
+
+        if weights.iter().all(|weight| *weight == 0.0) {
+            return ProbableFloat::from(arg);
+        }
+        ProbableFloat::from_weights(arg, weights)
+    }
+
+    /// The possibilities, whatever the probabilities
+    pub fn arg(&self) -> &FnArgFloat {
+        &self.arg
+    }
+
+    /// The probability of each flag
+    pub fn likelihoods(&self) -> Likelihoods {
+        let sum = |field: Field| {
+            kinds()
+                .iter()
+                .zip(self.weights)
+                .filter(|(kind, _)| kind.get(field) == Possible::Yes)
+                .map(|(_, weight)| weight)
+                .sum()
+        };
+
+        Likelihoods {
+            nan: sum(Field::Nan),
+            pos_zero: sum(Field::PosZero),
+            neg_zero: sum(Field::NegZero),
+            subnormal: sum(Field::Subnormal),
+            fractional: sum(Field::Fractional),
+            large: sum(Field::Large),
+            infinite: sum(Field::Infinite),
+            positive: sum(Field::Positive),
+            negative: sum(Field::Negative),
+        }
+    }
+
+    /// The kinds of `self`, with their probabilities
+    fn weighted_kinds(&self) -> Vec<(FnArgFloat, f64)> {
+        kinds()
+            .into_iter()
+            .zip(self.weights)
+            .filter(|(_, weight)| *weight > 0.0)
+            .map(|(kind, weight)| (self.arg.with_possibilities(kind), weight))
+            .collect()
+    }
+
+    /// `model`, the results of each kind, of the probability `probability`,
+    /// being spread over the kinds they reach
+    fn from_results(model: FnArgFloat, results: impl IntoIterator<Item = (FP, f64)>) -> Self {
+        let kinds = kinds();
+        let mut weights = [0.0; 15];
+
+        for (fp, probability) in results {
+            let reached = kinds.map(|kind| weight(level(&fp, &kind)));
+            let total: f64 = reached.iter().sum();
+            if total > 0.0 {
+                for (sum, share) in weights.iter_mut().zip(reached) {
+                    *sum += probability * share / total;
+                }
+            }
+        }
+
+        ProbableFloat::from_weights(model, weights)
+    }
+
+    /// The result of `op`, with its model
+    pub fn apply_unary(&self, op: UnaryOp) -> Self {
+        let model = op.model();
+        let results = self
+            .weighted_kinds()
+            .into_iter()
+            .map(|(x, weight)| (*model(&x).possibilities(), weight));
+
+        ProbableFloat::from_results(model(&self.arg), results)
+    }
+
+    /// The result of `op`, the arguments being independent, with its model
+    ///
+    /// # Panics
+    ///
+    /// If they are not of the same type
+    pub fn apply_binary(&self, op: BinaryOp, rhs: &Self) -> Self {
+        let model = op.model();
+        let ys = rhs.weighted_kinds();
+        let results = self.weighted_kinds().into_iter().flat_map(|(x, wx)| {
+            ys.iter()
+                .map(move |(y, wy)| (*model(&x, y).possibilities(), wx * wy))
+        });
+
+        ProbableFloat::from_results(model(&self.arg, &rhs.arg), results)
+    }
+}
+
+/// All the kinds of values of `arg` as likely, the rare ones weighted by `RARE`
+impl From<FnArgFloat> for ProbableFloat {
+    fn from(arg: FnArgFloat) -> Self {
+        let weights = kinds().map(|kind| weight(level(arg.possibilities(), &kind)));
+
+        ProbableFloat::from_weights(arg, weights)
+    }
+}
+
+macro_rules! unary {
+    ($($op:ident => $variant:ident),* $(,)?) => {
+        impl ProbableFloat {
+            $(
+                pub fn $op(&self) -> Self {
+                    self.apply_unary(UnaryOp::$variant)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident => $variant:ident),* $(,)?) => {
+        impl ProbableFloat {
+            $(
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    self.apply_binary(BinaryOp::$variant, rhs)
+                }
+            )*
+        }
+    };
+}
+
+unary!(
+    neg => Neg,
+    abs => Abs,
+    ceil => Ceil,
+    floor => Floor,
+    round => Round,
+    round_ties_even => RoundTiesEven,
+    trunc => Trunc,
+    fract => Fract,
+    signum => Signum,
+    sqrt => Sqrt,
+    exp => Exp,
+    exp2 => Exp2,
+    ln => Ln,
+    log2 => Log2,
+    log10 => Log10,
+    to_degrees => ToDegrees,
+    to_radians => ToRadians,
+    cbrt => Cbrt,
+    sin => Sin,
+    cos => Cos,
+    tan => Tan,
+    asin => Asin,
+    acos => Acos,
+    atan => Atan,
+    exp_m1 => ExpM1,
+    ln_1p => Ln1p,
+    sinh => Sinh,
+    cosh => Cosh,
+    tanh => Tanh,
+    asinh => Asinh,
+    acosh => Acosh,
+    atanh => Atanh,
+    recip => Recip,
+    next_up => NextUp,
+    next_down => NextDown,
+);
+
+binary!(
+    add => Add,
+    sub => Sub,
+    mul => Mul,
+    div => Div,
+    rem => Rem,
+    div_euclid => DivEuclid,
+    rem_euclid => RemEuclid,
+    powf => Powf,
+    hypot => Hypot,
+    atan2 => Atan2,
+    copysign => Copysign,
+    max => Max,
+    min => Min,
+    maximum => Maximum,
+    minimum => Minimum,
+    log => Log,
+    midpoint => Midpoint,
+);
@@ -0,0 +1,62 @@
+use crate::cast::convert;
+use crate::core::ops;
+use crate::{FnArgFloat, FP};
+
+/// Converts the narrower argument to the type of the other one, like `x as f64 + y`
+///
+/// `f16` and `bf16` are both converted to `f32`.
+///
+/// ```
+/// use fn_num_types::core::ops::promote;
+/// use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+///
+/// let x = FnArgFloat::F32(FloatPossibilities::positive());
+/// let y = FnArgFloat::F64(FloatPossibilities::nan());
+///
+/// let (x, y) = promote(&x, &y);
+/// assert!(x.is_same_type(&y));
+/// // The subnormals of `f32` are normal values of `f64`
+/// assert_eq!(x.possibilities().subnormal, Possible::No);
+/// ```
+pub const fn promote(lhs: &FnArgFloat, rhs: &FnArgFloat) -> (FnArgFloat, FnArgFloat) {
+    if lhs.is_same_type(rhs) {
+        return (*lhs, *rhs);
+    }
+
+    let target = if lhs.bits() < rhs.bits() {
+        *rhs
+    } else if lhs.bits() > rhs.bits() {
+        *lhs
+    } else {
+        FnArgFloat::F32(FP::any())
+    };
+
+    (convert(lhs, target), convert(rhs, target))
+}
+
+macro_rules! promoting {
+    ($feature:literal => $($op:ident => $promoting_op:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("`", stringify!($op), "` after `promote`")]
+            #[cfg(feature = $feature)]
+            pub const fn $promoting_op(lhs: &FnArgFloat, rhs: &FnArgFloat) -> FnArgFloat {
+                let (lhs, rhs) = promote(lhs, rhs);
+
+                ops::$op(&lhs, &rhs)
+            }
+        )*
+    };
+}
+
+promoting!("arith" =>
+    add => add_promoting,
+    mul => mul_promoting,
+    div => div_promoting,
+    rem => rem_promoting,
+    powf => powf_promoting,
+    hypot => hypot_promoting,
+    copysign => copysign_promoting,
+    max => max_promoting,
+    min => min_promoting,
+);
+promoting!("trig" => atan2 => atan2_promoting);
@@ -0,0 +1,224 @@
+//! Python bindings.
+//!
+//! Build the module with `maturin build --features python,pyo3/extension-module`.
+//!
+//! ```python
+//! import fn_num_types
+//! from fn_num_types import Float, Possible
+//!
+//! x = Float(64, Possible.No, Possible.No, Possible.No, Possible.Yes, Possible.No)
+//! fn_num_types.sqrt(x).nan  # Possible.No
+//!
+//! # Can this formula produce NaN?
+//! y = Float.parse("f64 +:Y large:Y")
+//! fn_num_types.evaluate("(x.abs() + y).sqrt()", x=x, y=y).nan  # Possible.No
+//! ```
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::core::ops;
+use crate::expr::{eval, parse};
+use crate::{FloatPossibilities, FnArgFloat, Possible};
+
+/// The possibilities of a float argument or result
+#[pyclass(frozen, skip_from_py_object)]
+#[derive(Clone, Copy, Debug)]
+pub struct Float(FnArgFloat);
+
+macro_rules! ops {
+    (unary: $($op:ident),* ; binary: $($op2:ident),* ; ternary: $($op3:ident),* $(,)?) => {
+        $(
+            #[pyfunction]
+            fn $op(lhs: &Float) -> Float {
+                Float(ops::$op(&lhs.0))
+            }
+        )*
+
+        $(
+            #[pyfunction]
+            fn $op2(lhs: &Float, rhs: &Float) -> PyResult<Float> {
+                FnArgFloat::check_same_type(&[lhs.0, rhs.0])
+                    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+                Ok(Float(ops::$op2(&lhs.0, &rhs.0)))
+            }
+        )*
+
+        $(
+            #[pyfunction]
+            fn $op3(a: &Float, b: &Float, c: &Float) -> PyResult<Float> {
+                FnArgFloat::check_same_type(&[a.0, b.0, c.0])
+                    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+                Ok(Float(ops::$op3(&a.0, &b.0, &c.0)))
+            }
+        )*
+
+        fn add_ops(m: &Bound<'_, PyModule>) -> PyResult<()> {
+            $(m.add_function(wrap_pyfunction!($op, m)?)?;)*
+            $(m.add_function(wrap_pyfunction!($op2, m)?)?;)*
+            $(m.add_function(wrap_pyfunction!($op3, m)?)?;)*
+            Ok(())
+        }
+    };
+}
+
+impl Float {
+    fn possibilities(&self) -> &FloatPossibilities {
+        self.0.possibilities()
+    }
+}
+
+#[pymethods]
+impl Float {
+    /// `bits` is the width of the float: 32 or 64
+    #[new]
+    // One argument per field, as in the Rust struct
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        bits: u8,
+        nan: Possible,
+        pos_zero: Possible,
+        neg_zero: Possible,
+        subnormal: Possible,
+        fractional: Possible,
+        large: Possible,
+        infinite: Possible,
+        positive: Possible,
+        negative: Possible,
+    ) -> PyResult<Self> {
+        let fp = FloatPossibilities {
+            nan,
+            pos_zero,
+            neg_zero,
+            subnormal,
+            fractional,
+            large,
+            infinite,
+            positive,
+            negative,
+        };
+
+        match bits {
+            32 => Ok(Float(FnArgFloat::F32(fp))),
+            64 => Ok(Float(FnArgFloat::F64(fp))),
+            _ => Err(PyValueError::new_err("Unsupported width")),
+        }
+    }
+
+    #[getter]
+    fn bits(&self) -> u8 {
+        self.0.bits()
+    }
+
+    #[getter]
+    fn nan(&self) -> Possible {
+        self.possibilities().nan
+    }
+
+    #[getter]
+    fn zero(&self) -> Possible {
+        self.possibilities().zero()
+    }
+
+    #[getter]
+    fn pos_zero(&self) -> Possible {
+        self.possibilities().pos_zero
+    }
+
+    #[getter]
+    fn neg_zero(&self) -> Possible {
+        self.possibilities().neg_zero
+    }
+
+    #[getter]
+    fn subnormal(&self) -> Possible {
+        self.possibilities().subnormal
+    }
+
+    #[getter]
+    fn fractional(&self) -> Possible {
+        self.possibilities().fractional
+    }
+
+    #[getter]
+    fn large(&self) -> Possible {
+        self.possibilities().large
+    }
+
+    #[getter]
+    fn infinite(&self) -> Possible {
+        self.possibilities().infinite
+    }
+
+    #[getter]
+    fn positive(&self) -> Possible {
+        self.possibilities().positive
+    }
+
+    #[getter]
+    fn negative(&self) -> Possible {
+        self.possibilities().negative
+    }
+
+    /// Returns true if the value is accepted
+    fn accept(&self, value: f64) -> bool {
+        self.possibilities().accept(value)
+    }
+
+    /// From the textual representation, like `"f64 zero:Y +:Y inf:N"`
+    #[staticmethod]
+    fn parse(spec: &str) -> PyResult<Self> {
+        spec.parse()
+            .map(Float)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// The possibilities of the result of `expression`, written like in Rust,
+/// for the variables given by name
+#[pyfunction]
+#[pyo3(signature = (expression, **vars))]
+fn evaluate(expression: &str, vars: Option<&Bound<'_, PyDict>>) -> PyResult<Float> {
+    let mut names = vec![];
+    let mut args = vec![];
+    for (name, value) in vars.into_iter().flatten() {
+        names.push(name.extract::<String>()?);
+        args.push(value.extract::<PyRef<'_, Float>>()?.0);
+    }
+
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    let expr = parse(expression, &names).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    if !args.is_empty() {
+        FnArgFloat::check_same_type(&args).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    }
+
+    Ok(Float(eval(&expr, &args)))
+}
+
+ops!(
+    unary: neg, abs, ceil, floor, round, round_ties_even, trunc, fract, signum, sqrt, exp, exp2, ln, log2, log10,
+    to_degrees, to_radians, cbrt, sin, cos, tan, asin, acos, atan, exp_m1, ln_1p, sinh, cosh, tanh,
+    asinh, acosh, atanh, recip, powi, next_up, next_down, to_f32, to_f64;
+    binary: add, mul, div, rem, div_euclid, rem_euclid, powf, hypot, atan2, copysign, max, min,
+    maximum, minimum, log, midpoint;
+    ternary: mul_add, clamp,
+);
+
+#[pymodule]
+fn fn_num_types(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Possible>()?;
+    m.add_class::<Float>()?;
+    m.add_function(wrap_pyfunction!(evaluate, m)?)?;
+    add_ops(m)
+}
@@ -0,0 +1,440 @@
+//! The operations by name, for the callers only knowing the name of the method
+
+use alloc::borrow::ToOwned;
+
+use crate::config::{Cases, RoundingMode::ToNearest};
+use crate::core::ops;
+use crate::FloatPossibilities as FP;
+use crate::{Error, FnArgFloat};
+
+type Unary = fn(&FnArgFloat) -> FnArgFloat;
+type Binary = fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat;
+type Ternary = fn(&FnArgFloat, &FnArgFloat, &FnArgFloat) -> FnArgFloat;
+type NAry = fn(&[FnArgFloat]) -> FnArgFloat;
+/// The special cases of an operation for its arguments, of the right number
+type Rules = fn(&[FnArgFloat]) -> Cases;
+
+const UNARY: &[(&str, Unary)] = &[
+    ("neg", ops::neg),
+    ("abs", ops::abs),
+    ("ceil", ops::ceil),
+    ("floor", ops::floor),
+    ("round", ops::round),
+    ("round_ties_even", ops::round_ties_even),
+    ("trunc", ops::trunc),
+    ("fract", ops::fract),
+    ("signum", ops::signum),
+    ("sqrt", ops::sqrt),
+    ("exp", ops::exp),
+    ("exp2", ops::exp2),
+    ("ln", ops::ln),
+    ("log2", ops::log2),
+    ("log10", ops::log10),
+    ("to_degrees", ops::to_degrees),
+    ("to_radians", ops::to_radians),
+    ("cbrt", ops::cbrt),
+    ("sin", ops::sin),
+    ("cos", ops::cos),
+    ("tan", ops::tan),
+    ("asin", ops::asin),
+    ("acos", ops::acos),
+    ("atan", ops::atan),
+    ("exp_m1", ops::exp_m1),
+    ("ln_1p", ops::ln_1p),
+    ("sinh", ops::sinh),
+    ("cosh", ops::cosh),
+    ("tanh", ops::tanh),
+    ("asinh", ops::asinh),
+    ("acosh", ops::acosh),
+    ("atanh", ops::atanh),
+    ("recip", ops::recip),
+    ("powi", ops::powi),
+    ("next_up", ops::next_up),
+    ("next_down", ops::next_down),
+    ("to_f32", ops::to_f32),
+    ("to_f64", ops::to_f64),
+    #[cfg(feature = "extra-widths")]
+    ("to_f16", ops::to_f16),
+    #[cfg(feature = "extra-widths")]
+    ("to_bf16", ops::to_bf16),
+    #[cfg(feature = "extra-widths")]
+    ("to_f128", ops::to_f128),
+    #[cfg(feature = "nightly-ops")]
+    ("exp10", ops::exp10),
+    #[cfg(feature = "nightly-ops")]
+    ("gamma", ops::gamma),
+    #[cfg(feature = "nightly-ops")]
+    ("ln_gamma", ops::ln_gamma),
+];
+
+const BINARY: &[(&str, Binary)] = &[
+    ("add", ops::add),
+    ("mul", ops::mul),
+    ("div", ops::div),
+    ("rem", ops::rem),
+    ("div_euclid", ops::div_euclid),
+    ("rem_euclid", ops::rem_euclid),
+    ("powf", ops::powf),
+    ("hypot", ops::hypot),
+    ("atan2", ops::atan2),
+    ("copysign", ops::copysign),
+    ("max", ops::max),
+    ("min", ops::min),
+    ("maximum", ops::maximum),
+    ("minimum", ops::minimum),
+    ("log", ops::log),
+    ("midpoint", ops::midpoint),
+    #[cfg(feature = "nightly-ops")]
+    ("abs_sub", ops::abs_sub),
+];
+
+const TERNARY: &[(&str, Ternary)] = &[("mul_add", ops::mul_add), ("clamp", ops::clamp)];
+
+/// The operations of any number of arguments, at least one
+const N_ARY: &[(&str, NAry)] = &[
+    ("sum", ops::sum),
+    ("product", ops::product),
+    ("sum_kahan", ops::sum_kahan),
+    ("mean", ops::mean),
+    ("variance", ops::variance),
+    ("stddev", ops::stddev),
+    ("norm", ops::norm),
+];
+
+const fn fp(args: &[FnArgFloat], index: usize) -> &FP {
+    args[index].possibilities()
+}
+
+/// The operations of the tables above with special cases, the others having none
+const CASES: &[(&str, Rules)] = {
+    use crate::add::add_outcome;
+    use crate::cast::convert_outcome;
+    use crate::copysign::copysign_outcome;
+    use crate::div::div_outcome;
+    use crate::euclid::{div_euclid_outcome, rem_euclid_outcome};
+    use crate::fp_ops::{exp2_outcome, exp_outcome, recip_outcome, to_radians_outcome};
+    use crate::hypot::hypot_outcome;
+    use crate::log::log_outcome;
+    use crate::midpoint::midpoint_outcome;
+    use crate::mul::mul_outcome;
+    use crate::mul_add::mul_add_outcome;
+    #[cfg(feature = "nightly-ops")]
+    use crate::nightly::{abs_sub_outcome, exp10_outcome, gamma_outcome, ln_gamma_outcome};
+    use crate::powf::powf_outcome;
+    use crate::rem::rem_outcome;
+    use crate::stats::{mean_outcome, product_outcome, sum_kahan_outcome, sum_outcome};
+    use crate::trig::atan2_outcome;
+
+    &[
+        ("exp", |args| exp_outcome(ToNearest, fp(args, 0)).cases),
+        ("exp2", |args| exp2_outcome(ToNearest, fp(args, 0)).cases),
+        ("to_radians", |args| {
+            to_radians_outcome(ToNearest, fp(args, 0)).cases
+        }),
+        ("recip", |args| recip_outcome(ToNearest, fp(args, 0)).cases),
+        ("to_f32", |args| {
+            convert_outcome(&args[0], &FnArgFloat::F32(FP::any())).cases
+        }),
+        ("to_f64", |args| {
+            convert_outcome(&args[0], &FnArgFloat::F64(FP::any())).cases
+        }),
+        #[cfg(feature = "extra-widths")]
+        ("to_f16", |args| {
+            convert_outcome(&args[0], &FnArgFloat::F16(FP::any())).cases
+        }),
+        #[cfg(feature = "extra-widths")]
+        ("to_bf16", |args| {
+            convert_outcome(&args[0], &FnArgFloat::BF16(FP::any())).cases
+        }),
+        #[cfg(feature = "extra-widths")]
+        ("to_f128", |args| {
+            convert_outcome(&args[0], &FnArgFloat::F128(FP::any())).cases
+        }),
+        #[cfg(feature = "nightly-ops")]
+        ("exp10", |args| exp10_outcome(fp(args, 0)).cases),
+        #[cfg(feature = "nightly-ops")]
+        ("gamma", |args| gamma_outcome(fp(args, 0)).cases),
+        #[cfg(feature = "nightly-ops")]
+        ("ln_gamma", |args| ln_gamma_outcome(fp(args, 0)).cases),
+        ("add", |args| {
+            add_outcome(ToNearest, fp(args, 0), fp(args, 1)).cases
+        }),
+        ("mul", |args| {
+            mul_outcome(ToNearest, fp(args, 0), fp(args, 1)).cases
+        }),
+        ("div", |args| {
+            div_outcome(ToNearest, fp(args, 0), fp(args, 1)).cases
+        }),
+        ("rem", |args| rem_outcome(fp(args, 0), fp(args, 1)).cases),
+        ("div_euclid", |args| {
+            div_euclid_outcome(ToNearest, fp(args, 0), fp(args, 1)).cases
+        }),
+        ("rem_euclid", |args| {
+            rem_euclid_outcome(fp(args, 0), fp(args, 1)).cases
+        }),
+        ("powf", |args| {
+            powf_outcome(ToNearest, fp(args, 0), fp(args, 1)).cases
+        }),
+        ("hypot", |args| {
+            hypot_outcome(ToNearest, fp(args, 0), fp(args, 1)).cases
+        }),
+        ("atan2", |args| {
+            atan2_outcome(ToNearest, fp(args, 0), fp(args, 1)).cases
+        }),
+        ("copysign", |args| {
+            copysign_outcome(fp(args, 0), fp(args, 1)).cases
+        }),
+        ("log", |args| log_outcome(fp(args, 0), fp(args, 1)).cases),
+        ("midpoint", |args| {
+            midpoint_outcome(ToNearest, fp(args, 0), fp(args, 1)).cases
+        }),
+        #[cfg(feature = "nightly-ops")]
+        ("abs_sub", |args| {
+            abs_sub_outcome(fp(args, 0), fp(args, 1)).cases
+        }),
+        ("mul_add", |args| {
+            mul_add_outcome(ToNearest, fp(args, 0), fp(args, 1), fp(args, 2)).cases
+        }),
+        ("sum", |args| sum_outcome(args).cases),
+        ("product", |args| product_outcome(args).cases),
+        ("sum_kahan", |args| sum_kahan_outcome(args).cases),
+        ("mean", |args| mean_outcome(args).cases),
+    ]
+};
+
+/// The special cases of the operation named `name` possible for `args`,
+/// in the order the models handle them, none if `apply` fails
+pub(crate) fn cases(name: &str, args: &[FnArgFloat]) -> Cases {
+    match (find(CASES, name), apply_untraced(name, args)) {
+        (Some(rules), Ok(_)) => rules(args),
+        _ => Cases::NONE,
+    }
+}
+
+/// How the result varies with the argument, over its domain
+///
+/// Not strictly: `floor` is increasing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonicity {
+    Increasing,
+    Decreasing,
+    Neither,
+}
+
+/// `f(-x)` against `f(x)`, for the non-NaN results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parity {
+    /// `f(-x) == -f(x)`
+    Odd,
+    /// `f(-x) == f(x)`
+    Even,
+    Neither,
+}
+
+/// The properties of an operation of one argument
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpMetadata {
+    pub monotonicity: Monotonicity,
+    pub parity: Parity,
+    /// The smallest range with the arguments of which the result is not NaN:
+    /// outside of it, the result is NaN
+    pub domain: (f64, f64),
+    /// The integers give integers, exactly, like for `floor`
+    pub exact_for_integers: bool,
+}
+
+const fn meta(
+    monotonicity: Monotonicity,
+    parity: Parity,
+    domain: (f64, f64),
+    exact_for_integers: bool,
+) -> OpMetadata {
+    OpMetadata {
+        monotonicity,
+        parity,
+        domain,
+        exact_for_integers,
+    }
+}
+
+const REALS: (f64, f64) = (f64::NEG_INFINITY, f64::INFINITY);
+/// The infinities are not periods
+const FINITE: (f64, f64) = (f64::MIN, f64::MAX);
+const NON_NEGATIVE: (f64, f64) = (0.0, f64::INFINITY);
+const UNIT: (f64, f64) = (-1.0, 1.0);
+
+const METADATA: &[(&str, OpMetadata)] = {
+    use Monotonicity::{Decreasing, Increasing, Neither as NonMonotonic};
+    use Parity::{Even, Neither, Odd};
+
+    &[
+        ("neg", meta(Decreasing, Odd, REALS, true)),
+        ("abs", meta(NonMonotonic, Even, REALS, true)),
+        ("ceil", meta(Increasing, Neither, REALS, true)),
+        ("floor", meta(Increasing, Neither, REALS, true)),
+        ("round", meta(Increasing, Odd, REALS, true)),
+        ("round_ties_even", meta(Increasing, Odd, REALS, true)),
+        ("trunc", meta(Increasing, Odd, REALS, true)),
+        ("fract", meta(NonMonotonic, Odd, FINITE, true)),
+        ("signum", meta(Increasing, Odd, REALS, true)),
+        ("sqrt", meta(Increasing, Neither, NON_NEGATIVE, false)),
+        ("exp", meta(Increasing, Neither, REALS, false)),
+        ("exp2", meta(Increasing, Neither, REALS, false)),
+        ("ln", meta(Increasing, Neither, NON_NEGATIVE, false)),
+        ("log2", meta(Increasing, Neither, NON_NEGATIVE, false)),
+        ("log10", meta(Increasing, Neither, NON_NEGATIVE, false)),
+        ("to_degrees", meta(Increasing, Odd, REALS, false)),
+        ("to_radians", meta(Increasing, Odd, REALS, false)),
+        ("cbrt", meta(Increasing, Odd, REALS, false)),
+        ("sin", meta(NonMonotonic, Odd, FINITE, false)),
+        ("cos", meta(NonMonotonic, Even, FINITE, false)),
+        ("tan", meta(NonMonotonic, Odd, FINITE, false)),
+        ("asin", meta(Increasing, Odd, UNIT, false)),
+        ("acos", meta(Decreasing, Neither, UNIT, false)),
+        ("atan", meta(Increasing, Odd, REALS, false)),
+        ("exp_m1", meta(Increasing, Neither, REALS, false)),
+        (
+            "ln_1p",
+            meta(Increasing, Neither, (-1.0, f64::INFINITY), false),
+        ),
+        ("sinh", meta(Increasing, Odd, REALS, false)),
+        ("cosh", meta(NonMonotonic, Even, REALS, false)),
+        ("tanh", meta(Increasing, Odd, REALS, false)),
+        ("asinh", meta(Increasing, Odd, REALS, false)),
+        (
+            "acosh",
+            meta(Increasing, Neither, (1.0, f64::INFINITY), false),
+        ),
+        ("atanh", meta(Increasing, Odd, UNIT, false)),
+        ("recip", meta(NonMonotonic, Odd, REALS, false)),
+        // Of any exponent
+        ("powi", meta(NonMonotonic, Neither, REALS, false)),
+        ("next_up", meta(Increasing, Neither, REALS, false)),
+        ("next_down", meta(Increasing, Neither, REALS, false)),
+        ("to_f32", meta(Increasing, Odd, REALS, false)),
+        ("to_f64", meta(Increasing, Odd, REALS, true)),
+        #[cfg(feature = "extra-widths")]
+        ("to_f16", meta(Increasing, Odd, REALS, false)),
+        #[cfg(feature = "extra-widths")]
+        ("to_bf16", meta(Increasing, Odd, REALS, false)),
+        #[cfg(feature = "extra-widths")]
+        ("to_f128", meta(Increasing, Odd, REALS, true)),
+        #[cfg(feature = "nightly-ops")]
+        ("exp10", meta(Increasing, Neither, REALS, false)),
+        // NaN for the negative integers too
+        #[cfg(feature = "nightly-ops")]
+        (
+            "gamma",
+            meta(NonMonotonic, Neither, (f64::MIN, f64::INFINITY), false),
+        ),
+        #[cfg(feature = "nightly-ops")]
+        ("ln_gamma", meta(NonMonotonic, Neither, REALS, false)),
+    ]
+};
+
+/// The properties of the operation of one argument named `name`,
+/// for the optimizers, `None` for the others
+///
+/// ```
+/// use fn_num_types::core::ops::{op_metadata, Monotonicity, Parity};
+///
+/// let asin = op_metadata("asin").unwrap();
+/// assert_eq!(asin.monotonicity, Monotonicity::Increasing);
+/// assert_eq!(asin.parity, Parity::Odd);
+/// assert_eq!(asin.domain, (-1.0, 1.0));
+/// assert!(!asin.exact_for_integers);
+///
+/// assert!(op_metadata("add").is_none());
+/// ```
+pub fn op_metadata(name: &str) -> Option<OpMetadata> {
+    find(METADATA, name)
+}
+
+/// The names of the operations of `apply`, with their number of arguments,
+/// `None` for any number
+///
+/// ```
+/// use fn_num_types::core::ops::op_names;
+///
+/// assert!(op_names().any(|op| op == ("sqrt", Some(1))));
+/// assert!(op_names().any(|op| op == ("sum", None)));
+/// ```
+pub fn op_names() -> impl Iterator<Item = (&'static str, Option<usize>)> {
+    UNARY
+        .iter()
+        .map(|(name, _)| (*name, Some(1)))
+        .chain(BINARY.iter().map(|(name, _)| (*name, Some(2))))
+        .chain(TERNARY.iter().map(|(name, _)| (*name, Some(3))))
+        .chain(N_ARY.iter().map(|(name, _)| (*name, None)))
+}
+
+/// Applies the operation named like the method of `f64`, like `sqrt`, to `args`
+///
+/// With `std`, the operations of `float_op::register_unary` and
+/// `float_op::register_binary` are also applied.
+///
+/// ```
+/// use fn_num_types::core::ops::{self, apply};
+/// use fn_num_types::{Error, FloatPossibilities, FnArgFloat};
+///
+/// let x = FnArgFloat::F64(FloatPossibilities::any());
+///
+/// assert_eq!(apply("sqrt", &[x]), Ok(ops::sqrt(&x)));
+/// assert_eq!(apply("sqrt", &[x, x]), Err(Error::Arity { op: "sqrt".to_owned(), found: 2 }));
+/// assert_eq!(apply("sqrtf", &[x]), Err(Error::UnknownOp("sqrtf".to_owned())));
+/// ```
+pub fn apply(name: &str, args: &[FnArgFloat]) -> Result<FnArgFloat, Error> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!("apply", op = name, ?args).entered();
+
+    let res = apply_untraced(name, args);
+
+    #[cfg(feature = "tracing")]
+    if res.is_ok() {
+        for (rule, possible) in cases(name, args).iter() {
+            tracing::trace!(rule, ?possible);
+        }
+        tracing::trace!(result = ?res);
+    }
+
+    res
+}
+
+fn apply_untraced(name: &str, args: &[FnArgFloat]) -> Result<FnArgFloat, Error> {
+    match (
+        args,
+        find(UNARY, name),
+        find(BINARY, name),
+        find(TERNARY, name),
+        find(N_ARY, name),
+    ) {
+        ([lhs], Some(op), _, _, _) => Ok(op(lhs)),
+        ([lhs, rhs], _, Some(op), _, _) => {
+            FnArgFloat::check_same_type(args)?;
+            Ok(op(lhs, rhs))
+        }
+        ([a, b, c], _, _, Some(op), _) => {
+            FnArgFloat::check_same_type(args)?;
+            Ok(op(a, b, c))
+        }
+        ([_, ..], _, _, _, Some(op)) => {
+            FnArgFloat::check_same_type(args)?;
+            Ok(op(args))
+        }
+        (_, None, None, None, None) => {
+            #[cfg(feature = "std")]
+            if let Some(res) = crate::float_op::apply_registered(name, args) {
+                return res;
+            }
+            Err(Error::UnknownOp(name.to_owned()))
+        }
+        _ => Err(Error::Arity {
+            op: name.to_owned(),
+            found: args.len(),
+        }),
+    }
+}
+
+fn find<T: Copy>(ops: &[(&str, T)], name: &str) -> Option<T> {
+    ops.iter().find(|(op, _)| *op == name).map(|(_, op)| *op)
+}
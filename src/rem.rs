@@ -0,0 +1,47 @@
+use crate::config::{Cases, Outcome};
+use crate::{FnArgFloat, Possible, FP};
+
+/// Remainder of the truncated division, like Rust's `%`
+pub const fn rem(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(a, b, rem_fp)
+}
+
+/// `core::ops::rem`
+pub const fn rem_fp(fp1: &FP, fp2: &FP) -> FP {
+    rem_outcome(fp1, fp2).fp
+}
+
+/// `core::ops::rem`, with the special cases it handled
+pub(crate) const fn rem_outcome(fp1: &FP, fp2: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan1 = any!(fp1.positive, fp1.negative);
+    let non_nan2 = any!(fp2.positive, fp2.negative);
+
+    // x % 0 and inf % y
+    cases = cases.fire("remainder by zero", fp2.zero());
+    cases = cases.fire("infinite dividend", fp1.infinite);
+
+    // The remainder is exact, and zero when the dividend is a multiple of the divisor
+    let multiple = all!(non_nan1, non_nan2);
+    cases = cases.fire("multiple of the divisor", multiple);
+
+    let res = FP {
+        nan: any!(fp1.nan, fp2.nan, fp2.zero(), fp1.infinite),
+        pos_zero: multiple,
+        neg_zero: multiple,
+        subnormal: multiple,
+        // Exact, so an integer for two integers
+        fractional: any!(fp1.fractional, fp2.fractional),
+        // Not greater than either argument in magnitude, and `x % inf == x`
+        large: all!(fp1.large, any!(fp2.large, fp2.infinite)),
+        infinite: Possible::No,
+        // The sign follows the dividend
+        positive: fp1.positive,
+        negative: fp1.negative,
+    };
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(res)
+    }
+}
@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Error, FloatValue, FnArgFloat, FP};
+
+/// The result of a function returning several floats, like `(f64, f64)` or a
+/// struct of floats, element by element
+///
+/// ```
+/// use fn_num_types::{FloatPossibilities, FnArgFloat, FnRet};
+///
+/// let polar = FnRet::from((
+///     FnArgFloat::F64(FloatPossibilities::positive()),
+///     FnArgFloat::F64(FloatPossibilities::finite()),
+/// ));
+/// let unit = FnRet::from([FnArgFloat::from(1.0), FnArgFloat::from(0.5)]);
+///
+/// assert!(unit.is_subset_of(&polar));
+/// assert!(polar.accept(&[2.0, -1.0]));
+/// assert!(!polar.accept(&[-2.0, 1.0]));
+/// assert_eq!(polar.union(&unit), Ok(polar.clone()));
+/// assert!(polar.to_string().starts_with("(f64 nan:N"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FnRet {
+    pub elements: Vec<FnArgFloat>,
+}
+
+impl FnRet {
+    pub fn new(elements: Vec<FnArgFloat>) -> Self {
+        FnRet { elements }
+    }
+
+    /// Returns true if both are of the same number of elements, of the same types
+    pub fn is_same_type(&self, other: &Self) -> bool {
+        self.elements.len() == other.elements.len()
+            && self
+                .elements
+                .iter()
+                .zip(&other.elements)
+                .all(|(a, b)| a.is_same_type(b))
+    }
+
+    /// The values of either, element by element
+    ///
+    /// # Errors
+    ///
+    /// `Error::DifferentTypes` if they are not of the same type
+    pub fn union(&self, rhs: &Self) -> Result<Self, Error> {
+        self.zip(rhs, |a, b| a.union(b))
+    }
+
+    /// The values of both, element by element
+    ///
+    /// # Errors
+    ///
+    /// `Error::DifferentTypes` if they are not of the same type
+    pub fn intersection(&self, rhs: &Self) -> Result<Self, Error> {
+        self.zip(rhs, |a, b| a.intersection(b))
+    }
+
+    /// Returns true if each element is a subset of the one of `other`, of the same type
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.is_same_type(other)
+            && self
+                .elements
+                .iter()
+                .zip(&other.elements)
+                .all(|(a, b)| a.possibilities().is_subset_of(b.possibilities()))
+    }
+
+    /// Returns true if each element is a sound approximation of the one of
+    /// `tighter`, of the same type: `FloatPossibilities::is_sound_approximation_of`
+    pub fn is_sound_approximation_of(&self, tighter: &Self) -> bool {
+        self.is_same_type(tighter)
+            && self
+                .elements
+                .iter()
+                .zip(&tighter.elements)
+                .all(|(a, b)| a.is_sound_approximation_of(b))
+    }
+
+    /// Returns true if each of the `values` is accepted by its element
+    pub fn accept<T: FloatValue>(&self, values: &[T]) -> bool {
+        self.elements.len() == values.len()
+            && self
+                .elements
+                .iter()
+                .zip(values)
+                .all(|(element, value)| element.accept(*value))
+    }
+
+    fn zip(&self, rhs: &Self, f: impl Fn(&FP, &FP) -> FP) -> Result<Self, Error> {
+        if !self.is_same_type(rhs) {
+            return Err(Error::DifferentTypes);
+        }
+
+        let elements = self
+            .elements
+            .iter()
+            .zip(&rhs.elements)
+            .map(|(a, b)| a.map(|fp| f(&fp, b.possibilities())))
+            .collect();
+        Ok(FnRet { elements })
+    }
+}
+
+/// A single float
+impl From<FnArgFloat> for FnRet {
+    fn from(ret: FnArgFloat) -> Self {
+        FnRet::new(alloc::vec![ret])
+    }
+}
+
+/// Like the result of `core::ops::sin_cos`
+impl From<(FnArgFloat, FnArgFloat)> for FnRet {
+    fn from((a, b): (FnArgFloat, FnArgFloat)) -> Self {
+        FnRet::new(alloc::vec![a, b])
+    }
+}
+
+impl<const N: usize> From<[FnArgFloat; N]> for FnRet {
+    fn from(elements: [FnArgFloat; N]) -> Self {
+        FnRet::new(elements.into())
+    }
+}
+
+/// Like `(f64 nan:N, f32 ...)`
+impl fmt::Display for FnRet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, element) in self.elements.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{element}")?;
+        }
+        f.write_str(")")
+    }
+}
@@ -0,0 +1,70 @@
+use rand::RngCore;
+
+use crate::FloatPossibilities;
+
+const MANTISSA: u64 = (1 << 52) - 1;
+
+/// The kinds of values, from random bits: the sign is chosen separately
+const KINDS: [fn(u64) -> f64; 8] = [
+    |bits| f64::from_bits(0x7FF8_0000_0000_0000 | (bits & MANTISSA)),
+    |_| 0.0,
+    |_| f64::INFINITY,
+    // Subnormal
+    |bits| f64::from_bits(1 + bits % MANTISSA),
+    // Normal, below one
+    |bits| f64::from_bits(((1 + (bits >> 52) % 1022) << 52) | (bits & MANTISSA)),
+    |_| 1.0,
+    // Normal, from one
+    |bits| f64::from_bits(((1023 + (bits >> 52) % 1024) << 52) | (bits & MANTISSA)),
+    |bits| EXTREMES[(bits % EXTREMES.len() as u64) as usize],
+];
+
+const EXTREMES: [f64; 6] = [
+    f64::MAX,
+    f64::MIN_POSITIVE,
+    // The smallest subnormal
+    5e-324,
+    // Around one
+    1.0 - f64::EPSILON / 2.0,
+    1.0 + f64::EPSILON,
+    // The largest with a fractional part
+    4_503_599_627_370_495.5,
+];
+
+impl FloatPossibilities {
+    /// A random value accepted by the possibilities
+    ///
+    /// All the kinds of values are equally likely: NaNs, zeros, infinities,
+    /// subnormals, values below or above one and extremes like `f64::MAX`.
+    ///
+    /// ```
+    /// use fn_num_types::FloatPossibilities;
+    ///
+    /// let fp = FloatPossibilities::strictly_negative_finite();
+    /// let mut rng = rand::rng();
+    ///
+    /// for _ in 0..100 {
+    ///     let value = fp.sample(&mut rng);
+    ///     assert!(value < 0.0 && value.is_finite());
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If no value is accepted, see `is_satisfiable`
+    pub fn sample<R: RngCore + ?Sized>(&self, rng: &mut R) -> f64 {
+        assert!(self.is_satisfiable(), "No value accepted");
+
+        // `1.0` or `-1.0` is accepted if a sign is, so it ends
+        loop {
+            let kind = rng.next_u64();
+            let bits = rng.next_u64();
+            let value = KINDS[(kind % KINDS.len() as u64) as usize](bits);
+            let value = if kind >> 63 == 0 { value } else { -value };
+
+            if self.accept(value) {
+                return value;
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Error, FnArgFloat, FnRet};
+
+/// The contract of a function, like `fn(NonNaN, Positive) -> Finite`
+///
+/// ```
+/// use fn_num_types::{Error, FloatPossibilities, FnArgFloat, FnSignature};
+///
+/// let signature = FnSignature::new(
+///     vec![
+///         FnArgFloat::F64(FloatPossibilities::non_nan()),
+///         FnArgFloat::F64(FloatPossibilities::positive()),
+///     ],
+///     FnArgFloat::F64(FloatPossibilities::finite()),
+/// );
+///
+/// let finite = FnArgFloat::F64(FloatPossibilities::finite());
+/// let positive = FnArgFloat::F64(FloatPossibilities::strictly_positive_finite());
+///
+/// assert_eq!(signature.call(&[finite, positive]), Ok(signature.ret));
+/// assert_eq!(
+///     signature.call(&[positive, finite]),
+///     Err(Error::NotAccepted { index: 1 })
+/// );
+/// ```
+///
+/// A function returning several floats, like `f64::sin_cos`, is of a `FnRet`:
+/// `FnSignature<FnRet>`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FnSignature<R = FnArgFloat> {
+    pub args: Vec<FnArgFloat>,
+    pub ret: R,
+}
+
+impl<R: Clone> FnSignature<R> {
+    pub fn new(args: Vec<FnArgFloat>, ret: R) -> Self {
+        FnSignature { args, ret }
+    }
+
+    /// Checks that the arguments of a call site are accepted by the signature:
+    /// of the same types, with possibilities included in those of the parameters
+    pub fn check(&self, args: &[FnArgFloat]) -> Result<(), Error> {
+        if args.len() != self.args.len() {
+            return Err(Error::ArgumentCount {
+                expected: self.args.len(),
+                found: args.len(),
+            });
+        }
+
+        for (index, (arg, param)) in args.iter().zip(&self.args).enumerate() {
+            if !arg.is_same_type(param) {
+                return Err(Error::DifferentTypes);
+            }
+            if !arg.possibilities().is_subset_of(param.possibilities()) {
+                return Err(Error::NotAccepted { index });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The possibilities of the result of a call with `args`, after `check`
+    pub fn call(&self, args: &[FnArgFloat]) -> Result<R, Error> {
+        self.check(args)?;
+
+        Ok(self.ret.clone())
+    }
+}
+
+impl FnSignature {
+    /// Returns true if `analysis`, the model of an implementation,
+    /// returns values of `ret` for the parameters: `ret` is a sound
+    /// approximation of its result
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, FnArgFloat, FnSignature};
+    ///
+    /// let signature = FnSignature::new(
+    ///     vec![FnArgFloat::F64(FloatPossibilities::positive_finite())],
+    ///     FnArgFloat::F64(FloatPossibilities::positive_finite()),
+    /// );
+    ///
+    /// assert!(signature.is_implemented_by(|args| args[0]));
+    /// assert!(!signature.is_implemented_by(|args| args[0].with_possibilities(FloatPossibilities::any())));
+    /// ```
+    pub fn is_implemented_by<F>(&self, analysis: F) -> bool
+    where
+        F: FnOnce(&[FnArgFloat]) -> FnArgFloat,
+    {
+        self.ret.is_sound_approximation_of(&analysis(&self.args))
+    }
+}
+
+impl FnSignature<FnRet> {
+    /// Returns true if `analysis`, the model of an implementation,
+    /// returns values of `ret` for the parameters, element by element
+    ///
+    /// ```
+    /// use fn_num_types::core::ops::{fract, trunc};
+    /// use fn_num_types::{FloatPossibilities, FnArgFloat, FnRet, FnSignature};
+    ///
+    /// // Like `modf`: the integral and fractional parts
+    /// let finite = FnArgFloat::F64(FloatPossibilities::finite());
+    /// let signature = FnSignature::new(vec![finite], FnRet::from((finite, finite)));
+    ///
+    /// let modf = |args: &[FnArgFloat]| FnRet::from((trunc(&args[0]), fract(&args[0])));
+    /// assert!(signature.is_implemented_by(modf));
+    /// assert!(!signature.is_implemented_by(|args| FnRet::from(args[0])));
+    /// ```
+    pub fn is_implemented_by<F>(&self, analysis: F) -> bool
+    where
+        F: FnOnce(&[FnArgFloat]) -> FnRet,
+    {
+        self.ret.is_sound_approximation_of(&analysis(&self.args))
+    }
+}
+
+/// Like `fn(f64 nan:N ..., f64 ...) -> f64 ...`
+impl<R: fmt::Display> fmt::Display for FnSignature<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("fn(")?;
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{arg}")?;
+        }
+        write!(f, ") -> {}", self.ret)
+    }
+}
@@ -0,0 +1,207 @@
+//! Vectors of floats, like the `Simd<f32, N>` of `std::simd`, lane by lane
+//!
+//! The lane-wise operations are the ones of the floats. The reductions are of
+//! the lanes in any order: the sum of finite lanes may be NaN, as
+//! `(MAX + MAX) + (-MAX - MAX)`, even if the sum of two of them may not.
+//!
+//! ```
+//! use fn_num_types::simd::SimdPossibilities;
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! let x = SimdPossibilities::<4>::splat(FnArgFloat::F32(FloatPossibilities::finite()));
+//!
+//! assert_eq!(x.add(&x).lanes()[0].possibilities().nan, Possible::No);
+//! assert_ne!(x.reduce_sum().possibilities().nan, Possible::No);
+//!
+//! // The norms of the lanes
+//! let norm = x.hypot(&x.sin());
+//! assert_eq!(norm.reduce_max().possibilities().negative, Possible::No);
+//! assert!(norm.accept([0.0f32, 0.5, 1.0, 3.0]));
+//! ```
+
+use crate::core::ops;
+use crate::{FloatValue, FnArgFloat};
+
+/// The possibilities of each of the `N` lanes of a vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SimdPossibilities<const N: usize> {
+    lanes: [FnArgFloat; N],
+}
+
+impl<const N: usize> SimdPossibilities<N> {
+    /// # Panics
+    ///
+    /// If there are no lanes, or if they are not all of the same type
+    pub fn new(lanes: [FnArgFloat; N]) -> Self {
+        assert!(N > 0, "No lanes");
+        if FnArgFloat::check_same_type(&lanes).is_err() {
+            panic!("Different types");
+        }
+
+        SimdPossibilities { lanes }
+    }
+
+    /// `Simd::splat(x)`, all the lanes of the same possibilities
+    ///
+    /// # Panics
+    ///
+    /// If there are no lanes
+    pub fn splat(x: FnArgFloat) -> Self {
+        Self::new([x; N])
+    }
+
+    pub fn lanes(&self) -> &[FnArgFloat; N] {
+        &self.lanes
+    }
+
+    /// The possibilities of any of the lanes
+    pub fn shared(&self) -> FnArgFloat {
+        self.lanes[1..]
+            .iter()
+            .fold(self.lanes[0], |acc, lane| union(&acc, lane))
+    }
+
+    /// Returns true if each of the `values` is accepted by its lane
+    pub fn accept<T: FloatValue>(&self, values: [T; N]) -> bool {
+        self.lanes
+            .iter()
+            .zip(values)
+            .all(|(lane, value)| lane.accept(value))
+    }
+
+    /// The operation `f` of one argument, lane by lane
+    pub fn map(&self, mut f: impl FnMut(&FnArgFloat) -> FnArgFloat) -> Self {
+        SimdPossibilities {
+            lanes: core::array::from_fn(|i| f(&self.lanes[i])),
+        }
+    }
+
+    /// The operation `f` of two arguments, lane by lane
+    ///
+    /// # Panics
+    ///
+    /// If they are not of the same type
+    pub fn zip(
+        &self,
+        rhs: &Self,
+        mut f: impl FnMut(&FnArgFloat, &FnArgFloat) -> FnArgFloat,
+    ) -> Self {
+        SimdPossibilities {
+            lanes: core::array::from_fn(|i| f(&self.lanes[i], &rhs.lanes[i])),
+        }
+    }
+
+    /// `x - y`, lane by lane
+    pub fn sub(&self, rhs: &Self) -> Self {
+        self.zip(rhs, |x, y| *x - *y)
+    }
+
+    /// `x.reduce_sum()`, of the lanes in any order
+    pub fn reduce_sum(&self) -> FnArgFloat {
+        self.reduce(ops::add)
+    }
+
+    /// `x.reduce_product()`, of the lanes in any order
+    pub fn reduce_product(&self) -> FnArgFloat {
+        self.reduce(ops::mul)
+    }
+
+    /// `x.reduce_max()`, a NaN lane is ignored like by `f64::max`
+    pub fn reduce_max(&self) -> FnArgFloat {
+        self.reduce(ops::max)
+    }
+
+    /// `x.reduce_min()`, a NaN lane is ignored like by `f64::min`
+    pub fn reduce_min(&self) -> FnArgFloat {
+        self.reduce(ops::min)
+    }
+
+    /// The reduction of the lanes by `op`, by any tree of operations
+    fn reduce(&self, op: fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat) -> FnArgFloat {
+        if N == 1 {
+            return self.lanes[0];
+        }
+
+        // The reductions of some of the lanes, of more and more operations:
+        // the two halves of the tree are of at most `N - 2` levels
+        let mut partial = self.shared();
+        for _ in 2..N {
+            let next = union(&partial, &op(&partial, &partial));
+            if next == partial {
+                break;
+            }
+            partial = next;
+        }
+
+        op(&partial, &partial)
+    }
+}
+
+fn union(a: &FnArgFloat, b: &FnArgFloat) -> FnArgFloat {
+    a.map(|fp| fp.union(b.possibilities()))
+}
+
+macro_rules! unary {
+    ($($op:ident),* $(,)?) => {
+        impl<const N: usize> SimdPossibilities<N> {
+            $(
+                pub fn $op(&self) -> Self {
+                    self.map(ops::$op)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident),* $(,)?) => {
+        impl<const N: usize> SimdPossibilities<N> {
+            $(
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    self.zip(rhs, ops::$op)
+                }
+            )*
+        }
+    };
+}
+
+unary!(
+    neg,
+    abs,
+    ceil,
+    floor,
+    round,
+    round_ties_even,
+    trunc,
+    fract,
+    signum,
+    sqrt,
+    exp,
+    exp2,
+    ln,
+    log2,
+    log10,
+    to_degrees,
+    to_radians,
+    cbrt,
+    sin,
+    cos,
+    tan,
+    asin,
+    acos,
+    atan,
+    exp_m1,
+    ln_1p,
+    sinh,
+    cosh,
+    tanh,
+    asinh,
+    acosh,
+    atanh,
+    recip,
+);
+
+binary!(
+    add, mul, div, rem, div_euclid, rem_euclid, powf, hypot, atan2, copysign, max, min, maximum,
+    minimum, midpoint,
+);
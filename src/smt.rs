@@ -0,0 +1,236 @@
+//! Export of the claims of the operations as SMT-LIB scripts,
+//! using the theory of floating point numbers (`QF_FP`).
+//!
+//! Each script asserts that the arguments are accepted by their possibilities
+//! and that the result is *not* accepted by the possibilities returned by the model.
+//! A solver answering `unsat` proves the claim, `sat` gives a counterexample.
+//!
+//! Only the operations expressible in the theory are supported:
+//! the transcendental functions are not.
+//!
+//! ```
+//! use fn_num_types::{FnArgFloat, Possible, FP};
+//!
+//! let x = FnArgFloat::F64(FP {
+//!     nan: Possible::No,
+//!     pos_zero: Possible::Yes,
+//!     neg_zero: Possible::Yes,
+//!     subnormal: Possible::Yes,
+//!     fractional: Possible::Yes,
+//!     large: Possible::Yes,
+//!     infinite: Possible::No,
+//!     positive: Possible::Yes,
+//!     negative: Possible::No,
+//! });
+//!
+//! let script = fn_num_types::smt::script("sqrt", &[x]).unwrap();
+//!
+//! assert!(script.contains("(fp.sqrt RNE x0)"));
+//! assert!(script.ends_with("(check-sat)\n"));
+//! ```
+
+use std::fmt::Write;
+
+use crate::{core::ops, Error, FnArgFloat, Possible};
+
+type Binary = fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat;
+
+/// Names of the supported operations
+pub const OPS: &[&str] = &[
+    "neg",
+    "abs",
+    "ceil",
+    "floor",
+    "round",
+    "round_ties_even",
+    "trunc",
+    "fract",
+    "signum",
+    "sqrt",
+    "to_degrees",
+    "to_radians",
+    "recip",
+    "powi",
+    "add",
+    "mul",
+    "div",
+    "max",
+    "min",
+    "mul_add",
+];
+
+fn sort(arg: &FnArgFloat) -> String {
+    let (exponent, significand) = arg.widths();
+
+    format!("(_ FloatingPoint {exponent} {significand})")
+}
+
+/// Literal of the bits of a `f32` or a `f64`
+fn bits_literal(bits: u64, exponent: usize, mantissa: usize) -> String {
+    let sign = bits >> (exponent + mantissa);
+    let e = (bits >> mantissa) & ((1 << exponent) - 1);
+    let m = bits & ((1 << mantissa) - 1);
+
+    format!("(fp #b{sign} #b{e:0exponent$b} #b{m:0mantissa$b})")
+}
+
+/// Literal of the same type as `arg`
+fn literal(arg: &FnArgFloat, value: f64) -> String {
+    match arg {
+        FnArgFloat::F32(_) => bits_literal(u64::from((value as f32).to_bits()), 8, 23),
+        FnArgFloat::F64(_) => bits_literal(value.to_bits(), 11, 52),
+        // Rounded from the `f64` literal
+        #[cfg(feature = "extra-widths")]
+        _ => {
+            let (exponent, significand) = arg.widths();
+            let value = bits_literal(value.to_bits(), 11, 52);
+
+            format!("((_ to_fp {exponent} {significand}) RNE {value})")
+        }
+    }
+}
+
+fn allowed(possible: Possible) -> &'static str {
+    if possible == Possible::No {
+        "false"
+    } else {
+        "true"
+    }
+}
+
+/// The value `v`, of the type of `arg`, is accepted by its possibilities,
+/// like `FloatPossibilities::accept`
+fn accepts(arg: &FnArgFloat, v: &str) -> String {
+    let fp = arg.possibilities();
+    let one = literal(arg, 1.0);
+
+    format!(
+        "(and (=> (fp.isNaN {v}) {}) (=> (fp.isSubnormal {v}) {}) (=> (not (or (fp.isNaN {v}) (fp.isInfinite {v}) (fp.eq (fp.roundToIntegral RNE {v}) {v}))) {}) (=> (and (not (fp.isInfinite {v})) (fp.gt (fp.abs {v}) {one})) {}) (=> (fp.isInfinite {v}) {}) (=> (and (fp.isZero {v}) (fp.isPositive {v})) {}) (=> (and (fp.isZero {v}) (fp.isNegative {v})) {}) (=> (fp.isPositive {v}) {}) (=> (fp.isNegative {v}) {}))",
+        allowed(fp.nan),
+        allowed(fp.subnormal),
+        allowed(fp.fractional),
+        allowed(fp.large),
+        allowed(fp.infinite),
+        allowed(fp.pos_zero),
+        allowed(fp.neg_zero),
+        allowed(fp.positive),
+        allowed(fp.negative),
+    )
+}
+
+/// Term computing the operation `op`, and the possibilities claimed by the model
+fn term(op: &str, args: &[FnArgFloat]) -> Result<(String, FnArgFloat), Error> {
+    let arity = |found| Error::Arity {
+        op: op.to_owned(),
+        found,
+    };
+
+    let binary: Option<(&str, Binary)> = match op {
+        "add" => Some(("(fp.add RNE x0 x1)", ops::add)),
+        "mul" => Some(("(fp.mul RNE x0 x1)", ops::mul)),
+        "div" => Some(("(fp.div RNE x0 x1)", ops::div)),
+        "max" => Some(("(fp.max x0 x1)", ops::max)),
+        "min" => Some(("(fp.min x0 x1)", ops::min)),
+        _ => None,
+    };
+
+    if let Some((term, op)) = binary {
+        return match args {
+            [lhs, rhs] => {
+                FnArgFloat::check_same_type(args)?;
+                Ok((term.to_owned(), op(lhs, rhs)))
+            }
+            _ => Err(arity(args.len())),
+        };
+    }
+
+    if op == "mul_add" {
+        return match args {
+            [a, b, c] => {
+                FnArgFloat::check_same_type(args)?;
+                Ok(("(fp.fma RNE x0 x1 x2)".to_owned(), ops::mul_add(a, b, c)))
+            }
+            _ => Err(arity(args.len())),
+        };
+    }
+
+    let [x] = args else {
+        return if OPS.contains(&op) {
+            Err(arity(args.len()))
+        } else {
+            Err(Error::UnknownOp(op.to_owned()))
+        };
+    };
+
+    let one = literal(x, 1.0);
+
+    let (term, op): (String, fn(&FnArgFloat) -> FnArgFloat) = match op {
+        "neg" => ("(fp.neg x0)".to_owned(), ops::neg),
+        "abs" => ("(fp.abs x0)".to_owned(), ops::abs),
+        "ceil" => ("(fp.roundToIntegral RTP x0)".to_owned(), ops::ceil),
+        "floor" => ("(fp.roundToIntegral RTN x0)".to_owned(), ops::floor),
+        "round" => ("(fp.roundToIntegral RNA x0)".to_owned(), ops::round),
+        "round_ties_even" => (
+            "(fp.roundToIntegral RNE x0)".to_owned(),
+            ops::round_ties_even,
+        ),
+        "trunc" => ("(fp.roundToIntegral RTZ x0)".to_owned(), ops::trunc),
+        "fract" => (
+            "(fp.sub RNE x0 (fp.roundToIntegral RTZ x0))".to_owned(),
+            ops::fract,
+        ),
+        "signum" => (
+            format!("(ite (fp.isNaN x0) x0 (ite (fp.isPositive x0) {one} (fp.neg {one})))"),
+            ops::signum,
+        ),
+        "sqrt" => ("(fp.sqrt RNE x0)".to_owned(), ops::sqrt),
+        "to_degrees" => {
+            let factor = match x {
+                FnArgFloat::F32(_) => literal(x, f64::from(1.0f32.to_degrees())),
+                _ => literal(x, 180.0f64 / core::f64::consts::PI),
+            };
+            (format!("(fp.mul RNE x0 {factor})"), ops::to_degrees)
+        }
+        "to_radians" => {
+            let factor = match x {
+                FnArgFloat::F32(_) => literal(x, f64::from(core::f32::consts::PI / 180.0)),
+                _ => literal(x, core::f64::consts::PI / 180.0),
+            };
+            (format!("(fp.mul RNE x0 {factor})"), ops::to_radians)
+        }
+        "recip" => (format!("(fp.div RNE {one} x0)"), ops::recip),
+        // x.powi(2)
+        "powi" => ("(fp.mul RNE x0 x0)".to_owned(), ops::powi),
+        _ => return Err(Error::UnknownOp(op.to_owned())),
+    };
+
+    Ok((term, op(x)))
+}
+
+/// Returns the SMT-LIB script checking the result of `op` applied to `args`
+pub fn script(op: &str, args: &[FnArgFloat]) -> Result<String, Error> {
+    let (term, result) = term(op, args)?;
+
+    let mut script = String::new();
+
+    let _ = writeln!(script, "; {op}({}) = {result}", join(args));
+    let _ = writeln!(script, "(set-logic QF_FP)");
+
+    for (i, arg) in args.iter().enumerate() {
+        let _ = writeln!(script, "(declare-const x{i} {})", sort(arg));
+        let _ = writeln!(script, "(assert {})", accepts(arg, &format!("x{i}")));
+    }
+
+    let _ = writeln!(script, "(define-fun r () {} {term})", sort(&result));
+    let _ = writeln!(script, "(assert (not {}))", accepts(&result, "r"));
+    let _ = writeln!(script, "(check-sat)");
+
+    Ok(script)
+}
+
+fn join(args: &[FnArgFloat]) -> String {
+    args.iter()
+        .map(|arg| arg.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,210 @@
+//! Signaling NaNs, on top of the possibilities.
+//!
+//! The possibilities don't tell the NaNs apart. A `SignalingFloat` also carries
+//! the possibility that its NaN is signaling, for the code that traps on them:
+//! the operations on the bits, like `neg`, `abs` and `copysign`, keep the payload
+//! of a NaN, while the arithmetic operations quiet it.
+//!
+//! ```
+//! use fn_num_types::snan::SignalingFloat;
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! // From C: any NaN may be signaling
+//! let x = SignalingFloat::from(FnArgFloat::F64(FloatPossibilities::any()));
+//! assert_eq!(x.snan(), Possible::Yes);
+//!
+//! assert_eq!(x.abs().neg().snan(), Possible::Yes);
+//! assert_eq!(x.abs().sqrt().snan(), Possible::No);
+//! assert_eq!(x.add(&x).arg().possibilities().nan, Possible::Yes);
+//!
+//! let snan = f64::from_bits(0x7FF0_0000_0000_0001);
+//! assert!(x.neg().accept(-snan));
+//! assert!(!x.sqrt().accept(snan));
+//! ```
+
+use crate::core::ops;
+use crate::{BoolPossibility, FloatValue, FnArgFloat, Possible};
+
+/// A primitive float, whose NaNs are signaling or quiet
+pub trait NanBits: FloatValue {
+    /// Returns true if `self` is a NaN whose quiet bit is clear
+    fn is_signaling(self) -> bool;
+}
+
+impl NanBits for f32 {
+    fn is_signaling(self) -> bool {
+        self.is_nan() && self.to_bits() & (1 << 22) == 0
+    }
+}
+
+impl NanBits for f64 {
+    fn is_signaling(self) -> bool {
+        self.is_nan() && self.to_bits() & (1 << 51) == 0
+    }
+}
+
+/// The possibilities of a float, with the possibility that its NaN is signaling
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalingFloat {
+    arg: FnArgFloat,
+    snan: Possible,
+}
+
+impl SignalingFloat {
+    /// `arg`, whose NaN may be signaling as told by `snan`
+    ///
+    /// It is bounded by the possibility of NaN.
+    pub fn new(arg: FnArgFloat, snan: Possible) -> Self {
+        SignalingFloat {
+            arg,
+            snan: snan & arg.possibilities().nan,
+        }
+    }
+
+    /// `arg`, whose NaN is quiet, like the results of the arithmetic operations
+    pub fn quiet(arg: FnArgFloat) -> Self {
+        SignalingFloat::new(arg, Possible::No)
+    }
+
+    /// The possibilities, whatever the NaN
+    pub fn arg(&self) -> &FnArgFloat {
+        &self.arg
+    }
+
+    /// The possibility that `self` is a signaling NaN
+    pub fn snan(&self) -> Possible {
+        self.snan
+    }
+
+    /// Returns true if `value` is accepted by the possibilities,
+    /// and is not a signaling NaN unless it may be
+    pub fn accept<T: NanBits>(&self, value: T) -> bool {
+        self.arg.accept(value) && (self.snan != Possible::No || !value.is_signaling())
+    }
+
+    /// `x.copysign(y)`: only the sign of `y` is read, the payload of `x` is kept
+    pub fn copysign(&self, rhs: &Self) -> Self {
+        SignalingFloat::new(ops::copysign(&self.arg, &rhs.arg), self.snan)
+    }
+
+    /// `f64::max`: a NaN is ignored, but of two NaNs either may be returned unchanged
+    pub fn max(&self, rhs: &Self) -> Self {
+        SignalingFloat::new(ops::max(&self.arg, &rhs.arg), self.either_nan(rhs))
+    }
+
+    /// `f64::min`: a NaN is ignored, but of two NaNs either may be returned unchanged
+    pub fn min(&self, rhs: &Self) -> Self {
+        SignalingFloat::new(ops::min(&self.arg, &rhs.arg), self.either_nan(rhs))
+    }
+
+    /// `then` if `cond` may be true, and `otherwise` if it may be false, unchanged
+    pub fn select(cond: BoolPossibility, then: &Self, otherwise: &Self) -> Self {
+        SignalingFloat::new(
+            ops::select(cond, &then.arg, &otherwise.arg),
+            then.snan | otherwise.snan,
+        )
+    }
+
+    /// A signaling NaN returned when both are NaN
+    fn either_nan(&self, rhs: &Self) -> Possible {
+        let nan1 = self.arg.possibilities().nan;
+        let nan2 = rhs.arg.possibilities().nan;
+
+        (self.snan & nan2) | (nan1 & rhs.snan)
+    }
+}
+
+/// From outside: any NaN may be signaling
+impl From<FnArgFloat> for SignalingFloat {
+    fn from(arg: FnArgFloat) -> Self {
+        SignalingFloat::new(arg, Possible::Yes)
+    }
+}
+
+// The operations on the bits, keeping the payload
+macro_rules! bitwise {
+    ($($op:ident),* $(,)?) => {
+        impl SignalingFloat {
+            $(
+                pub fn $op(&self) -> Self {
+                    SignalingFloat::new(ops::$op(&self.arg), self.snan)
+                }
+            )*
+        }
+    };
+}
+
+// The arithmetic operations, quieting a signaling NaN
+macro_rules! unary {
+    ($($op:ident),* $(,)?) => {
+        impl SignalingFloat {
+            $(
+                pub fn $op(&self) -> Self {
+                    SignalingFloat::quiet(ops::$op(&self.arg))
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident),* $(,)?) => {
+        impl SignalingFloat {
+            $(
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    SignalingFloat::quiet(ops::$op(&self.arg, &rhs.arg))
+                }
+            )*
+        }
+    };
+}
+
+bitwise!(neg, abs);
+
+unary!(
+    ceil,
+    floor,
+    round,
+    round_ties_even,
+    trunc,
+    fract,
+    signum,
+    sqrt,
+    exp,
+    exp2,
+    ln,
+    log2,
+    log10,
+    to_degrees,
+    to_radians,
+    cbrt,
+    sin,
+    cos,
+    tan,
+    asin,
+    acos,
+    atan,
+    exp_m1,
+    ln_1p,
+    sinh,
+    cosh,
+    tanh,
+    asinh,
+    acosh,
+    atanh,
+    recip,
+);
+
+binary!(add, mul, div, rem, div_euclid, rem_euclid, powf, hypot, atan2, maximum, minimum);
+
+impl SignalingFloat {
+    /// `x - y`
+    pub fn sub(&self, rhs: &Self) -> Self {
+        SignalingFloat::quiet(self.arg - rhs.arg)
+    }
+
+    /// `x * y + z`
+    pub fn mul_add(&self, y: &Self, z: &Self) -> Self {
+        SignalingFloat::quiet(ops::mul_add(&self.arg, &y.arg, &z.arg))
+    }
+}
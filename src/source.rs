@@ -0,0 +1,214 @@
+//! Rust expressions, parsed with `syn`, for the lints and the reviews of a code
+//!
+//! ```
+//! use fn_num_types::source::analyze_source;
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! let x = FnArgFloat::F64(FloatPossibilities::any());
+//! let y = FnArgFloat::F64(FloatPossibilities::non_nan());
+//!
+//! let res = analyze_source("x.abs().mul_add(y, 1.0)", &[("x", x), ("y", y)]).unwrap();
+//! assert_eq!(res.possibilities().nan, Possible::Yes);
+//!
+//! let res = analyze_source("(y.abs() + 1.0).sqrt()", &[("y", y)]).unwrap();
+//! assert_eq!(res.possibilities().nan, Possible::No);
+//! ```
+
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use quote::ToTokens;
+use syn::{BinOp, Expr, Lit, UnOp};
+
+use crate::core::ops;
+use crate::expr::{constant, BinaryOp};
+use crate::{Error, FnArgFloat, ParseError};
+
+/// The possibilities of the result of the Rust expression `source`,
+/// its variables being bound by `bindings`
+///
+/// The expression is made of the variables, the literals, the operators
+/// `-x`, `+`, `-`, `*`, `/` and `%`, the methods of `core::ops::apply` and `powi`,
+/// called like `x.sqrt()` or `f64::sqrt(x)`, the casts `as f32` and `as f64`,
+/// and the constants like `f64::MAX`. A literal without suffix is of the type
+/// of the first binding, like for `expr::eval`.
+///
+/// # Errors
+///
+/// `Error::Parse` for an invalid or an unsupported expression, like an unbound
+/// variable, and the errors of `core::ops::apply` for the methods.
+pub fn analyze_source(source: &str, bindings: &[(&str, FnArgFloat)]) -> Result<FnArgFloat, Error> {
+    let expr: Expr = syn::parse_str(source).map_err(|_| parse_error(source))?;
+
+    Evaluator { bindings }.eval(&expr)
+}
+
+fn parse_error(token: &str) -> Error {
+    Error::Parse(ParseError {
+        token: token.to_owned(),
+    })
+}
+
+fn unsupported(tokens: &impl ToTokens) -> Error {
+    parse_error(&tokens.to_token_stream().to_string())
+}
+
+struct Evaluator<'a> {
+    bindings: &'a [(&'a str, FnArgFloat)],
+}
+
+impl Evaluator<'_> {
+    fn eval(&self, expr: &Expr) -> Result<FnArgFloat, Error> {
+        match expr {
+            Expr::Paren(expr) => self.eval(&expr.expr),
+            Expr::Group(expr) => self.eval(&expr.expr),
+            Expr::Lit(lit) => self.literal(&lit.lit),
+            Expr::Path(path) => match path.path.get_ident() {
+                Some(ident) => self
+                    .bindings
+                    .iter()
+                    .find(|(name, _)| ident == name)
+                    .map(|(_, arg)| *arg)
+                    .ok_or_else(|| unsupported(ident)),
+                None => Self::associated_constant(path),
+            },
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+                Ok(ops::neg(&self.eval(&unary.expr)?))
+            }
+            Expr::Binary(binary) => {
+                let op = match binary.op {
+                    BinOp::Add(_) => BinaryOp::Add,
+                    BinOp::Sub(_) => BinaryOp::Sub,
+                    BinOp::Mul(_) => BinaryOp::Mul,
+                    BinOp::Div(_) => BinaryOp::Div,
+                    BinOp::Rem(_) => BinaryOp::Rem,
+                    _ => return Err(unsupported(&binary.op)),
+                };
+                let args = [self.eval(&binary.left)?, self.eval(&binary.right)?];
+                FnArgFloat::check_same_type(&args)?;
+
+                Ok(op.model()(&args[0], &args[1]))
+            }
+            Expr::MethodCall(call) => {
+                let receiver = self.eval(&call.receiver)?;
+                self.call(&call.method.to_string(), receiver, call.args.iter())
+            }
+            // `f64::sqrt(x)`
+            Expr::Call(call) => {
+                let Expr::Path(path) = &*call.func else {
+                    return Err(unsupported(&call.func));
+                };
+                let name = match path.path.segments.last() {
+                    Some(segment) => segment.ident.to_string(),
+                    None => return Err(unsupported(path)),
+                };
+                let mut args = call.args.iter();
+                let receiver = match args.next() {
+                    Some(receiver) => self.eval(receiver)?,
+                    None => return Err(unsupported(call)),
+                };
+                self.call(&name, receiver, args)
+            }
+            Expr::Cast(cast) => {
+                let x = self.eval(&cast.expr)?;
+                match cast.ty.to_token_stream().to_string().as_str() {
+                    "f32" => Ok(ops::to_f32(&x)),
+                    "f64" => Ok(ops::to_f64(&x)),
+                    _ => Err(unsupported(&cast.ty)),
+                }
+            }
+            _ => Err(unsupported(expr)),
+        }
+    }
+
+    /// The method `name` of `receiver`, with the other arguments `args`
+    fn call<'e>(
+        &self,
+        name: &str,
+        receiver: FnArgFloat,
+        args: impl Iterator<Item = &'e Expr>,
+    ) -> Result<FnArgFloat, Error> {
+        let args: Vec<&Expr> = args.collect();
+
+        // Of an integer exponent, known if it is a literal
+        if name == "powi" {
+            return match args[..] {
+                [Expr::Lit(lit)] => match &lit.lit {
+                    Lit::Int(n) => {
+                        let n = n.base10_parse::<i32>().map_err(|_| unsupported(n))?;
+                        Ok(ops::powi_n(&receiver, n))
+                    }
+                    lit => Err(unsupported(lit)),
+                },
+                [_] => Ok(ops::powi(&receiver)),
+                _ => Err(Error::Arity {
+                    op: name.to_owned(),
+                    found: args.len() + 1,
+                }),
+            };
+        }
+
+        let mut values = Vec::with_capacity(args.len() + 1);
+        values.push(receiver);
+        for arg in args {
+            values.push(self.eval(arg)?);
+        }
+
+        ops::apply(name, &values)
+    }
+
+    /// A number, of the type of its suffix or of the first binding
+    fn literal(&self, lit: &Lit) -> Result<FnArgFloat, Error> {
+        let (value, suffix) = match lit {
+            Lit::Float(lit) => (lit.base10_parse::<f64>(), lit.suffix()),
+            Lit::Int(lit) => (lit.base10_parse::<f64>(), lit.suffix()),
+            lit => return Err(unsupported(lit)),
+        };
+        let value = value.map_err(|_| unsupported(lit))?;
+
+        match suffix {
+            "f32" => Ok(constant(value, Some(&FnArgFloat::f32_any()))),
+            "f64" => Ok(constant(value, Some(&FnArgFloat::f64_any()))),
+            "" => Ok(constant(value, self.bindings.first().map(|(_, arg)| arg))),
+            _ => Err(unsupported(lit)),
+        }
+    }
+
+    /// Like `f64::MAX` or `f32::consts::PI`
+    fn associated_constant(path: &syn::ExprPath) -> Result<FnArgFloat, Error> {
+        let segments: Vec<String> = path
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        let (ty, name) = match &segments[..] {
+            [.., ty, name] if ty == "f32" || ty == "f64" => (ty.as_str(), name.as_str()),
+            [.., ty, consts, name] if consts == "consts" => (ty.as_str(), name.as_str()),
+            _ => return Err(unsupported(path)),
+        };
+
+        macro_rules! constants {
+            ($ty:ident, $($name:ident),* ; $($consts:ident),*) => {
+                match name {
+                    $(stringify!($name) => FnArgFloat::from($ty::$name),)*
+                    $(stringify!($consts) => FnArgFloat::from(core::$ty::consts::$consts),)*
+                    _ => return Err(unsupported(path)),
+                }
+            };
+        }
+
+        Ok(match ty {
+            "f32" => constants!(
+                f32, INFINITY, NEG_INFINITY, NAN, MAX, MIN, MIN_POSITIVE, EPSILON;
+                PI, E, TAU, SQRT_2, LN_2, LN_10
+            ),
+            "f64" => constants!(
+                f64, INFINITY, NEG_INFINITY, NAN, MAX, MIN, MIN_POSITIVE, EPSILON;
+                PI, E, TAU, SQRT_2, LN_2, LN_10
+            ),
+            _ => return Err(unsupported(path)),
+        })
+    }
+}
@@ -0,0 +1,305 @@
+use crate::add::add_outcome;
+use crate::config::{round_underflow, Cases, Outcome};
+use crate::core::ops::{add, iterate_to_fixpoint, mul, sqrt};
+use crate::mul::mul_outcome;
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+use core::num::FpCategory;
+
+/// The first argument, and the others
+///
+/// # Panics
+///
+/// If `args` is empty or its elements are not of the same type
+pub(crate) const fn split_first(args: &[FnArgFloat]) -> (&FnArgFloat, &[FnArgFloat]) {
+    let Some((first, rest)) = args.split_first() else {
+        panic!("No arguments")
+    };
+
+    let mut i = 0;
+    while i < rest.len() {
+        assert!(first.is_same_type(&rest[i]), "Different types");
+        i += 1;
+    }
+
+    (first, rest)
+}
+
+/// Naive sum, from left to right
+///
+/// # Panics
+///
+/// If `args` is empty or its elements are not of the same type
+pub const fn sum(args: &[FnArgFloat]) -> FnArgFloat {
+    let sum = sum_outcome(args);
+
+    args[0].with_possibilities(sum.fp)
+}
+
+/// The operations folded by `fold_outcome`
+#[derive(Clone, Copy)]
+enum FoldOp {
+    Add,
+    Mul,
+}
+
+/// `op` from left to right, with the special cases of each application
+const fn fold_outcome(args: &[FnArgFloat], op: FoldOp) -> Outcome {
+    let (first, rest) = split_first(args);
+
+    let mut acc = Outcome::without_overflow(*first.possibilities());
+    let mut i = 0;
+    while i < rest.len() {
+        let (fp1, fp2) = (&acc.fp, rest[i].possibilities());
+        let next = match op {
+            FoldOp::Add => add_outcome(RoundingMode::ToNearest, fp1, fp2),
+            FoldOp::Mul => mul_outcome(RoundingMode::ToNearest, fp1, fp2),
+        };
+        acc = Outcome {
+            overflow: any!(acc.overflow, next.overflow),
+            cases: acc.cases.extend(next.cases),
+            ..next
+        };
+        i += 1;
+    }
+
+    acc
+}
+
+/// `sum`, with the special cases of the additions
+pub(crate) const fn sum_outcome(args: &[FnArgFloat]) -> Outcome {
+    fold_outcome(args, FoldOp::Add)
+}
+
+/// Naive product, from left to right
+///
+/// # Panics
+///
+/// If `args` is empty or its elements are not of the same type
+pub const fn product(args: &[FnArgFloat]) -> FnArgFloat {
+    let product = product_outcome(args);
+
+    args[0].with_possibilities(product.fp)
+}
+
+/// `product`, with the special cases of the multiplications
+pub(crate) const fn product_outcome(args: &[FnArgFloat]) -> Outcome {
+    fold_outcome(args, FoldOp::Mul)
+}
+
+/// `args.iter().sum()`, of the type of `ty`: `-0.0` for an empty slice,
+/// the identity used by `Iterator::sum`
+///
+/// ```
+/// use fn_num_types::core::ops::iter_sum;
+/// use fn_num_types::{FloatPossibilities, FnArgFloat};
+///
+/// let ty = FnArgFloat::F32(FloatPossibilities::any());
+///
+/// assert!(iter_sum(&ty, &[]).accept(-0.0f32));
+/// assert!(!iter_sum(&ty, &[]).accept(0.0f32));
+/// ```
+///
+/// # Panics
+///
+/// If the elements of `args` are not of the type of `ty`
+pub const fn iter_sum(ty: &FnArgFloat, args: &[FnArgFloat]) -> FnArgFloat {
+    let Some(first) = args.first() else {
+        return ty.with_possibilities(FP::of_value(FpCategory::Zero, true, false, false));
+    };
+    assert!(ty.is_same_type(first), "Different types");
+
+    sum(args)
+}
+
+/// `args.iter().product()`, of the type of `ty`: `1.0` for an empty slice
+///
+/// # Panics
+///
+/// If the elements of `args` are not of the type of `ty`
+pub const fn iter_product(ty: &FnArgFloat, args: &[FnArgFloat]) -> FnArgFloat {
+    let Some(first) = args.first() else {
+        return ty.with_possibilities(FP::of_value(FpCategory::Normal, false, false, false));
+    };
+    assert!(ty.is_same_type(first), "Different types");
+
+    product(args)
+}
+
+/// `iter.sum()` for any number of elements of possibilities `elem`, even zero
+///
+/// An overflow is unlikely when adding two values, but not when adding many.
+///
+/// ```
+/// use fn_num_types::core::ops::{add, sum_unbounded};
+/// use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+///
+/// let elem = FnArgFloat::F64(FloatPossibilities::strictly_positive_finite());
+/// assert_eq!(add(&elem, &elem).possibilities().infinite, Possible::ShouldNot);
+/// assert_eq!(sum_unbounded(&elem).possibilities().infinite, Possible::Yes);
+///
+/// // Adding values up to one saturates below `f64::MAX`
+/// let small = FnArgFloat::F64(FloatPossibilities::strictly_positive_finite().with_large(Possible::No));
+/// assert_eq!(sum_unbounded(&small).possibilities().infinite, Possible::No);
+/// ```
+pub fn sum_unbounded(elem: &FnArgFloat) -> FnArgFloat {
+    iterate_to_fixpoint(&iter_sum(elem, &[]), |acc| add(acc, elem))
+}
+
+/// `iter.product()` for any number of elements of possibilities `elem`, even zero
+///
+/// An overflow or an underflow is unlikely when multiplying two values, but not when
+/// multiplying many.
+pub fn product_unbounded(elem: &FnArgFloat) -> FnArgFloat {
+    iterate_to_fixpoint(&iter_product(elem, &[]), |acc| mul(acc, elem))
+}
+
+/// Kahan compensated sum, from left to right, starting with `sum = args[0]`
+///
+/// The compensation makes the result more accurate,
+/// but it is `NaN` when an intermediate sum, other than the last one, is infinite.
+///
+/// # Panics
+///
+/// If `args` is empty or its elements are not of the same type
+pub const fn sum_kahan(args: &[FnArgFloat]) -> FnArgFloat {
+    let sum = sum_kahan_outcome(args);
+
+    args[0].with_possibilities(sum.fp)
+}
+
+/// `sum_kahan`, with the special cases of the additions
+pub(crate) const fn sum_kahan_outcome(args: &[FnArgFloat]) -> Outcome {
+    let (first, rest) = split_first(args);
+
+    let Some((last, middle)) = rest.split_last() else {
+        return Outcome::without_overflow(*first.possibilities());
+    };
+
+    let mut sum = *first.possibilities();
+    let mut cases = Cases::NONE;
+    let mut i = 0;
+    while i < middle.len() {
+        let next = add_outcome(RoundingMode::ToNearest, &sum, middle[i].possibilities());
+        cases = cases.extend(next.cases);
+        let fp = &next.fp;
+        sum = FP {
+            // The compensation `(t - sum) - y` is NaN if `t` is infinite,
+            // and the next `x - c` may overflow
+            nan: any!(
+                fp.nan,
+                fp.infinite,
+                all!(any!(fp.positive, fp.negative), Possible::ShouldNot)
+            ),
+            infinite: Possible::No,
+            ..*fp
+        };
+        i += 1;
+    }
+
+    let sum = add_outcome(RoundingMode::ToNearest, &sum, last.possibilities());
+    Outcome {
+        cases: cases.extend(sum.cases),
+        ..sum
+    }
+}
+
+/// `sum(args) / n`
+///
+/// # Panics
+///
+/// If `args` is empty or its elements are not of the same type
+pub const fn mean(args: &[FnArgFloat]) -> FnArgFloat {
+    let mean = mean_outcome(args);
+
+    args[0].with_possibilities(mean.fp)
+}
+
+/// `mean`, with the special cases of the sum and of the division
+pub(crate) const fn mean_outcome(args: &[FnArgFloat]) -> Outcome {
+    let sum = sum_outcome(args);
+
+    if args.len() == 1 {
+        return sum;
+    }
+    let fp = &sum.fp;
+    let mut cases = sum.cases;
+
+    // Dividing a subnormal sum
+    let underflow = all!(any!(fp.positive, fp.negative), Possible::ShouldNot);
+    cases = cases.fire("underflow", underflow);
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(round_underflow(
+            FP {
+                pos_zero: fp.zero(),
+                neg_zero: fp.zero(),
+                subnormal: any!(fp.positive, fp.negative),
+                fractional: any!(fp.positive, fp.negative),
+                ..*fp
+            },
+            underflow,
+            RoundingMode::ToNearest,
+        ))
+    }
+}
+
+/// Population variance
+///
+/// Both the two-pass algorithm, `sum((x - mean)²) / n`,
+/// and the one-pass formula, `sum(x²) / n - mean²`, are covered.
+/// The catastrophic cancellation of the latter may give a slightly negative result.
+///
+/// # Panics
+///
+/// If `args` is empty or its elements are not of the same type
+pub const fn variance(args: &[FnArgFloat]) -> FnArgFloat {
+    let (first, rest) = split_first(args);
+
+    // All the arguments are numbers, some are not zero, and some are NaN or infinite
+    let mut all_numbers = Possible::Yes;
+    let mut any_number = Possible::No;
+    let mut any_special = Possible::No;
+    let mut i = 0;
+    while i < args.len() {
+        let fp = args[i].possibilities();
+        all_numbers = all!(all_numbers, any!(fp.positive, fp.negative));
+        any_number = any!(any_number, fp.positive, fp.negative);
+        any_special = any!(any_special, fp.nan, fp.infinite);
+        i += 1;
+    }
+
+    let non_zero = all!(any_number, Possible::ShouldNot);
+    let overflow = non_zero;
+    let cancellation = if !rest.is_empty() {
+        all!(all_numbers, non_zero)
+    } else {
+        Possible::No
+    };
+
+    first.with_possibilities(FP {
+        // inf - inf, and the overflow of the one-pass formula
+        nan: any!(any_special, overflow),
+        pos_zero: all_numbers,
+        neg_zero: all_numbers,
+        subnormal: all_numbers,
+        fractional: all_numbers,
+        large: all_numbers,
+        infinite: overflow,
+        positive: any!(all_numbers, overflow),
+        negative: cancellation,
+    })
+}
+
+/// `sqrt(variance(args))`
+///
+/// # Panics
+///
+/// If `args` is empty or its elements are not of the same type
+pub const fn stddev(args: &[FnArgFloat]) -> FnArgFloat {
+    return_fp!(&sqrt(&variance(args)), |fp| FP {
+        // The variance is never -0.0, the square root of a negative variance is NaN
+        negative: Possible::No,
+        ..*fp
+    })
+}
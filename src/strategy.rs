@@ -0,0 +1,101 @@
+//! Strategies of `proptest`, for property tests of the analysis
+//!
+//! ```
+//! use fn_num_types::FloatPossibilities;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn union_is_commutative(a: FloatPossibilities, b: FloatPossibilities) {
+//!         prop_assert_eq!(a.union(&b), b.union(&a));
+//!     }
+//! }
+//! # union_is_commutative();
+//! ```
+
+use alloc::vec;
+use proptest::prelude::*;
+use proptest::strategy::Union;
+
+use crate::{FloatPossibilities, FnArgFloat, Possible};
+
+impl Arbitrary for Possible {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            Just(Possible::No),
+            Just(Possible::ShouldNot),
+            Just(Possible::Should),
+            Just(Possible::Yes),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for FloatPossibilities {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (
+            any::<Possible>(),
+            any::<Possible>(),
+            any::<Possible>(),
+            any::<Possible>(),
+            any::<Possible>(),
+            any::<Possible>(),
+            any::<Possible>(),
+            any::<Possible>(),
+            any::<Possible>(),
+        )
+            .prop_map(
+                |(
+                    nan,
+                    pos_zero,
+                    neg_zero,
+                    subnormal,
+                    fractional,
+                    large,
+                    infinite,
+                    positive,
+                    negative,
+                )| {
+                    FloatPossibilities {
+                        nan,
+                        pos_zero,
+                        neg_zero,
+                        subnormal,
+                        fractional,
+                        large,
+                        infinite,
+                        positive,
+                        negative,
+                    }
+                },
+            )
+            .boxed()
+    }
+}
+
+impl Arbitrary for FnArgFloat {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        let fp = any::<FloatPossibilities>;
+        #[allow(unused_mut)]
+        let mut types = vec![
+            fp().prop_map(FnArgFloat::F32).boxed(),
+            fp().prop_map(FnArgFloat::F64).boxed(),
+        ];
+        #[cfg(feature = "extra-widths")]
+        types.extend([
+            fp().prop_map(FnArgFloat::F16).boxed(),
+            fp().prop_map(FnArgFloat::BF16).boxed(),
+            fp().prop_map(FnArgFloat::F128).boxed(),
+        ]);
+
+        Union::new(types).boxed()
+    }
+}
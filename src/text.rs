@@ -0,0 +1,165 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{FloatPossibilities, FnArgFloat, Possible};
+
+/// Error returned when parsing the textual representation fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The part of the input that could not be parsed
+    pub token: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid token `{}`", self.token)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+fn error(token: &str) -> ParseError {
+    ParseError {
+        token: token.to_owned(),
+    }
+}
+
+/// `Y`, `S`, `SN` or `N`
+impl fmt::Display for Possible {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Possible::No => "N",
+            Possible::ShouldNot => "SN",
+            Possible::Should => "S",
+            Possible::Yes => "Y",
+        })
+    }
+}
+
+impl FromStr for Possible {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N" => Ok(Possible::No),
+            "SN" => Ok(Possible::ShouldNot),
+            "S" => Ok(Possible::Should),
+            "Y" => Ok(Possible::Yes),
+            _ => Err(error(s)),
+        }
+    }
+}
+
+/// Compact representation, like `nan:N zero:Y sub:Y frac:Y large:Y inf:SN +:Y -:N`
+///
+/// ```
+/// use fn_num_types::{FloatPossibilities, Possible};
+///
+/// let fp: FloatPossibilities = "zero:Y +:Y inf:SN".parse().unwrap();
+///
+/// assert_eq!(fp.nan, Possible::No);
+/// assert_eq!(fp.infinite, Possible::ShouldNot);
+/// assert_eq!(fp.to_string(), "nan:N zero:Y sub:N frac:N large:N inf:SN +:Y -:N");
+///
+/// // The zeros are written separately when they differ
+/// let fp: FloatPossibilities = "+0:N -0:Y -:Y".parse().unwrap();
+/// assert_eq!(fp.to_string(), "nan:N +0:N -0:Y sub:N frac:N large:N inf:N +:N -:Y");
+/// ```
+impl fmt::Display for FloatPossibilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "nan:{} ", self.nan)?;
+
+        if self.pos_zero == self.neg_zero {
+            write!(f, "zero:{} ", self.pos_zero)?;
+        } else {
+            write!(f, "+0:{} -0:{} ", self.pos_zero, self.neg_zero)?;
+        }
+
+        write!(
+            f,
+            "sub:{} frac:{} large:{} inf:{} +:{} -:{}",
+            self.subnormal,
+            self.fractional,
+            self.large,
+            self.infinite,
+            self.positive,
+            self.negative
+        )
+    }
+}
+
+/// Parses whitespace separated `field:level` pairs.
+/// `zero` sets both `+0` and `-0`. The omitted fields are `Possible::No`.
+impl FromStr for FloatPossibilities {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields: [Option<Possible>; 9] = [None; 9];
+
+        for token in s.split_whitespace() {
+            let (name, level) = token.split_once(':').ok_or_else(|| error(token))?;
+
+            let indexes: &[usize] = match name {
+                "nan" => &[0],
+                "zero" => &[1, 2],
+                "+0" => &[1],
+                "-0" => &[2],
+                "sub" => &[3],
+                "frac" => &[4],
+                "large" => &[5],
+                "inf" => &[6],
+                "+" => &[7],
+                "-" => &[8],
+                _ => return Err(error(token)),
+            };
+
+            let level = level.parse().map_err(|_| error(token))?;
+
+            for &index in indexes {
+                if fields[index].is_some() {
+                    return Err(error(token));
+                }
+
+                fields[index] = Some(level);
+            }
+        }
+
+        let [nan, pos_zero, neg_zero, subnormal, fractional, large, infinite, positive, negative] =
+            fields.map(|f| f.unwrap_or(Possible::No));
+
+        Ok(FloatPossibilities {
+            nan,
+            pos_zero,
+            neg_zero,
+            subnormal,
+            fractional,
+            large,
+            infinite,
+            positive,
+            negative,
+        })
+    }
+}
+
+/// The type followed by the possibilities, like `f64 nan:N zero:Y sub:Y frac:Y large:Y inf:N +:Y -:N`
+impl fmt::Display for FnArgFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.type_name(), self.possibilities())
+    }
+}
+
+impl FromStr for FnArgFloat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_start();
+        let (ty, possibilities) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+
+        let fp = possibilities.parse()?;
+
+        FnArgFloat::from_type_name(ty, fp).ok_or_else(|| error(ty))
+    }
+}
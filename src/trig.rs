@@ -0,0 +1,134 @@
+use crate::config::{round_underflow, Cases, Outcome};
+use crate::{FnArgFloat, Possible, RoundingMode, FP};
+
+/// Interval asserted to contain the argument of the trigonometric functions
+///
+/// Infinite arguments are outside of both domains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AngleDomain {
+    /// `[-π, π]`
+    Symmetric,
+    /// `[0, 2π)`
+    Positive,
+}
+
+// No float other than zero is a multiple of π/2,
+// so only a zero argument may give a zero `sin` or `tan`, and `cos` is never zero.
+
+pub const fn sin_in(domain: AngleDomain, lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| match domain {
+        // The `PI` of `f32` is above π: the sign of `sin(PI)` is the opposite
+        AngleDomain::Symmetric => FP {
+            infinite: Possible::No,
+            fractional: any!(fp.positive, fp.negative),
+            large: Possible::No,
+            positive: any!(
+                fp.positive,
+                all!(fp.negative, fp.large, Possible::ShouldNot)
+            ),
+            negative: any!(
+                fp.negative,
+                all!(fp.positive, fp.large, Possible::ShouldNot)
+            ),
+            ..*fp
+        },
+        AngleDomain::Positive => FP {
+            positive: fp.positive,
+            negative: any!(fp.positive, fp.negative),
+            pos_zero: fp.pos_zero,
+            neg_zero: fp.neg_zero,
+            subnormal: fp.subnormal,
+            fractional: any!(fp.positive, fp.negative),
+            large: Possible::No,
+            infinite: Possible::No,
+            nan: fp.nan,
+        },
+    })
+}
+
+pub const fn cos_in(_domain: AngleDomain, lhs: &FnArgFloat) -> FnArgFloat {
+    return_fp!(lhs, |fp| FP {
+        positive: any!(fp.positive, fp.negative),
+        negative: any!(fp.positive, fp.negative),
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        // cos(0.0) == 1.0
+        fractional: any!(fp.positive, fp.negative),
+        large: Possible::No,
+        infinite: Possible::No,
+        nan: fp.nan,
+    })
+}
+
+pub const fn tan_in(_domain: AngleDomain, lhs: &FnArgFloat) -> FnArgFloat {
+    // tan(π/2) is finite
+    return_fp!(lhs, |fp| FP {
+        positive: any!(fp.positive, fp.negative),
+        negative: any!(fp.positive, fp.negative),
+        pos_zero: fp.pos_zero,
+        neg_zero: fp.neg_zero,
+        subnormal: fp.subnormal,
+        fractional: any!(fp.positive, fp.negative),
+        large: any!(fp.positive, fp.negative),
+        infinite: Possible::No,
+        nan: fp.nan,
+    })
+}
+
+/// Angle of the point `(x, y)`, in `[-π, π]`
+pub const fn atan2(y: &FnArgFloat, x: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(y, x, atan2_fp)
+}
+
+/// `atan2`, rounded in the direction `mode`
+pub const fn atan2_with(mode: RoundingMode, y: &FnArgFloat, x: &FnArgFloat) -> FnArgFloat {
+    return_fp2!(y, x, |fp_y, fp_x| atan2_fp_with(mode, fp_y, fp_x))
+}
+
+/// `core::ops::atan2`
+#[inline]
+pub const fn atan2_fp(fp_y: &FP, fp_x: &FP) -> FP {
+    atan2_fp_with(RoundingMode::ToNearest, fp_y, fp_x)
+}
+
+/// `core::ops::atan2`, rounded in the direction `mode`
+pub const fn atan2_fp_with(mode: RoundingMode, fp_y: &FP, fp_x: &FP) -> FP {
+    atan2_outcome(mode, fp_y, fp_x).fp
+}
+
+/// `core::ops::atan2`, with the special cases it handled
+pub(crate) const fn atan2_outcome(mode: RoundingMode, fp_y: &FP, fp_x: &FP) -> Outcome {
+    let mut cases = Cases::NONE;
+    let non_nan_y = any!(fp_y.positive, fp_y.negative);
+    let non_nan_x = any!(fp_x.positive, fp_x.negative);
+
+    // A tiny `y` over a huge `x` may underflow
+    let underflow = all!(non_nan_y, non_nan_x, Possible::ShouldNot);
+    cases = cases.fire("underflow", underflow);
+
+    // On the positive side of the x axis, or toward it at infinity
+    let on_axis = any!(
+        all!(fp_y.zero(), fp_x.positive),
+        all!(non_nan_y, fp_x.infinite, fp_x.positive)
+    );
+    cases = cases.fire("positive x axis", on_axis);
+
+    let res = FP {
+        nan: any!(fp_y.nan, fp_x.nan),
+        pos_zero: on_axis,
+        neg_zero: on_axis,
+        subnormal: all!(non_nan_y, non_nan_x),
+        fractional: all!(non_nan_y, non_nan_x),
+        large: all!(non_nan_y, non_nan_x),
+        infinite: Possible::No,
+        // The sign follows `y`
+        positive: all!(fp_y.positive, non_nan_x),
+        negative: all!(fp_y.negative, non_nan_x),
+    };
+
+    Outcome {
+        cases,
+        ..Outcome::without_overflow(round_underflow(res, underflow, mode))
+    }
+}
@@ -0,0 +1,87 @@
+//! Conversions with the types of `typed_floats`
+//!
+//! ```
+//! use fn_num_types::core::ops;
+//! use fn_num_types::{FloatPossibilities, FnArgFloat};
+//! use typed_floats::{NonNaN, StrictlyPositiveFinite};
+//!
+//! let x = FnArgFloat::F64(FloatPossibilities::of::<StrictlyPositiveFinite>());
+//! let y = ops::sqrt(&x);
+//!
+//! assert!(y.possibilities().is_representable_by::<StrictlyPositiveFinite>());
+//! assert!(ops::ln(&x).possibilities().is_representable_by::<NonNaN>());
+//! ```
+
+use crate::{FloatPossibilities, FnArgFloat, FP};
+
+/// A type of `typed_floats`, with the possibilities of its values
+pub trait TypedFloatsType {
+    const POSSIBILITIES: FloatPossibilities;
+}
+
+impl FloatPossibilities {
+    /// The possibilities of the values of the type `T` of `typed_floats`
+    pub const fn of<T: TypedFloatsType>() -> Self {
+        T::POSSIBILITIES
+    }
+
+    /// Returns true if all the possible values are values of the type `T` of `typed_floats`
+    pub fn is_representable_by<T: TypedFloatsType>(&self) -> bool {
+        self.is_subset_of(&T::POSSIBILITIES)
+    }
+}
+
+macro_rules! typed_floats {
+    ($($ty:ident => $possibilities:ident),* $(,)?) => {
+        $(
+            impl TypedFloatsType for typed_floats::$ty<f32> {
+                const POSSIBILITIES: FloatPossibilities = FP::$possibilities();
+            }
+
+            impl TypedFloatsType for typed_floats::$ty<f64> {
+                const POSSIBILITIES: FloatPossibilities = FP::$possibilities();
+            }
+
+            /// The possibilities of that value only
+            impl From<typed_floats::$ty<f32>> for FloatPossibilities {
+                fn from(value: typed_floats::$ty<f32>) -> Self {
+                    FP::from(value.get())
+                }
+            }
+
+            /// The possibilities of that value only
+            impl From<typed_floats::$ty<f64>> for FloatPossibilities {
+                fn from(value: typed_floats::$ty<f64>) -> Self {
+                    FP::from(value.get())
+                }
+            }
+
+            impl From<typed_floats::$ty<f32>> for FnArgFloat {
+                fn from(value: typed_floats::$ty<f32>) -> Self {
+                    FnArgFloat::F32(FP::from(value))
+                }
+            }
+
+            impl From<typed_floats::$ty<f64>> for FnArgFloat {
+                fn from(value: typed_floats::$ty<f64>) -> Self {
+                    FnArgFloat::F64(FP::from(value))
+                }
+            }
+        )*
+    };
+}
+
+typed_floats!(
+    NonNaN => non_nan,
+    NonNaNFinite => finite,
+    NonZeroNonNaN => non_zero_non_nan,
+    NonZeroNonNaNFinite => non_zero_non_nan_finite,
+    Positive => positive,
+    Negative => negative,
+    PositiveFinite => positive_finite,
+    NegativeFinite => negative_finite,
+    StrictlyPositive => strictly_positive,
+    StrictlyNegative => strictly_negative,
+    StrictlyPositiveFinite => strictly_positive_finite,
+    StrictlyNegativeFinite => strictly_negative_finite,
+);
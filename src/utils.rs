@@ -1,3 +1,12 @@
+use core::cmp::Ordering;
+use core::num::FpCategory;
+#[cfg(feature = "std")]
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "std")]
+use crate::interval::Interval;
+use crate::kind::{FloatKind, F32, F64};
+
 /// Is that value possible to reach?
 ///
 /// E.g.: If `x` is a floating point strictly positive finite number:
@@ -5,7 +14,16 @@
 /// - `x + 1.0 positive ?` is `Possible::Yes`
 /// - `x * x == 0.0 ?` is `Possible::ShouldNot` because `f64::MIN_POSITIVE * f64::MIN_POSITIVE == 0.0`
 /// - `sin(x) == 0.0 ?` is `Possible::Should` because mathematically it should be possible, but because of the rounding error, it may not happen
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[repr(u8)]
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, ord, frozen, from_py_object)
+)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Possible {
     No,
     // Theorically no, but may happen because of the rounding error
@@ -17,6 +35,14 @@ pub enum Possible {
 }
 
 impl Possible {
+    /// The levels, from the weakest to the strongest
+    pub const ALL: [Possible; 4] = [
+        Possible::No,
+        Possible::ShouldNot,
+        Possible::Should,
+        Possible::Yes,
+    ];
+
     /// If something is possible for two reasons,
     /// we return the strongest one :
     ///
@@ -31,8 +57,61 @@ impl Possible {
     /// assert_eq!(Possible::ShouldNot, Possible::No | Possible::ShouldNot);
     /// assert_eq!(Possible::No, Possible::No | Possible::No);
     /// ```
-    pub fn any(a: Self, b: Self) -> Self {
-        std::cmp::max(a, b)
+    pub const fn any(a: Self, b: Self) -> Self {
+        if a as u8 >= b as u8 {
+            a
+        } else {
+            b
+        }
+    }
+
+    /// If something needs two conditions, the weakest one:
+    /// the dual of `any`, like `&`
+    ///
+    /// ```
+    /// use fn_num_types::Possible;
+    ///
+    /// assert_eq!(Possible::ShouldNot, Possible::all(Possible::Yes, Possible::ShouldNot));
+    /// assert_eq!(Possible::No, Possible::all(Possible::No, Possible::Should));
+    /// ```
+    pub const fn all(a: Self, b: Self) -> Self {
+        if a as u8 <= b as u8 {
+            a
+        } else {
+            b
+        }
+    }
+
+    /// The sound two-valued answer to "may it happen at all?":
+    /// the rounding errors may make anything that is not `No` happen
+    ///
+    /// ```
+    /// use fn_num_types::Possible;
+    ///
+    /// assert_eq!(Possible::ShouldNot.to_strict(), Possible::Yes);
+    /// assert_eq!(Possible::No.to_strict(), Possible::No);
+    /// ```
+    pub const fn to_strict(self) -> Self {
+        match self {
+            Possible::No => Possible::No,
+            _ => Possible::Yes,
+        }
+    }
+
+    /// The two-valued answer of the exact arithmetic, ignoring the rounding errors:
+    /// `Should` becomes `Yes` and `ShouldNot` becomes `No`
+    ///
+    /// ```
+    /// use fn_num_types::Possible;
+    ///
+    /// assert_eq!(Possible::ShouldNot.to_relaxed(), Possible::No);
+    /// assert_eq!(Possible::Should.to_relaxed(), Possible::Yes);
+    /// ```
+    pub const fn to_relaxed(self) -> Self {
+        match self {
+            Possible::No | Possible::ShouldNot => Possible::No,
+            Possible::Should | Possible::Yes => Possible::Yes,
+        }
     }
 }
 
@@ -40,7 +119,7 @@ impl core::ops::BitOr for Possible {
     type Output = Self;
 
     fn bitor(self, rhs: Self) -> Self {
-        std::cmp::max(self, rhs)
+        Possible::any(self, rhs)
     }
 }
 
@@ -48,22 +127,121 @@ impl core::ops::BitAnd for Possible {
     type Output = Self;
 
     fn bitand(self, rhs: Self) -> Self {
-        std::cmp::min(self, rhs)
+        Possible::all(self, rhs)
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
-pub struct FloatPossibilities {
-    pub nan: Possible,
-    pub zero: Possible,
-    pub infinite: Possible,
-    pub positive: Possible,
-    pub negative: Possible,
+/// The possibility of the opposite: what is certain becomes impossible,
+/// and the rounding errors are mirrored
+///
+/// ```
+/// use fn_num_types::Possible;
+///
+/// assert_eq!(!Possible::Yes, Possible::No);
+/// assert_eq!(!Possible::Should, Possible::ShouldNot);
+/// ```
+impl core::ops::Not for Possible {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        match self {
+            Possible::No => Possible::Yes,
+            Possible::ShouldNot => Possible::Should,
+            Possible::Should => Possible::ShouldNot,
+            Possible::Yes => Possible::No,
+        }
+    }
+}
+
+/// The possible values, a flag per kind of value
+///
+/// The flags are `Possible` ones, the domain of the models, or of another
+/// `PossibilityLattice`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct FloatPossibilities<L = Possible> {
+    pub nan: L,
+    /// `+0.0`, also limited by `positive`
+    pub pos_zero: L,
+    /// `-0.0`, also limited by `negative`
+    pub neg_zero: L,
+    /// Finite values below the smallest normal one, also limited by the signs
+    pub subnormal: L,
+    /// Finite values that are not integers, `No` when all of them are
+    pub fractional: L,
+    /// Finite values greater than one in magnitude, `No` when all of them are in `[-1, 1]`
+    pub large: L,
+    pub infinite: L,
+    pub positive: L,
+    pub negative: L,
 }
 
 pub type FP = FloatPossibilities;
 
+/// A field of `FloatPossibilities`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Field {
+    Nan,
+    PosZero,
+    NegZero,
+    Subnormal,
+    Fractional,
+    Large,
+    Infinite,
+    Positive,
+    Negative,
+}
+
+impl Field {
+    pub const ALL: [Field; 9] = [
+        Field::Nan,
+        Field::PosZero,
+        Field::NegZero,
+        Field::Subnormal,
+        Field::Fractional,
+        Field::Large,
+        Field::Infinite,
+        Field::Positive,
+        Field::Negative,
+    ];
+}
+
 impl FloatPossibilities {
+    /// Returns the value of `field`
+    pub const fn get(&self, field: Field) -> Possible {
+        match field {
+            Field::Nan => self.nan,
+            Field::PosZero => self.pos_zero,
+            Field::NegZero => self.neg_zero,
+            Field::Subnormal => self.subnormal,
+            Field::Fractional => self.fractional,
+            Field::Large => self.large,
+            Field::Infinite => self.infinite,
+            Field::Positive => self.positive,
+            Field::Negative => self.negative,
+        }
+    }
+
+    /// Either zero, whatever its sign
+    pub const fn zero(&self) -> Possible {
+        Possible::any(self.pos_zero, self.neg_zero)
+    }
+
+    /// Returns true if the finite values are all integers, like the results of `round`
+    ///
+    /// Casting them to an integer type, with `as`, truncates nothing.
+    pub fn is_integral(&self) -> bool {
+        self.fractional == Possible::No
+    }
+
+    /// Returns true if the values other than NaN are in `[-1, 1]`, like the results of `sin`
+    pub fn is_bounded_by_one(&self) -> bool {
+        self.large == Possible::No && self.infinite == Possible::No
+    }
+
     /// Returns true if the value is accepted
     ///
     /// ```
@@ -71,7 +249,11 @@ impl FloatPossibilities {
     ///
     /// let possibilities = FloatPossibilities {
     ///     nan: Possible::Yes,
-    ///     zero: Possible::Yes,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
     ///     infinite: Possible::Yes,
     ///     positive: Possible::Yes,
     ///     negative: Possible::Yes,
@@ -84,45 +266,996 @@ impl FloatPossibilities {
     /// assert!(possibilities.accept(-0.0));
     /// assert!(possibilities.accept(1.0));
     /// assert!(possibilities.accept(-1.0));
+    ///
+    /// // Like `x.fract()` for a negative `x`
+    /// let negative_zero = FloatPossibilities {
+    ///     pos_zero: Possible::No,
+    ///     ..possibilities
+    /// };
+    ///
+    /// assert!(negative_zero.accept(-0.0));
+    /// assert!(!negative_zero.accept(0.0));
+    ///
+    /// // Like `x.round()`
+    /// let integral = FloatPossibilities {
+    ///     fractional: Possible::No,
+    ///     ..possibilities
+    /// };
+    ///
+    /// assert!(integral.accept(2.0));
+    /// assert!(!integral.accept(2.5));
+    ///
+    /// // Like `x.sin()`
+    /// let bounded = FloatPossibilities {
+    ///     large: Possible::No,
+    ///     ..possibilities
+    /// };
+    ///
+    /// assert!(bounded.accept(-1.0));
+    /// assert!(!bounded.accept(1.5));
+    /// ```
+    pub const fn accept(&self, value: f64) -> bool {
+        let (category, sign_negative, fractional, large) = classify(value);
+
+        self.accept_category(category, sign_negative)
+            && self.accept_fraction(fractional)
+            && self.accept_magnitude(large)
+    }
+
+    /// Returns true if all the values are accepted
+    pub fn accept_all<I: IntoIterator<Item = f64>>(&self, values: I) -> bool {
+        values.into_iter().all(|value| self.accept(value))
+    }
+
+    /// Returns true if all the `f64` values of the closed range are accepted,
+    /// true for an empty range
+    ///
+    /// Like `<=`, a range containing zero contains both zeros.
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let bounded = FloatPossibilities::finite().with_large(Possible::No);
+    ///
+    /// assert!(bounded.accept_range(-1.0..=1.0));
+    /// assert!(!bounded.accept_range(0.5..=1.5));
+    /// assert!(!bounded.with_neg_zero(Possible::No).accept_range(-1.0..=0.0));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn accept_range(&self, range: RangeInclusive<f64>) -> bool {
+        let (start, end) = range.into_inner();
+        if start.is_nan() || end.is_nan() || start > end {
+            return true;
+        }
+
+        // The flags of the values of the range
+        let required = Interval::new(start, end)
+            .possibilities(&FnArgFloat::F64(*self))
+            .with_nan(Possible::No);
+
+        Field::ALL
+            .into_iter()
+            .all(|field| required.get(field) == Possible::No || self.get(field) != Possible::No)
+    }
+
+    /// Returns true if the `f32` value is accepted, with the subnormals of `f32`
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let normal = FloatPossibilities::any().with_subnormal(Possible::No);
+    ///
+    /// // Normal once widened to `f64`
+    /// assert!(normal.accept(f64::from(1.0e-40f32)));
+    /// assert!(!normal.accept_f32(1.0e-40));
+    /// ```
+    pub fn accept_f32(&self, value: f32) -> bool {
+        F32::accept(self, value)
+    }
+
+    /// Returns true if a finite value with (or without) a fractional part is accepted
+    ///
+    /// The categories don't tell the normal values with a fractional part apart,
+    /// so `accept_category` accepts them.
+    pub const fn accept_fraction(&self, fractional: bool) -> bool {
+        !fractional || !matches!(self.fractional, Possible::No)
+    }
+
+    /// Returns true if a finite value greater (or not) than one in magnitude is accepted
+    ///
+    /// Like `accept_fraction`, the categories of the normal values don't tell.
+    pub const fn accept_magnitude(&self, large: bool) -> bool {
+        !large || !matches!(self.large, Possible::No)
+    }
+
+    /// Returns true if a value of this category and sign is accepted
+    ///
+    /// Works for any float type, like `f16`, `f128` or `half::bf16`,
+    /// whose values may not be exactly converted to `f64`.
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    /// use std::num::FpCategory;
+    ///
+    /// let finite = FloatPossibilities {
+    ///     nan: Possible::No,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::No,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::Yes,
+    /// };
+    ///
+    /// // Like `1e4000f128`, which is infinite once converted to `f64`
+    /// assert!(finite.accept_category(FpCategory::Normal, false));
+    /// assert!(!finite.accept_category(FpCategory::Infinite, false));
     /// ```
-    pub fn accept(&self, value: f64) -> bool {
-        if value.is_nan() {
-            return self.nan != Possible::No;
+    pub const fn accept_category(&self, category: FpCategory, sign_negative: bool) -> bool {
+        if matches!(category, FpCategory::Nan) {
+            return !matches!(self.nan, Possible::No);
         }
 
-        if value.is_infinite() && self.infinite == Possible::No {
+        if matches!(category, FpCategory::Infinite) && matches!(self.infinite, Possible::No) {
             return false;
         }
 
-        if value == 0.0 && self.zero == Possible::No {
+        // The subnormals are below one
+        if matches!(category, FpCategory::Subnormal)
+            && (matches!(self.subnormal, Possible::No) || matches!(self.fractional, Possible::No))
+        {
             return false;
         }
 
-        if value.is_sign_positive() && self.positive == Possible::No {
+        if matches!(category, FpCategory::Zero) {
+            let zero = if sign_negative {
+                self.neg_zero
+            } else {
+                self.pos_zero
+            };
+
+            if matches!(zero, Possible::No) {
+                return false;
+            }
+        }
+
+        if !sign_negative && matches!(self.positive, Possible::No) {
             return false;
         }
 
-        if value.is_sign_negative() && self.negative == Possible::No {
+        if sign_negative && matches!(self.negative, Possible::No) {
             return false;
         }
 
         true
     }
 
-    pub fn union(&self, rhs: &Self) -> Self {
+    pub const fn union(&self, rhs: &Self) -> Self {
+        FP {
+            nan: Possible::any(self.nan, rhs.nan),
+            pos_zero: Possible::any(self.pos_zero, rhs.pos_zero),
+            neg_zero: Possible::any(self.neg_zero, rhs.neg_zero),
+            subnormal: Possible::any(self.subnormal, rhs.subnormal),
+            fractional: Possible::any(self.fractional, rhs.fractional),
+            large: Possible::any(self.large, rhs.large),
+            infinite: Possible::any(self.infinite, rhs.infinite),
+            positive: Possible::any(self.positive, rhs.positive),
+            negative: Possible::any(self.negative, rhs.negative),
+        }
+    }
+
+    /// The values possible according to both `self` and `rhs`,
+    /// like two independent sources of knowledge about the same value
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let non_nan = FloatPossibilities {
+    ///     nan: Possible::No,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::Yes,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::Yes,
+    /// };
+    /// let positive = FloatPossibilities {
+    ///     nan: Possible::Yes,
+    ///     pos_zero: Possible::Should,
+    ///     neg_zero: Possible::Should,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::Yes,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::No,
+    /// };
+    ///
+    /// let both = non_nan.intersection(&positive);
+    /// assert_eq!(both.nan, Possible::No);
+    /// assert_eq!(both.zero(), Possible::Should);
+    /// assert_eq!(both.negative, Possible::No);
+    /// ```
+    pub const fn intersection(&self, rhs: &Self) -> Self {
+        FP {
+            nan: Possible::all(self.nan, rhs.nan),
+            pos_zero: Possible::all(self.pos_zero, rhs.pos_zero),
+            neg_zero: Possible::all(self.neg_zero, rhs.neg_zero),
+            subnormal: Possible::all(self.subnormal, rhs.subnormal),
+            fractional: Possible::all(self.fractional, rhs.fractional),
+            large: Possible::all(self.large, rhs.large),
+            infinite: Possible::all(self.infinite, rhs.infinite),
+            positive: Possible::all(self.positive, rhs.positive),
+            negative: Possible::all(self.negative, rhs.negative),
+        }
+    }
+
+    /// Returns true if each field is at most the one of `other`,
+    /// like a computed result contained in a declared contract
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let result = FloatPossibilities {
+    ///     nan: Possible::No,
+    ///     pos_zero: Possible::ShouldNot,
+    ///     neg_zero: Possible::ShouldNot,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::No,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::No,
+    /// };
+    /// let contract = FloatPossibilities {
+    ///     nan: Possible::No,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::Yes,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::No,
+    /// };
+    ///
+    /// assert!(result.is_subset_of(&contract));
+    /// assert!(!contract.is_subset_of(&result));
+    /// assert!(result < contract);
+    /// ```
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        Field::ALL
+            .into_iter()
+            .all(|field| self.get(field) <= other.get(field))
+    }
+
+    /// Returns true if `self` accepts all the values of `tighter`, like a
+    /// declared contract covering the result computed by an analysis
+    ///
+    /// The levels above `No` are all possible: a `ShouldNot` of `self` covers
+    /// a `Yes` of `tighter`, but a `No` of `self` does not cover a `ShouldNot`,
+    /// as unlikely as it is. `is_subset_of` compares the levels too.
+    ///
+    /// ```
+    /// use fn_num_types::{Field, FloatPossibilities, Possible};
+    ///
+    /// let contract = FloatPossibilities::finite().with(Field::Infinite, Possible::ShouldNot);
+    /// let overflowing = FloatPossibilities::finite().with(Field::Infinite, Possible::Yes);
+    ///
+    /// assert!(contract.is_sound_approximation_of(&overflowing));
+    /// assert!(!overflowing.is_subset_of(&contract));
+    /// assert!(!FloatPossibilities::finite().is_sound_approximation_of(&contract));
+    /// ```
+    pub fn is_sound_approximation_of(&self, tighter: &Self) -> bool {
+        Field::ALL
+            .into_iter()
+            .all(|field| tighter.get(field) == Possible::No || self.get(field) != Possible::No)
+    }
+
+    /// Returns true if at least one value is accepted
+    ///
+    /// Zeros and infinities have a sign: they are not possible without one.
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let unsigned_infinity = FloatPossibilities {
+    ///     nan: Possible::No,
+    ///     pos_zero: Possible::No,
+    ///     neg_zero: Possible::No,
+    ///     subnormal: Possible::No,
+    ///     fractional: Possible::No,
+    ///     large: Possible::No,
+    ///     infinite: Possible::Yes,
+    ///     positive: Possible::No,
+    ///     negative: Possible::No,
+    /// };
+    ///
+    /// assert!(!unsigned_infinity.is_satisfiable());
+    /// ```
+    pub const fn is_satisfiable(&self) -> bool {
+        !matches!(
+            Possible::any(self.nan, Possible::any(self.positive, self.negative)),
+            Possible::No
+        )
+    }
+
+    /// The same accepted values, without the flags that can't be reached:
+    /// zeros and infinities are at most as possible as their signs,
+    /// and the subnormals as the fractional values
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let nan = FloatPossibilities {
+    ///     nan: Possible::Yes,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::No,
+    ///     fractional: Possible::No,
+    ///     large: Possible::No,
+    ///     infinite: Possible::Should,
+    ///     positive: Possible::No,
+    ///     negative: Possible::No,
+    /// };
+    ///
+    /// let canonical = nan.canonicalize();
+    /// assert_eq!(canonical.zero(), Possible::No);
+    /// assert_eq!(canonical.infinite, Possible::No);
+    /// assert_eq!(canonical.nan, Possible::Yes);
+    /// ```
+    pub fn canonicalize(&self) -> Self {
+        let non_nan = self.positive | self.negative;
+
+        FP {
+            pos_zero: self.pos_zero & self.positive,
+            neg_zero: self.neg_zero & self.negative,
+            subnormal: self.subnormal & self.fractional & non_nan,
+            fractional: self.fractional & non_nan,
+            large: self.large & non_nan,
+            infinite: self.infinite & non_nan,
+            ..*self
+        }
+    }
+
+    /// The possibility of each class of values: NaN, `+0`, `-0`, positive and negative subnormals,
+    /// `+inf`, `-inf`, then the positive and negative normal values, each split
+    /// between integers and fractional values, in `[-1, 1]` or not
+    fn classes(&self) -> [Possible; 15] {
+        let subnormal = self.subnormal & self.fractional;
+        let large = |sign: Possible| sign & self.large;
+        let fractional = |sign: Possible| sign & self.fractional;
+
+        [
+            self.nan,
+            self.pos_zero & self.positive,
+            self.neg_zero & self.negative,
+            subnormal & self.positive,
+            subnormal & self.negative,
+            self.infinite & self.positive,
+            self.infinite & self.negative,
+            // `1.0` and `-1.0`
+            self.positive,
+            self.negative,
+            large(self.positive),
+            large(self.negative),
+            fractional(self.positive),
+            fractional(self.negative),
+            large(fractional(self.positive)),
+            large(fractional(self.negative)),
+        ]
+    }
+
+    /// The smallest possibilities containing those classes
+    fn from_classes(classes: [Possible; 15]) -> Self {
+        let [nan, pos_zero, neg_zero, pos_sub, neg_sub, pos_inf, neg_inf, pos_one, neg_one, pos_large_int, neg_large_int, pos_frac, neg_frac, pos_large_frac, neg_large_frac] =
+            classes;
+
         FP {
-            nan: self.nan | rhs.nan,
-            zero: self.zero | rhs.zero,
-            infinite: self.infinite | rhs.infinite,
-            positive: self.positive | rhs.positive,
-            negative: self.negative | rhs.negative,
+            nan,
+            pos_zero,
+            neg_zero,
+            subnormal: pos_sub | neg_sub,
+            fractional: pos_sub | neg_sub | pos_frac | neg_frac | pos_large_frac | neg_large_frac,
+            large: pos_large_int | neg_large_int | pos_large_frac | neg_large_frac,
+            infinite: pos_inf | neg_inf,
+            positive: pos_zero
+                | pos_sub
+                | pos_inf
+                | pos_one
+                | pos_large_int
+                | pos_frac
+                | pos_large_frac,
+            negative: neg_zero
+                | neg_sub
+                | neg_inf
+                | neg_one
+                | neg_large_int
+                | neg_frac
+                | neg_large_frac,
+        }
+    }
+
+    /// The values not accepted by `self`, like the `else` branch of a guard
+    ///
+    /// Values that are certainly possible (`Yes`) are not in the complement,
+    /// impossible ones (`No`) certainly are, and `Should` and `ShouldNot` are swapped,
+    /// as the result is as uncertain as `self`.
+    /// As zeros, infinities and finite values share the sign flags, the result may contain more.
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let non_nan = FloatPossibilities {
+    ///     nan: Possible::No,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::Yes,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::Yes,
+    /// };
+    ///
+    /// let nan = non_nan.complement();
+    /// assert_eq!(nan.nan, Possible::Yes);
+    /// assert_eq!(nan.positive, Possible::No);
+    /// ```
+    pub fn complement(&self) -> Self {
+        Self::from_classes(self.classes().map(|class| !class))
+    }
+
+    /// Each field collapsed with `Possible::to_strict`, for the verifiers
+    pub fn to_strict(&self) -> Self {
+        self.map(Possible::to_strict)
+    }
+
+    /// Each field collapsed with `Possible::to_relaxed`, ignoring the rounding errors
+    pub fn to_relaxed(&self) -> Self {
+        self.map(Possible::to_relaxed)
+    }
+
+    /// Two bits per field, in the order of `Field::ALL`, from the lowest bits
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let fp = FloatPossibilities::positive().with_infinite(Possible::ShouldNot);
+    ///
+    /// assert!(fp.to_bits() < 1 << 18);
+    /// assert_eq!(FloatPossibilities::from_bits(fp.to_bits()), Some(fp));
+    /// assert_eq!(FloatPossibilities::from_bits(1 << 18), None);
+    /// ```
+    pub fn to_bits(&self) -> u32 {
+        Field::ALL
+            .into_iter()
+            .enumerate()
+            .fold(0, |bits, (i, field)| {
+                bits | (self.get(field) as u32) << (2 * i)
+            })
+    }
+
+    /// The possibilities encoded by `to_bits`, `None` if a higher bit is set
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        if bits >> (2 * Field::ALL.len()) != 0 {
+            return None;
+        }
+
+        let possible = |i: usize| match (bits >> (2 * i)) & 0b11 {
+            0 => Possible::No,
+            1 => Possible::ShouldNot,
+            2 => Possible::Should,
+            _ => Possible::Yes,
+        };
+
+        Some(
+            Field::ALL
+                .into_iter()
+                .enumerate()
+                .fold(FP::any(), |fp, (i, field)| fp.with(field, possible(i))),
+        )
+    }
+
+    /// The values accepted by `self` but not by `other`, with the levels of `complement`
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let any = FloatPossibilities {
+    ///     nan: Possible::Yes,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::Yes,
+    ///     positive: Possible::Yes,
+    ///     negative: Possible::Yes,
+    /// };
+    /// let negative = FloatPossibilities {
+    ///     nan: Possible::No,
+    ///     pos_zero: Possible::Yes,
+    ///     neg_zero: Possible::Yes,
+    ///     subnormal: Possible::Yes,
+    ///     fractional: Possible::Yes,
+    ///     large: Possible::Yes,
+    ///     infinite: Possible::Yes,
+    ///     positive: Possible::No,
+    ///     negative: Possible::Yes,
+    /// };
+    ///
+    /// let rest = any.difference(&negative);
+    /// assert_eq!(rest.negative, Possible::No);
+    /// assert_eq!(rest.nan, Possible::Yes);
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut classes = self.classes();
+
+        for (class, other) in classes.iter_mut().zip(other.classes()) {
+            *class = *class & !other;
         }
+
+        Self::from_classes(classes)
     }
 }
 
+/// The order of the lattice: `a <= b` if `a.is_subset_of(&b)`,
+/// and no order if neither is a subset of the other
+///
+/// ```
+/// use fn_num_types::{FloatPossibilities, Possible};
+///
+/// let nan = FloatPossibilities {
+///     nan: Possible::Yes,
+///     pos_zero: Possible::No,
+///     neg_zero: Possible::No,
+///     subnormal: Possible::No,
+///     fractional: Possible::No,
+///     large: Possible::No,
+///     infinite: Possible::No,
+///     positive: Possible::No,
+///     negative: Possible::No,
+/// };
+/// let zero = FloatPossibilities {
+///     nan: Possible::No,
+///     pos_zero: Possible::Yes,
+///     neg_zero: Possible::Yes,
+///     subnormal: Possible::Yes,
+///     fractional: Possible::Yes,
+///     large: Possible::Yes,
+///     infinite: Possible::No,
+///     positive: Possible::Yes,
+///     negative: Possible::No,
+/// };
+///
+/// assert_eq!(nan.partial_cmp(&zero), None);
+/// ```
+impl PartialOrd for FloatPossibilities {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.is_subset_of(other), other.is_subset_of(self)) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+impl FloatPossibilities {
+    /// Exactly the flags of a value of this category and sign
+    pub(crate) const fn of_value(
+        category: FpCategory,
+        sign_negative: bool,
+        fractional: bool,
+        large: bool,
+    ) -> Self {
+        const fn possible(cond: bool) -> Possible {
+            if cond {
+                Possible::Yes
+            } else {
+                Possible::No
+            }
+        }
+        let nan = matches!(category, FpCategory::Nan);
+        let zero = matches!(category, FpCategory::Zero);
+        let subnormal = matches!(category, FpCategory::Subnormal);
+
+        FP {
+            nan: possible(nan),
+            pos_zero: possible(zero && !sign_negative),
+            neg_zero: possible(zero && sign_negative),
+            subnormal: possible(subnormal),
+            // The subnormals are below one
+            fractional: possible(fractional || subnormal),
+            large: possible(large),
+            infinite: possible(matches!(category, FpCategory::Infinite)),
+            positive: possible(!nan && !sign_negative),
+            negative: possible(!nan && sign_negative),
+        }
+    }
+
+    /// `FloatPossibilities::from(value)`, in a `const`
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// const HALF: FloatPossibilities = FloatPossibilities::from_f64(0.5);
+    ///
+    /// assert_eq!(HALF, FloatPossibilities::from(0.5));
+    /// assert_eq!(HALF.fractional, Possible::Yes);
+    /// ```
+    pub const fn from_f64(value: f64) -> Self {
+        let (category, sign_negative, fractional, large) = classify(value);
+
+        FP::of_value(category, sign_negative, fractional, large)
+    }
+
+    /// `FloatPossibilities::from(value)`, in a `const`, with the subnormals of `f32`
+    pub const fn from_f32(value: f32) -> Self {
+        // `f32::to_bits` is not const before Rust 1.83
+        #[allow(unknown_lints, unnecessary_transmutes)]
+        let bits = unsafe { core::mem::transmute::<f32, u32>(value) };
+        let (category, sign_negative, fractional, large) = classify_bits(bits as u64, 8, 23);
+
+        FP::of_value(category, sign_negative, fractional, large)
+    }
+}
+
+/// The possibilities of that constant only
+///
+/// ```
+/// use fn_num_types::{FloatPossibilities, Possible};
+///
+/// let fp = FloatPossibilities::from(-0.0);
+///
+/// assert_eq!(fp.neg_zero, Possible::Yes);
+/// assert_eq!(fp.pos_zero, Possible::No);
+/// assert_eq!(fp.positive, Possible::No);
+/// assert!(fp.accept(-0.0));
+/// assert!(!fp.accept(0.0));
+/// assert!(!fp.accept(-0.5));
+/// ```
+impl From<f64> for FloatPossibilities {
+    fn from(value: f64) -> Self {
+        FP::from_f64(value)
+    }
+}
+
+/// The possibilities of that constant only, with the subnormals of `f32`
+impl From<f32> for FloatPossibilities {
+    fn from(value: f32) -> Self {
+        FP::from_f32(value)
+    }
+}
+
+/// Named sets of possibilities, like the types of `typed_floats`
+///
+/// The signed types accept their infinity unless `finite`,
+/// and their zero unless `strictly`.
+///
+/// ```
+/// use fn_num_types::FloatPossibilities;
+///
+/// let x = FloatPossibilities::strictly_positive_finite();
+///
+/// assert!(x.accept(f64::MIN_POSITIVE));
+/// assert!(!x.accept(0.0));
+/// assert!(!x.accept(f64::INFINITY));
+/// assert!(x.is_subset_of(&FloatPossibilities::positive()));
+/// ```
+impl FloatPossibilities {
+    /// Any value, NaN included
+    pub const fn any() -> Self {
+        FP {
+            nan: Possible::Yes,
+            pos_zero: Possible::Yes,
+            neg_zero: Possible::Yes,
+            subnormal: Possible::Yes,
+            fractional: Possible::Yes,
+            large: Possible::Yes,
+            infinite: Possible::Yes,
+            positive: Possible::Yes,
+            negative: Possible::Yes,
+        }
+    }
+
+    /// Only NaN
+    pub const fn nan() -> Self {
+        FP {
+            nan: Possible::Yes,
+            pos_zero: Possible::No,
+            neg_zero: Possible::No,
+            subnormal: Possible::No,
+            fractional: Possible::No,
+            large: Possible::No,
+            infinite: Possible::No,
+            positive: Possible::No,
+            negative: Possible::No,
+        }
+    }
+
+    pub const fn non_nan() -> Self {
+        FP {
+            nan: Possible::No,
+            ..FP::any()
+        }
+    }
+
+    pub const fn finite() -> Self {
+        FP {
+            infinite: Possible::No,
+            ..FP::non_nan()
+        }
+    }
+
+    pub const fn non_zero_non_nan() -> Self {
+        FP {
+            pos_zero: Possible::No,
+            neg_zero: Possible::No,
+            ..FP::non_nan()
+        }
+    }
+
+    pub const fn non_zero_non_nan_finite() -> Self {
+        FP {
+            infinite: Possible::No,
+            ..FP::non_zero_non_nan()
+        }
+    }
+
+    /// `+0.0` and above, `+inf` included
+    pub const fn positive() -> Self {
+        FP {
+            neg_zero: Possible::No,
+            negative: Possible::No,
+            ..FP::non_nan()
+        }
+    }
+
+    /// `-0.0` and below, `-inf` included
+    pub const fn negative() -> Self {
+        FP {
+            pos_zero: Possible::No,
+            positive: Possible::No,
+            ..FP::non_nan()
+        }
+    }
+
+    pub const fn positive_finite() -> Self {
+        FP {
+            infinite: Possible::No,
+            ..FP::positive()
+        }
+    }
+
+    pub const fn negative_finite() -> Self {
+        FP {
+            infinite: Possible::No,
+            ..FP::negative()
+        }
+    }
+
+    pub const fn strictly_positive() -> Self {
+        FP {
+            pos_zero: Possible::No,
+            ..FP::positive()
+        }
+    }
+
+    pub const fn strictly_negative() -> Self {
+        FP {
+            neg_zero: Possible::No,
+            ..FP::negative()
+        }
+    }
+
+    pub const fn strictly_positive_finite() -> Self {
+        FP {
+            infinite: Possible::No,
+            ..FP::strictly_positive()
+        }
+    }
+
+    pub const fn strictly_negative_finite() -> Self {
+        FP {
+            infinite: Possible::No,
+            ..FP::strictly_negative()
+        }
+    }
+}
+
+macro_rules! setters {
+    ($($setter:ident => $field:ident),* $(,)?) => {
+        impl FloatPossibilities {
+            $(
+                #[doc = concat!("The same possibilities, with `", stringify!($field), "` replaced")]
+                #[must_use]
+                pub const fn $setter(self, possible: Possible) -> Self {
+                    FP {
+                        $field: possible,
+                        ..self
+                    }
+                }
+            )*
+        }
+    };
+}
+
+// Chainable, unlike the struct update syntax which lists the fields
+setters!(
+    with_nan => nan,
+    with_pos_zero => pos_zero,
+    with_neg_zero => neg_zero,
+    with_subnormal => subnormal,
+    with_fractional => fractional,
+    with_large => large,
+    with_infinite => infinite,
+    with_positive => positive,
+    with_negative => negative,
+);
+
+impl FloatPossibilities {
+    /// The same possibilities, with both zeros replaced
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let x = FloatPossibilities::any()
+    ///     .with_nan(Possible::No)
+    ///     .with_zero(Possible::No)
+    ///     .with_negative(Possible::No);
+    ///
+    /// assert_eq!(x, FloatPossibilities::strictly_positive());
+    /// ```
+    #[must_use]
+    pub const fn with_zero(self, possible: Possible) -> Self {
+        self.with_pos_zero(possible).with_neg_zero(possible)
+    }
+
+    /// The same possibilities, with `field` replaced
+    #[must_use]
+    pub const fn with(self, field: Field, possible: Possible) -> Self {
+        match field {
+            Field::Nan => self.with_nan(possible),
+            Field::PosZero => self.with_pos_zero(possible),
+            Field::NegZero => self.with_neg_zero(possible),
+            Field::Subnormal => self.with_subnormal(possible),
+            Field::Fractional => self.with_fractional(possible),
+            Field::Large => self.with_large(possible),
+            Field::Infinite => self.with_infinite(possible),
+            Field::Positive => self.with_positive(possible),
+            Field::Negative => self.with_negative(possible),
+        }
+    }
+
+    /// All the possibilities whose fields are among `levels`
+    ///
+    /// From `nan` as the most significant field, each field takes the
+    /// `levels` in their order: the first possibilities have all their
+    /// fields at `levels[0]`.
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, Possible};
+    ///
+    /// let mut all = FloatPossibilities::enumerate(&[Possible::Yes, Possible::No]);
+    /// assert_eq!(all.next(), Some(FloatPossibilities::any()));
+    /// assert_eq!(
+    ///     all.next(),
+    ///     Some(FloatPossibilities::any().with_negative(Possible::No))
+    /// );
+    /// assert_eq!(all.count(), 510);
+    ///
+    /// assert_eq!(FloatPossibilities::enumerate(&Possible::ALL).count(), 262_144);
+    /// ```
+    pub fn enumerate(levels: &[Possible]) -> impl Iterator<Item = Self> + '_ {
+        let count = levels.len().pow(Field::ALL.len() as u32);
+
+        (0..count).map(move |index| {
+            let (fp, _) = Field::ALL.into_iter().rev().fold(
+                (FloatPossibilities::any(), index),
+                |(fp, rest), field| {
+                    (
+                        fp.with(field, levels[rest % levels.len()]),
+                        rest / levels.len(),
+                    )
+                },
+            );
+            fp
+        })
+    }
+}
+
+/// A `FloatPossibilities` literal, the omitted fields being `Yes`
+///
+/// The levels are `no`, `should_not`, `should` and `yes`,
+/// and `zero` sets both zeros. Usable in constants.
+///
+/// ```
+/// use fn_num_types::{fp, FloatPossibilities, Possible};
+///
+/// const NON_NEGATIVE: FloatPossibilities = fp! { nan: no, neg_zero: no, negative: no };
+///
+/// assert_eq!(NON_NEGATIVE, FloatPossibilities::positive());
+///
+/// let x = fp! { nan: no, zero: yes, infinite: should_not, positive: yes, negative: no };
+/// assert_eq!(x.infinite, Possible::ShouldNot);
+/// assert_eq!(x.neg_zero, Possible::Yes);
+/// ```
+#[macro_export]
+macro_rules! fp {
+    ($($field:ident : $possible:ident),* $(,)?) => {{
+        let fp = $crate::FloatPossibilities::any();
+        $(
+            let fp = $crate::__fp_field!(fp, $field, $crate::__fp_possible!($possible));
+        )*
+        fp
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fp_field {
+    ($fp:ident, nan, $possible:expr) => {
+        $fp.with_nan($possible)
+    };
+    ($fp:ident, zero, $possible:expr) => {
+        $fp.with_zero($possible)
+    };
+    ($fp:ident, pos_zero, $possible:expr) => {
+        $fp.with_pos_zero($possible)
+    };
+    ($fp:ident, neg_zero, $possible:expr) => {
+        $fp.with_neg_zero($possible)
+    };
+    ($fp:ident, subnormal, $possible:expr) => {
+        $fp.with_subnormal($possible)
+    };
+    ($fp:ident, fractional, $possible:expr) => {
+        $fp.with_fractional($possible)
+    };
+    ($fp:ident, large, $possible:expr) => {
+        $fp.with_large($possible)
+    };
+    ($fp:ident, infinite, $possible:expr) => {
+        $fp.with_infinite($possible)
+    };
+    ($fp:ident, positive, $possible:expr) => {
+        $fp.with_positive($possible)
+    };
+    ($fp:ident, negative, $possible:expr) => {
+        $fp.with_negative($possible)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fp_possible {
+    (no) => {
+        $crate::Possible::No
+    };
+    (should_not) => {
+        $crate::Possible::ShouldNot
+    };
+    (should) => {
+        $crate::Possible::Should
+    };
+    (yes) => {
+        $crate::Possible::Yes
+    };
+}
+
 pub const ZERO_POSSIBILITIES: FP = FP {
     nan: Possible::No,
-    zero: Possible::Yes,
+    pos_zero: Possible::Yes,
+    neg_zero: Possible::Yes,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
     infinite: Possible::No,
     positive: Possible::Yes,
     negative: Possible::No,
@@ -130,7 +1263,11 @@ pub const ZERO_POSSIBILITIES: FP = FP {
 
 pub const ZERO_NEG_POSSIBILITIES: FP = FP {
     nan: Possible::No,
-    zero: Possible::Yes,
+    pos_zero: Possible::Yes,
+    neg_zero: Possible::Yes,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
     infinite: Possible::No,
     positive: Possible::No,
     negative: Possible::Yes,
@@ -138,7 +1275,11 @@ pub const ZERO_NEG_POSSIBILITIES: FP = FP {
 
 pub const INF_POSSIBILITIES: FP = FP {
     nan: Possible::No,
-    zero: Possible::No,
+    pos_zero: Possible::No,
+    neg_zero: Possible::No,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
     infinite: Possible::Yes,
     positive: Possible::Yes,
     negative: Possible::Yes,
@@ -146,35 +1287,354 @@ pub const INF_POSSIBILITIES: FP = FP {
 
 pub const INF_NEG_POSSIBILITIES: FP = FP {
     nan: Possible::No,
-    zero: Possible::No,
+    pos_zero: Possible::No,
+    neg_zero: Possible::No,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
     infinite: Possible::Yes,
     positive: Possible::No,
     negative: Possible::Yes,
 };
 
-#[derive(Clone, Copy, Debug)]
+/// Returns true if `value` is finite and not an integer, as `value.fract() != 0.0`
+/// without `std`
+pub(crate) fn is_fractional(value: impl Into<f64>) -> bool {
+    let value = value.into();
+    // From 2^52 all the values are integers, and below they fit in an `i64`
+    const INTEGERS: f64 = 4_503_599_627_370_496.0;
+
+    value > -INTEGERS && value < INTEGERS && value as i64 as f64 != value
+}
+
+/// Returns true if `value` is finite and greater than one in magnitude
+pub(crate) fn is_large(value: impl Into<f64>) -> bool {
+    let value = value.into();
+    value.is_finite() && !(-1.0..=1.0).contains(&value)
+}
+
+/// The category and the sign of `value`, and whether it is fractional and large,
+/// like `is_fractional` and `is_large`, from its bits
+const fn classify(value: f64) -> (FpCategory, bool, bool, bool) {
+    // `f64::to_bits` is not const before Rust 1.83
+    #[allow(unknown_lints, unnecessary_transmutes)]
+    let bits = unsafe { core::mem::transmute::<f64, u64>(value) };
+
+    classify_bits(bits, 11, 52)
+}
+
+/// `classify`, of the bits of a float with `exponent_bits` bits of exponent
+/// and `mantissa_bits` stored bits of significand
+const fn classify_bits(
+    bits: u64,
+    exponent_bits: u32,
+    mantissa_bits: u32,
+) -> (FpCategory, bool, bool, bool) {
+    let sign_negative = bits >> (exponent_bits + mantissa_bits) & 1 == 1;
+    let max_exponent = (1 << exponent_bits) - 1;
+    let exponent = (bits >> mantissa_bits & max_exponent) as i32;
+    let mantissa = bits & ((1 << mantissa_bits) - 1);
+    let bias = (1 << (exponent_bits - 1)) - 1;
+
+    match exponent {
+        _ if exponent as u64 == max_exponent && mantissa == 0 => {
+            (FpCategory::Infinite, sign_negative, false, false)
+        }
+        _ if exponent as u64 == max_exponent => (FpCategory::Nan, sign_negative, false, false),
+        0 if mantissa == 0 => (FpCategory::Zero, sign_negative, false, false),
+        0 => (FpCategory::Subnormal, sign_negative, true, false),
+        _ => {
+            // The value is `(2^mantissa_bits + mantissa) * 2^(exponent - bias - mantissa_bits)`:
+            // below one, or with some of the low bits of the significand set
+            let shift = bias + mantissa_bits as i32 - exponent;
+            let fractional = shift > mantissa_bits as i32
+                || (shift > 0 && (mantissa | 1 << mantissa_bits) & ((1 << shift) - 1) != 0);
+            let large = exponent > bias || (exponent == bias && mantissa != 0);
+
+            (FpCategory::Normal, sign_negative, fractional, large)
+        }
+    }
+}
+
+/// A primitive float, whose values can be checked against `FnArgFloat`
+pub trait FloatValue: Copy {
+    /// Returns true if `arg` is of the type of `self`, and accepts it
+    fn accepted_by(self, arg: &FnArgFloat) -> bool;
+}
+
+impl FloatValue for f32 {
+    fn accepted_by(self, arg: &FnArgFloat) -> bool {
+        matches!(arg, FnArgFloat::F32(fp) if F32::accept(fp, self))
+    }
+}
+
+impl FloatValue for f64 {
+    fn accepted_by(self, arg: &FnArgFloat) -> bool {
+        matches!(arg, FnArgFloat::F64(fp) if F64::accept(fp, self))
+    }
+}
+
+/// The possibilities of a float of a given type
+///
+/// `F16`, `BF16` and `F128` require the `extra-widths` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(C, u8)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum FnArgFloat {
     F32(FloatPossibilities),
     F64(FloatPossibilities),
+    /// IEEE 754 half precision (`f16`)
+    #[cfg(feature = "extra-widths")]
+    F16(FloatPossibilities),
+    /// bfloat16 (`half::bf16`), with the exponent of `f32`
+    #[cfg(feature = "extra-widths")]
+    BF16(FloatPossibilities),
+    /// IEEE 754 quadruple precision (`f128`)
+    #[cfg(feature = "extra-widths")]
+    F128(FloatPossibilities),
+}
+
+/// The possibilities of that `f64` constant only
+impl From<f64> for FnArgFloat {
+    fn from(value: f64) -> Self {
+        FnArgFloat::F64(FP::from(value))
+    }
 }
 
-pub(crate) fn return_fp<F>(lhs: &FnArgFloat, possibilities: F) -> FnArgFloat
-where
-    F: FnOnce(&FP) -> FP,
-{
-    match lhs {
-        FnArgFloat::F32(fp) => FnArgFloat::F32(possibilities(fp)),
-        FnArgFloat::F64(fp) => FnArgFloat::F64(possibilities(fp)),
+/// The possibilities of that `f32` constant only
+///
+/// ```
+/// use fn_num_types::{FnArgFloat, Possible};
+///
+/// match FnArgFloat::from(1.0f32) {
+///     FnArgFloat::F32(fp) => {
+///         assert_eq!(fp.positive, Possible::Yes);
+///         assert_eq!(fp.fractional, Possible::No);
+///         assert_eq!(fp.large, Possible::No);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+impl From<f32> for FnArgFloat {
+    fn from(value: f32) -> Self {
+        FnArgFloat::F32(FP::from(value))
     }
 }
 
-pub(crate) fn return_fp2<F>(lhs: &FnArgFloat, rhs: &FnArgFloat, possibilities: F) -> FnArgFloat
-where
-    F: FnOnce(&FP, &FP) -> FP,
-{
-    match (lhs, rhs) {
-        (FnArgFloat::F32(fp1), FnArgFloat::F32(fp2)) => FnArgFloat::F32(possibilities(fp1, fp2)),
-        (FnArgFloat::F64(fp1), FnArgFloat::F64(fp2)) => FnArgFloat::F64(possibilities(fp1, fp2)),
-        _ => panic!("Different types"),
+impl FnArgFloat {
+    /// The possibilities, whatever the type
+    #[inline]
+    pub const fn possibilities(&self) -> &FP {
+        match self {
+            FnArgFloat::F32(fp) | FnArgFloat::F64(fp) => fp,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F16(fp) | FnArgFloat::BF16(fp) | FnArgFloat::F128(fp) => fp,
+        }
+    }
+
+    /// Any `f32`, NaN included
+    pub const fn f32_any() -> Self {
+        FnArgFloat::F32(FP::any())
+    }
+
+    /// Any `f64`, NaN included
+    pub const fn f64_any() -> Self {
+        FnArgFloat::F64(FP::any())
+    }
+
+    /// Returns true if `self` is an `f32`
+    pub const fn is_f32(&self) -> bool {
+        matches!(self, FnArgFloat::F32(_))
+    }
+
+    /// Returns true if `self` is an `f64`
+    pub const fn is_f64(&self) -> bool {
+        matches!(self, FnArgFloat::F64(_))
+    }
+
+    /// The possibilities replaced by `f` of them, of the same type
+    ///
+    /// ```
+    /// use fn_num_types::{FnArgFloat, Possible};
+    ///
+    /// let x = FnArgFloat::f32_any().map(|fp| fp.with_nan(Possible::No));
+    ///
+    /// assert!(x.is_f32());
+    /// assert_eq!(x.possibilities().nan, Possible::No);
+    /// assert_eq!(x.bits(), 32);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: FnOnce(FP) -> FP,
+    {
+        self.with_possibilities(f(*self.possibilities()))
+    }
+
+    /// Other possibilities, of the same type
+    #[inline]
+    pub const fn with_possibilities(&self, fp: FP) -> Self {
+        match self {
+            FnArgFloat::F32(_) => FnArgFloat::F32(fp),
+            FnArgFloat::F64(_) => FnArgFloat::F64(fp),
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F16(_) => FnArgFloat::F16(fp),
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::BF16(_) => FnArgFloat::BF16(fp),
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F128(_) => FnArgFloat::F128(fp),
+        }
+    }
+
+    /// The name of the type, like `f64`
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            FnArgFloat::F32(_) => "f32",
+            FnArgFloat::F64(_) => "f64",
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F16(_) => "f16",
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::BF16(_) => "bf16",
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F128(_) => "f128",
+        }
+    }
+
+    /// The possibilities `fp` for the type named `name`, like `f64`
+    pub fn from_type_name(name: &str, fp: FP) -> Option<Self> {
+        match name {
+            "f32" => Some(FnArgFloat::F32(fp)),
+            "f64" => Some(FnArgFloat::F64(fp)),
+            #[cfg(feature = "extra-widths")]
+            "f16" => Some(FnArgFloat::F16(fp)),
+            #[cfg(feature = "extra-widths")]
+            "bf16" => Some(FnArgFloat::BF16(fp)),
+            #[cfg(feature = "extra-widths")]
+            "f128" => Some(FnArgFloat::F128(fp)),
+            _ => None,
+        }
+    }
+
+    /// Width in bits: `f16` and `bf16` are both 16 bits wide
+    pub const fn bits(&self) -> u8 {
+        match self {
+            FnArgFloat::F32(_) => 32,
+            FnArgFloat::F64(_) => 64,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F16(_) | FnArgFloat::BF16(_) => 16,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F128(_) => 128,
+        }
+    }
+
+    /// The possibilities packed with `FloatPossibilities::to_bits`, and the type
+    /// above them: never `0`
+    #[cfg_attr(not(feature = "arith"), allow(dead_code))]
+    pub(crate) fn pack(&self) -> u32 {
+        self.type_id() << 18 | self.possibilities().to_bits()
+    }
+
+    /// The type, as a number from one
+    const fn type_id(&self) -> u32 {
+        match self {
+            FnArgFloat::F32(_) => 1,
+            FnArgFloat::F64(_) => 2,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F16(_) => 3,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::BF16(_) => 4,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F128(_) => 5,
+        }
+    }
+
+    /// Widths of the exponent and of the significand, including the hidden bit
+    #[cfg_attr(not(feature = "arith"), allow(dead_code))]
+    pub(crate) const fn widths(&self) -> (u32, u32) {
+        match self {
+            FnArgFloat::F32(_) => (8, 24),
+            FnArgFloat::F64(_) => (11, 53),
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F16(_) => (5, 11),
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::BF16(_) => (8, 8),
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F128(_) => (15, 113),
+        }
+    }
+
+    /// The largest finite value of the type
+    pub fn max_value(&self) -> f64 {
+        match self {
+            FnArgFloat::F32(_) => f64::from(f32::MAX),
+            FnArgFloat::F64(_) => f64::MAX,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F16(_) => 65504.0,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::BF16(_) => 3.389_531_389_251_535e38,
+            // Above `f64::MAX`
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F128(_) => f64::INFINITY,
+        }
+    }
+
+    /// The smallest positive normal value of the type, the subnormals are below
+    pub fn min_positive(&self) -> f64 {
+        match self {
+            FnArgFloat::F32(_) => f64::from(f32::MIN_POSITIVE),
+            FnArgFloat::F64(_) => f64::MIN_POSITIVE,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F16(_) => 6.103_515_625e-5,
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::BF16(_) => f64::from(f32::MIN_POSITIVE),
+            // Below the range of `f64`, its subnormals are all below `f64::MIN_POSITIVE`
+            #[cfg(feature = "extra-widths")]
+            FnArgFloat::F128(_) => f64::MIN_POSITIVE,
+        }
+    }
+
+    /// Returns true if `value` is of the type of `self`, and accepted
+    ///
+    /// ```
+    /// use fn_num_types::{FloatPossibilities, FnArgFloat};
+    ///
+    /// let x = FnArgFloat::F32(FloatPossibilities::finite());
+    ///
+    /// assert!(x.accept(1.5f32));
+    /// assert!(!x.accept(f32::INFINITY));
+    /// assert!(!x.accept(1.5f64));
+    /// ```
+    pub fn accept<T: FloatValue>(&self, value: T) -> bool {
+        value.accepted_by(self)
+    }
+
+    #[inline]
+    pub const fn is_same_type(&self, other: &Self) -> bool {
+        self.type_id() == other.type_id()
+    }
+
+    /// Of the same type, and `FloatPossibilities::is_sound_approximation_of`
+    ///
+    /// ```
+    /// use fn_num_types::core::ops;
+    /// use fn_num_types::{FloatPossibilities, FnArgFloat};
+    ///
+    /// let x = FnArgFloat::F32(FloatPossibilities::positive());
+    /// let declared = FnArgFloat::F32(FloatPossibilities::non_nan());
+    ///
+    /// assert!(declared.is_sound_approximation_of(&ops::sqrt(&x)));
+    /// assert!(!declared.is_sound_approximation_of(&ops::sqrt(&x.map(|_| FloatPossibilities::any()))));
+    /// // Of another type
+    /// let wider = FnArgFloat::F64(*x.possibilities());
+    /// assert!(!declared.is_sound_approximation_of(&ops::sqrt(&wider)));
+    /// ```
+    pub fn is_sound_approximation_of(&self, tighter: &Self) -> bool {
+        self.is_same_type(tighter)
+            && self
+                .possibilities()
+                .is_sound_approximation_of(tighter.possibilities())
     }
 }
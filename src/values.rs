@@ -0,0 +1,409 @@
+//! Exact sets of values, on top of the possibilities.
+//!
+//! Some operations only reach a few values, like `signum`, or the rounding
+//! of values between `-1.0` and `1.0`. An `ExactFloat` also carries the set of
+//! its values other than NaN when it is small, computed by the operations
+//! that are exact or whose result is specified by IEEE 754 on special values,
+//! like `acos(1.0)` that is `0.0`.
+//!
+//! Only `F32` and `F64` get sets.
+//!
+//! ```
+//! use fn_num_types::values::ExactFloat;
+//! use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+//!
+//! let x = ExactFloat::from(FnArgFloat::F64(FloatPossibilities::finite()));
+//!
+//! let sign = x.signum();
+//! assert_eq!(sign.values(), Some(&[-1.0, 1.0][..]));
+//! assert_eq!(sign.arg().possibilities().fractional, Possible::No);
+//!
+//! let one = ExactFloat::from(1.0);
+//! assert_eq!(one.acos().values(), Some(&[0.0][..]));
+//! assert_eq!(one.acos().arg().possibilities().fractional, Possible::No);
+//! ```
+
+use crate::core::ops;
+use crate::expr::{BinaryOp, UnaryOp};
+use crate::float_op::{BinaryFloatOp, UnaryFloatOp};
+use crate::{BoolPossibility, FloatPossibilities, FloatValue, FnArgFloat, Possible};
+
+/// The largest set kept, larger ones are forgotten
+pub const MAX_VALUES: usize = 8;
+
+/// The operations computing the result of any value exactly, in `f32` like in `f64`
+const EXACT_UNARY: &[UnaryOp] = &[
+    UnaryOp::Neg,
+    UnaryOp::Abs,
+    UnaryOp::Ceil,
+    UnaryOp::Floor,
+    UnaryOp::Round,
+    UnaryOp::RoundTiesEven,
+    UnaryOp::Trunc,
+    UnaryOp::Fract,
+    UnaryOp::Signum,
+    UnaryOp::Sqrt,
+    UnaryOp::Recip,
+];
+
+/// The operations computing the result of any values exactly, in `f32` like in `f64`:
+/// computed in `f64`, the results of `f32` values are only rounded once
+const EXACT_BINARY: &[BinaryOp] = &[
+    BinaryOp::Add,
+    BinaryOp::Sub,
+    BinaryOp::Mul,
+    BinaryOp::Div,
+    BinaryOp::Rem,
+    BinaryOp::Copysign,
+    BinaryOp::Max,
+    BinaryOp::Min,
+    BinaryOp::Maximum,
+    BinaryOp::Minimum,
+    BinaryOp::Midpoint,
+];
+
+/// The values whose results are specified by IEEE 754 for all the operations
+fn is_special(value: f64) -> bool {
+    value.is_nan() || value == 0.0 || value.abs() == 1.0 || value.is_infinite()
+}
+
+/// The possibilities of a float, with the set of its values when it is small
+///
+/// Both are kept consistent: the set narrows the possibilities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExactFloat {
+    arg: FnArgFloat,
+    /// Sorted by `total_cmp`, without NaN
+    values: Option<Vec<f64>>,
+}
+
+impl ExactFloat {
+    /// The possibilities of `arg`, with values in `values` if any
+    ///
+    /// The values not accepted by `arg` are ignored, the others rounded to its type.
+    pub fn new(arg: FnArgFloat, values: Option<&[f64]>) -> Self {
+        let results = values.map(|values| {
+            let mut results = values.to_vec();
+            if arg.possibilities().nan != Possible::No {
+                results.push(f64::NAN);
+            }
+            results
+        });
+
+        ExactFloat::from_results(arg, results)
+    }
+
+    /// The possibilities, whatever the values
+    pub fn arg(&self) -> &FnArgFloat {
+        &self.arg
+    }
+
+    /// The values other than NaN, sorted, `None` if there are too many or unknown
+    pub fn values(&self) -> Option<&[f64]> {
+        self.values.as_deref()
+    }
+
+    /// Returns true if the value is accepted by both the possibilities and the set
+    pub fn accept(&self, value: f64) -> bool {
+        let in_set = match &self.values {
+            Some(values) => value.is_nan() || values.iter().any(|v| v.to_bits() == value.to_bits()),
+            None => true,
+        };
+
+        in_set && accepts(&self.arg, value)
+    }
+
+    /// The set, with NaN if it is possible
+    fn candidates(&self) -> Option<Vec<f64>> {
+        let mut values = self.values.clone()?;
+        if self.arg.possibilities().nan != Possible::No {
+            values.push(f64::NAN);
+        }
+        Some(values)
+    }
+
+    /// `model`, narrowed by the results of all the values, NaN included, if known
+    fn from_results(model: FnArgFloat, results: Option<Vec<f64>>) -> Self {
+        let Some(results) = results else {
+            return ExactFloat {
+                arg: model,
+                values: None,
+            };
+        };
+        let Some(mut results) = results
+            .into_iter()
+            .map(|value| round(&model, value))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return ExactFloat {
+                arg: model,
+                values: None,
+            };
+        };
+
+        results.retain(|value| value.is_nan() || accepts(&model, *value));
+
+        let reached = results
+            .iter()
+            .map(|value| possibilities(&model, *value))
+            .fold(
+                FloatPossibilities::nan().with_nan(Possible::No),
+                |reached, fp| reached.union(&fp),
+            );
+        let arg = model.with_possibilities(model.possibilities().intersection(&reached));
+
+        results.retain(|value| !value.is_nan());
+        results.sort_by(f64::total_cmp);
+        results.dedup_by(|a, b| a.to_bits() == b.to_bits());
+
+        ExactFloat {
+            arg,
+            values: (results.len() <= MAX_VALUES).then_some(results),
+        }
+    }
+
+    /// The result of `op`, with its model
+    pub fn apply_unary(&self, op: UnaryOp) -> Self {
+        let model = op.apply_abstract(&self.arg);
+
+        let results = match self.candidates() {
+            Some(values) => {
+                let results: Vec<f64> = values.iter().map(|v| op.apply_concrete(*v)).collect();
+                let special = values.iter().chain(&results).all(|v| is_special(*v));
+
+                (EXACT_UNARY.contains(&op) || special).then_some(results)
+            }
+            None => self.tiny_range(op),
+        };
+
+        ExactFloat::from_results(model, results)
+    }
+
+    /// The values reached by `op` whatever the values of `self`, if few
+    fn tiny_range(&self, op: UnaryOp) -> Option<Vec<f64>> {
+        let fp = self.arg.possibilities();
+        let mut results = match op {
+            UnaryOp::Signum => vec![-1.0, 1.0],
+            // Between `-1.0` and `1.0`
+            UnaryOp::Ceil
+            | UnaryOp::Floor
+            | UnaryOp::Round
+            | UnaryOp::RoundTiesEven
+            | UnaryOp::Trunc
+                if fp.large == Possible::No && fp.infinite == Possible::No =>
+            {
+                vec![-1.0, -0.0, 0.0, 1.0]
+            }
+            _ => return None,
+        };
+
+        if fp.nan != Possible::No {
+            results.push(f64::NAN);
+        }
+        Some(results)
+    }
+
+    /// The result of `op`, with its model
+    pub fn apply_binary(&self, op: BinaryOp, rhs: &Self) -> Self {
+        let model = op.apply_abstract(&self.arg, &rhs.arg);
+
+        let results = self
+            .candidates()
+            .zip(rhs.candidates())
+            .and_then(|(xs, ys)| {
+                let pairs: Vec<(f64, f64)> = xs
+                    .iter()
+                    .flat_map(|x| ys.iter().map(move |y| (*x, *y)))
+                    .collect();
+                let results: Vec<f64> = pairs
+                    .iter()
+                    .map(|(x, y)| op.apply_concrete(*x, *y))
+                    .collect();
+                let special = pairs
+                    .iter()
+                    .flat_map(|(x, y)| [x, y])
+                    .chain(&results)
+                    .all(|v| is_special(*v));
+
+                (EXACT_BINARY.contains(&op) || special).then_some(results)
+            });
+
+        ExactFloat::from_results(model, results)
+    }
+
+    /// `cmp` on all the values if both sets are known, else `model`
+    fn compare(
+        &self,
+        rhs: &Self,
+        cmp: fn(f64, f64) -> bool,
+        model: fn(&FnArgFloat, &FnArgFloat) -> BoolPossibility,
+    ) -> BoolPossibility {
+        match (self.candidates(), rhs.candidates()) {
+            (Some(xs), Some(ys)) if self.arg.is_same_type(&rhs.arg) => {
+                let results: Vec<bool> = xs
+                    .iter()
+                    .flat_map(|x| ys.iter().map(move |y| cmp(*x, *y)))
+                    .collect();
+
+                BoolPossibility::new(results.contains(&true), results.contains(&false))
+            }
+            _ => model(&self.arg, &rhs.arg),
+        }
+    }
+
+    /// `then` if `cond` may be true, and `otherwise` if it may be false
+    pub fn select(cond: BoolPossibility, then: &Self, otherwise: &Self) -> Self {
+        match cond {
+            BoolPossibility::True => then.clone(),
+            BoolPossibility::False => otherwise.clone(),
+            BoolPossibility::Maybe => {
+                let model = ops::select(cond, &then.arg, &otherwise.arg);
+                let results = then
+                    .candidates()
+                    .zip(otherwise.candidates())
+                    .map(|(mut a, b)| {
+                        a.extend(b);
+                        a
+                    });
+
+                ExactFloat::from_results(model, results)
+            }
+        }
+    }
+}
+
+/// Only this value
+impl From<f64> for ExactFloat {
+    fn from(value: f64) -> Self {
+        ExactFloat::new(FnArgFloat::from(value), Some(&[value]))
+    }
+}
+
+/// Without set
+impl From<FnArgFloat> for ExactFloat {
+    fn from(arg: FnArgFloat) -> Self {
+        ExactFloat { arg, values: None }
+    }
+}
+
+/// `value` rounded to the type of `arg`, `None` if it has no set
+fn round(arg: &FnArgFloat, value: f64) -> Option<f64> {
+    match arg {
+        FnArgFloat::F32(_) => Some(f64::from(value as f32)),
+        FnArgFloat::F64(_) => Some(value),
+        #[cfg(feature = "extra-widths")]
+        _ => None,
+    }
+}
+
+/// Returns true if `arg` accepts `value`, of its type
+fn accepts(arg: &FnArgFloat, value: f64) -> bool {
+    match arg {
+        FnArgFloat::F32(_) => (value as f32).accepted_by(arg),
+        _ => arg.possibilities().accept(value),
+    }
+}
+
+/// The possibilities of only `value`, of the type of `arg`
+fn possibilities(arg: &FnArgFloat, value: f64) -> FloatPossibilities {
+    match arg {
+        FnArgFloat::F32(_) => FloatPossibilities::from(value as f32),
+        _ => FloatPossibilities::from(value),
+    }
+}
+
+macro_rules! unary {
+    ($($op:ident => $variant:ident),* $(,)?) => {
+        impl ExactFloat {
+            $(
+                pub fn $op(&self) -> Self {
+                    self.apply_unary(UnaryOp::$variant)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident => $variant:ident),* $(,)?) => {
+        impl ExactFloat {
+            $(
+                pub fn $op(&self, rhs: &Self) -> Self {
+                    self.apply_binary(BinaryOp::$variant, rhs)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! cmp {
+    ($($op:ident),* $(,)?) => {
+        impl ExactFloat {
+            $(
+                pub fn $op(&self, rhs: &Self) -> BoolPossibility {
+                    self.compare(rhs, |x, y| x.$op(&y), ops::cmp::$op)
+                }
+            )*
+        }
+    };
+}
+
+unary!(
+    neg => Neg,
+    abs => Abs,
+    ceil => Ceil,
+    floor => Floor,
+    round => Round,
+    round_ties_even => RoundTiesEven,
+    trunc => Trunc,
+    fract => Fract,
+    signum => Signum,
+    sqrt => Sqrt,
+    exp => Exp,
+    exp2 => Exp2,
+    ln => Ln,
+    log2 => Log2,
+    log10 => Log10,
+    to_degrees => ToDegrees,
+    to_radians => ToRadians,
+    cbrt => Cbrt,
+    sin => Sin,
+    cos => Cos,
+    tan => Tan,
+    asin => Asin,
+    acos => Acos,
+    atan => Atan,
+    exp_m1 => ExpM1,
+    ln_1p => Ln1p,
+    sinh => Sinh,
+    cosh => Cosh,
+    tanh => Tanh,
+    asinh => Asinh,
+    acosh => Acosh,
+    atanh => Atanh,
+    recip => Recip,
+    next_up => NextUp,
+    next_down => NextDown,
+);
+
+binary!(
+    add => Add,
+    sub => Sub,
+    mul => Mul,
+    div => Div,
+    rem => Rem,
+    div_euclid => DivEuclid,
+    rem_euclid => RemEuclid,
+    powf => Powf,
+    hypot => Hypot,
+    atan2 => Atan2,
+    copysign => Copysign,
+    max => Max,
+    min => Min,
+    maximum => Maximum,
+    minimum => Minimum,
+    log => Log,
+    midpoint => Midpoint,
+);
+
+cmp!(eq, ne, lt, le, gt, ge);
@@ -0,0 +1,226 @@
+//! Kani proof harnesses, run with `cargo kani --features verification`.
+//!
+//! For every `f32` or `f64` value and every possibilities accepting it,
+//! the result of the operation is accepted by the possibilities returned by the model.
+//! Only the operations supported by the model checker are covered.
+
+use crate::core::ops;
+use crate::{FnArgFloat, Possible, FP};
+
+fn any_possible() -> Possible {
+    match kani::any::<u8>() % 4 {
+        0 => Possible::No,
+        1 => Possible::ShouldNot,
+        2 => Possible::Should,
+        _ => Possible::Yes,
+    }
+}
+
+fn any_possibilities() -> FP {
+    FP {
+        nan: any_possible(),
+        pos_zero: any_possible(),
+        neg_zero: any_possible(),
+        subnormal: any_possible(),
+        fractional: any_possible(),
+        large: any_possible(),
+        infinite: any_possible(),
+        positive: any_possible(),
+        negative: any_possible(),
+    }
+}
+
+/// Any value accepted by the possibilities `fp`
+fn any_accepted(fp: &FP) -> f32 {
+    let x: f32 = kani::any();
+    kani::assume(fp.accept_f32(x));
+    x
+}
+
+/// Any value accepted by the possibilities `fp`
+fn any_accepted_f64(fp: &FP) -> f64 {
+    let x: f64 = kani::any();
+    kani::assume(fp.accept(x));
+    x
+}
+
+fn check(res: FnArgFloat, value: f32) {
+    match res {
+        FnArgFloat::F32(fp) => assert!(fp.accept_f32(value)),
+        _ => panic!("Invalid result"),
+    }
+}
+
+fn check_f64(res: FnArgFloat, value: f64) {
+    match res {
+        FnArgFloat::F64(fp) => assert!(fp.accept(value)),
+        _ => panic!("Invalid result"),
+    }
+}
+
+macro_rules! unary {
+    ($float:ident, $variant:ident, $any_accepted:ident, $check:ident; $($op:ident => $f:expr),* $(,)?) => {
+        $(
+            #[kani::proof]
+            fn $op() {
+                let fp = any_possibilities();
+                let x = $any_accepted(&fp);
+                let f: fn($float) -> $float = $f;
+
+                $check(ops::$op(&FnArgFloat::$variant(fp)), f(x));
+            }
+        )*
+    };
+}
+
+unary!(
+    f32, F32, any_accepted, check;
+    neg => |x| -x,
+    abs => f32::abs,
+    ceil => f32::ceil,
+    floor => f32::floor,
+    round => f32::round,
+    trunc => f32::trunc,
+    fract => f32::fract,
+    signum => f32::signum,
+    sqrt => f32::sqrt,
+    recip => f32::recip,
+    powi => |x| x.powi(2),
+);
+
+/// The `f64` models, the same harnesses with the `f64` corner cases
+mod double {
+    use super::*;
+
+    unary!(
+        f64, F64, any_accepted_f64, check_f64;
+        neg => |x| -x,
+        abs => f64::abs,
+        ceil => f64::ceil,
+        floor => f64::floor,
+        round => f64::round,
+        trunc => f64::trunc,
+        fract => f64::fract,
+        signum => f64::signum,
+        sqrt => f64::sqrt,
+        recip => f64::recip,
+        powi => |x| x.powi(2),
+    );
+}
+
+#[kani::proof]
+fn add() {
+    let fp1 = any_possibilities();
+    let fp2 = any_possibilities();
+    let x = any_accepted(&fp1);
+    let y = any_accepted(&fp2);
+
+    check(
+        ops::add(&FnArgFloat::F32(fp1), &FnArgFloat::F32(fp2)),
+        x + y,
+    );
+}
+
+#[kani::proof]
+fn mul() {
+    let fp1 = any_possibilities();
+    let fp2 = any_possibilities();
+    let x = any_accepted(&fp1);
+    let y = any_accepted(&fp2);
+
+    check(
+        ops::mul(&FnArgFloat::F32(fp1), &FnArgFloat::F32(fp2)),
+        x * y,
+    );
+}
+
+#[kani::proof]
+fn div() {
+    let fp1 = any_possibilities();
+    let fp2 = any_possibilities();
+    let x = any_accepted(&fp1);
+    let y = any_accepted(&fp2);
+
+    check(
+        ops::div(&FnArgFloat::F32(fp1), &FnArgFloat::F32(fp2)),
+        x / y,
+    );
+}
+
+#[kani::proof]
+fn rem() {
+    let fp1 = any_possibilities();
+    let fp2 = any_possibilities();
+    let x = any_accepted(&fp1);
+    let y = any_accepted(&fp2);
+
+    check(
+        ops::rem(&FnArgFloat::F32(fp1), &FnArgFloat::F32(fp2)),
+        x % y,
+    );
+}
+
+#[kani::proof]
+fn div_euclid() {
+    let fp1 = any_possibilities();
+    let fp2 = any_possibilities();
+    let x = any_accepted(&fp1);
+    let y = any_accepted(&fp2);
+
+    check(
+        ops::div_euclid(&FnArgFloat::F32(fp1), &FnArgFloat::F32(fp2)),
+        x.div_euclid(y),
+    );
+}
+
+#[kani::proof]
+fn rem_euclid() {
+    let fp1 = any_possibilities();
+    let fp2 = any_possibilities();
+    let x = any_accepted(&fp1);
+    let y = any_accepted(&fp2);
+
+    check(
+        ops::rem_euclid(&FnArgFloat::F32(fp1), &FnArgFloat::F32(fp2)),
+        x.rem_euclid(y),
+    );
+}
+
+#[kani::proof]
+fn copysign() {
+    let fp1 = any_possibilities();
+    let fp2 = any_possibilities();
+    let x = any_accepted(&fp1);
+    let y = any_accepted(&fp2);
+
+    check(
+        ops::copysign(&FnArgFloat::F32(fp1), &FnArgFloat::F32(fp2)),
+        x.copysign(y),
+    );
+}
+
+#[kani::proof]
+fn max() {
+    let fp1 = any_possibilities();
+    let fp2 = any_possibilities();
+    let x = any_accepted(&fp1);
+    let y = any_accepted(&fp2);
+
+    check(
+        ops::max(&FnArgFloat::F32(fp1), &FnArgFloat::F32(fp2)),
+        x.max(y),
+    );
+}
+
+#[kani::proof]
+fn min() {
+    let fp1 = any_possibilities();
+    let fp2 = any_possibilities();
+    let x = any_accepted(&fp1);
+    let y = any_accepted(&fp2);
+
+    check(
+        ops::min(&FnArgFloat::F32(fp1), &FnArgFloat::F32(fp2)),
+        x.min(y),
+    );
+}
@@ -0,0 +1,721 @@
+//! Brute-force verification of the models of operations: for each set of
+//! possibilities and each value of a grid it accepts, the result of the
+//! operation must be accepted by the analysis
+//!
+//! ```
+//! use fn_num_types::core::ops;
+//! use fn_num_types::verify::{verify_binary, verify_unary};
+//!
+//! assert!(verify_unary(f64::sqrt, ops::sqrt).is_empty());
+//! assert!(verify_binary(f64::hypot, ops::hypot).is_empty());
+//!
+//! // A wrong model of `abs`
+//! let counterexamples = verify_unary(f64::abs, ops::neg);
+//! assert!(counterexamples.iter().any(|c| c.values == [1.0] && c.result == 1.0));
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::{E, FRAC_PI_2, PI};
+use core::fmt;
+
+use crate::{Field, FloatPossibilities, FnArgFloat, Possible};
+
+/// The values tested, in increasing order: the special values, the extremes,
+/// the values where the functions change, normal values between `-1.0` and
+/// `1.0`, and both ends of the subnormals
+pub const VALUES: [f64; 31] = [
+    f64::NAN,
+    f64::NEG_INFINITY,
+    f64::MIN,
+    f64::MIN / 2.0,
+    -PI,
+    -E,
+    -2.0,
+    -FRAC_PI_2,
+    -1.0,
+    -0.5,
+    -0.1,
+    -f64::MIN_POSITIVE,
+    -2.225073858507201e-308, // The largest subnormal
+    -1.0e-308,               // Subnormal
+    -5.0e-324,               // The smallest subnormal
+    -0.0,
+    0.0,
+    5.0e-324,
+    1.0e-308,
+    2.225073858507201e-308,
+    f64::MIN_POSITIVE,
+    0.1,
+    0.5,
+    1.0,
+    FRAC_PI_2,
+    2.0,
+    E,
+    PI,
+    f64::MAX / 2.0,
+    f64::MAX,
+    f64::INFINITY,
+];
+
+/// A call whose result is not accepted by the analysis
+#[derive(Debug, Clone, PartialEq)]
+pub struct Counterexample {
+    /// The possibilities of the arguments
+    pub args: Vec<FloatPossibilities>,
+    /// The arguments, accepted by `args`
+    pub values: Vec<f64>,
+    pub result: f64,
+    /// The analysis of `args`
+    pub analysis: FnArgFloat,
+}
+
+/// Like `(-1.0) = 1.0 with <args> = <analysis>`, to follow the name of the function
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{value:?}")?;
+        }
+        write!(f, ") = {:?} with", self.result)?;
+        for arg in &self.args {
+            write!(f, " {arg}")?;
+        }
+        write!(f, " = {}", self.analysis)
+    }
+}
+
+/// All the possibilities whose fields are `Yes` or `No`
+pub fn possibilities() -> Vec<FloatPossibilities> {
+    // So that `Yes` comes first
+    FloatPossibilities::enumerate(&[Possible::Yes, Possible::No]).collect()
+}
+
+/// The possibilities refining at most one of `subnormal`, `fractional` and `large`,
+/// keeping the product of two enumerations tractable
+fn binary_possibilities() -> Vec<FloatPossibilities> {
+    possibilities()
+        .into_iter()
+        .filter(|p| {
+            [p.subnormal, p.fractional, p.large]
+                .iter()
+                .filter(|flag| **flag == Possible::No)
+                .count()
+                <= 1
+        })
+        .collect()
+}
+
+/// The possibilities with `subnormal`, `fractional` and `large` set,
+/// keeping the product of three enumerations tractable: they still cover every value
+pub(crate) fn ternary_possibilities() -> Vec<FloatPossibilities> {
+    possibilities()
+        .into_iter()
+        .filter(|p| {
+            p.subnormal == Possible::Yes
+                && p.fractional == Possible::Yes
+                && p.large == Possible::Yes
+        })
+        .collect()
+}
+
+/// The float types that can be verified, `f32` and `f64`
+pub trait Float:
+    Copy + fmt::Debug + Into<f64> + Into<FloatPossibilities> + Send + Sync + 'static
+{
+    /// The values tested
+    const VALUES: &'static [Self];
+
+    /// The argument of this type with the possibilities `fp`
+    fn arg(fp: FloatPossibilities) -> FnArgFloat;
+
+    /// Returns true if `analysis` is of this type and accepts all the values of `reached`
+    fn accepts(analysis: &FnArgFloat, reached: &FloatPossibilities) -> bool;
+}
+
+impl Float for f64 {
+    const VALUES: &'static [f64] = &VALUES;
+
+    fn arg(fp: FloatPossibilities) -> FnArgFloat {
+        FnArgFloat::F64(fp)
+    }
+
+    fn accepts(analysis: &FnArgFloat, reached: &FloatPossibilities) -> bool {
+        matches!(analysis, FnArgFloat::F64(fp) if fp.violation(*reached).is_none())
+    }
+}
+
+impl Float for f32 {
+    // `1.0e-308` is zero for `f32`, its subnormals are larger
+    const VALUES: &'static [f32] = &[
+        f32::NAN,
+        f32::NEG_INFINITY,
+        f32::MIN,
+        f32::MIN / 2.0,
+        -core::f32::consts::PI,
+        -core::f32::consts::E,
+        -2.0,
+        -core::f32::consts::FRAC_PI_2,
+        -1.0,
+        -0.5,
+        -0.1,
+        -f32::MIN_POSITIVE,
+        -1.1754942e-38,
+        -1.0e-40,
+        -1.0e-45,
+        -0.0,
+        0.0,
+        1.0e-45,
+        1.0e-40,
+        1.1754942e-38,
+        f32::MIN_POSITIVE,
+        0.1,
+        0.5,
+        1.0,
+        core::f32::consts::FRAC_PI_2,
+        2.0,
+        core::f32::consts::E,
+        core::f32::consts::PI,
+        f32::MAX / 2.0,
+        f32::MAX,
+        f32::INFINITY,
+    ];
+
+    fn arg(fp: FloatPossibilities) -> FnArgFloat {
+        FnArgFloat::F32(fp)
+    }
+
+    fn accepts(analysis: &FnArgFloat, reached: &FloatPossibilities) -> bool {
+        matches!(analysis, FnArgFloat::F32(fp) if fp.violation(*reached).is_none())
+    }
+}
+
+/// The values of the grid of `T` grouped by their possibilities: a set of
+/// possibilities accepts all the values of a group, or none
+fn groups<T: Float>() -> Vec<(FloatPossibilities, Vec<T>)> {
+    let mut groups: Vec<(FloatPossibilities, Vec<T>)> = vec![];
+    for value in T::VALUES {
+        let fp = (*value).into();
+        match groups.iter_mut().find(|(group, _)| *group == fp) {
+            Some((_, values)) => values.push(*value),
+            None => groups.push((fp, vec![*value])),
+        }
+    }
+    groups
+}
+
+/// The indices of the groups accepted by each of `possibles`
+fn accepted_groups(
+    possibles: &[FloatPossibilities],
+    groups: &[(FloatPossibilities, impl Sized)],
+) -> Vec<Vec<usize>> {
+    possibles
+        .iter()
+        .map(|p| {
+            (0..groups.len())
+                .filter(|i| p.violation(groups[*i].0).is_none())
+                .collect()
+        })
+        .collect()
+}
+
+/// No value
+const NONE: FloatPossibilities = FloatPossibilities::nan().with_nan(Possible::No);
+
+/// `f` on each item, on all the available threads with `std`, the results in order
+fn par_map<I: Sync, R: Send>(items: &[I], f: impl Fn(&I) -> R + Sync) -> Vec<R> {
+    #[cfg(feature = "std")]
+    {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk = ((items.len() + threads - 1) / threads).max(1);
+
+        std::thread::scope(|s| {
+            let f = &f;
+            let handles: Vec<_> = items
+                .chunks(chunk)
+                .map(|chunk| s.spawn(move || chunk.iter().map(f).collect::<Vec<_>>()))
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("Verification thread panicked"))
+                .collect()
+        })
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        items.iter().map(f).collect()
+    }
+}
+
+/// The union of `reached` for the indices of `indices`
+fn union_of(reached: &[FloatPossibilities], indices: &[usize]) -> FloatPossibilities {
+    indices
+        .iter()
+        .fold(NONE, |union, i| union.union(&reached[*i]))
+}
+
+/// The counterexamples of the model `analysis` of `op`, empty if none
+///
+/// All the possibilities of `possibilities` are enumerated, with the values
+/// of the grid they accept.
+pub fn verify_unary<T: Float>(
+    op: impl Fn(T) -> T + Sync,
+    analysis: impl Fn(&FnArgFloat) -> FnArgFloat + Sync,
+) -> Vec<Counterexample> {
+    let possibles = possibilities();
+    let groups = groups::<T>();
+    let accepted = accepted_groups(&possibles, &groups);
+    let args: Vec<_> = possibles.iter().zip(&accepted).collect();
+
+    par_map(&args, |(p1, a1)| {
+        let res_p = analysis(&T::arg(**p1));
+        let mut counterexamples = vec![];
+
+        for v1 in a1.iter().flat_map(|i| &groups[*i].1) {
+            let result = op(*v1);
+            if !T::accepts(&res_p, &result.into()) {
+                counterexamples.push(Counterexample {
+                    args: vec![**p1],
+                    values: vec![(*v1).into()],
+                    result: result.into(),
+                    analysis: res_p,
+                });
+            }
+        }
+
+        counterexamples
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// The counterexamples of the model of `op`, with `verify_unary`
+#[cfg(all(feature = "std", feature = "full"))]
+pub fn verify_unary_op(op: &dyn crate::float_op::UnaryFloatOp) -> Vec<Counterexample> {
+    verify_unary(|x: f64| op.apply_concrete(x), |x| op.apply_abstract(x))
+}
+
+/// The counterexamples of the model of `op`, with `verify_binary`
+#[cfg(all(feature = "std", feature = "full"))]
+pub fn verify_binary_op(op: &dyn crate::float_op::BinaryFloatOp) -> Vec<Counterexample> {
+    verify_binary(
+        |x: f64, y: f64| op.apply_concrete(x, y),
+        |x, y| op.apply_abstract(x, y),
+    )
+}
+
+/// The counterexamples of the model `analysis` of `op`, empty if none
+///
+/// Only the possibilities refining at most one of `subnormal`, `fractional`
+/// and `large` are enumerated, all the values are still tested.
+///
+/// The results are computed once per couple of values of the grid, and joined
+/// per group of values: the values are only enumerated again for the
+/// possibilities whose analysis doesn't accept them, to report them.
+pub fn verify_binary<T: Float>(
+    op: impl Fn(T, T) -> T + Sync,
+    analysis: impl Fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat + Sync,
+) -> Vec<Counterexample> {
+    let possibles = binary_possibilities();
+    let groups = groups::<T>();
+    let accepted = accepted_groups(&possibles, &groups);
+    let args: Vec<_> = possibles.iter().zip(&accepted).collect();
+
+    // The possibilities of the results, for each couple of groups
+    let reached: Vec<Vec<FloatPossibilities>> = groups
+        .iter()
+        .map(|(_, xs)| {
+            groups
+                .iter()
+                .map(|(_, ys)| {
+                    xs.iter()
+                        .flat_map(|x| ys.iter().map(|y| op(*x, *y).into()))
+                        .fold(NONE, |union, fp: FloatPossibilities| union.union(&fp))
+                })
+                .collect()
+        })
+        .collect();
+
+    par_map(&args, |(p1, a1)| {
+        // For each group of the second argument
+        let reached1: Vec<_> = (0..groups.len())
+            .map(|j| {
+                a1.iter()
+                    .fold(NONE, |union, i| union.union(&reached[*i][j]))
+            })
+            .collect();
+        let mut counterexamples = vec![];
+
+        for (p2, a2) in &args {
+            let res_p = analysis(&T::arg(**p1), &T::arg(**p2));
+            if T::accepts(&res_p, &union_of(&reached1, a2)) {
+                continue;
+            }
+
+            for v1 in a1.iter().flat_map(|i| &groups[*i].1) {
+                for v2 in a2.iter().flat_map(|j| &groups[*j].1) {
+                    let result = op(*v1, *v2);
+                    if !T::accepts(&res_p, &result.into()) {
+                        counterexamples.push(Counterexample {
+                            args: vec![**p1, **p2],
+                            values: vec![(*v1).into(), (*v2).into()],
+                            result: result.into(),
+                            analysis: res_p,
+                        });
+                    }
+                }
+            }
+        }
+
+        counterexamples
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// The counterexamples of the model `analysis` of `op`, empty if none,
+/// like `verify_binary`
+///
+/// `op` returns `None` if the arguments are invalid, like the bounds of `clamp`.
+/// Only the possibilities with `subnormal`, `fractional` and `large` are enumerated.
+pub fn verify_ternary<T: Float>(
+    op: impl Fn(T, T, T) -> Option<T> + Sync,
+    analysis: impl Fn(&FnArgFloat, &FnArgFloat, &FnArgFloat) -> FnArgFloat + Sync,
+) -> Vec<Counterexample> {
+    let possibles = ternary_possibilities();
+    let groups = groups::<T>();
+    let accepted = accepted_groups(&possibles, &groups);
+    let args: Vec<_> = possibles.iter().zip(&accepted).collect();
+
+    // The possibilities of the results, for each triple of groups
+    let reached: Vec<Vec<Vec<FloatPossibilities>>> = groups
+        .iter()
+        .map(|(_, xs)| {
+            groups
+                .iter()
+                .map(|(_, ys)| {
+                    groups
+                        .iter()
+                        .map(|(_, zs)| {
+                            xs.iter()
+                                .flat_map(|x| {
+                                    ys.iter()
+                                        .flat_map(move |y| zs.iter().map(move |z| (*x, *y, *z)))
+                                })
+                                .filter_map(|(x, y, z)| op(x, y, z))
+                                .fold(NONE, |union, res| union.union(&res.into()))
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect();
+
+    par_map(&args, |(p1, a1)| {
+        let mut counterexamples = vec![];
+
+        for (p2, a2) in &args {
+            // For each group of the third argument
+            let reached2: Vec<_> = (0..groups.len())
+                .map(|k| {
+                    a1.iter()
+                        .flat_map(|i| a2.iter().map(move |j| (*i, *j)))
+                        .fold(NONE, |union, (i, j)| union.union(&reached[i][j][k]))
+                })
+                .collect();
+
+            for (p3, a3) in &args {
+                let res_p = analysis(&T::arg(**p1), &T::arg(**p2), &T::arg(**p3));
+                if T::accepts(&res_p, &union_of(&reached2, a3)) {
+                    continue;
+                }
+
+                for v1 in a1.iter().flat_map(|i| &groups[*i].1) {
+                    for v2 in a2.iter().flat_map(|j| &groups[*j].1) {
+                        for v3 in a3.iter().flat_map(|k| &groups[*k].1) {
+                            let Some(result) = op(*v1, *v2, *v3) else {
+                                continue;
+                            };
+                            if !T::accepts(&res_p, &result.into()) {
+                                counterexamples.push(Counterexample {
+                                    args: vec![**p1, **p2, **p3],
+                                    values: vec![(*v1).into(), (*v2).into(), (*v3).into()],
+                                    result: result.into(),
+                                    analysis: res_p,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        counterexamples
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Fields that the analysis claims `Yes` but that no value tested reaches
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unreached {
+    /// The possibilities of the arguments
+    pub args: Vec<FloatPossibilities>,
+    /// The analysis of `args`
+    pub analysis: FnArgFloat,
+    pub fields: Vec<Field>,
+}
+
+/// More values than `VALUES`: the values of the grid, then for each exponent
+/// 17 values from the smallest to the largest, of both signs
+pub fn witnesses() -> Vec<f64> {
+    VALUES.into_iter().chain(sweep(1, 16)).collect()
+}
+
+/// For one exponent out of `step`, `divisions + 1` values from the smallest
+/// to the largest, of both signs
+fn sweep(step: usize, divisions: u64) -> impl Iterator<Item = f64> {
+    const MANTISSA: u64 = (1 << 52) - 1;
+
+    (0..2047u64).step_by(step).flat_map(move |exponent| {
+        (0..=divisions).flat_map(move |i| {
+            let mantissa = MANTISSA / divisions * i
+                + if i == divisions {
+                    MANTISSA % divisions
+                } else {
+                    0
+                };
+            let value = f64::from_bits((exponent << 52) | mantissa);
+            [value, -value]
+        })
+    })
+}
+
+/// The possibilities without flags that can't be reached, the others have the same values
+fn canonical_possibilities() -> Vec<FloatPossibilities> {
+    possibilities()
+        .into_iter()
+        .filter(|p| *p == p.canonicalize())
+        .collect()
+}
+
+/// The fields `Yes` in `analysis` and `No` in `reached`
+fn unreached(
+    args: Vec<FloatPossibilities>,
+    analysis: FnArgFloat,
+    reached: FloatPossibilities,
+) -> Option<Unreached> {
+    let FnArgFloat::F64(fp) = analysis else {
+        return None;
+    };
+    // Without the flags that can't be reached by construction, like a zero without sign
+    let fp = fp.canonicalize();
+
+    let fields: Vec<Field> = Field::ALL
+        .into_iter()
+        .filter(|field| fp.get(*field) == Possible::Yes && reached.get(*field) == Possible::No)
+        .collect();
+
+    (!fields.is_empty()).then_some(Unreached {
+        args,
+        analysis,
+        fields,
+    })
+}
+
+/// The groups of values and their results: `(argument, result)` for one argument
+fn reached_groups<T: PartialEq>(groups: impl Iterator<Item = T>) -> Vec<T> {
+    groups.fold(vec![], |mut unique, group| {
+        if !unique.contains(&group) {
+            unique.push(group);
+        }
+        unique
+    })
+}
+
+/// The fields of the model `analysis` of `op`, for `f64`, claimed `Yes` without
+/// any witness among `witnesses`, empty if none
+///
+/// A loose model returning `Yes` for a field that can't be reached is still sound,
+/// this catches it. The witnesses are a sample, so a reported field may be
+/// reached by a value not tested.
+///
+/// ```
+/// use fn_num_types::core::ops;
+/// use fn_num_types::verify::verify_tight_unary;
+/// use fn_num_types::{Field, FloatPossibilities, FnArgFloat};
+///
+/// assert!(verify_tight_unary(f64::abs, ops::abs).is_empty());
+///
+/// // Sound but loose
+/// let loose = verify_tight_unary(f64::abs, |_| FnArgFloat::F64(FloatPossibilities::any()));
+/// assert!(loose.iter().all(|u| u.fields.contains(&Field::Negative)));
+/// ```
+pub fn verify_tight_unary(
+    op: impl Fn(f64) -> f64,
+    analysis: impl Fn(&FnArgFloat) -> FnArgFloat,
+) -> Vec<Unreached> {
+    let groups = reached_groups(
+        witnesses()
+            .into_iter()
+            .map(|v| (FloatPossibilities::from(v), FloatPossibilities::from(op(v)))),
+    );
+
+    canonical_possibilities()
+        .into_iter()
+        .filter_map(|p| {
+            let reached = groups
+                .iter()
+                .filter(|(arg, _)| p.violation(*arg).is_none())
+                .fold(
+                    FloatPossibilities::nan().with_nan(Possible::No),
+                    |reached, (_, res)| reached.union(res),
+                );
+            unreached(vec![p], analysis(&FnArgFloat::F64(p)), reached)
+        })
+        .collect()
+}
+
+/// Like `verify_tight_unary`, for two arguments, on the possibilities of `verify_binary`
+///
+/// The witnesses are `VALUES` and, for one exponent out of 64, its smallest,
+/// middle and largest values.
+pub fn verify_tight_binary(
+    op: impl Fn(f64, f64) -> f64,
+    analysis: impl Fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat,
+) -> Vec<Unreached> {
+    let values: Vec<f64> = VALUES.into_iter().chain(sweep(64, 2)).collect();
+    let groups = reached_groups(values.iter().flat_map(|v1| {
+        values.iter().map(|v2| {
+            (
+                FloatPossibilities::from(*v1),
+                FloatPossibilities::from(*v2),
+                FloatPossibilities::from(op(*v1, *v2)),
+            )
+        })
+    }));
+
+    let possibles: Vec<_> = binary_possibilities()
+        .into_iter()
+        .filter(|p| *p == p.canonicalize())
+        .collect();
+    let mut unreached_fields = vec![];
+    for p1 in &possibles {
+        for p2 in &possibles {
+            let reached = groups
+                .iter()
+                .filter(|(arg1, arg2, _)| {
+                    p1.violation(*arg1).is_none() && p2.violation(*arg2).is_none()
+                })
+                .fold(
+                    FloatPossibilities::nan().with_nan(Possible::No),
+                    |reached, (_, _, res)| reached.union(res),
+                );
+            let analysis = analysis(&FnArgFloat::F64(*p1), &FnArgFloat::F64(*p2));
+            unreached_fields.extend(unreached(vec![*p1, *p2], analysis, reached));
+        }
+    }
+
+    unreached_fields
+}
+
+/// The counterexamples of the model `analysis` of `op`, for every `f32`,
+/// empty if none: slow, on all the available threads
+///
+/// The values are grouped by the possibilities of the argument and of the
+/// result, so the first value of each group is reported.
+///
+/// ```no_run
+/// use fn_num_types::core::ops;
+/// use fn_num_types::verify::verify_unary_f32_exhaustive;
+///
+/// assert!(verify_unary_f32_exhaustive(f32::sqrt, ops::sqrt).is_empty());
+/// ```
+#[cfg(feature = "std")]
+pub fn verify_unary_f32_exhaustive(
+    op: impl Fn(f32) -> f32 + Sync,
+    analysis: impl Fn(&FnArgFloat) -> FnArgFloat,
+) -> Vec<Counterexample> {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get()) as u64;
+    let chunk = ((1u64 << 32) + threads - 1) / threads;
+
+    // The first argument of each group of the argument and of the result
+    let mut firsts = [[None::<f32>; GROUPS]; GROUPS];
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|thread| {
+                let op = &op;
+                s.spawn(move || {
+                    let mut firsts = [[None; GROUPS]; GROUPS];
+                    for bits in thread * chunk..((thread + 1) * chunk).min(1 << 32) {
+                        let value = f32::from_bits(bits as u32);
+                        firsts[group(value)][group(op(value))].get_or_insert(value);
+                    }
+                    firsts
+                })
+            })
+            .collect();
+
+        // In the order of the values
+        for handle in handles {
+            let thread = handle.join().expect("Verification thread panicked");
+            for (first, value) in firsts.iter_mut().flatten().zip(thread.iter().flatten()) {
+                if first.is_none() {
+                    *first = *value;
+                }
+            }
+        }
+    });
+
+    let analyses: Vec<_> = possibilities()
+        .into_iter()
+        .map(|p| (p, analysis(&FnArgFloat::F32(p))))
+        .collect();
+
+    let mut values: Vec<f32> = firsts.iter().flatten().flatten().copied().collect();
+    values.sort_by_key(|value| value.to_bits());
+
+    let mut counterexamples = vec![];
+    for value in values {
+        let result = op(value);
+        for (p, res_p) in analyses
+            .iter()
+            .filter(|(p, _)| p.violation(value).is_none())
+        {
+            if !matches!(res_p, FnArgFloat::F32(fp) if fp.violation(result).is_none()) {
+                counterexamples.push(Counterexample {
+                    args: vec![*p],
+                    values: vec![value.into()],
+                    result: result.into(),
+                    analysis: *res_p,
+                });
+            }
+        }
+    }
+
+    counterexamples
+}
+
+/// The number of groups of `group`
+#[cfg(feature = "std")]
+const GROUPS: usize = 5 * 8;
+
+/// The values of a group have the same possibilities: same category, sign,
+/// and whether they are fractional or large
+#[cfg(feature = "std")]
+fn group(value: f32) -> usize {
+    use crate::utils::{is_fractional, is_large};
+
+    value.classify() as usize * 8
+        + usize::from(value.is_sign_negative()) * 4
+        + usize::from(is_fractional(value)) * 2
+        + usize::from(is_large(value))
+}
@@ -0,0 +1,260 @@
+//! JavaScript bindings.
+//!
+//! ```js
+//! const x = new Float(64, Possible.No, Possible.No, Possible.No, Possible.Yes, Possible.No);
+//! x.sqrt().nan; // Possible.No
+//!
+//! const y = Float.parse("f64 +:Y large:Y");
+//! evaluate("(x.abs() + y).sqrt()", ["x", "y"], [x, y]).nan; // Possible.No
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::core::ops;
+use crate::expr::{eval, parse};
+use crate::{FloatPossibilities, FnArgFloat, Possible};
+
+/// The possibilities of a float argument or result
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct Float(FnArgFloat);
+
+macro_rules! unary {
+    ($($op:ident),* $(,)?) => {
+        #[wasm_bindgen]
+        impl Float {
+            $(
+                pub fn $op(&self) -> Float {
+                    Float(ops::$op(&self.0))
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! binary {
+    ($($op:ident),* $(,)?) => {
+        #[wasm_bindgen]
+        impl Float {
+            $(
+                pub fn $op(&self, rhs: &Float) -> Result<Float, JsError> {
+                    FnArgFloat::check_same_type(&[self.0, rhs.0])?;
+
+                    Ok(Float(ops::$op(&self.0, &rhs.0)))
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! ternary {
+    ($($op:ident),* $(,)?) => {
+        #[wasm_bindgen]
+        impl Float {
+            $(
+                pub fn $op(&self, b: &Float, c: &Float) -> Result<Float, JsError> {
+                    FnArgFloat::check_same_type(&[self.0, b.0, c.0])?;
+
+                    Ok(Float(ops::$op(&self.0, &b.0, &c.0)))
+                }
+            )*
+        }
+    };
+}
+
+#[wasm_bindgen]
+impl Float {
+    /// `bits` is the width of the float: 32 or 64
+    #[wasm_bindgen(constructor)]
+    // One argument per field, as in the Rust struct
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        bits: u8,
+        nan: Possible,
+        pos_zero: Possible,
+        neg_zero: Possible,
+        subnormal: Possible,
+        fractional: Possible,
+        large: Possible,
+        infinite: Possible,
+        positive: Possible,
+        negative: Possible,
+    ) -> Result<Float, JsError> {
+        let fp = FloatPossibilities {
+            nan,
+            pos_zero,
+            neg_zero,
+            subnormal,
+            fractional,
+            large,
+            infinite,
+            positive,
+            negative,
+        };
+
+        match bits {
+            32 => Ok(Float(FnArgFloat::F32(fp))),
+            64 => Ok(Float(FnArgFloat::F64(fp))),
+            _ => Err(JsError::new("Unsupported width")),
+        }
+    }
+
+    fn possibilities(&self) -> &FloatPossibilities {
+        self.0.possibilities()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bits(&self) -> u8 {
+        self.0.bits()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn nan(&self) -> Possible {
+        self.possibilities().nan
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn zero(&self) -> Possible {
+        self.possibilities().zero()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn pos_zero(&self) -> Possible {
+        self.possibilities().pos_zero
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn neg_zero(&self) -> Possible {
+        self.possibilities().neg_zero
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn subnormal(&self) -> Possible {
+        self.possibilities().subnormal
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn fractional(&self) -> Possible {
+        self.possibilities().fractional
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn large(&self) -> Possible {
+        self.possibilities().large
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn infinite(&self) -> Possible {
+        self.possibilities().infinite
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn positive(&self) -> Possible {
+        self.possibilities().positive
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn negative(&self) -> Possible {
+        self.possibilities().negative
+    }
+
+    /// Returns true if the value is accepted
+    pub fn accept(&self, value: f64) -> bool {
+        self.possibilities().accept(value)
+    }
+
+    /// From the textual representation, like `"f64 zero:Y +:Y inf:N"`
+    pub fn parse(spec: &str) -> Result<Float, JsError> {
+        Ok(Float(spec.parse()?))
+    }
+
+    /// The textual representation, read by `parse`
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// The possibilities of the result of `expression`, written like in Rust,
+/// for the variables `names` of possibilities `args`
+#[wasm_bindgen]
+pub fn evaluate(expression: &str, names: Vec<String>, args: Vec<Float>) -> Result<Float, JsError> {
+    if names.len() != args.len() {
+        return Err(crate::Error::ArgumentCount {
+            expected: names.len(),
+            found: args.len(),
+        }
+        .into());
+    }
+
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    let args: Vec<FnArgFloat> = args.iter().map(|arg| arg.0).collect();
+
+    let expr = parse(expression, &names)?;
+    if !args.is_empty() {
+        FnArgFloat::check_same_type(&args)?;
+    }
+
+    Ok(Float(eval(&expr, &args)))
+}
+
+unary!(
+    neg,
+    abs,
+    ceil,
+    floor,
+    round,
+    round_ties_even,
+    trunc,
+    fract,
+    signum,
+    sqrt,
+    exp,
+    exp2,
+    ln,
+    log2,
+    log10,
+    to_degrees,
+    to_radians,
+    cbrt,
+    sin,
+    cos,
+    tan,
+    asin,
+    acos,
+    atan,
+    exp_m1,
+    ln_1p,
+    sinh,
+    cosh,
+    tanh,
+    asinh,
+    acosh,
+    atanh,
+    recip,
+    powi,
+    next_up,
+    next_down,
+    to_f32,
+    to_f64,
+);
+
+binary!(add);
+binary!(mul);
+binary!(div);
+binary!(rem);
+binary!(div_euclid);
+binary!(rem_euclid);
+binary!(powf);
+binary!(hypot);
+binary!(atan2);
+binary!(copysign);
+binary!(max);
+binary!(min);
+binary!(maximum);
+binary!(minimum);
+binary!(log);
+binary!(midpoint);
+
+ternary!(mul_add);
+ternary!(clamp);
@@ -0,0 +1,150 @@
+#![cfg(feature = "full")]
+
+use fn_num_types::core::ops;
+use fn_num_types::{Analyzer, Error, FloatPossibilities, FnArgFloat, Guard, Possible};
+
+const GUARDS: [Guard; 8] = [
+    Guard::NotNan,
+    Guard::Nan,
+    Guard::Finite,
+    Guard::NotFinite,
+    Guard::Positive,
+    Guard::Negative,
+    Guard::Nonzero,
+    Guard::Zero,
+];
+
+/// Whether `value` passes the guard
+fn passes(guard: Guard, value: f64) -> bool {
+    match guard {
+        Guard::NotNan => !value.is_nan(),
+        Guard::Nan => value.is_nan(),
+        Guard::Finite => value.is_finite(),
+        Guard::NotFinite => !value.is_finite(),
+        Guard::Positive => value.is_sign_positive(),
+        Guard::Negative => value.is_sign_negative(),
+        Guard::Nonzero => value != 0.0,
+        Guard::Zero => value == 0.0,
+    }
+}
+
+#[test]
+fn test_guards() {
+    let any = FnArgFloat::F64(FloatPossibilities::any());
+    let values = [
+        f64::NAN,
+        f64::NEG_INFINITY,
+        -2.5,
+        -0.0,
+        0.0,
+        1e-310,
+        1.0,
+        f64::INFINITY,
+    ];
+
+    for guard in GUARDS {
+        assert_eq!(guard.complement().complement(), guard);
+
+        for value in values {
+            let branch = if passes(guard, value) {
+                guard
+            } else {
+                guard.complement()
+            };
+            assert!(branch.narrow(&any).accept(value), "{guard:?} {value}");
+        }
+    }
+}
+
+#[test]
+fn test_assign() {
+    let mut analyzer = Analyzer::new();
+    let x = FnArgFloat::F32(FloatPossibilities::positive());
+    analyzer.bind("x", x).unwrap();
+    analyzer.assign("y", "x.sqrt() * 2.0").unwrap();
+    analyzer.assign("x", "x + y").unwrap();
+
+    let y = ops::mul(&ops::sqrt(&x), &FnArgFloat::from(2.0f32));
+    assert_eq!(analyzer.get("y"), Some(&y));
+    assert_eq!(analyzer.get("x"), Some(&ops::add(&x, &y)));
+    assert_eq!(analyzer.get("z"), None);
+    assert_eq!(
+        analyzer.vars().map(|(name, _)| name).collect::<Vec<_>>(),
+        ["x", "y"]
+    );
+
+    assert!(matches!(
+        analyzer.assign("z", "w + 1.0"),
+        Err(Error::Parse(_))
+    ));
+    assert_eq!(
+        analyzer.bind("w", FnArgFloat::from(1.0)),
+        Err(Error::DifferentTypes)
+    );
+    // Rebinding the only variable may change its type
+    let mut single = Analyzer::new();
+    single.bind("x", x).unwrap();
+    single.bind("x", FnArgFloat::from(1.0)).unwrap();
+}
+
+#[test]
+fn test_branches() {
+    let mut analyzer = Analyzer::new();
+    analyzer
+        .bind("x", FnArgFloat::F64(FloatPossibilities::any()))
+        .unwrap();
+
+    // let y = if x.is_nan() { 0.0 } else { x.abs() };
+    let (mut then, mut otherwise) = analyzer.fork("x", Guard::Nan);
+    then.assign("y", "0.0").unwrap();
+    otherwise.assign("y", "x.abs()").unwrap();
+    let merged = then.merge(&otherwise).unwrap();
+
+    let y = merged.get("y").unwrap().possibilities();
+    assert_eq!(y.nan, Possible::No);
+    assert_eq!(y.negative, Possible::No);
+    assert_eq!(merged.get("x"), analyzer.get("x"));
+
+    // Defined in one branch only
+    let mut otherwise = otherwise.clone();
+    otherwise.assign("z", "x").unwrap();
+    assert_eq!(then.merge(&otherwise).unwrap().get("z"), None);
+}
+
+#[test]
+fn test_unreachable() {
+    let mut analyzer = Analyzer::new();
+    analyzer
+        .bind("x", FnArgFloat::F64(FloatPossibilities::finite()))
+        .unwrap();
+    analyzer.assign("y", "x * 2.0").unwrap();
+
+    let (then, otherwise) = analyzer.fork("x", Guard::Finite);
+    assert!(then.is_reachable());
+    assert!(!otherwise.is_reachable());
+    assert_eq!(then.merge(&otherwise), Ok(then.clone()));
+    assert_eq!(otherwise.merge(&then), Ok(then.clone()));
+
+    let mut dead = analyzer.clone();
+    dead.assume("x", Guard::Nan);
+    assert!(!dead.is_reachable());
+}
+
+#[test]
+fn test_display() {
+    let mut analyzer = Analyzer::new();
+    analyzer.bind("b", FnArgFloat::from(1.0)).unwrap();
+    analyzer.bind("a", FnArgFloat::from(-0.0)).unwrap();
+
+    let text = analyzer.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("a: f64"));
+    assert!(lines[1].starts_with("b: f64"));
+}
+
+#[test]
+#[should_panic(expected = "Unknown variable")]
+fn test_unknown_variable() {
+    Analyzer::new().fork("x", Guard::Zero);
+}
@@ -0,0 +1,467 @@
+use fn_num_types::{fp, Field, FloatPossibilities, FloatType, FnArgFloat, Possible};
+
+const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+
+fn get_possibilities() -> Vec<FloatPossibilities> {
+    FloatPossibilities::enumerate(&YESNO).collect()
+}
+
+const VALUES: [f64; 10] = [
+    f64::NAN,
+    -f64::NAN,
+    f64::NEG_INFINITY,
+    f64::MIN,
+    -1.0,
+    -0.0,
+    0.0,
+    1.0,
+    f64::MAX,
+    f64::INFINITY,
+];
+
+type Assume = fn(&FloatPossibilities) -> FloatPossibilities;
+
+/// The guard, the narrowing when it is true and when it is false
+type Guard = (&'static str, fn(f64) -> bool, Assume, Assume);
+
+#[test]
+fn test_assume() {
+    let guards: [Guard; 4] = [
+        (
+            "is_nan",
+            f64::is_nan,
+            FloatPossibilities::assume_nan,
+            FloatPossibilities::assume_not_nan,
+        ),
+        (
+            "is_finite",
+            f64::is_finite,
+            FloatPossibilities::assume_finite,
+            FloatPossibilities::assume_not_finite,
+        ),
+        (
+            "is_sign_positive",
+            f64::is_sign_positive,
+            FloatPossibilities::assume_positive,
+            FloatPossibilities::assume_negative,
+        ),
+        (
+            "== 0.0",
+            |x| x == 0.0,
+            FloatPossibilities::assume_zero,
+            FloatPossibilities::assume_nonzero,
+        ),
+    ];
+
+    for p in get_possibilities() {
+        for (name, guard, then, otherwise) in guards {
+            let (narrowed, complement) = (then(&p), otherwise(&p));
+
+            for v in VALUES.iter().filter(|v| p.accept(**v)) {
+                if guard(*v) {
+                    assert!(narrowed.accept(*v), "{name}({v:?}): {narrowed}");
+                } else {
+                    assert!(complement.accept(*v), "!{name}({v:?}): {complement}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_assume_arg() {
+    let x = FnArgFloat::F32(FloatPossibilities {
+        nan: Possible::Yes,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::Yes,
+        positive: Possible::Yes,
+        negative: Possible::Yes,
+    });
+
+    // if x > 0.0
+    let positive = x.assume_not_nan().assume_nonzero().assume_positive();
+    assert_eq!(
+        positive,
+        FnArgFloat::F32(FloatPossibilities {
+            nan: Possible::No,
+            pos_zero: Possible::No,
+            neg_zero: Possible::No,
+            subnormal: Possible::Yes,
+            fractional: Possible::Yes,
+            large: Possible::Yes,
+            infinite: Possible::Yes,
+            positive: Possible::Yes,
+            negative: Possible::No,
+        })
+    );
+
+    let not_finite = x.assume_not_finite();
+    assert_eq!(not_finite.possibilities().zero(), Possible::No);
+    assert_eq!(not_finite.possibilities().infinite, Possible::Yes);
+}
+
+#[test]
+fn test_canonicalize() {
+    for p in get_possibilities() {
+        let canonical = p.canonicalize();
+
+        assert!(canonical.is_subset_of(&p));
+        assert_eq!(canonical.canonicalize(), canonical);
+        assert_eq!(
+            p.is_satisfiable(),
+            VALUES.iter().any(|v| p.accept(*v)),
+            "{p}"
+        );
+
+        for v in VALUES {
+            assert_eq!(canonical.accept(v), p.accept(v), "{v:?}: {p}");
+        }
+    }
+}
+
+#[test]
+fn test_complement_difference() {
+    for p in get_possibilities() {
+        let complement = p.complement();
+
+        for v in VALUES {
+            if !p.accept(v) {
+                assert!(complement.accept(v), "{v:?}: {p} => {complement}");
+            }
+        }
+
+        for q in get_possibilities() {
+            let difference = p.difference(&q);
+
+            for v in VALUES.iter().filter(|v| p.accept(**v) && !q.accept(**v)) {
+                assert!(difference.accept(*v), "{v:?}: {p} - {q}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_from_value() {
+    for v in VALUES.into_iter().chain([0.5, -1.5, 2.0, 1.0e-310]) {
+        let fp = FloatPossibilities::from(v);
+
+        assert!(fp.accept(v), "{v:?}: {fp}");
+        assert_eq!(fp.canonicalize(), fp, "{v:?}");
+
+        // Only the values with fewer flags, like `-1.0` for `-inf`
+        for other in VALUES.into_iter().filter(|other| fp.accept(*other)) {
+            assert!(
+                FloatPossibilities::from(other).is_subset_of(&fp),
+                "{v:?} accepts {other:?}"
+            );
+        }
+    }
+
+    // Normal in `f64`
+    let subnormal = FloatPossibilities::from(1.0e-40f32);
+    assert_eq!(subnormal.subnormal, Possible::Yes);
+    assert_eq!(FloatPossibilities::from(1.0e-40f64).subnormal, Possible::No);
+
+    // Like the same value in `f64`, but for the subnormals of `f32`
+    for bits in (0..=u32::MAX).step_by(4099) {
+        let v = f32::from_bits(bits);
+        let fp = FloatPossibilities::from(v);
+
+        assert!(fp.accept_f32(v), "{v:?}: {fp}");
+        if v.is_subnormal() {
+            assert_eq!(fp.subnormal, Possible::Yes, "{v:?}");
+        } else {
+            assert_eq!(fp, FloatPossibilities::from(f64::from(v)), "{v:?}");
+        }
+    }
+
+    assert_eq!(
+        FnArgFloat::from(1.0f32),
+        FnArgFloat::F32(FloatPossibilities::from(1.0))
+    );
+}
+
+#[test]
+fn test_named() {
+    type Named = (&'static str, FloatPossibilities, fn(f64) -> bool);
+
+    let named: [Named; 14] = [
+        ("any", FloatPossibilities::any(), |_| true),
+        ("nan", FloatPossibilities::nan(), f64::is_nan),
+        ("non_nan", FloatPossibilities::non_nan(), |v| !v.is_nan()),
+        ("finite", FloatPossibilities::finite(), f64::is_finite),
+        (
+            "non_zero_non_nan",
+            FloatPossibilities::non_zero_non_nan(),
+            |v| !v.is_nan() && v != 0.0,
+        ),
+        (
+            "non_zero_non_nan_finite",
+            FloatPossibilities::non_zero_non_nan_finite(),
+            |v| v.is_finite() && v != 0.0,
+        ),
+        ("positive", FloatPossibilities::positive(), |v| {
+            !v.is_nan() && v.is_sign_positive()
+        }),
+        ("negative", FloatPossibilities::negative(), |v| {
+            !v.is_nan() && v.is_sign_negative()
+        }),
+        (
+            "positive_finite",
+            FloatPossibilities::positive_finite(),
+            |v| v.is_finite() && v.is_sign_positive(),
+        ),
+        (
+            "negative_finite",
+            FloatPossibilities::negative_finite(),
+            |v| v.is_finite() && v.is_sign_negative(),
+        ),
+        (
+            "strictly_positive",
+            FloatPossibilities::strictly_positive(),
+            |v| v > 0.0,
+        ),
+        (
+            "strictly_negative",
+            FloatPossibilities::strictly_negative(),
+            |v| v < 0.0,
+        ),
+        (
+            "strictly_positive_finite",
+            FloatPossibilities::strictly_positive_finite(),
+            |v| v.is_finite() && v > 0.0,
+        ),
+        (
+            "strictly_negative_finite",
+            FloatPossibilities::strictly_negative_finite(),
+            |v| v.is_finite() && v < 0.0,
+        ),
+    ];
+
+    for (name, fp, expected) in named {
+        assert_eq!(fp.canonicalize(), fp, "{name}");
+
+        for v in VALUES.into_iter().chain([0.5, -1.5, 1.0e-310, -1.0e-310]) {
+            assert_eq!(fp.accept(v), expected(v), "{name}: {v:?}");
+        }
+    }
+
+    assert_eq!(FloatPossibilities::any(), FloatPossibilities::default());
+}
+
+#[test]
+fn test_setters() {
+    for p in get_possibilities().into_iter().step_by(7) {
+        for field in Field::ALL {
+            for possible in [Possible::No, Possible::ShouldNot, Possible::Should] {
+                let q = p.with(field, possible);
+
+                assert_eq!(q.get(field), possible);
+
+                for other in Field::ALL.into_iter().filter(|other| *other != field) {
+                    assert_eq!(q.get(other), p.get(other));
+                }
+            }
+        }
+    }
+
+    assert_eq!(
+        FloatPossibilities::any()
+            .with_nan(Possible::No)
+            .with_infinite(Possible::No),
+        FloatPossibilities::finite()
+    );
+}
+
+#[test]
+fn test_fp_macro() {
+    assert_eq!(fp! {}, FloatPossibilities::any());
+    assert_eq!(fp! { nan: no, infinite: no }, FloatPossibilities::finite());
+    assert_eq!(
+        fp! { nan: yes, zero: no, subnormal: no, fractional: no, large: no, infinite: no, positive: no, negative: no },
+        FloatPossibilities::nan()
+    );
+    assert_eq!(
+        fp! { pos_zero: should, neg_zero: should_not, },
+        FloatPossibilities::any()
+            .with_pos_zero(Possible::Should)
+            .with_neg_zero(Possible::ShouldNot)
+    );
+}
+
+#[test]
+fn test_accept_f32() {
+    let values = [
+        f32::NAN,
+        f32::NEG_INFINITY,
+        f32::MIN,
+        -1.0,
+        -f32::from_bits(1),
+        -0.0,
+        0.0,
+        f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        0.5,
+        1.5,
+        f32::MAX,
+        f32::INFINITY,
+    ];
+
+    for p in get_possibilities() {
+        for v in values {
+            // Only the subnormals of `f32` are classified differently
+            if !v.is_subnormal() {
+                assert_eq!(p.accept_f32(v), p.accept(f64::from(v)), "{v:?}: {p}");
+            }
+
+            assert_eq!(FnArgFloat::F32(p).accept(v), p.accept_f32(v));
+            assert!(!FnArgFloat::F64(p).accept(v));
+            assert!(!FnArgFloat::F32(p).accept(f64::from(v)));
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_accept_range() {
+    let bounds = [
+        f64::NEG_INFINITY,
+        f64::MIN,
+        -2.0,
+        -1.0,
+        -0.5,
+        -f64::MIN_POSITIVE,
+        -0.0,
+        0.0,
+        1.0e-310,
+        0.5,
+        1.0,
+        1.5,
+        2.0,
+        f64::MAX,
+        f64::INFINITY,
+    ];
+    let samples: Vec<f64> = VALUES
+        .into_iter()
+        .chain(bounds)
+        .chain([-2.5, -1.5, -0.75, -1.0e-310, 0.25, 0.75, 2.5, 3.0, 1.0e300])
+        .collect();
+
+    for p in get_possibilities() {
+        assert!(p.accept_all([]));
+        assert_eq!(
+            p.accept_all(samples.iter().copied()),
+            samples.iter().all(|v| p.accept(*v))
+        );
+
+        for start in bounds {
+            for end in bounds.into_iter().filter(|end| start <= *end) {
+                let accepted = p.accept_range(start..=end);
+
+                let mut in_range = samples.iter().filter(|v| start <= **v && **v <= end);
+                if accepted {
+                    assert!(in_range.all(|v| p.accept(*v)), "{start:?}..={end:?}: {p}");
+                } else {
+                    assert!(in_range.any(|v| !p.accept(*v)), "{start:?}..={end:?}: {p}");
+                }
+            }
+        }
+    }
+
+    assert!(FloatPossibilities::nan().accept_range(1.0..=0.0));
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::HashMap;
+
+    let possibilities = get_possibilities();
+    let mut canonical: HashMap<FnArgFloat, FloatPossibilities> = HashMap::new();
+
+    for p in possibilities.iter() {
+        canonical.insert(FnArgFloat::F64(*p), p.canonicalize());
+        canonical.insert(FnArgFloat::F32(*p), p.canonicalize());
+    }
+
+    assert_eq!(canonical.len(), 2 * possibilities.len());
+    assert_eq!(
+        canonical[&FnArgFloat::F64(FloatPossibilities::finite())],
+        FloatPossibilities::finite()
+    );
+}
+
+#[test]
+fn test_bits() {
+    let levels = [
+        Possible::No,
+        Possible::ShouldNot,
+        Possible::Should,
+        Possible::Yes,
+    ];
+
+    for p in get_possibilities() {
+        for level in levels {
+            let q = p.with_large(level).with_nan(level);
+            assert_eq!(FloatPossibilities::from_bits(q.to_bits()), Some(q));
+        }
+    }
+
+    // Every encoding is valid
+    for bits in (0..1 << 18).step_by(97) {
+        assert_eq!(FloatPossibilities::from_bits(bits).unwrap().to_bits(), bits);
+    }
+
+    assert_eq!(FloatPossibilities::nan().to_bits(), 0b11);
+    assert_eq!(FloatPossibilities::from_bits(u32::MAX), None);
+}
+
+#[test]
+fn test_const() {
+    const POSITIVE: FloatPossibilities = FloatPossibilities::strictly_positive_finite()
+        .union(&FloatPossibilities::nan())
+        .intersection(&FloatPossibilities::positive().with(Field::Nan, Possible::ShouldNot));
+    const _: () = assert!(POSITIVE.is_satisfiable());
+
+    assert_eq!(POSITIVE.nan, Possible::ShouldNot);
+    assert_eq!(POSITIVE.zero(), Possible::No);
+
+    for p in get_possibilities() {
+        for q in get_possibilities().into_iter().step_by(13) {
+            for field in Field::ALL {
+                assert_eq!(p.union(&q).get(field), p.get(field) | q.get(field));
+                assert_eq!(p.intersection(&q).get(field), p.get(field) & q.get(field));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tightest_type() {
+    for p in get_possibilities() {
+        let ty = p.tightest_type();
+        if !p.is_satisfiable() {
+            assert_eq!(ty, FloatType::Never);
+            continue;
+        }
+        assert!(p.is_subset_of(&ty.possibilities()), "{p} => {ty}");
+
+        for other in FloatType::ALL {
+            if other.possibilities().is_subset_of(&ty.possibilities()) && other != ty {
+                assert!(
+                    !p.is_subset_of(&other.possibilities()),
+                    "{p} => {ty}, not {other}"
+                );
+            }
+        }
+    }
+
+    assert_eq!(
+        FloatType::Never.possibilities().tightest_type(),
+        FloatType::Never
+    );
+    assert_eq!(FloatType::NonNaNFinite.to_string(), "NonNaNFinite");
+}
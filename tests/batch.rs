@@ -0,0 +1,54 @@
+#![cfg(feature = "arith")]
+
+use fn_num_types::core::ops::{self, apply_binary_batch, apply_unary_batch};
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+
+const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+
+/// More possibilities than memoized, of both types, repeated
+fn inputs() -> Vec<FnArgFloat> {
+    let f64s = FloatPossibilities::enumerate(&YESNO).map(FnArgFloat::F64);
+    let f32s = FloatPossibilities::enumerate(&YESNO).map(FnArgFloat::F32);
+    let once: Vec<FnArgFloat> = f64s.chain(f32s).collect();
+
+    once.iter().chain(once.iter().rev()).copied().collect()
+}
+
+#[test]
+fn test_unary_batch() {
+    let inputs = inputs();
+    let mut out = vec![FnArgFloat::f64_any(); inputs.len()];
+
+    for op in [ops::sqrt, ops::neg, ops::recip, ops::floor] {
+        apply_unary_batch(op, &inputs, &mut out);
+        for (x, res) in inputs.iter().zip(&out) {
+            assert_eq!(*res, op(x), "{x:?}");
+        }
+    }
+}
+
+#[test]
+fn test_binary_batch() {
+    let lhs = inputs();
+    // Of the same type, in another order
+    let rhs: Vec<FnArgFloat> = lhs
+        .chunks(512)
+        .flat_map(|chunk| chunk.iter().rev())
+        .copied()
+        .collect();
+    let mut out = vec![FnArgFloat::f64_any(); lhs.len()];
+
+    for op in [ops::add, ops::mul, ops::div, ops::powf] {
+        apply_binary_batch(op, &lhs, &rhs, &mut out);
+        for ((x, y), res) in lhs.iter().zip(&rhs).zip(&out) {
+            assert_eq!(*res, op(x, y), "{x:?} {y:?}");
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Different lengths")]
+fn test_lengths() {
+    let mut out = [FnArgFloat::f64_any(); 1];
+    apply_unary_batch(ops::sqrt, &[], &mut out);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "integer")]
+
+use fn_num_types::core::ops::{from_bits, to_bits};
+use fn_num_types::{FloatPossibilities, FnArgFloat, FnArgInt, IntPossibilities, Possible};
+
+const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+
+const BITS: &[u64] = &[
+    0,
+    1,
+    0x000F_FFFF_FFFF_FFFF,
+    0x3FF0_0000_0000_0000,
+    0x3FF8_0000_0000_0000,
+    0x7FEF_FFFF_FFFF_FFFF,
+    0x7FF0_0000_0000_0000,
+    0x7FF0_0000_0000_0001,
+    0x7FF8_0000_0000_0000,
+    0x8000_0000_0000_0000,
+    0xBFF0_0000_0000_0000,
+    0xFFF0_0000_0000_0000,
+    0xFFF8_0000_0000_0000,
+    u64::MAX,
+];
+
+#[test]
+fn test_to_bits() {
+    for fp in FloatPossibilities::enumerate(&YESNO) {
+        let bits64 = to_bits(&FnArgFloat::F64(fp));
+        let bits32 = to_bits(&FnArgFloat::F32(fp));
+
+        for bits in BITS {
+            let x = f64::from_bits(*bits);
+            if fp.accept(x) {
+                assert!(bits64.possibilities().accept(*bits), "{fp:?} {x}");
+            }
+
+            let bits = (*bits >> 32) as u32 | (*bits as u32 & 1);
+            let x = f32::from_bits(bits);
+            if fp.accept_f32(x) {
+                assert!(bits32.possibilities().accept(bits), "{fp:?} {x}");
+            }
+        }
+    }
+
+    // Only `+0.0` is zero, only a NaN all ones
+    let finite = to_bits(&FnArgFloat::F64(FloatPossibilities::finite()));
+    assert!(matches!(finite, FnArgInt::U64(_)));
+    assert_eq!(finite.possibilities().max_value, Possible::No);
+    let non_zero = to_bits(&FnArgFloat::F32(FloatPossibilities::non_zero_non_nan()));
+    assert_eq!(non_zero.possibilities().zero, Possible::No);
+}
+
+#[test]
+fn test_from_bits() {
+    for ip in BITS.iter().map(|bits| IntPossibilities::from_value(*bits)) {
+        let x = from_bits(&FnArgInt::U64(ip));
+
+        for bits in BITS.iter().filter(|bits| ip.accept(**bits)) {
+            assert!(x.accept(f64::from_bits(*bits)), "{bits:x}");
+        }
+    }
+
+    let x = from_bits(&FnArgInt::U32(IntPossibilities::from_value(0x7FC0_0000u32)));
+    assert!(matches!(x, FnArgFloat::F32(_)));
+    assert!(x.accept(f32::NAN));
+    assert_eq!(x.possibilities().pos_zero, Possible::No);
+
+    // Round trip
+    let fp = FloatPossibilities::positive_finite();
+    let x = from_bits(&to_bits(&FnArgFloat::F64(fp)));
+    assert!(fp.is_subset_of(x.possibilities()));
+}
+
+#[test]
+#[should_panic(expected = "Not the bits of a float")]
+fn test_from_bits_signed() {
+    from_bits(&FnArgInt::I64(IntPossibilities::from_value(0i64)));
+}
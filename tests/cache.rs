@@ -0,0 +1,53 @@
+#![cfg(all(feature = "std", feature = "full"))]
+
+use fn_num_types::core::ops::{apply, op_names};
+use fn_num_types::verify::possibilities;
+use fn_num_types::{AnalysisCache, Error, FloatPossibilities, FnArgFloat};
+
+#[test]
+fn test_cached_apply() {
+    let mut cache = AnalysisCache::new();
+    assert!(cache.is_empty());
+
+    for p in possibilities().into_iter().step_by(7) {
+        for x in [FnArgFloat::F32(p), FnArgFloat::F64(p)] {
+            for (name, arity) in op_names() {
+                let args = vec![x; arity.unwrap_or(2)];
+                assert_eq!(cache.cached_apply(name, &args), apply(name, &args));
+                // From the cache
+                assert_eq!(cache.cached_apply(name, &args), apply(name, &args));
+            }
+        }
+    }
+
+    // The same possibilities of other types are different keys
+    let len = cache.len();
+    let any = FloatPossibilities::any();
+    cache.cached_apply("sqrt", &[FnArgFloat::F32(any)]).unwrap();
+    cache.cached_apply("sqrt", &[FnArgFloat::F64(any)]).unwrap();
+    assert_eq!(cache.len(), len);
+
+    // Nor the number of arguments
+    let x = FnArgFloat::F64(any);
+    assert_eq!(cache.cached_apply("sum", &[x]), apply("sum", &[x]));
+    assert_eq!(
+        cache.cached_apply("sum", &[x, x, x]),
+        apply("sum", &[x, x, x])
+    );
+    assert_eq!(cache.cached_apply("sum", &[x; 5]), apply("sum", &[x; 5]));
+
+    // The errors are not kept
+    let len = cache.len();
+    assert_eq!(
+        cache.cached_apply("unknown", &[x]),
+        Err(Error::UnknownOp("unknown".to_owned()))
+    );
+    assert_eq!(
+        cache.cached_apply("add", &[x, FnArgFloat::F32(any)]),
+        Err(Error::DifferentTypes)
+    );
+    assert_eq!(cache.len(), len);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,282 @@
+#![cfg(feature = "casts")]
+
+use fn_num_types::core::ops::{
+    cast_to_int, int_to_f32, int_to_f64, is_to_int_unchecked_safe, to_f32, to_f64,
+};
+use fn_num_types::{
+    FloatPossibilities, FnArgFloat, FnArgInt, IntKind, IntPossibilities, IntValue, Possible,
+};
+
+const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+
+fn get_possibilities() -> Vec<FloatPossibilities> {
+    FloatPossibilities::enumerate(&YESNO).collect()
+}
+
+fn get_int_possibilities() -> Vec<IntPossibilities> {
+    let mut possibles = vec![];
+
+    for zero in YESNO {
+        for positive in YESNO {
+            for negative in YESNO {
+                for min_value in YESNO {
+                    for max_value in YESNO {
+                        possibles.push(IntPossibilities {
+                            zero,
+                            positive,
+                            negative,
+                            min_value,
+                            max_value,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    possibles
+}
+
+macro_rules! get_test_values {
+    ($float:ident) => {
+        [
+            $float::NAN,
+            $float::NEG_INFINITY,
+            $float::MIN,
+            -1.0e30,
+            -1.0e10,
+            -129.0,
+            -128.5,
+            -1.5,
+            -0.5,
+            -$float::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            $float::MIN_POSITIVE,
+            0.5,
+            1.0,
+            127.5,
+            255.5,
+            256.0,
+            1.0e10,
+            1.0e30,
+            $float::MAX,
+            $float::INFINITY,
+        ]
+    };
+}
+
+macro_rules! test_cast {
+    ($float:ident, $mod:ident, $($int:ident),*) => {
+        for p in get_possibilities() {
+            let arg = FnArgFloat::$mod(p);
+
+            $(
+                let ip = cast_to_int(&arg, <$int as IntValue>::KIND);
+
+                for v in get_test_values!($float).iter().filter(|v| p.accept(**v as f64)) {
+                    let result = *v as $int;
+                    assert!(
+                        ip.accept(result),
+                        "{v:?} as {} = {result}: {p} => {ip:?}",
+                        stringify!($int)
+                    );
+                }
+            )*
+        }
+    };
+}
+
+#[test]
+fn test_cast_to_int() {
+    test_cast!(f32, F32, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+    test_cast!(f64, F64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+}
+
+#[test]
+fn test_f32_to_u128() {
+    let finite = FnArgFloat::F32(FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::No,
+        positive: Possible::Yes,
+        negative: Possible::No,
+    });
+
+    // `f32::MAX` is below `u128::MAX`
+    assert_eq!(cast_to_int(&finite, IntKind::U128).max_value, Possible::No);
+    assert_eq!(cast_to_int(&finite, IntKind::U64).max_value, Possible::Yes);
+}
+
+#[test]
+fn test_integral_to_int() {
+    let integral = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        large: Possible::Yes,
+        infinite: Possible::No,
+        positive: Possible::Yes,
+        negative: Possible::No,
+    });
+
+    // Nothing is truncated to zero
+    assert_eq!(cast_to_int(&integral, IntKind::I64).zero, Possible::No);
+}
+
+#[test]
+fn test_is_to_int_unchecked_safe() {
+    for p in get_possibilities() {
+        let safe = is_to_int_unchecked_safe(&FnArgFloat::F64(p));
+
+        if safe {
+            for v in get_test_values!(f64) {
+                assert!(!p.accept(v), "{v:?} is accepted by {p}");
+            }
+        }
+    }
+
+    let none = FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        large: Possible::No,
+        infinite: Possible::No,
+        positive: Possible::No,
+        negative: Possible::No,
+    };
+    assert!(is_to_int_unchecked_safe(&FnArgFloat::F64(none)));
+}
+
+macro_rules! test_int_to_float {
+    ($($int:ident => $mod:ident),*) => {
+        $(
+            let values = [
+                $int::MIN,
+                $int::MIN + 1,
+                $int::MIN / 2,
+                0,
+                1,
+                $int::MAX / 2,
+                $int::MAX - 1,
+                $int::MAX,
+            ];
+
+            for p in get_int_possibilities() {
+                let arg = FnArgInt::$mod(p);
+
+                for v in values.iter().filter(|v| p.accept(**v)) {
+                    match int_to_f32(&arg) {
+                        FnArgFloat::F32(fp) => assert!(
+                            fp.accept((*v as f32).into()),
+                            "{v} as f32: {p:?} => {fp}"
+                        ),
+                        _ => panic!("Invalid result"),
+                    }
+
+                    match int_to_f64(&arg) {
+                        FnArgFloat::F64(fp) => {
+                            assert!(fp.accept(*v as f64), "{v} as f64: {p:?} => {fp}")
+                        }
+                        _ => panic!("Invalid result"),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[test]
+fn test_int_to_float() {
+    test_int_to_float!(
+        i8 => I8,
+        i16 => I16,
+        i32 => I32,
+        i64 => I64,
+        i128 => I128,
+        u8 => U8,
+        u16 => U16,
+        u32 => U32,
+        u64 => U64,
+        u128 => U128
+    );
+
+    // Never NaN nor infinite below `u128`
+    match int_to_f32(&FnArgInt::U64(IntPossibilities::from_value(u64::MAX))) {
+        FnArgFloat::F32(fp) => {
+            assert_eq!(fp.nan, Possible::No);
+            assert_eq!(fp.infinite, Possible::No);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_to_f32() {
+    let mut values = get_test_values!(f64).to_vec();
+    // Subnormal in `f64`, zero in `f32`
+    values.extend([1.0e-300, -1.0e-300, 1.0e-40, f32::MAX as f64 * 2.0]);
+
+    for p in get_possibilities() {
+        let res = match to_f32(&FnArgFloat::F64(p)) {
+            FnArgFloat::F32(res) => res,
+            _ => panic!("Invalid result"),
+        };
+
+        for v in values.iter().filter(|v| p.accept(**v)) {
+            let result = *v as f32;
+            assert!(
+                res.accept(result.into()),
+                "{v:?} as f32 = {result:?}: {p} => {res}"
+            );
+        }
+
+        assert_eq!(to_f32(&FnArgFloat::F32(p)), FnArgFloat::F32(p));
+    }
+}
+
+#[test]
+fn test_to_f64() {
+    for p in get_possibilities() {
+        // The subnormals of `f32` are normal in `f64`
+        let widened = FloatPossibilities {
+            subnormal: Possible::No,
+            ..p
+        };
+        assert_eq!(to_f64(&FnArgFloat::F32(p)), FnArgFloat::F64(widened));
+        assert_eq!(to_f64(&FnArgFloat::F64(p)), FnArgFloat::F64(p));
+
+        for v in get_test_values!(f32)
+            .iter()
+            .filter(|v| p.accept(f64::from(**v)))
+        {
+            assert!(
+                widened.accept(f64::from(*v)),
+                "{v:?} as f64: {p} => {widened}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "arith")]
+#[test]
+fn test_promote() {
+    use fn_num_types::core::ops::{add, add_promoting, promote};
+
+    for p in get_possibilities() {
+        let (x, y) = (FnArgFloat::F32(p), FnArgFloat::F64(p));
+
+        assert_eq!(promote(&x, &y), (to_f64(&x), y));
+        assert_eq!(promote(&y, &x), (y, to_f64(&x)));
+        assert_eq!(promote(&x, &x), (x, x));
+        assert_eq!(add_promoting(&x, &y), add(&to_f64(&x), &y));
+    }
+}
@@ -0,0 +1,45 @@
+use fn_num_types::{changelog, revisions, Field, FloatPossibilities, Possible};
+
+#[test]
+fn test_changelog() {
+    for revision in changelog() {
+        assert!(!revision.version.is_empty());
+        assert!(!revision.rationale.is_empty());
+        assert_ne!(revision.old, revision.new, "{revision:?}");
+        assert!(revisions(revision.op).any(|r| r == revision));
+    }
+
+    assert_eq!(revisions("unknown").count(), 0);
+}
+
+#[test]
+fn test_field() {
+    let fp = FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::ShouldNot,
+        neg_zero: Possible::ShouldNot,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::Should,
+        positive: Possible::Yes,
+        negative: Possible::No,
+    };
+
+    let values: Vec<Possible> = Field::ALL.iter().map(|field| fp.get(*field)).collect();
+
+    assert_eq!(
+        values,
+        [
+            Possible::No,
+            Possible::ShouldNot,
+            Possible::ShouldNot,
+            Possible::Yes,
+            Possible::Yes,
+            Possible::Yes,
+            Possible::Should,
+            Possible::Yes,
+            Possible::No
+        ]
+    );
+}
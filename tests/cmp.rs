@@ -0,0 +1,132 @@
+use std::cmp::Ordering;
+
+use fn_num_types::core::ops::cmp;
+use fn_num_types::{BoolPossibility, FloatPossibilities, FnArgFloat, Possible};
+
+const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+
+fn get_possibilities() -> Vec<FloatPossibilities> {
+    FloatPossibilities::enumerate(&YESNO).collect()
+}
+
+const VALUES: [f64; 13] = [
+    f64::NAN,
+    f64::NEG_INFINITY,
+    f64::MIN,
+    -2.0,
+    -1.0,
+    -0.0,
+    0.0,
+    1.0e-310,
+    1.0,
+    2.0,
+    f64::MAX,
+    f64::INFINITY,
+    -f64::NAN,
+];
+
+type Comparison = (
+    &'static str,
+    fn(&FnArgFloat, &FnArgFloat) -> BoolPossibility,
+    fn(&f64, &f64) -> bool,
+);
+
+#[test]
+fn test_comparisons() {
+    let comparisons: [Comparison; 6] = [
+        ("eq", cmp::eq, f64::eq),
+        ("ne", cmp::ne, f64::ne),
+        ("lt", cmp::lt, f64::lt),
+        ("le", cmp::le, f64::le),
+        ("gt", cmp::gt, f64::gt),
+        ("ge", cmp::ge, f64::ge),
+    ];
+
+    for p1 in get_possibilities() {
+        for p2 in get_possibilities() {
+            let (x, y) = (FnArgFloat::F64(p1), FnArgFloat::F64(p2));
+            let ordering = cmp::partial_cmp(&x, &y);
+            let results: Vec<_> = comparisons.iter().map(|(_, op, _)| op(&x, &y)).collect();
+
+            for v1 in VALUES.iter().filter(|v| p1.accept(**v)) {
+                for v2 in VALUES.iter().filter(|v| p2.accept(**v)) {
+                    let possible = match v1.partial_cmp(v2) {
+                        None => ordering.none,
+                        Some(Ordering::Less) => ordering.ordering.less,
+                        Some(Ordering::Equal) => ordering.ordering.equal,
+                        Some(Ordering::Greater) => ordering.ordering.greater,
+                    };
+                    assert_ne!(possible, Possible::No, "{v1:?} <=> {v2:?}: {ordering:?}");
+
+                    for ((name, _, f), res) in comparisons.iter().zip(&results) {
+                        assert!(res.accept(f(v1, v2)), "{name}({v1:?}, {v2:?}): {res:?}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_total_cmp() {
+    for p1 in get_possibilities() {
+        for p2 in get_possibilities() {
+            let ordering = cmp::total_cmp(&FnArgFloat::F64(p1), &FnArgFloat::F64(p2));
+
+            for v1 in VALUES.iter().filter(|v| p1.accept(**v)) {
+                for v2 in VALUES.iter().filter(|v| p2.accept(**v)) {
+                    let possible = match v1.total_cmp(v2) {
+                        Ordering::Less => ordering.less,
+                        Ordering::Equal => ordering.equal,
+                        Ordering::Greater => ordering.greater,
+                    };
+                    assert_ne!(possible, Possible::No, "{v1:?} <=> {v2:?}: {ordering:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pruning() {
+    let positive = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::No,
+        positive: Possible::Yes,
+        negative: Possible::No,
+    });
+    let negative = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::Yes,
+        positive: Possible::No,
+        negative: Possible::Yes,
+    });
+
+    assert_eq!(cmp::lt(&negative, &positive), BoolPossibility::True);
+    assert_eq!(cmp::ge(&negative, &positive), BoolPossibility::False);
+    assert_eq!(cmp::eq(&positive, &positive), BoolPossibility::Maybe);
+    assert_eq!(cmp::ne(&negative, &positive), BoolPossibility::True);
+
+    let ordering = cmp::total_cmp(&negative, &positive);
+    assert_eq!(ordering.less, Possible::Yes);
+    assert_eq!(ordering.equal, Possible::No);
+    assert_eq!(ordering.greater, Possible::No);
+}
+
+#[test]
+#[should_panic(expected = "Different types")]
+fn test_different_types() {
+    let fp = FloatPossibilities::default();
+
+    cmp::eq(&FnArgFloat::F32(fp), &FnArgFloat::F64(fp));
+}
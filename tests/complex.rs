@@ -0,0 +1,112 @@
+#![cfg(feature = "complex")]
+
+use fn_num_types::core::ops::complex::{add, arg, mul, norm, sqrt};
+use fn_num_types::{ComplexPossibilities, FloatPossibilities, FnArgComplex, Possible};
+use num_complex::Complex;
+
+const VALUES: &[f64] = &[
+    f64::NAN,
+    f64::NEG_INFINITY,
+    -1e300,
+    -2.0,
+    -0.5,
+    -0.0,
+    0.0,
+    1e-310,
+    0.5,
+    1.0,
+    3.0,
+    1e300,
+    f64::INFINITY,
+];
+
+fn complexes() -> impl Iterator<Item = Complex<f64>> {
+    VALUES
+        .iter()
+        .flat_map(|re| VALUES.iter().map(move |im| Complex::new(*re, *im)))
+}
+
+fn c64(z: Complex<f64>) -> FnArgComplex {
+    FnArgComplex::C64(ComplexPossibilities {
+        re: FloatPossibilities::from(z.re),
+        im: FloatPossibilities::from(z.im),
+    })
+}
+
+fn c32(z: Complex<f32>) -> FnArgComplex {
+    FnArgComplex::C32(ComplexPossibilities {
+        re: FloatPossibilities::from(z.re),
+        im: FloatPossibilities::from(z.im),
+    })
+}
+
+#[test]
+fn test_binary() {
+    for z in complexes() {
+        for w in complexes() {
+            assert!(add(&c64(z), &c64(w)).accept(z + w), "{z} + {w}");
+            assert!(mul(&c64(z), &c64(w)).accept(z * w), "{z} * {w}");
+
+            let (z, w) = (
+                Complex::new(z.re as f32, z.im as f32),
+                Complex::new(w.re as f32, w.im as f32),
+            );
+            assert!(add(&c32(z), &c32(w)).accept(z + w), "{z} + {w}");
+            assert!(mul(&c32(z), &c32(w)).accept(z * w), "{z} * {w}");
+        }
+    }
+}
+
+#[test]
+fn test_unary() {
+    for z in complexes() {
+        assert!(norm(&c64(z)).accept(z.norm()), "{z}");
+        assert!(arg(&c64(z)).accept(z.arg()), "{z}");
+        assert!(sqrt(&c64(z)).accept(z.sqrt()), "sqrt({z}) = {}", z.sqrt());
+
+        let z = Complex::new(z.re as f32, z.im as f32);
+        assert!(norm(&c32(z)).accept(z.norm()), "{z}");
+        assert!(arg(&c32(z)).accept(z.arg()), "{z}");
+        assert!(sqrt(&c32(z)).accept(z.sqrt()), "sqrt({z}) = {}", z.sqrt());
+    }
+}
+
+#[test]
+fn test_sets() {
+    let finite = FnArgComplex::C64(ComplexPossibilities {
+        re: FloatPossibilities::finite(),
+        im: FloatPossibilities::finite(),
+    });
+
+    for z in complexes().filter(|z| z.re.is_finite() && z.im.is_finite()) {
+        assert!(finite.accept(z));
+        assert!(sqrt(&finite).accept(z.sqrt()), "{z}");
+        assert!(mul(&finite, &finite).accept(z * z), "{z}");
+    }
+
+    // The norm and the argument of finite numbers are never NaN
+    assert_eq!(norm(&finite).possibilities().nan, Possible::No);
+    assert_eq!(norm(&finite).possibilities().negative, Possible::No);
+    assert_eq!(arg(&finite).possibilities().nan, Possible::No);
+
+    // But their product may overflow to `inf - inf`
+    assert_ne!(mul(&finite, &finite).possibilities().re.nan, Possible::No);
+    assert_eq!(add(&finite, &finite).possibilities().re.nan, Possible::No);
+}
+
+#[test]
+fn test_parts() {
+    let z = c32(Complex::new(1.0, f32::NAN));
+    assert!(z.re().is_f32());
+    assert_eq!(z.im().possibilities().nan, Possible::Yes);
+    assert_eq!(FnArgComplex::new(z.re(), z.im()), z);
+    assert!(!z.accept(Complex::new(1.0f64, f64::NAN)));
+}
+
+#[test]
+#[should_panic(expected = "Different types")]
+fn test_different_types() {
+    let z = c32(Complex::new(1.0, 1.0));
+    let w = c64(Complex::new(1.0, 1.0));
+    add(&z, &w);
+}
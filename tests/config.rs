@@ -0,0 +1,267 @@
+#![cfg(feature = "arith")]
+
+use fn_num_types::{
+    AnalysisConfig, FloatPossibilities, FnArgFloat, Possible, Precision, RoundingMode, Verdicts,
+};
+
+const POSITIVE_FINITE: FloatPossibilities = FloatPossibilities {
+    nan: Possible::No,
+    pos_zero: Possible::No,
+    neg_zero: Possible::No,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
+    infinite: Possible::No,
+    positive: Possible::Yes,
+    negative: Possible::No,
+};
+
+const POSITIVE_NORMAL: FloatPossibilities = FloatPossibilities {
+    subnormal: Possible::No,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
+    ..POSITIVE_FINITE
+};
+
+fn unwrap(arg: FnArgFloat) -> FloatPossibilities {
+    match arg {
+        FnArgFloat::F64(fp) => fp,
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_default() {
+    let config = AnalysisConfig::default();
+    let x = FnArgFloat::F64(POSITIVE_NORMAL);
+
+    let res = unwrap(config.apply(fn_num_types::core::ops::recip_with, &x));
+
+    assert_eq!(res.zero(), Possible::No);
+    assert_eq!(res.infinite, Possible::No);
+}
+
+#[test]
+fn test_flush_to_zero() {
+    let config = AnalysisConfig {
+        flush_to_zero: true,
+        ..Default::default()
+    };
+    let x = FnArgFloat::F64(POSITIVE_NORMAL);
+
+    let res = unwrap(config.apply(fn_num_types::core::ops::recip_with, &x));
+
+    // A zero of the same sign
+    assert_eq!(res.pos_zero, Possible::Yes);
+    assert_eq!(res.neg_zero, Possible::No);
+    assert_eq!(res.subnormal, Possible::No);
+    assert_eq!(res.infinite, Possible::No);
+
+    let res = unwrap(config.apply(|_, x| fn_num_types::core::ops::neg(x), &x));
+
+    assert_eq!(res.pos_zero, Possible::No);
+    assert_eq!(res.neg_zero, Possible::No);
+
+    let res = unwrap(config.apply(
+        |_, x| fn_num_types::core::ops::neg(x),
+        &FnArgFloat::F64(POSITIVE_FINITE),
+    ));
+
+    assert_eq!(res.pos_zero, Possible::No);
+    assert_eq!(res.neg_zero, Possible::Yes);
+}
+
+#[test]
+fn test_extended() {
+    let x87 = AnalysisConfig {
+        precision: Precision::Extended,
+        ..Default::default()
+    };
+    let x = FnArgFloat::F64(POSITIVE_NORMAL);
+
+    // Rounded twice, the result may underflow or overflow
+    let res = unwrap(x87.apply2(fn_num_types::core::ops::add_with, &x, &x));
+
+    assert_eq!(res.pos_zero, Possible::ShouldNot);
+    assert_eq!(res.subnormal, Possible::ShouldNot);
+    assert_eq!(res.infinite, Possible::ShouldNot);
+
+    // The exact operations are not rounded
+    for op in [
+        fn_num_types::core::ops::neg,
+        fn_num_types::core::ops::abs,
+        fn_num_types::core::ops::trunc,
+    ] {
+        let res = x87.apply_exact(|_, x| op(x), &x);
+        assert_eq!(res, op(&x));
+    }
+
+    let res = x87.apply2_exact(|_, x, y| fn_num_types::core::ops::min(x, y), &x, &x);
+    assert_eq!(res, x);
+}
+
+#[test]
+fn test_denormals_are_zero() {
+    let config = AnalysisConfig {
+        denormals_are_zero: true,
+        ..Default::default()
+    };
+    let x = FnArgFloat::F64(POSITIVE_FINITE);
+
+    let res = unwrap(config.apply(fn_num_types::core::ops::recip_with, &x));
+
+    // The subnormals are read as `+0.0`
+    assert_eq!(res.zero(), Possible::No);
+    assert_eq!(res.infinite, Possible::Yes);
+    assert_eq!(res.negative, Possible::No);
+}
+
+#[test]
+fn test_gpu() {
+    let config = AnalysisConfig::gpu();
+    let x = FnArgFloat::F64(POSITIVE_FINITE);
+
+    let res = unwrap(config.apply(fn_num_types::core::ops::recip_with, &x));
+
+    // `1.0 / +0.0`, the infinity being handled as NaN
+    assert_eq!(res.zero(), Possible::Yes);
+    assert_eq!(res.infinite, Possible::Yes);
+    assert_eq!(res.nan, Possible::Yes);
+
+    let res = unwrap(config.apply(|_, x| fn_num_types::core::ops::neg(x), &x));
+
+    assert_eq!(res.nan, Possible::No);
+    assert_eq!(res.negative, Possible::Yes);
+    assert_eq!(res.positive, Possible::No);
+
+    let x = FnArgFloat::F64(FloatPossibilities {
+        negative: Possible::Yes,
+        positive: Possible::No,
+        ..POSITIVE_FINITE
+    });
+
+    // NaN may not be produced
+    let res = unwrap(config.apply(|_, x| fn_num_types::core::ops::sqrt(x), &x));
+
+    assert_eq!(res.nan, Possible::Yes);
+    assert_eq!(res.positive, Possible::Yes);
+    assert_eq!(res.zero(), Possible::Yes);
+}
+
+#[test]
+fn test_fast_math() {
+    let config = AnalysisConfig {
+        fast_math: true,
+        ..Default::default()
+    };
+    let x = FnArgFloat::F64(POSITIVE_FINITE);
+
+    let (res, violation) = config.apply_checked(|_, x| fn_num_types::core::ops::sqrt(x), &x);
+
+    assert_eq!(unwrap(res).nan, Possible::No);
+    assert_eq!(violation, Possible::No);
+
+    let zero = FnArgFloat::F64(FloatPossibilities {
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        ..POSITIVE_FINITE
+    });
+
+    let (res, violation) = config.apply_checked(fn_num_types::core::ops::recip_with, &zero);
+
+    assert_eq!(unwrap(res).infinite, Possible::No);
+    assert_eq!(violation, Possible::Yes);
+
+    let (res, violation) = config.apply2_checked(fn_num_types::core::ops::add_with, &x, &x);
+
+    assert_eq!(unwrap(res).infinite, Possible::No);
+    assert_eq!(violation, Possible::ShouldNot);
+
+    let (_, violation) =
+        AnalysisConfig::default().apply_checked(fn_num_types::core::ops::recip_with, &zero);
+
+    assert_eq!(violation, Possible::No);
+}
+
+#[test]
+fn test_rounding() {
+    let toward_zero = AnalysisConfig {
+        rounding: RoundingMode::TowardZero,
+        ..Default::default()
+    };
+    let x = FnArgFloat::F64(POSITIVE_FINITE);
+
+    // The overflow gives `MAX`
+    let res = unwrap(toward_zero.apply2(fn_num_types::core::ops::add_with, &x, &x));
+
+    assert_eq!(res.infinite, Possible::No);
+    assert_eq!(res.large, Possible::Yes);
+
+    // Only with the mode
+    let res = unwrap(fn_num_types::core::ops::add(&x, &x));
+
+    assert_eq!(res.infinite, Possible::ShouldNot);
+
+    // But the division by zero is exact
+    let zero = FnArgFloat::F64(FloatPossibilities {
+        pos_zero: Possible::Yes,
+        ..POSITIVE_NORMAL
+    });
+    let res = unwrap(toward_zero.apply2(fn_num_types::core::ops::div_with, &x, &zero));
+
+    assert_eq!(res.infinite, Possible::Yes);
+
+    let downward = AnalysisConfig {
+        rounding: RoundingMode::Downward,
+        ..Default::default()
+    };
+    let y = FnArgFloat::F64(FloatPossibilities {
+        positive: Possible::No,
+        negative: Possible::Yes,
+        ..POSITIVE_NORMAL
+    });
+
+    // `x - x` is `-0.0`
+    let res = unwrap(downward.apply2(fn_num_types::core::ops::add_with, &x, &y));
+
+    assert_eq!(res.pos_zero, Possible::No);
+    assert_eq!(res.neg_zero, Possible::Yes);
+    assert_eq!(res.infinite, Possible::No);
+}
+
+#[test]
+fn test_verdicts() {
+    let x = FnArgFloat::F64(POSITIVE_NORMAL);
+
+    let res = unwrap(AnalysisConfig::default().apply(fn_num_types::core::ops::recip_with, &x));
+
+    assert_eq!(res.subnormal, Possible::Yes);
+    assert_eq!(res.fractional, Possible::Yes);
+
+    let strict = AnalysisConfig {
+        verdicts: Verdicts::Strict,
+        ..Default::default()
+    };
+
+    // The overflow may happen
+    let res = unwrap(strict.apply2(fn_num_types::core::ops::mul_with, &x, &x));
+
+    assert_eq!(res.infinite, Possible::Yes);
+    assert_eq!(res.zero(), Possible::Yes);
+    assert_eq!(res.nan, Possible::No);
+
+    let relaxed = AnalysisConfig {
+        verdicts: Verdicts::Relaxed,
+        ..Default::default()
+    };
+
+    // But not in the exact arithmetic
+    let res = unwrap(relaxed.apply2(fn_num_types::core::ops::mul_with, &x, &x));
+
+    assert_eq!(res.infinite, Possible::No);
+    assert_eq!(res.zero(), Possible::No);
+    assert_eq!(res.positive, Possible::Yes);
+}
@@ -1,64 +1,74 @@
-use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
-
-macro_rules! get_test_values {
-    ($float_type:ident) => {
-        [
-            core::$float_type::NAN,
-            core::$float_type::NEG_INFINITY,
-            core::$float_type::MIN,
-            core::$float_type::MIN / 2.0,
-            -core::$float_type::consts::PI,
-            -core::$float_type::consts::E,
-            -2.0,
-            -core::$float_type::consts::FRAC_PI_2,
-            -1.0,
-            -core::$float_type::MIN_POSITIVE,
-            -1.0e-308, // Smallest negative subnormal. Rounded to zero for f32
-            -0.0,
-            0.0,
-            1.0e-308, // Smallest positive subnormal. Rounded to zero for f32
-            core::$float_type::MIN_POSITIVE,
-            1.0,
-            core::$float_type::consts::FRAC_PI_2,
-            2.0,
-            core::$float_type::consts::E,
-            core::$float_type::consts::PI,
-            core::$float_type::MAX / 2.0,
-            core::$float_type::MAX,
-            core::$float_type::INFINITY,
-        ]
-    };
-}
+#[allow(unused_imports)]
+use fn_num_types::verify::{possibilities, verify_binary, verify_ternary, verify_unary, Float};
+#[allow(unused_imports)]
+use fn_num_types::{FloatPossibilities, FloatValue, FnArgFloat, Possible};
 
-const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+#[cfg(feature = "oracle")]
+use fn_num_types::oracle::{self, Exact};
+#[cfg(feature = "oracle")]
+use fn_num_types::Field;
 
-fn get_possibilities() -> Vec<FloatPossibilities> {
-    let mut possibles = vec![];
+/// The first outcome misjudged by `model`, of an operation rounded to `rounded`
+/// whose exact result reaches `exact`
+///
+/// The outcomes reached exactly must be `Possible::Should` or above. The others,
+/// reached only because of the rounding, must be allowed, `Possible::ShouldNot`
+/// being kept for them.
+#[cfg(feature = "oracle")]
+fn misjudged(
+    rounded: f64,
+    exact: &FloatPossibilities,
+    model: &FloatPossibilities,
+) -> Option<Field> {
+    let rounded = Exact::from_f64(rounded).possibilities();
 
-    for nan in YESNO {
-        for zero in YESNO {
-            for infinite in YESNO {
-                for positive in YESNO {
-                    for negative in YESNO {
-                        possibles.push(FloatPossibilities {
-                            nan,
-                            zero,
-                            infinite,
-                            positive,
-                            negative,
-                        });
-                    }
-                }
-            }
+    Field::ALL.into_iter().find(|field| {
+        let level = model.get(*field);
+        if exact.get(*field) != Possible::No {
+            level < Possible::Should
+        } else {
+            rounded.get(*field) != Possible::No && level == Possible::No
         }
-    }
+    })
+}
 
-    possibles
+/// The functions of `libm` for both widths that `libm::Libm` misses
+#[cfg(feature = "libm-ops")]
+trait LibmExt: Sized {
+    fn fmaximum(x: Self, y: Self) -> Self;
+    fn fminimum(x: Self, y: Self) -> Self;
+    fn fmaximum_num(x: Self, y: Self) -> Self;
+    fn fminimum_num(x: Self, y: Self) -> Self;
 }
 
+#[cfg(feature = "libm-ops")]
+macro_rules! impl_libm_ext {
+    ($float:ident, $fmaximum:ident, $fminimum:ident, $fmaximum_num:ident, $fminimum_num:ident) => {
+        impl LibmExt for $float {
+            fn fmaximum(x: Self, y: Self) -> Self {
+                libm::$fmaximum(x, y)
+            }
+            fn fminimum(x: Self, y: Self) -> Self {
+                libm::$fminimum(x, y)
+            }
+            fn fmaximum_num(x: Self, y: Self) -> Self {
+                libm::$fmaximum_num(x, y)
+            }
+            fn fminimum_num(x: Self, y: Self) -> Self {
+                libm::$fminimum_num(x, y)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "libm-ops")]
+impl_libm_ext!(f32, fmaximumf, fminimumf, fmaximum_numf, fminimum_numf);
+#[cfg(feature = "libm-ops")]
+impl_libm_ext!(f64, fmaximum, fminimum, fmaximum_num, fminimum_num);
+
 #[test]
 fn test_values() {
-    let values = get_test_values!(f64);
+    let values = f64::VALUES;
 
     for w in values.windows(2) {
         let a = w[0];
@@ -79,28 +89,447 @@ fn test_values() {
     }
 }
 
+#[test]
+#[cfg(feature = "arith")]
+fn test_tight() {
+    use fn_num_types::core::ops;
+    use fn_num_types::verify::{verify_tight_binary, verify_tight_unary};
+    use fn_num_types::Field;
+
+    assert_eq!(verify_tight_unary(|x| -x, ops::neg), vec![]);
+    assert_eq!(verify_tight_unary(f64::abs, ops::abs), vec![]);
+    assert_eq!(verify_tight_unary(f64::signum, ops::signum), vec![]);
+
+    // `max` as a model of `copysign`: with a positive sign, the result is never negative
+    let loose = verify_tight_binary(f64::copysign, ops::max);
+    assert!(loose.iter().any(
+        |u| u.args[1] == FloatPossibilities::positive() && u.fields.contains(&Field::Negative)
+    ));
+}
+
+#[test]
+#[cfg(feature = "full")]
+fn test_methods() {
+    use fn_num_types::core::ops;
+
+    for p in possibilities() {
+        let x = FnArgFloat::F32(p);
+        let y = FnArgFloat::F32(FloatPossibilities::any());
+
+        assert_eq!(x.abs().sqrt().ln(), ops::ln(&ops::sqrt(&ops::abs(&x))));
+        assert_eq!(x.powf(&y), ops::powf(&x, &y));
+        assert_eq!(x.mul_add(&y, &x), ops::mul_add(&x, &y, &x));
+        assert_eq!(x.is_nan(), ops::is_nan(&x));
+    }
+}
+
+#[test]
+#[cfg(feature = "full")]
+fn test_compose() {
+    use fn_num_types::core::ops::{self, compose, identity};
+
+    let exp_abs = compose(ops::abs, ops::exp);
+    let chained = compose(compose(ops::sin, exp_abs), identity);
+
+    for p in possibilities() {
+        let x = FnArgFloat::F64(p);
+
+        assert_eq!(identity(&x), x);
+        assert_eq!(chained(&x), ops::exp(&ops::abs(&ops::sin(&x))));
+    }
+}
+
+#[test]
+#[cfg(feature = "arith")]
+fn test_iter_identities() {
+    use fn_num_types::core::ops::{iter_product, iter_sum};
+
+    for p in possibilities() {
+        let ty = FnArgFloat::F32(p);
+
+        assert_eq!(
+            iter_sum(&ty, &[]),
+            FnArgFloat::F32(FloatPossibilities::from(-0.0))
+        );
+        assert_eq!(
+            iter_product(&ty, &[]),
+            FnArgFloat::F32(FloatPossibilities::from(1.0))
+        );
+        assert_eq!(iter_sum(&ty, &[ty]), ty);
+    }
+}
+
+#[test]
+#[cfg(feature = "arith")]
+fn test_unbounded() {
+    use fn_num_types::core::ops::{product_unbounded, sum_unbounded};
+
+    let values = f64::VALUES;
+
+    for p in possibilities() {
+        let elem = FnArgFloat::F64(p);
+        let sum = *sum_unbounded(&elem).possibilities();
+        let product = *product_unbounded(&elem).possibilities();
+
+        let accepted: Vec<f64> = values.iter().copied().filter(|v| p.accept(*v)).collect();
+        for v in &accepted {
+            for w in &accepted {
+                for n in [0, 1, 2, 3, 1000] {
+                    let elems = || std::iter::once(*w).chain(std::iter::repeat(*v).take(n));
+
+                    let s: f64 = elems().sum();
+                    assert!(
+                        sum.accept(s),
+                        "sum of {w:?} and {n} {v:?} = {s:?}: {p} => {sum}"
+                    );
+                    let r: f64 = elems().product();
+                    assert!(
+                        product.accept(r),
+                        "product of {w:?} and {n} {v:?} = {r:?}: {p} => {product}"
+                    );
+                }
+            }
+        }
+        assert!(sum.accept(-0.0));
+        assert!(product.accept(1.0));
+    }
+}
+
+#[test]
+#[cfg(feature = "full")]
+fn test_iterate_to_fixpoint() {
+    use fn_num_types::core::ops::{self, iterate_to_fixpoint};
+
+    // `x * x + 0.5`
+    let loops: [fn(&FnArgFloat) -> FnArgFloat; 3] = [ops::exp, ops::cos, |x| {
+        ops::mul_add(x, x, &FnArgFloat::F64(FloatPossibilities::from(0.5)))
+    }];
+
+    for p in possibilities() {
+        let x = FnArgFloat::F64(p);
+
+        for op in loops {
+            let fixpoint = iterate_to_fixpoint(&x, op);
+            let fp = fixpoint.possibilities();
+
+            assert!(p.is_subset_of(fp));
+            assert!(op(&fixpoint).possibilities().is_subset_of(fp));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "arith")]
+fn test_signed_zeros() {
+    use fn_num_types::core::ops::{abs, add, ceil, fract, neg};
+
+    let negative = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::No,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::No,
+        positive: Possible::No,
+        negative: Possible::Yes,
+    });
+
+    // -0.0 + -0.0 is -0.0, the cancellation of x + -x is +0.0
+    let sum = add(&negative, &negative);
+    assert_eq!(sum.possibilities().pos_zero, Possible::No);
+    assert_eq!(sum.possibilities().neg_zero, Possible::Yes);
+
+    // ceil(-0.5) is -0.0
+    assert!(!ceil(&negative).possibilities().accept(0.0));
+
+    // The fractional part is +0.0 even for negative values
+    let fract = fract(&negative);
+    assert!(fract.possibilities().accept(0.0));
+    assert!(!fract.possibilities().accept(-0.0));
+
+    let opposite = neg(&negative);
+    assert!(opposite.possibilities().accept(0.0));
+    assert!(!opposite.possibilities().accept(-0.0));
+    assert_eq!(abs(&negative), opposite);
+}
+
+#[test]
+#[cfg(feature = "arith")]
+fn test_subnormals() {
+    use fn_num_types::core::ops::{is_normal, mul, round, sqrt};
+    use fn_num_types::BoolPossibility;
+
+    let normal = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::No,
+        positive: Possible::Yes,
+        negative: Possible::No,
+    });
+
+    assert!(!normal.possibilities().accept(1.0e-310));
+    assert_eq!(is_normal(&normal), BoolPossibility::True);
+
+    // f64::MIN_POSITIVE * 0.5 underflows to a subnormal
+    assert_eq!(
+        mul(&normal, &normal).possibilities().subnormal,
+        Possible::Yes
+    );
+
+    // Integers are never subnormal, and neither is a square root
+    assert_eq!(round(&normal).possibilities().subnormal, Possible::No);
+    assert_eq!(sqrt(&normal).possibilities().subnormal, Possible::No);
+}
+
+#[test]
+#[cfg(all(feature = "arith", feature = "exp-log", feature = "trig"))]
+fn test_underflow() {
+    use fn_num_types::core::ops::{exp, mul, neg, to_radians};
+
+    let positive = FnArgFloat::F64(FloatPossibilities::strictly_positive_finite());
+    let finite = FnArgFloat::F64(FloatPossibilities::finite());
+    let bounded = FnArgFloat::F64(FloatPossibilities {
+        large: Possible::No,
+        ..FloatPossibilities::finite()
+    });
+
+    // A rounding artifact, to a zero of the sign of the result
+    let product = *mul(&positive, &positive).possibilities();
+    assert_eq!(product.pos_zero, Possible::ShouldNot);
+    assert_eq!(product.neg_zero, Possible::No);
+    let product = *mul(&positive, &neg(&positive)).possibilities();
+    assert_eq!(product.pos_zero, Possible::No);
+    assert_eq!(product.neg_zero, Possible::ShouldNot);
+
+    // Only for very negative arguments
+    assert_eq!(exp(&finite).possibilities().pos_zero, Possible::ShouldNot);
+    assert_eq!(exp(&finite).possibilities().neg_zero, Possible::No);
+    assert_eq!(exp(&bounded).possibilities().zero(), Possible::No);
+
+    // Only for the subnormals
+    assert_eq!(
+        to_radians(&positive).possibilities().pos_zero,
+        Possible::ShouldNot
+    );
+    let normal = FnArgFloat::F64(FloatPossibilities {
+        subnormal: Possible::No,
+        ..FloatPossibilities::strictly_positive_finite()
+    });
+    assert_eq!(to_radians(&normal).possibilities().zero(), Possible::No);
+}
+
+#[test]
+#[cfg(feature = "arith")]
+fn test_mul_pow2() {
+    use fn_num_types::core::ops::mul_pow2;
+
+    // `]0, 1]`
+    let bounded = FloatPossibilities {
+        large: Possible::No,
+        ..FloatPossibilities::strictly_positive_finite()
+    };
+    let integers = FloatPossibilities {
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        ..FloatPossibilities::finite()
+    };
+
+    // Exact, unless it leaves the range of the type
+    let res = *mul_pow2(&FnArgFloat::F64(bounded), 100).possibilities();
+    assert_eq!(res.infinite, Possible::No);
+    assert_eq!(res.negative, Possible::No);
+    let res = *mul_pow2(&FnArgFloat::F32(bounded), 200).possibilities();
+    assert_eq!(res.infinite, Possible::ShouldNot);
+    assert_eq!(res.subnormal, Possible::No);
+
+    // The integers stay integers, and the zeros stay
+    let res = *mul_pow2(&FnArgFloat::F64(integers), 3).possibilities();
+    assert_eq!(res.fractional, Possible::No);
+    assert_eq!(res.subnormal, Possible::No);
+    assert_eq!(res.zero(), Possible::Yes);
+    assert_eq!(res.infinite, Possible::ShouldNot);
+
+    // Down to the subnormals and to zero
+    let res = *mul_pow2(&FnArgFloat::F64(integers), -3).possibilities();
+    assert_eq!(res.fractional, Possible::Yes);
+    assert_eq!(res.subnormal, Possible::No);
+    let res = *mul_pow2(&FnArgFloat::F64(bounded), -3).possibilities();
+    assert_eq!(res.subnormal, Possible::Yes);
+    assert_eq!(res.pos_zero, Possible::ShouldNot);
+    assert_eq!(res.neg_zero, Possible::No);
+    assert_eq!(res.large, Possible::No);
+    assert_eq!(res.infinite, Possible::No);
+}
+
+#[test]
+#[cfg(feature = "arith")]
+fn test_integral() {
+    use fn_num_types::core::ops::{add, div, mul, rem, round};
+
+    let finite = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::No,
+        positive: Possible::Yes,
+        negative: Possible::Yes,
+    });
+
+    assert!(!finite.possibilities().is_integral());
+
+    let integral = round(&finite);
+    assert!(integral.possibilities().is_integral());
+    assert!(integral.possibilities().accept(3.0));
+    assert!(!integral.possibilities().accept(0.5));
+
+    // Exact, or rounded to an integer above 2^53
+    assert!(add(&integral, &integral).possibilities().is_integral());
+    assert!(mul(&integral, &integral).possibilities().is_integral());
+    assert!(rem(&integral, &integral).possibilities().is_integral());
+
+    assert!(!div(&integral, &integral).possibilities().is_integral());
+    assert!(!add(&integral, &finite).possibilities().is_integral());
+}
+
+#[test]
+#[cfg(all(feature = "arith", feature = "trig"))]
+fn test_bounded() {
+    use fn_num_types::core::ops::{add, cos, mul, sin};
+
+    let any = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::Yes,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::Yes,
+        positive: Possible::Yes,
+        negative: Possible::Yes,
+    });
+
+    let (x, y) = (sin(&any), cos(&any));
+    assert!(x.possibilities().is_bounded_by_one());
+    assert!(!any.possibilities().is_bounded_by_one());
+
+    // In `[-2, 2]`, far from the overflow
+    let sum = *add(&x, &y).possibilities();
+    assert_eq!(sum.infinite, Possible::No);
+    assert_eq!(sum.large, Possible::Yes);
+
+    assert!(mul(&x, &y).possibilities().is_bounded_by_one());
+}
+
+#[test]
+#[cfg(all(feature = "exp-log", feature = "trig"))]
+fn test_dedicated_models() {
+    use fn_num_types::core::ops::{cos, exp, exp2, ln, log10, log2, sin};
+
+    let any = FnArgFloat::F64(FloatPossibilities::any());
+    // `[-1, 1]`, NaN excluded
+    let small = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::No,
+        large: Possible::No,
+        infinite: Possible::No,
+        ..FloatPossibilities::any()
+    });
+    // Zero, one and the larger integers
+    let naturals = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        infinite: Possible::No,
+        negative: Possible::No,
+        ..FloatPossibilities::any()
+    });
+
+    // `cos` is never zero, and positive near zero
+    assert_eq!(sin(&any).possibilities().zero(), Possible::Yes);
+    assert_eq!(cos(&any).possibilities().zero(), Possible::No);
+    assert_eq!(cos(&any).possibilities().subnormal, Possible::No);
+    assert_eq!(sin(&small).possibilities().negative, Possible::Yes);
+    assert_eq!(cos(&small).possibilities().negative, Possible::No);
+
+    // The powers of two of the integers are integers
+    assert_eq!(exp(&naturals).possibilities().fractional, Possible::Yes);
+    assert_eq!(exp2(&naturals).possibilities().fractional, Possible::No);
+    assert_eq!(exp2(&naturals).possibilities().zero(), Possible::No);
+    assert_eq!(exp2(&small).possibilities().subnormal, Possible::No);
+    assert_eq!(exp2(&small).possibilities().infinite, Possible::No);
+
+    // From one, the logarithms are never negative
+    for log in [ln, log2, log10] {
+        let res = *log(&naturals).possibilities();
+        assert_eq!(res.negative, Possible::Yes, "log(0.0) is -inf");
+        assert_eq!(res.nan, Possible::No);
+
+        let one_and_above = FnArgFloat::F64(FloatPossibilities {
+            pos_zero: Possible::No,
+            ..*naturals.possibilities()
+        });
+        let res = *log(&one_and_above).possibilities();
+        assert_eq!(res.negative, Possible::No);
+        assert_eq!(res.infinite, Possible::No);
+        assert_eq!(res.subnormal, Possible::No);
+    }
+
+    // The bases only change which arguments give integers, like `log2(8.0)`,
+    // which the possibilities cannot tell
+    for fp in FloatPossibilities::enumerate(&Possible::ALL) {
+        let x = FnArgFloat::F64(fp);
+        assert_eq!(log2(&x), ln(&x), "{fp:?}");
+        assert_eq!(log10(&x), ln(&x), "{fp:?}");
+    }
+}
+
 macro_rules! generate_tests {
     ($float:ident, $mod:ident) => {
         fn test_op(name: &str, op: fn($float) -> $float, ty: fn(&FnArgFloat) -> FnArgFloat) {
-            let possibles = get_possibilities();
-            let values = get_test_values!($float);
+            if let Some(c) = verify_unary(op, ty).first() {
+                panic!("{name}{c}");
+            }
+        }
 
-            for v in values.iter() {
+        // Only the values for which `domain` is true are tested
+        #[cfg(feature = "trig")]
+        fn test_op_in(
+            name: &str,
+            domain: fn($float) -> bool,
+            op: fn($float) -> $float,
+            ty: fn(&FnArgFloat) -> FnArgFloat,
+        ) {
+            let possibles = possibilities();
+            let values = $float::VALUES;
+            let angles = [
+                -core::$float::consts::PI * 0.75,
+                core::$float::consts::PI * 0.75,
+                core::$float::consts::PI * 1.5,
+                core::$float::consts::TAU * (1.0 - $float::EPSILON),
+            ];
+
+            for v in values.iter().chain(angles.iter()).filter(|v| domain(**v)) {
                 for p in possibles.iter() {
-                    if !p.accept(*v) {
+                    if !p.accept(*v as f64) {
                         continue;
                     }
 
                     let result = op(*v);
                     let res_p = ty(&FnArgFloat::$mod(*p));
-
-                    println!("Testing {name}");
-                    println!("Testing {v:?} = {result:?}");
-                    println!("Testing {p:?} = {res_p:?}");
-
                     match res_p {
                         FnArgFloat::$mod(res_p) => {
-                            assert!(res_p.accept(result));
+                            assert!(
+                                res_p.accept(result as f64),
+                                "{name}({v:?}) = {result:?} with {p} = {res_p}"
+                            );
                         }
                         _ => panic!("Invalid result"),
                     }
@@ -113,36 +542,19 @@ macro_rules! generate_tests {
             op: fn($float, $float) -> $float,
             ty: fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat,
         ) {
-            let possibles = get_possibilities();
-            let values = get_test_values!($float);
-
-            for v1 in values.iter() {
-                for p1 in possibles.iter() {
-                    if !p1.accept(*v1) {
-                        continue;
-                    }
-                    for v2 in values.iter() {
-                        for p2 in possibles.iter() {
-                            if !p2.accept(*v2) {
-                                continue;
-                            }
-
-                            let result = op(*v1, *v2);
-                            let res_p = ty(&FnArgFloat::$mod(*p1), &FnArgFloat::$mod(*p2));
-
-                            println!("Testing {name}");
-                            println!("Testing {v1:?} {v2:?} = {result:?}");
-                            println!("Testing {p1:?} {p2:?} = {res_p:?}");
+            if let Some(c) = verify_binary(op, ty).first() {
+                panic!("{name}{c}");
+            }
+        }
 
-                            match res_p {
-                                FnArgFloat::$mod(res_p) => {
-                                    assert!(res_p.accept(result));
-                                }
-                                _ => panic!("Invalid result"),
-                            }
-                        }
-                    }
-                }
+        fn test_op3(
+            name: &str,
+            // `None` if the arguments are invalid
+            op: fn($float, $float, $float) -> Option<$float>,
+            ty: fn(&FnArgFloat, &FnArgFloat, &FnArgFloat) -> FnArgFloat,
+        ) {
+            if let Some(c) = verify_ternary(op, ty).first() {
+                panic!("{name}{c}");
             }
         }
 
@@ -153,58 +565,895 @@ macro_rules! generate_tests {
         }
 
         #[test]
+        #[cfg(feature = "arith")]
         fn test_ops() {
             test_op("neg", |x| -x, fn_num_types::core::ops::neg);
             test_op!(abs);
             test_op!(ceil);
             test_op!(floor);
             test_op!(round);
+            test_op(
+                "round_ties_even",
+                libm::Libm::<$float>::roundeven,
+                fn_num_types::core::ops::round_ties_even,
+            );
             test_op!(trunc);
             test_op!(fract);
             test_op!(signum);
             test_op!(sqrt);
+            test_op!(cbrt);
+            test_op!(recip);
+            test_op("powi", |x| x.powi(2), |x| fn_num_types::core::ops::powi(x));
+            test_op(
+                "powi_n(-3)",
+                |x| x.powi(-3),
+                |x| fn_num_types::core::ops::powi_n(x, -3),
+            );
+            test_op(
+                "powi_n(-2)",
+                |x| x.powi(-2),
+                |x| fn_num_types::core::ops::powi_n(x, -2),
+            );
+            test_op(
+                "powi_n(-1)",
+                |x| x.powi(-1),
+                |x| fn_num_types::core::ops::powi_n(x, -1),
+            );
+            test_op(
+                "powi_n(0)",
+                |x| x.powi(0),
+                |x| fn_num_types::core::ops::powi_n(x, 0),
+            );
+            test_op(
+                "powi_n(1)",
+                |x| x.powi(1),
+                |x| fn_num_types::core::ops::powi_n(x, 1),
+            );
+            test_op(
+                "powi_n(2)",
+                |x| x.powi(2),
+                |x| fn_num_types::core::ops::powi_n(x, 2),
+            );
+            test_op(
+                "powi_n(3)",
+                |x| x.powi(3),
+                |x| fn_num_types::core::ops::powi_n(x, 3),
+            );
+            test_op("next_up", next_up, fn_num_types::core::ops::next_up);
+            test_op("next_down", next_down, fn_num_types::core::ops::next_down);
+        }
+
+        #[test]
+        #[cfg(feature = "arith")]
+        fn test_mul_pow2_ops() {
+            use libm::Libm;
+
+            let possibles = possibilities();
+            let exps = [
+                -2000, -1100, -1075, -1074, -1023, -200, -150, -149, -60, -53, -52, -3, -1, 1, 2,
+                52, 53, 127, 128, 1023, 1024, 2000,
+            ];
+            for n in exps {
+                for &v in $float::VALUES {
+                    let result = Libm::<$float>::scalbn(v, n);
+                    for p in possibles.iter().filter(|p| p.accept(v as f64)) {
+                        let res_p = fn_num_types::core::ops::mul_pow2(&FnArgFloat::$mod(*p), n);
+                        assert!(
+                            result.accepted_by(&res_p),
+                            "mul_pow2({v:?}, {n}) = {result:?} with {p}"
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "exp-log")]
+        fn test_exp_log_ops() {
             test_op!(exp);
             test_op!(exp2);
             test_op!(ln);
             test_op!(log2);
             test_op!(log10);
+            test_op!(exp_m1);
+            test_op!(ln_1p);
+        }
+
+        #[test]
+        #[cfg(feature = "trig")]
+        fn test_trig_ops() {
             test_op!(to_degrees);
             test_op!(to_radians);
-            test_op!(cbrt);
             test_op!(sin);
             test_op!(cos);
+            test_op(
+                "sin_cos.0",
+                |x| x.sin_cos().0,
+                |x| fn_num_types::core::ops::sin_cos(x).0,
+            );
+            test_op(
+                "sin_cos.1",
+                |x| x.sin_cos().1,
+                |x| fn_num_types::core::ops::sin_cos(x).1,
+            );
             test_op!(tan);
             test_op!(asin);
             test_op!(acos);
             test_op!(atan);
-            test_op!(exp_m1);
-            test_op!(ln_1p);
+        }
+
+        #[test]
+        #[cfg(feature = "hyperbolic")]
+        fn test_hyperbolic_ops() {
             test_op!(sinh);
             test_op!(cosh);
             test_op!(tanh);
             test_op!(asinh);
             test_op!(acosh);
             test_op!(atanh);
-            test_op!(recip);
-            test_op("powi", |x| x.powi(2), |x| fn_num_types::core::ops::powi(x));
         }
 
         #[test]
+        #[cfg(feature = "nightly-ops")]
+        fn test_nightly_ops() {
+            use fn_num_types::core::ops::{abs_sub, exp10, gamma, ln_gamma};
+
+            test_op("exp10", |x| (10.0 as $float).powf(x), exp10);
+            test_op("gamma", |x| libm::tgamma(x.into()) as $float, gamma);
+            test_op("ln_gamma", |x| libm::lgamma(x.into()) as $float, ln_gamma);
+            #[allow(deprecated)]
+            test_op2("abs_sub", <$float>::abs_sub, abs_sub);
+        }
+
+        #[test]
+        #[cfg(feature = "libm-ops")]
+        fn test_libm_ops() {
+            use fn_num_types::libm_ops;
+            use libm::Libm;
+
+            test_op("erf", Libm::<$float>::erf, libm_ops::erf);
+            test_op("erfc", Libm::<$float>::erfc, libm_ops::erfc);
+            test_op("j0", Libm::<$float>::j0, libm_ops::j0);
+            test_op("j1", Libm::<$float>::j1, libm_ops::j1);
+            test_op("y0", Libm::<$float>::y0, libm_ops::y0);
+            test_op("y1", Libm::<$float>::y1, libm_ops::y1);
+            test_op("rint", Libm::<$float>::rint, libm_ops::rint);
+            test_op("roundeven", Libm::<$float>::roundeven, libm_ops::roundeven);
+            test_op("log", Libm::<$float>::log, libm_ops::log);
+            test_op("log1p", Libm::<$float>::log1p, libm_ops::log1p);
+            test_op("expm1", Libm::<$float>::expm1, libm_ops::expm1);
+            test_op("tgamma", Libm::<$float>::tgamma, libm_ops::tgamma);
+            test_op("lgamma", Libm::<$float>::lgamma, libm_ops::lgamma);
+
+            for n in [-3, 2, 5] {
+                let possibles = possibilities();
+                for &v in $float::VALUES {
+                    for p in possibles
+                        .iter()
+                        .filter(|p| v.accepted_by(&FnArgFloat::$mod(**p)))
+                    {
+                        let checks = [
+                            (
+                                Libm::<$float>::jn(n, v),
+                                libm_ops::jn(n, &FnArgFloat::$mod(*p)),
+                            ),
+                            (
+                                Libm::<$float>::yn(n, v),
+                                libm_ops::yn(n, &FnArgFloat::$mod(*p)),
+                            ),
+                            (
+                                Libm::<$float>::scalbn(v, n * 400),
+                                libm_ops::scalbn(&FnArgFloat::$mod(*p), n * 400),
+                            ),
+                        ];
+
+                        for (result, res_p) in checks {
+                            assert!(
+                                result.accepted_by(&res_p),
+                                "{n} {v:?} = {result:?} with {p}"
+                            );
+                        }
+                    }
+                }
+            }
+
+            test_op2("fmod", Libm::<$float>::fmod, libm_ops::fmod);
+            test_op2("remainder", Libm::<$float>::remainder, libm_ops::remainder);
+            test_op2("nextafter", Libm::<$float>::nextafter, libm_ops::nextafter);
+            test_op2("pow", Libm::<$float>::pow, libm_ops::pow);
+            test_op2("fdim", Libm::<$float>::fdim, libm_ops::fdim);
+            test_op2("fmax", Libm::<$float>::fmax, libm_ops::fmax);
+            test_op2("fmin", Libm::<$float>::fmin, libm_ops::fmin);
+            test_op2("atan2", Libm::<$float>::atan2, libm_ops::atan2);
+            test_op2("hypot", Libm::<$float>::hypot, libm_ops::hypot);
+            test_op2(
+                "fmaximum",
+                <$float as LibmExt>::fmaximum,
+                libm_ops::fmaximum,
+            );
+            test_op2(
+                "fminimum",
+                <$float as LibmExt>::fminimum,
+                libm_ops::fminimum,
+            );
+            test_op2(
+                "fmaximum_num",
+                <$float as LibmExt>::fmaximum_num,
+                libm_ops::fmaximum_num,
+            );
+            test_op2(
+                "fminimum_num",
+                <$float as LibmExt>::fminimum_num,
+                libm_ops::fminimum_num,
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "exp-log")]
+        fn test_exp_log_ops2() {
+            test_op2("log", <$float>::log, |x, base| {
+                fn_num_types::core::ops::log(x, base)
+            });
+        }
+
+        #[test]
+        #[cfg(feature = "trig")]
+        fn test_trig_ops2() {
+            test_op2("atan2", <$float>::atan2, |y, x| {
+                fn_num_types::core::ops::atan2(y, x)
+            });
+        }
+
+        // Values not in the grid, where `next_up` and `next_down` reach zero or infinity
+        #[test]
+        #[cfg(feature = "arith")]
+        fn test_next_up_down() {
+            let tiny = $float::from_bits(1);
+
+            for v in [-tiny, tiny, $float::MIN, $float::MAX] {
+                for p in possibilities()
+                    .iter()
+                    .filter(|p| v.accepted_by(&FnArgFloat::$mod(**p)))
+                {
+                    let up = fn_num_types::core::ops::next_up(&FnArgFloat::$mod(*p));
+                    let down = fn_num_types::core::ops::next_down(&FnArgFloat::$mod(*p));
+
+                    assert!(next_up(v).accepted_by(&up), "next_up({v:?}) with {p}");
+                    assert!(next_down(v).accepted_by(&down), "next_down({v:?}) with {p}");
+                }
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "arith")]
+        fn test_ops3() {
+            test_op3(
+                "clamp",
+                // Panics if a bound is NaN or `min > max`
+                |x, min, max| (min <= max).then(|| x.clamp(min, max)),
+                |x, min, max| fn_num_types::core::ops::clamp(x, min, max),
+            );
+            test_op3(
+                "mul_add",
+                |a, b, c| Some(a.mul_add(b, c)),
+                |a, b, c| fn_num_types::core::ops::mul_add(a, b, c),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "arith")]
         fn test_ops2() {
             test_op2(
                 "add",
                 |x, y| x + y,
                 |x, y| fn_num_types::core::ops::add(x, y),
             );
+            test_op2(
+                "mul",
+                |x, y| x * y,
+                |x, y| fn_num_types::core::ops::mul(x, y),
+            );
+            test_op2(
+                "div",
+                |x, y| x / y,
+                |x, y| fn_num_types::core::ops::div(x, y),
+            );
+            test_op2(
+                "rem",
+                |x, y| x % y,
+                |x, y| fn_num_types::core::ops::rem(x, y),
+            );
+            test_op2("div_euclid", <$float>::div_euclid, |x, y| {
+                fn_num_types::core::ops::div_euclid(x, y)
+            });
+            test_op2("rem_euclid", <$float>::rem_euclid, |x, y| {
+                fn_num_types::core::ops::rem_euclid(x, y)
+            });
+            test_op2("powf", <$float>::powf, |x, y| {
+                fn_num_types::core::ops::powf(x, y)
+            });
+            test_op2("hypot", <$float>::hypot, |x, y| {
+                fn_num_types::core::ops::hypot(x, y)
+            });
+            test_op2("copysign", <$float>::copysign, |x, y| {
+                fn_num_types::core::ops::copysign(x, y)
+            });
+            test_op2("midpoint", midpoint, |x, y| {
+                fn_num_types::core::ops::midpoint(x, y)
+            });
+            // The operators of `FnArgFloat`
+            test_op2("sub", |x, y| x - y, |x, y| x - y);
+            test_op2("-x * y", |x, y| -x * y, |x, y| -x * y);
+        }
+
+        // IEEE 754-2019 `maximumNumber`
+        fn maximum_number(x: $float, y: $float) -> $float {
+            if x.is_nan() {
+                y
+            } else if y.is_nan() {
+                x
+            } else if x == y {
+                if x.is_sign_positive() {
+                    x
+                } else {
+                    y
+                }
+            } else {
+                x.max(y)
+            }
+        }
+
+        // IEEE 754-2019 `minimumNumber`
+        fn minimum_number(x: $float, y: $float) -> $float {
+            -maximum_number(-x, -y)
+        }
+
+        // IEEE 754-2019 `maximum`
+        fn maximum(x: $float, y: $float) -> $float {
+            if x.is_nan() || y.is_nan() {
+                $float::NAN
+            } else {
+                maximum_number(x, y)
+            }
+        }
+
+        // IEEE 754-2019 `minimum`
+        fn minimum(x: $float, y: $float) -> $float {
+            -maximum(-x, -y)
+        }
+
+        #[test]
+        #[cfg(feature = "arith")]
+        fn test_min_max() {
+            use fn_num_types::core::ops::{max_with, min_with};
+            use fn_num_types::NanPolicy;
+
+            test_op2(
+                "max",
+                |x, y| x.max(y),
+                |x, y| max_with(NanPolicy::Std, x, y),
+            );
+            test_op2(
+                "min",
+                |x, y| x.min(y),
+                |x, y| min_with(NanPolicy::Std, x, y),
+            );
+            test_op2("maximumNumber", maximum_number, |x, y| {
+                max_with(NanPolicy::MaximumNumber, x, y)
+            });
+            test_op2("minimumNumber", minimum_number, |x, y| {
+                min_with(NanPolicy::MaximumNumber, x, y)
+            });
+            test_op2("maximum", maximum, |x, y| {
+                max_with(NanPolicy::Propagate, x, y)
+            });
+            test_op2("minimum", minimum, |x, y| {
+                min_with(NanPolicy::Propagate, x, y)
+            });
+            test_op2("max", <$float>::max, fn_num_types::core::ops::max);
+            test_op2("min", <$float>::min, fn_num_types::core::ops::min);
+            test_op2("maximum", maximum, fn_num_types::core::ops::maximum);
+            test_op2("minimum", minimum, fn_num_types::core::ops::minimum);
+        }
+
+        #[test]
+        #[cfg(feature = "arith")]
+        fn test_clamp() {
+            use fn_num_types::core::ops::clamp_with;
+            use fn_num_types::NanPolicy;
+
+            const NEG_ONE: FnArgFloat = FnArgFloat::$mod(FloatPossibilities {
+                nan: Possible::No,
+                pos_zero: Possible::No,
+                neg_zero: Possible::No,
+                subnormal: Possible::Yes,
+                fractional: Possible::Yes,
+                large: Possible::Yes,
+                infinite: Possible::No,
+                positive: Possible::No,
+                negative: Possible::Yes,
+            });
+            const ZERO: FnArgFloat = FnArgFloat::$mod(FloatPossibilities {
+                nan: Possible::No,
+                pos_zero: Possible::Yes,
+                neg_zero: Possible::Yes,
+                subnormal: Possible::Yes,
+                fractional: Possible::Yes,
+                large: Possible::Yes,
+                infinite: Possible::No,
+                positive: Possible::Yes,
+                negative: Possible::No,
+            });
+            const INF: FnArgFloat = FnArgFloat::$mod(FloatPossibilities {
+                nan: Possible::No,
+                pos_zero: Possible::No,
+                neg_zero: Possible::No,
+                subnormal: Possible::Yes,
+                fractional: Possible::Yes,
+                large: Possible::Yes,
+                infinite: Possible::Yes,
+                positive: Possible::Yes,
+                negative: Possible::No,
+            });
+
+            test_op(
+                "clamp(-1, 0)",
+                |x| x.clamp(-1.0, 0.0),
+                |x| clamp_with(NanPolicy::Std, x, &NEG_ONE, &ZERO),
+            );
+            test_op(
+                "clamp(0, inf)",
+                |x| x.clamp(0.0, $float::INFINITY),
+                |x| clamp_with(NanPolicy::Std, x, &ZERO, &INF),
+            );
+            test_op(
+                "clampNumber(-1, 0)",
+                |x| minimum_number(maximum_number(x, -1.0), 0.0),
+                |x| clamp_with(NanPolicy::MaximumNumber, x, &NEG_ONE, &ZERO),
+            );
+            test_op(
+                "clampPropagate(0, inf)",
+                |x| minimum(maximum(x, 0.0), $float::INFINITY),
+                |x| clamp_with(NanPolicy::Propagate, x, &ZERO, &INF),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "trig")]
+        fn test_trig_in() {
+            use fn_num_types::core::ops::{cos_in, sin_in, tan_in};
+            use fn_num_types::AngleDomain;
+            use std::$float::consts::{PI, TAU};
+
+            fn symmetric(x: $float) -> bool {
+                (-PI..=PI).contains(&x)
+            }
+
+            fn positive(x: $float) -> bool {
+                (0.0..TAU).contains(&x)
+            }
+
+            test_op_in(
+                "sin",
+                symmetric,
+                |x| x.sin(),
+                |x| sin_in(AngleDomain::Symmetric, x),
+            );
+            test_op_in(
+                "cos",
+                symmetric,
+                |x| x.cos(),
+                |x| cos_in(AngleDomain::Symmetric, x),
+            );
+            test_op_in(
+                "tan",
+                symmetric,
+                |x| x.tan(),
+                |x| tan_in(AngleDomain::Symmetric, x),
+            );
+            test_op_in(
+                "sin",
+                positive,
+                |x| x.sin(),
+                |x| sin_in(AngleDomain::Positive, x),
+            );
+            test_op_in(
+                "cos",
+                positive,
+                |x| x.cos(),
+                |x| cos_in(AngleDomain::Positive, x),
+            );
+            test_op_in(
+                "tan",
+                positive,
+                |x| x.tan(),
+                |x| tan_in(AngleDomain::Positive, x),
+            );
+        }
+
+        // `f64::next_up`, stable since Rust 1.86
+        fn next_up(x: $float) -> $float {
+            let bits = x.to_bits();
+            let abs = bits & !(-0.0 as $float).to_bits();
+
+            if x.is_nan() || x == $float::INFINITY {
+                x
+            } else if abs == 0 {
+                // The smallest positive subnormal
+                $float::from_bits(1)
+            } else if bits == abs {
+                $float::from_bits(bits + 1)
+            } else {
+                $float::from_bits(bits - 1)
+            }
+        }
+
+        // `f64::next_down`, stable since Rust 1.86
+        fn next_down(x: $float) -> $float {
+            -next_up(-x)
+        }
+
+        // `f64::midpoint`, stable since Rust 1.85
+        fn midpoint(a: $float, b: $float) -> $float {
+            const LO: $float = $float::MIN_POSITIVE * 2.0;
+            const HI: $float = $float::MAX / 2.0;
+
+            let (abs_a, abs_b) = (a.abs(), b.abs());
+
+            if abs_a <= HI && abs_b <= HI {
+                (a + b) / 2.0
+            } else if abs_a < LO {
+                a + b / 2.0
+            } else if abs_b < LO {
+                a / 2.0 + b
+            } else {
+                a / 2.0 + b / 2.0
+            }
+        }
+
+        fn two_pass_variance(values: &[$float]) -> $float {
+            let n = values.len() as $float;
+            let mean = values.iter().sum::<$float>() / n;
+
+            values
+                .iter()
+                .map(|x| (x - mean) * (x - mean))
+                .sum::<$float>()
+                / n
+        }
+
+        fn one_pass_variance(values: &[$float]) -> $float {
+            let n = values.len() as $float;
+            let mean = values.iter().sum::<$float>() / n;
+
+            values.iter().map(|x| x * x).sum::<$float>() / n - mean * mean
+        }
+
+        fn norm(values: &[$float]) -> $float {
+            values.iter().map(|x| x * x).sum::<$float>().sqrt()
+        }
+
+        #[test]
+        #[cfg(feature = "arith")]
+        fn test_norm() {
+            use fn_num_types::core::ops;
+
+            test_op("norm", |x| norm(&[x]), |x| ops::norm(&[*x]));
+            test_op(
+                "normalize",
+                |x| x / norm(&[x]),
+                |x| ops::normalize(&[*x])[0],
+            );
+
+            test_op2("norm", |x, y| norm(&[x, y]), |x, y| ops::norm(&[*x, *y]));
+            test_op2(
+                "normalize",
+                |x, y| x / norm(&[x, y]),
+                |x, y| ops::normalize(&[*x, *y])[0],
+            );
+            test_op2(
+                "normalize",
+                |x, y| y / norm(&[x, y]),
+                |x, y| ops::normalize(&[*x, *y])[1],
+            );
+        }
+
+        fn kahan(values: &[$float]) -> $float {
+            let mut sum = values[0];
+            let mut c = 0.0;
+
+            for x in &values[1..] {
+                let y = x - c;
+                let t = sum + y;
+                c = (t - sum) - y;
+                sum = t;
+            }
+
+            sum
+        }
+
+        #[test]
+        #[cfg(feature = "arith")]
+        fn test_sum_kahan() {
+            use fn_num_types::core::ops::sum_kahan;
+
+            test_op("sum_kahan", |x| kahan(&[x]), |x| sum_kahan(&[*x]));
+            test_op2(
+                "sum_kahan",
+                |x, y| kahan(&[x, y]),
+                |x, y| sum_kahan(&[*x, *y]),
+            );
+            test_op2(
+                "sum_kahan",
+                |x, y| kahan(&[x, y, y]),
+                |x, y| sum_kahan(&[*x, *y, *y]),
+            );
+            test_op2(
+                "sum_kahan",
+                |x, y| kahan(&[x, y, x, y]),
+                |x, y| sum_kahan(&[*x, *y, *x, *y]),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "arith")]
+        fn test_stats() {
+            use fn_num_types::core::ops::{
+                iter_product, iter_sum, mean, product, stddev, sum, variance,
+            };
+
+            test_op("sum", |x| x, |x| sum(&[*x]));
+            test_op("product", |x| x, |x| product(&[*x]));
+            test_op("mean", |x| x, |x| mean(&[*x]));
+            test_op("variance", |x| two_pass_variance(&[x]), |x| variance(&[*x]));
+            test_op("variance", |x| one_pass_variance(&[x]), |x| variance(&[*x]));
+            test_op(
+                "stddev",
+                |x| two_pass_variance(&[x]).sqrt(),
+                |x| stddev(&[*x]),
+            );
+            test_op(
+                "stddev",
+                |x| one_pass_variance(&[x]).sqrt(),
+                |x| stddev(&[*x]),
+            );
+
+            test_op2("sum", |x, y| x + y, |x, y| sum(&[*x, *y]));
+            test_op2("product", |x, y| x * y, |x, y| product(&[*x, *y]));
+            test_op2(
+                "product",
+                |x, y| [x, y, y].iter().product(),
+                |x, y| iter_product(x, &[*x, *y, *y]),
+            );
+            test_op2(
+                "sum",
+                |x, y| [y, x, y].iter().sum(),
+                |x, y| iter_sum(x, &[*y, *x, *y]),
+            );
+            test_op2("mean", |x, y| (x + y) / 2.0, |x, y| mean(&[*x, *y]));
+            test_op2(
+                "variance",
+                |x, y| two_pass_variance(&[x, y]),
+                |x, y| variance(&[*x, *y]),
+            );
+            test_op2(
+                "variance",
+                |x, y| one_pass_variance(&[x, y]),
+                |x, y| variance(&[*x, *y]),
+            );
+            test_op2(
+                "stddev",
+                |x, y| two_pass_variance(&[x, y]).sqrt(),
+                |x, y| stddev(&[*x, *y]),
+            );
+            test_op2(
+                "stddev",
+                |x, y| one_pass_variance(&[x, y]).sqrt(),
+                |x, y| stddev(&[*x, *y]),
+            );
+        }
+
+        #[cfg(feature = "oracle")]
+        fn test_oracle(
+            name: &str,
+            op: fn($float) -> $float,
+            exact: fn($float) -> Exact,
+            ty: fn(&FnArgFloat) -> FnArgFloat,
+        ) {
+            let possibles = possibilities();
+            let values = $float::VALUES;
+
+            for v in values.iter() {
+                for p in possibles.iter() {
+                    // With the subnormals of the width
+                    if !FnArgFloat::$mod(*p).accept(*v) {
+                        continue;
+                    }
+
+                    let rounded = op(*v);
+                    let result = exact(*v);
+                    let res_p = ty(&FnArgFloat::$mod(*p));
+                    match res_p {
+                        FnArgFloat::$mod(res_p) => {
+                            if let Some(field) =
+                                misjudged(rounded as f64, &result.possibilities(), &res_p)
+                            {
+                                panic!(
+                                    "{name}({v:?}) = {rounded:?}, exactly {result:?}: {field:?} with {p} = {res_p}"
+                                );
+                            }
+                        }
+                        _ => panic!("Invalid result"),
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "oracle")]
+        fn test_oracle2(
+            name: &str,
+            op: fn($float, $float) -> $float,
+            exact: fn($float, $float) -> Exact,
+            ty: fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat,
+        ) {
+            let possibles = possibilities();
+            let values = $float::VALUES;
+
+            let results: Vec<Vec<($float, Exact, FloatPossibilities)>> = values
+                .iter()
+                .map(|v1| {
+                    values
+                        .iter()
+                        .map(|v2| {
+                            let result = exact(*v1, *v2);
+                            let reached = result.possibilities();
+                            (op(*v1, *v2), result, reached)
+                        })
+                        .collect()
+                })
+                .collect();
+            let accepted: Vec<Vec<usize>> = possibles
+                .iter()
+                .map(|p| {
+                    (0..values.len())
+                        .filter(|i| FnArgFloat::$mod(*p).accept(values[*i]))
+                        .collect()
+                })
+                .collect();
+
+            for (p1, indexes1) in possibles.iter().zip(&accepted) {
+                for (p2, indexes2) in possibles.iter().zip(&accepted) {
+                    let res_p = match ty(&FnArgFloat::$mod(*p1), &FnArgFloat::$mod(*p2)) {
+                        FnArgFloat::$mod(res_p) => res_p,
+                        _ => panic!("Invalid result"),
+                    };
+
+                    for i in indexes1 {
+                        for j in indexes2 {
+                            let (rounded, result, reached) = &results[*i][*j];
+                            if let Some(field) = misjudged(*rounded as f64, reached, &res_p) {
+                                panic!(
+                                    "{name}({:?}, {:?}) = {rounded:?}, exactly {result:?}: {field:?} with {p1} {p2} = {res_p}",
+                                    values[*i],
+                                    values[*j]
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Operations whose floating point result is always exact
+        #[cfg(feature = "oracle")]
+        macro_rules! test_oracle_exact {
+            ($op:ident) => {
+                test_oracle(
+                    stringify!($op),
+                    |x| x.$op(),
+                    |x| Exact::from_f64(x.$op() as f64),
+                    fn_num_types::core::ops::$op,
+                );
+            };
+        }
+
+        #[cfg(feature = "oracle")]
+        macro_rules! test_oracle_exact2 {
+            ($op:ident) => {
+                test_oracle2(
+                    stringify!($op),
+                    |x, y| x.$op(y),
+                    |x, y| Exact::from_f64(x.$op(y) as f64),
+                    |x, y| fn_num_types::core::ops::$op(x, y),
+                );
+            };
+        }
+
+        #[cfg(feature = "oracle")]
+        #[test]
+        fn test_oracle_ops() {
+            test_oracle(
+                "neg",
+                |x| -x,
+                |x| Exact::from_f64(-x as f64),
+                fn_num_types::core::ops::neg,
+            );
+            test_oracle_exact!(abs);
+            test_oracle_exact!(ceil);
+            test_oracle_exact!(floor);
+            test_oracle_exact!(round);
+            test_oracle(
+                "round_ties_even",
+                |x| libm::Libm::<$float>::roundeven(x),
+                |x| Exact::from_f64(libm::Libm::<$float>::roundeven(x) as f64),
+                fn_num_types::core::ops::round_ties_even,
+            );
+            test_oracle_exact!(trunc);
+            test_oracle_exact!(fract);
+            test_oracle_exact!(signum);
+            test_oracle(
+                "recip",
+                |x| x.recip(),
+                |x| oracle::recip(&Exact::from_f64(x as f64)),
+                fn_num_types::core::ops::recip,
+            );
+            test_oracle(
+                "powi",
+                |x| x.powi(2),
+                |x| oracle::mul(&Exact::from_f64(x as f64), &Exact::from_f64(x as f64)),
+                fn_num_types::core::ops::powi,
+            );
+        }
+
+        #[cfg(feature = "oracle")]
+        #[test]
+        fn test_oracle_ops2() {
+            test_oracle2(
+                "add",
+                |x, y| x + y,
+                |x, y| oracle::add(&Exact::from_f64(x as f64), &Exact::from_f64(y as f64)),
+                |x, y| fn_num_types::core::ops::add(x, y),
+            );
+            test_oracle2(
+                "mul",
+                |x, y| x * y,
+                |x, y| oracle::mul(&Exact::from_f64(x as f64), &Exact::from_f64(y as f64)),
+                |x, y| fn_num_types::core::ops::mul(x, y),
+            );
+            test_oracle2(
+                "div",
+                |x, y| x / y,
+                |x, y| oracle::div(&Exact::from_f64(x as f64), &Exact::from_f64(y as f64)),
+                |x, y| fn_num_types::core::ops::div(x, y),
+            );
+            test_oracle2(
+                "rem",
+                |x, y| x % y,
+                |x, y| oracle::rem(&Exact::from_f64(x as f64), &Exact::from_f64(y as f64)),
+                |x, y| fn_num_types::core::ops::rem(x, y),
+            );
+            test_oracle2(
+                "sub",
+                |x, y| x - y,
+                |x, y| oracle::sub(&Exact::from_f64(x as f64), &Exact::from_f64(y as f64)),
+                |x, y| x - y,
+            );
+            test_oracle_exact2!(min);
+            test_oracle_exact2!(max);
+            test_oracle_exact2!(copysign);
         }
     };
 }
 
-// mod f32 {
-//     use super::*;
-//     generate_tests!(f32, F32);
-// }
+// Without all the op families, some helpers are unused
+#[cfg_attr(not(feature = "full"), allow(dead_code, unused_macros))]
+mod f32 {
+    use super::*;
+    generate_tests!(f32, F32);
+}
 
+// Without all the op families, some helpers are unused
+#[cfg_attr(not(feature = "full"), allow(dead_code, unused_macros))]
 mod f64 {
     use super::*;
     generate_tests!(f64, F64);
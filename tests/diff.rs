@@ -0,0 +1,43 @@
+use fn_num_types::{FieldDiffs, FloatPossibilities, Possible};
+
+#[test]
+fn test_diff() {
+    for (i, a) in Possible::ALL.iter().enumerate() {
+        for (j, b) in Possible::ALL.iter().enumerate() {
+            let before = FloatPossibilities {
+                pos_zero: *a,
+                ..Default::default()
+            };
+            let after = FloatPossibilities {
+                pos_zero: *b,
+                ..Default::default()
+            };
+
+            let diffs = before.diff(&after);
+
+            assert_eq!(diffs.pos_zero, j as i8 - i as i8);
+            assert_eq!(diffs.widened().is_empty(), j <= i);
+            assert_eq!(diffs.narrowed().is_empty(), j >= i);
+            assert_eq!(diffs.is_empty(), i == j);
+            assert_eq!(after.diff(&before).pos_zero, -diffs.pos_zero);
+        }
+    }
+
+    assert_eq!(
+        FloatPossibilities::default().diff(&FloatPossibilities::default()),
+        FieldDiffs::default()
+    );
+}
+
+#[test]
+fn test_display() {
+    let before = FloatPossibilities::any();
+    let after = FloatPossibilities {
+        nan: Possible::ShouldNot,
+        subnormal: Possible::No,
+        ..before
+    };
+
+    assert_eq!(before.diff(&after).to_string(), "nan -2, subnormal -3");
+    assert_eq!(after.diff(&before).to_string(), "nan +2, subnormal +3");
+}
@@ -0,0 +1,57 @@
+use fn_num_types::{Error, FloatPossibilities, FnArgFloat, ParseError};
+
+#[test]
+fn test_check_same_type() {
+    let fp = FloatPossibilities::default();
+
+    assert_eq!(FnArgFloat::check_same_type(&[FnArgFloat::F32(fp)]), Ok(()));
+    assert_eq!(
+        FnArgFloat::check_same_type(&[FnArgFloat::F64(fp), FnArgFloat::F64(fp)]),
+        Ok(())
+    );
+    assert_eq!(
+        FnArgFloat::check_same_type(&[FnArgFloat::F64(fp), FnArgFloat::F32(fp)]),
+        Err(Error::DifferentTypes)
+    );
+    assert_eq!(FnArgFloat::check_same_type(&[]), Err(Error::NoArguments));
+}
+
+#[test]
+fn test_parse_error() {
+    let err: Error = "f8".parse::<FnArgFloat>().unwrap_err().into();
+
+    assert_eq!(
+        err,
+        Error::Parse(ParseError {
+            token: "f8".to_owned()
+        })
+    );
+    assert_eq!(err.to_string(), "invalid token `f8`");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_error_source() {
+    use std::error::Error as _;
+
+    let err: Error = "f8".parse::<FnArgFloat>().unwrap_err().into();
+
+    assert!(err.source().is_some());
+    assert!(Error::DifferentTypes.source().is_none());
+}
+
+#[cfg(feature = "arith")]
+#[test]
+fn test_try_ops() {
+    use fn_num_types::core::ops;
+
+    let fp = FloatPossibilities::default();
+    let (a, b) = (FnArgFloat::F32(fp), FnArgFloat::F64(fp));
+
+    assert_eq!(ops::try_add(&a, &a), Ok(ops::add(&a, &a)));
+    assert_eq!(ops::try_add(&a, &b), Err(Error::DifferentTypes));
+    assert_eq!(ops::try_mul_add(&b, &b, &a), Err(Error::DifferentTypes));
+    assert_eq!(ops::try_sum(&[b, b]), Ok(ops::sum(&[b, b])));
+    assert_eq!(ops::try_sum(&[b, a]), Err(Error::DifferentTypes));
+    assert_eq!(ops::try_mean(&[]), Err(Error::NoArguments));
+}
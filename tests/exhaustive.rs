@@ -0,0 +1,73 @@
+//! Every `f32`, too slow for the default tests:
+//! `cargo test --release --test exhaustive -- --ignored`
+
+#![cfg(all(feature = "std", feature = "full"))]
+
+use fn_num_types::core::ops;
+use fn_num_types::verify::verify_unary_f32_exhaustive;
+use fn_num_types::FnArgFloat;
+
+fn verify(name: &str, op: fn(f32) -> f32, analysis: fn(&FnArgFloat) -> FnArgFloat) {
+    if let Some(c) = verify_unary_f32_exhaustive(op, analysis).first() {
+        panic!("{name}{c}");
+    }
+}
+
+macro_rules! verify {
+    ($op:ident) => {
+        verify(stringify!($op), f32::$op, ops::$op);
+    };
+}
+
+#[test]
+#[ignore = "exhaustive"]
+fn test_arith_exhaustive() {
+    verify("neg", |x| -x, ops::neg);
+    verify!(abs);
+    verify!(ceil);
+    verify!(floor);
+    verify!(round);
+    verify("round_ties_even", libm::roundevenf, ops::round_ties_even);
+    verify!(trunc);
+    verify!(fract);
+    verify!(signum);
+    verify!(sqrt);
+    verify!(cbrt);
+    verify!(recip);
+}
+
+#[test]
+#[ignore = "exhaustive"]
+fn test_exp_log_exhaustive() {
+    verify!(exp);
+    verify!(exp2);
+    verify!(ln);
+    verify!(log2);
+    verify!(log10);
+    verify!(exp_m1);
+    verify!(ln_1p);
+}
+
+#[test]
+#[ignore = "exhaustive"]
+fn test_trig_exhaustive() {
+    verify!(to_degrees);
+    verify!(to_radians);
+    verify!(sin);
+    verify!(cos);
+    verify!(tan);
+    verify!(asin);
+    verify!(acos);
+    verify!(atan);
+}
+
+#[test]
+#[ignore = "exhaustive"]
+fn test_hyperbolic_exhaustive() {
+    verify!(sinh);
+    verify!(cosh);
+    verify!(tanh);
+    verify!(asinh);
+    verify!(acosh);
+    verify!(atanh);
+}
@@ -0,0 +1,102 @@
+#![cfg(all(feature = "std", feature = "full"))]
+
+use fn_num_types::core::ops::{self, op_names};
+use fn_num_types::explain::explain;
+use fn_num_types::{Error, Field, FloatPossibilities, FnArgFloat, Possible};
+
+const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+
+#[test]
+fn test_rules_have_fields() {
+    let possibilities: Vec<FnArgFloat> = FloatPossibilities::enumerate(&YESNO)
+        .map(FnArgFloat::F64)
+        .collect();
+    let any = FnArgFloat::f64_any();
+
+    for (name, arity) in op_names() {
+        for x in possibilities.iter() {
+            let args = match arity {
+                Some(1) => vec![vec![*x]],
+                Some(3) => vec![vec![*x, any, any], vec![any, *x, any], vec![any, any, *x]],
+                // The binary and the n-ary ones
+                _ => possibilities.iter().map(|y| vec![*x, *y]).collect(),
+            };
+
+            for args in args {
+                let Ok(explanation) = explain(name, &args) else {
+                    continue;
+                };
+                for rule in explanation.rules {
+                    assert!(!rule.fields().is_empty(), "{name}: {}", rule.name);
+                    assert_ne!(rule.possible, Possible::No);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rules() {
+    let any = FnArgFloat::f64_any();
+    let finite = FnArgFloat::F64(FloatPossibilities::finite());
+
+    let rules = explain("mul", &[finite, finite]).unwrap().rules;
+    let names: Vec<&str> = rules.iter().map(|rule| rule.name).collect();
+    assert_eq!(names, ["underflow", "overflow"]);
+
+    // Those of the models applied, in order
+    let rules = explain("sum", &[any, any, any]).unwrap().rules;
+    let names: Vec<&str> = rules.iter().map(|rule| rule.name).collect();
+    assert_eq!(
+        names,
+        [
+            "negative sum",
+            "positive sum",
+            "negative overflow",
+            "positive overflow",
+            "opposite infinities",
+            "cancellation"
+        ]
+    );
+
+    // Not possible, not fired
+    assert!(explain("abs", &[any]).unwrap().rules.is_empty());
+    let positive = FnArgFloat::F64(FloatPossibilities::strictly_positive_finite());
+    let rules = explain("add", &[positive, positive]).unwrap().rules;
+    assert!(rules.iter().all(|rule| rule.name != "opposite infinities"));
+}
+
+#[test]
+fn test_explain() {
+    let non_nan = FnArgFloat::F64(FloatPossibilities::non_nan());
+    let positive = FnArgFloat::F64(FloatPossibilities::strictly_positive_finite());
+
+    let explanation = explain("sqrt", &[non_nan]).unwrap();
+    assert_eq!(explanation.result, ops::sqrt(&non_nan));
+    let nan = explanation.field(Field::Nan).unwrap();
+    assert_eq!(nan.inputs, [(0, Field::Negative)]);
+    assert_eq!(nan.to_string(), "nan is Yes, from negative of argument 0");
+    let infinite = explanation.field(Field::Infinite).unwrap();
+    assert_eq!(infinite.inputs, [(0, Field::Infinite)]);
+    assert!(infinite.rules.is_empty());
+
+    let explanation = explain("div", &[positive, non_nan]).unwrap();
+    let infinite = explanation.field(Field::Infinite).unwrap();
+    let names: Vec<&str> = infinite.rules.iter().map(|rule| rule.name).collect();
+    assert_eq!(names, ["overflow", "division by zero"]);
+    assert_eq!(infinite.possible, Possible::Yes);
+    assert!(explanation.field(Field::Nan).is_none());
+
+    // The possible fields only, in order
+    let fields: Vec<Field> = explanation.fields().iter().map(|f| f.field).collect();
+    let expected: Vec<Field> = Field::ALL
+        .into_iter()
+        .filter(|field| explanation.result.possibilities().get(*field) != Possible::No)
+        .collect();
+    assert_eq!(fields, expected);
+
+    assert_eq!(
+        explain("sqrtf", &[non_nan]),
+        Err(Error::UnknownOp("sqrtf".to_owned()))
+    );
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "full")]
+
+use fn_num_types::core::ops::{self, op_names};
+use fn_num_types::export::op_table;
+use fn_num_types::FnArgFloat;
+
+#[test]
+fn test_op_table() {
+    let table = op_table();
+
+    let fixed = op_names().filter(|(_, arity)| arity.is_some()).count();
+    assert_eq!(table.len(), fixed);
+
+    for op in &table {
+        let expected = match op.arity {
+            1 => 512,
+            arity => 64usize.pow(arity as u32),
+        };
+        assert_eq!(op.transfer.len(), expected, "{}", op.name);
+        assert!(op.transfer.iter().all(|t| t.args.len() == op.arity));
+    }
+
+    let add = table.iter().find(|op| op.name == "add").unwrap();
+    for transfer in add.transfer.iter().step_by(97) {
+        let [x, y] = [transfer.args[0], transfer.args[1]].map(FnArgFloat::F64);
+        assert_eq!(*ops::add(&x, &y).possibilities(), transfer.result);
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_json() {
+    let table = op_table();
+    let neg = table.iter().find(|op| op.name == "neg").unwrap();
+
+    let json = serde_json::to_value(neg).unwrap();
+    assert_eq!(json["name"], "neg");
+    assert_eq!(json["arity"], 1);
+    assert_eq!(json["transfer"][0]["args"][0]["nan"], "Yes");
+    assert_eq!(json["transfer"][0]["result"]["nan"], "Yes");
+}
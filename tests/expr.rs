@@ -0,0 +1,123 @@
+#![cfg(feature = "full")]
+
+use fn_num_types::core::ops;
+use fn_num_types::expr::{eval, parse, BinaryOp, Expr, UnaryOp};
+use fn_num_types::{Error, FloatPossibilities, FnArgFloat, ParseError, Possible};
+
+fn var(index: usize) -> Box<Expr> {
+    Box::new(Expr::Var(index))
+}
+
+#[test]
+fn test_eval() {
+    let x = FnArgFloat::F64(FloatPossibilities::any());
+    let y = FnArgFloat::F64(FloatPossibilities::positive_finite());
+
+    // `(x - y).abs().powf(y)`
+    let expr = Expr::Binary(
+        BinaryOp::Powf,
+        Box::new(Expr::Unary(
+            UnaryOp::Abs,
+            Box::new(Expr::Binary(BinaryOp::Sub, var(0), var(1))),
+        )),
+        var(1),
+    );
+
+    assert_eq!(
+        eval(&expr, &[x, y]),
+        ops::powf(&ops::abs(&ops::add(&x, &ops::neg(&y))), &y)
+    );
+    assert_eq!(eval(&Expr::Var(1), &[x, y]), y);
+}
+
+#[test]
+fn test_constants() {
+    let tiny = Expr::Const(1.0e-40);
+
+    // Subnormal in `f32`, normal in `f64`
+    let p = eval(&tiny, &[FnArgFloat::F32(FloatPossibilities::any())]);
+    assert!(p.is_f32());
+    assert_eq!(p.possibilities().subnormal, Possible::Yes);
+
+    let p = eval(&tiny, &[]);
+    assert!(p.is_f64());
+    assert_eq!(p.possibilities().subnormal, Possible::No);
+
+    // `1.0 / x` with a constant of the type of `x`
+    let expr = Expr::Binary(BinaryOp::Div, Box::new(Expr::Const(1.0)), var(0));
+    let x = FnArgFloat::F32(FloatPossibilities::strictly_positive_finite());
+    let one = FnArgFloat::F32(FloatPossibilities::from(1.0f32));
+    assert_eq!(eval(&expr, &[x]), ops::div(&one, &x));
+}
+
+#[test]
+fn test_parse() {
+    let konst = |value| Box::new(Expr::Const(value));
+
+    assert_eq!(
+        parse("(x - y).abs().powf(y)", &["x", "y"]),
+        Ok(Expr::Binary(
+            BinaryOp::Powf,
+            Box::new(Expr::Unary(
+                UnaryOp::Abs,
+                Box::new(Expr::Binary(BinaryOp::Sub, var(0), var(1))),
+            )),
+            var(1),
+        ))
+    );
+
+    // The precedence and the associativity of Rust
+    assert_eq!(
+        parse("1 - x * 2.5 % y - 1e3", &["x", "y"]),
+        Ok(Expr::Binary(
+            BinaryOp::Sub,
+            Box::new(Expr::Binary(
+                BinaryOp::Sub,
+                konst(1.0),
+                Box::new(Expr::Binary(
+                    BinaryOp::Rem,
+                    Box::new(Expr::Binary(BinaryOp::Mul, var(0), konst(2.5))),
+                    var(1),
+                )),
+            )),
+            konst(1000.0),
+        ))
+    );
+    assert_eq!(
+        parse("-2.0.exp_m1()", &[]),
+        Ok(Expr::Unary(
+            UnaryOp::Neg,
+            Box::new(Expr::Unary(UnaryOp::ExpM1, konst(2.0))),
+        ))
+    );
+
+    assert_eq!(
+        parse("x.sqrtf()", &["x"]),
+        Err(Error::UnknownOp("sqrtf".to_owned()))
+    );
+    assert_eq!(
+        parse("x.sqrt(x)", &["x"]),
+        Err(Error::Arity {
+            op: "sqrt".to_owned(),
+            found: 2
+        })
+    );
+    assert_eq!(
+        parse("x + y", &["x"]),
+        Err(Error::Parse(ParseError {
+            token: "y".to_owned()
+        }))
+    );
+    assert_eq!(
+        parse("(x", &["x"]),
+        Err(Error::Parse(ParseError {
+            token: "end".to_owned()
+        }))
+    );
+    assert_eq!(
+        parse("x y", &["x", "y"]),
+        Err(Error::Parse(ParseError {
+            token: "y".to_owned()
+        }))
+    );
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "full")]
+
+use fn_num_types::core::ops;
+use fn_num_types::extension::{Extended, Extension};
+use fn_num_types::{FloatPossibilities, FnArgFloat};
+
+/// The names of the operations applied, in order
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Last(Option<&'static str>);
+
+impl Extension for Last {
+    fn unary(op: &'static str, _: &Extended<Self>, _: &FnArgFloat) -> Self {
+        Last(Some(op))
+    }
+
+    fn binary(op: &'static str, _: &Extended<Self>, _: &Extended<Self>, _: &FnArgFloat) -> Self {
+        Last(Some(op))
+    }
+}
+
+/// Only the default hooks
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Known(bool);
+
+impl Extension for Known {}
+
+#[test]
+fn test_hooks() {
+    let x: Extended<Last> = Extended::from(FnArgFloat::F64(FloatPossibilities::any()));
+    assert_eq!(x.ext(), &Last(None));
+
+    assert_eq!(x.sqrt().ext(), &Last(Some("sqrt")));
+    assert_eq!(x.sqrt().add(&x).ext(), &Last(Some("add")));
+    assert_eq!(x.sub(&x).ext(), &Last(Some("sub")));
+    assert_eq!(x.sin().arg(), &ops::sin(x.arg()));
+}
+
+#[test]
+fn test_default_hooks() {
+    let x = Extended::new(FnArgFloat::F64(FloatPossibilities::finite()), Known(true));
+
+    assert_eq!(x.ext(), &Known(true));
+    assert_eq!(x.neg().ext(), &Known(false));
+    assert_eq!(x.mul(&x).ext(), &Known(false));
+    assert_eq!(x.mul(&x).arg(), &ops::mul(x.arg(), x.arg()));
+    assert!(x.accept(1.5));
+    assert!(!x.accept(f64::INFINITY));
+
+    let y: Extended<()> = Extended::from(*x.arg());
+    assert_eq!(y.div(&y).arg(), &ops::div(x.arg(), x.arg()));
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "ffi")]
+
+use fn_num_types::ffi::plugin::{self, fnt_register_op, FntOp, FNT_PLUGIN_ABI_VERSION};
+use fn_num_types::ffi::*;
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+
+const POSITIVE: FloatPossibilities = FloatPossibilities {
+    nan: Possible::No,
+    pos_zero: Possible::No,
+    neg_zero: Possible::No,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
+    infinite: Possible::No,
+    positive: Possible::Yes,
+    negative: Possible::No,
+};
+
+#[test]
+fn test_unary() {
+    match fnt_neg(FnArgFloat::F64(POSITIVE)) {
+        FnArgFloat::F64(fp) => {
+            assert_eq!(fp.positive, Possible::No);
+            assert_eq!(fp.negative, Possible::Yes);
+        }
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_binary() {
+    let mut res = FnArgFloat::F64(POSITIVE);
+
+    assert!(fnt_add(
+        FnArgFloat::F32(POSITIVE),
+        FnArgFloat::F32(POSITIVE),
+        &mut res
+    ));
+    assert!(res.is_f32());
+
+    assert!(!fnt_add(
+        FnArgFloat::F32(POSITIVE),
+        FnArgFloat::F64(POSITIVE),
+        &mut res
+    ));
+    assert!(res.is_f32());
+}
+
+#[test]
+fn test_accept() {
+    assert!(fnt_accept(FnArgFloat::F64(POSITIVE), 1.5));
+    assert!(!fnt_accept(FnArgFloat::F64(POSITIVE), -1.5));
+    assert!(!fnt_accept(FnArgFloat::F64(POSITIVE), f64::NAN));
+    // `1e300` is infinite in `f32`
+    assert!(fnt_accept(FnArgFloat::F64(POSITIVE), 1e300));
+    assert!(!fnt_accept(FnArgFloat::F32(POSITIVE), 1e300));
+}
+
+#[test]
+fn test_pack() {
+    let bits = fnt_pack(POSITIVE);
+    assert!(bits < 1 << 18);
+
+    let mut res = FloatPossibilities::any();
+    assert!(fnt_unpack(bits, &mut res));
+    assert_eq!(res, POSITIVE);
+
+    assert!(!fnt_unpack(1 << 18, &mut res));
+    assert_eq!(res, POSITIVE);
+}
+
+unsafe extern "C" fn plugin_abs(args: *const FnArgFloat, res: *mut FnArgFloat) -> bool {
+    res.write(fnt_abs(*args));
+    true
+}
+
+#[test]
+fn test_plugin() {
+    let op = FntOp {
+        abi_version: FNT_PLUGIN_ABI_VERSION,
+        name: b"plugin_abs\0".as_ptr().cast(),
+        arity: 1,
+        apply: plugin_abs,
+    };
+
+    assert!(unsafe { fnt_register_op(&op) });
+    // Already registered
+    assert!(!unsafe { fnt_register_op(&op) });
+
+    let res = plugin::apply("plugin_abs", &[FnArgFloat::F64(POSITIVE)]);
+    assert!(matches!(res, Some(FnArgFloat::F64(fp)) if fp.negative == Possible::No));
+
+    // Invalid arity
+    assert!(plugin::apply("plugin_abs", &[]).is_none());
+    // Unknown operation
+    assert!(plugin::apply("unknown", &[FnArgFloat::F64(POSITIVE)]).is_none());
+}
+
+#[test]
+fn test_plugin_abi_version() {
+    let op = FntOp {
+        abi_version: FNT_PLUGIN_ABI_VERSION + 1,
+        name: b"plugin_next_abi\0".as_ptr().cast(),
+        arity: 1,
+        apply: plugin_abs,
+    };
+
+    assert!(!unsafe { fnt_register_op(&op) });
+    assert!(!unsafe { fnt_register_op(std::ptr::null()) });
+}
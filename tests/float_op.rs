@@ -0,0 +1,93 @@
+#![cfg(all(feature = "std", feature = "full"))]
+
+use fn_num_types::core::ops::{self, apply};
+use fn_num_types::expr::{BinaryOp, UnaryOp};
+use fn_num_types::float_op::{
+    binary_op, register_binary, register_unary, unary_op, BinaryFloatOp, UnaryFloatOp,
+};
+use fn_num_types::verify::{verify_binary_op, verify_unary_op};
+use fn_num_types::{Error, FloatPossibilities, FnArgFloat};
+
+/// `x * y`, badly modeled as `x + y`
+struct WrongMul;
+
+impl BinaryFloatOp for WrongMul {
+    fn name(&self) -> &str {
+        "wrong_mul"
+    }
+
+    fn apply_concrete(&self, x: f64, y: f64) -> f64 {
+        x * y
+    }
+
+    fn apply_abstract(&self, x: &FnArgFloat, y: &FnArgFloat) -> FnArgFloat {
+        ops::add(x, y)
+    }
+}
+
+/// `x.abs()`, under another name
+struct Magnitude;
+
+impl UnaryFloatOp for Magnitude {
+    fn name(&self) -> &str {
+        "magnitude"
+    }
+
+    fn apply_concrete(&self, x: f64) -> f64 {
+        x.abs()
+    }
+
+    fn apply_abstract(&self, x: &FnArgFloat) -> FnArgFloat {
+        ops::abs(x)
+    }
+}
+
+#[test]
+fn test_builtin_ops() {
+    for op in UnaryOp::ALL {
+        assert!(verify_unary_op(op).is_empty(), "{}", op.name());
+        assert!(unary_op(op.name()).is_some());
+    }
+    for op in BinaryOp::ALL {
+        assert!(verify_binary_op(op).is_empty(), "{}", op.name());
+        assert!(binary_op(op.name()).is_some());
+    }
+}
+
+#[test]
+fn test_register() {
+    let x = FnArgFloat::F64(FloatPossibilities::any());
+    let y = FnArgFloat::F32(FloatPossibilities::any());
+
+    assert_eq!(
+        apply("magnitude", &[x]),
+        Err(Error::UnknownOp("magnitude".to_owned()))
+    );
+    assert_eq!(register_unary(Magnitude), Ok(()));
+    assert_eq!(
+        register_unary(Magnitude),
+        Err(Error::DuplicateOp("magnitude".to_owned()))
+    );
+
+    assert_eq!(apply("magnitude", &[x]), Ok(ops::abs(&x)));
+    assert_eq!(
+        apply("magnitude", &[x, x]),
+        Err(Error::Arity {
+            op: "magnitude".to_owned(),
+            found: 2
+        })
+    );
+    assert_eq!(unary_op("magnitude").unwrap().apply_concrete(-2.0), 2.0);
+
+    assert_eq!(register_binary(WrongMul), Ok(()));
+    assert_eq!(apply("wrong_mul", &[x, y]), Err(Error::DifferentTypes));
+    assert!(binary_op("wrong_mul").is_some());
+    assert!(!verify_binary_op(&WrongMul).is_empty());
+
+    // The built-in names are taken
+    assert_eq!(
+        register_unary(UnaryOp::Sqrt),
+        Err(Error::DuplicateOp("sqrt".to_owned()))
+    );
+    assert_eq!(apply("sqrt", &[x]), Ok(ops::sqrt(&x)));
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "full")]
+
+use fn_num_types::core::ops::{self, fp};
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible, FP};
+
+type Unary = (fn(&FP) -> FP, fn(&FnArgFloat) -> FnArgFloat);
+type Binary = (
+    fn(&FP, &FP) -> FP,
+    fn(&FnArgFloat, &FnArgFloat) -> FnArgFloat,
+);
+
+const LEVELS: [Possible; 2] = [Possible::Yes, Possible::No];
+
+/// The possibilities, of the widths of `FnArgFloat`
+fn args(x: FloatPossibilities) -> Vec<FnArgFloat> {
+    vec![
+        FnArgFloat::F32(x),
+        FnArgFloat::F64(x),
+        #[cfg(feature = "extra-widths")]
+        FnArgFloat::F16(x),
+        #[cfg(feature = "extra-widths")]
+        FnArgFloat::BF16(x),
+        #[cfg(feature = "extra-widths")]
+        FnArgFloat::F128(x),
+    ]
+}
+
+#[test]
+fn test_unary() {
+    let ops: &[Unary] = &[
+        (fp::neg, ops::neg),
+        (fp::abs, ops::abs),
+        (fp::round_ties_even, ops::round_ties_even),
+        (fp::sqrt, ops::sqrt),
+        (fp::exp, ops::exp),
+        (fp::ln, ops::ln),
+        (fp::sin, ops::sin),
+        (fp::atanh, ops::atanh),
+        (fp::recip, ops::recip),
+        (fp::next_up, ops::next_up),
+    ];
+
+    for x in FloatPossibilities::enumerate(&LEVELS) {
+        for arg in args(x) {
+            for (direct, op) in ops {
+                assert_eq!(direct(&x), *op(&arg).possibilities(), "{arg:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_binary() {
+    let ops: &[Binary] = &[
+        (fp::add, ops::add),
+        (fp::sub, |x, y| *x - *y),
+        (fp::mul, ops::mul),
+        (fp::div, ops::div),
+        (fp::powf, ops::powf),
+        (fp::hypot, ops::hypot),
+        (fp::max, ops::max),
+    ];
+
+    let xs: Vec<FloatPossibilities> = FloatPossibilities::enumerate(&LEVELS).step_by(5).collect();
+    for x in &xs {
+        for y in &xs {
+            for (a, b) in args(*x).into_iter().zip(args(*y)) {
+                for (direct, op) in ops {
+                    assert_eq!(direct(x, y), *op(&a, &b).possibilities(), "{a:?} {b:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ternary() {
+    let xs: Vec<FloatPossibilities> = FloatPossibilities::enumerate(&LEVELS).step_by(41).collect();
+    for x in &xs {
+        for y in &xs {
+            for z in &xs {
+                let (a, b, c) = (
+                    FnArgFloat::F32(*x),
+                    FnArgFloat::F32(*y),
+                    FnArgFloat::F32(*z),
+                );
+                assert_eq!(
+                    fp::mul_add(x, y, z),
+                    *ops::mul_add(&a, &b, &c).possibilities()
+                );
+                assert_eq!(fp::clamp(x, y, z), *ops::clamp(&a, &b, &c).possibilities());
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "generator")]
+
+use fn_num_types::generator::{Generator, Witness};
+use fn_num_types::{Error, Field, FloatPossibilities, FnArgFloat, Possible};
+
+#[test]
+fn test_deterministic() {
+    let a = Generator::new(7).program(3, 16);
+    let b = Generator::new(7).program(3, 16);
+
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_programs() {
+    for seed in 0..200 {
+        let mut generator = Generator::new(seed);
+        let program = generator.program(2, 6);
+        let possibilities = program.possibilities();
+
+        for _ in 0..50 {
+            let inputs = generator.inputs(&program);
+
+            for (input, fp) in inputs.iter().zip(&program.inputs) {
+                assert!(fp.accept(*input));
+            }
+
+            let result = program.evaluate(&inputs);
+
+            match possibilities {
+                FnArgFloat::F64(fp) => assert!(
+                    fp.accept(result),
+                    "{program:?} {inputs:?} = {result:?} not in {fp:?}"
+                ),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+}
+
+const POSITIVE_FINITE: FloatPossibilities = FloatPossibilities {
+    nan: Possible::No,
+    pos_zero: Possible::No,
+    neg_zero: Possible::No,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
+    infinite: Possible::No,
+    positive: Possible::Yes,
+    negative: Possible::No,
+};
+
+#[test]
+fn test_find_witness() {
+    let mut generator = Generator::new(0);
+
+    match generator.find_witness("add", &[POSITIVE_FINITE; 2], Field::Infinite, 0) {
+        Ok(Witness::Found { inputs, result }) => {
+            assert!(inputs.iter().all(|x| POSITIVE_FINITE.accept(*x)));
+            assert_eq!(inputs[0] + inputs[1], result);
+            assert!(result.is_infinite());
+        }
+        witness => panic!("{witness:?}"),
+    }
+
+    assert_eq!(
+        generator.find_witness("sqrt", &[POSITIVE_FINITE], Field::Nan, 100),
+        Ok(Witness::NotFound { tried: 105 })
+    );
+
+    assert_eq!(
+        generator.find_witness("foo", &[POSITIVE_FINITE], Field::Nan, 100),
+        Err(Error::UnknownOp("foo".to_owned()))
+    );
+    assert_eq!(
+        generator.find_witness("add", &[POSITIVE_FINITE], Field::Nan, 100),
+        Err(Error::Arity {
+            op: "add".to_owned(),
+            found: 1
+        })
+    );
+}
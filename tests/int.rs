@@ -0,0 +1,306 @@
+#![cfg(feature = "integer")]
+
+use fn_num_types::core::ops::int;
+use fn_num_types::{
+    FnArgInt, IntChecked, IntOutcome, IntOverflowing, IntPossibilities, IntValue, Possible,
+};
+
+const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+
+fn get_possibilities() -> Vec<IntPossibilities> {
+    let mut possibles = vec![];
+
+    for zero in YESNO {
+        for positive in YESNO {
+            for negative in YESNO {
+                for min_value in YESNO {
+                    for max_value in YESNO {
+                        possibles.push(IntPossibilities {
+                            zero,
+                            positive,
+                            negative,
+                            min_value,
+                            max_value,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    possibles
+}
+
+/// The possibilities of exactly this value
+fn exact<T: IntValue>(value: T) -> IntPossibilities {
+    let possible = |b: bool| if b { Possible::Yes } else { Possible::No };
+
+    IntPossibilities {
+        zero: possible(value == T::ZERO),
+        positive: possible(value > T::ZERO),
+        negative: possible(value < T::ZERO),
+        min_value: possible(value == T::MIN),
+        max_value: possible(value == T::MAX),
+    }
+}
+
+type Op = fn(&FnArgInt, &FnArgInt) -> IntOutcome;
+
+macro_rules! generate_tests {
+    ($int:ident, $mod:ident) => {
+        mod $int {
+            use super::*;
+
+            const VALUES: [$int; 11] = [
+                $int::MIN,
+                $int::MIN + 1,
+                $int::MIN / 2,
+                (-2i8) as $int,
+                (-1i8) as $int,
+                0,
+                1,
+                2,
+                $int::MAX / 2,
+                $int::MAX - 1,
+                $int::MAX,
+            ];
+
+            /// `checked` returns `None` on overflow, `panics` tells if it always panics
+            fn check(
+                name: &str,
+                checked: fn($int, $int) -> Option<$int>,
+                panics: fn($int, $int) -> bool,
+                v1: $int,
+                v2: $int,
+                outcome: &IntOutcome,
+            ) {
+                let ip = possibilities(&outcome.value);
+
+                if panics(v1, v2) {
+                    assert_ne!(outcome.panic, Possible::No, "{name}({v1}, {v2}) panics");
+                } else {
+                    match checked(v1, v2) {
+                        Some(result) => {
+                            assert!(
+                                ip.accept(result),
+                                "{name}({v1}, {v2}) = {result}: {outcome:?}"
+                            )
+                        }
+                        None => assert_ne!(
+                            outcome.overflow,
+                            Possible::No,
+                            "{name}({v1}, {v2}) overflows"
+                        ),
+                    }
+                }
+            }
+
+            /// Calls `check` with the possibilities of the grid and of all the values,
+            /// and the values they accept
+            fn for_each_input(check: impl Fn(&FnArgInt, &FnArgInt, $int, $int)) {
+                let possibles = get_possibilities();
+
+                for p1 in possibles.iter() {
+                    for p2 in possibles.iter() {
+                        let (arg1, arg2) = (FnArgInt::$mod(*p1), FnArgInt::$mod(*p2));
+
+                        for v1 in VALUES.iter().filter(|v| p1.accept(**v)) {
+                            for v2 in VALUES.iter().filter(|v| p2.accept(**v)) {
+                                check(&arg1, &arg2, *v1, *v2);
+                            }
+                        }
+                    }
+                }
+
+                // All the values, with their exact possibilities
+                for v1 in $int::MIN..=$int::MAX {
+                    for v2 in $int::MIN..=$int::MAX {
+                        let (arg1, arg2) = (FnArgInt::$mod(exact(v1)), FnArgInt::$mod(exact(v2)));
+                        check(&arg1, &arg2, v1, v2);
+                    }
+                }
+            }
+
+            fn possibilities(value: &FnArgInt) -> IntPossibilities {
+                match value {
+                    FnArgInt::$mod(ip) => *ip,
+                    _ => panic!("Invalid result"),
+                }
+            }
+
+            fn test_op(
+                name: &str,
+                op: Op,
+                checked: fn($int, $int) -> Option<$int>,
+                panics: fn($int, $int) -> bool,
+            ) {
+                for_each_input(|arg1, arg2, v1, v2| {
+                    check(name, checked, panics, v1, v2, &op(arg1, arg2));
+                });
+            }
+
+            fn test_variants(
+                name: &str,
+                ops: (
+                    fn(&FnArgInt, &FnArgInt) -> IntChecked,
+                    fn(&FnArgInt, &FnArgInt) -> FnArgInt,
+                    fn(&FnArgInt, &FnArgInt) -> FnArgInt,
+                    fn(&FnArgInt, &FnArgInt) -> IntOverflowing,
+                ),
+                std: (
+                    fn($int, $int) -> Option<$int>,
+                    fn($int, $int) -> $int,
+                    fn($int, $int) -> $int,
+                    fn($int, $int) -> ($int, bool),
+                ),
+            ) {
+                for_each_input(|arg1, arg2, v1, v2| {
+                    let checked = ops.0(arg1, arg2);
+                    match std.0(v1, v2) {
+                        Some(result) => assert!(
+                            possibilities(&checked.value).accept(result),
+                            "checked_{name}({v1}, {v2}) = {result}: {checked:?}"
+                        ),
+                        None => {
+                            assert_ne!(checked.none, Possible::No, "checked_{name}({v1}, {v2})")
+                        }
+                    }
+
+                    let result = std.1(v1, v2);
+                    let wrapping = ops.1(arg1, arg2);
+                    assert!(
+                        possibilities(&wrapping).accept(result),
+                        "wrapping_{name}({v1}, {v2}) = {result}: {wrapping:?}"
+                    );
+
+                    let result = std.2(v1, v2);
+                    let saturating = ops.2(arg1, arg2);
+                    assert!(
+                        possibilities(&saturating).accept(result),
+                        "saturating_{name}({v1}, {v2}) = {result}: {saturating:?}"
+                    );
+
+                    let (result, overflow) = std.3(v1, v2);
+                    let overflowing = ops.3(arg1, arg2);
+                    assert!(
+                        possibilities(&overflowing.value).accept(result),
+                        "overflowing_{name}({v1}, {v2}) = {result}: {overflowing:?}"
+                    );
+                    if overflow {
+                        assert_ne!(
+                            overflowing.overflow,
+                            Possible::No,
+                            "overflowing_{name}({v1}, {v2})"
+                        );
+                    }
+                });
+            }
+
+            fn never(_: $int, _: $int) -> bool {
+                false
+            }
+
+            #[test]
+            fn test_ops() {
+                test_op("add", int::add, $int::checked_add, never);
+                test_op("sub", int::sub, $int::checked_sub, never);
+                test_op("mul", int::mul, $int::checked_mul, never);
+                // Division by zero and MIN / -1
+                test_op("div", int::div, $int::checked_div, |v1, v2| {
+                    v1.checked_div(v2).is_none()
+                });
+                test_op("rem", int::rem, $int::checked_rem, |v1, v2| {
+                    v1.checked_rem(v2).is_none()
+                });
+            }
+
+            #[test]
+            fn test_overflow_variants() {
+                test_variants(
+                    "add",
+                    (
+                        int::checked_add,
+                        int::wrapping_add,
+                        int::saturating_add,
+                        int::overflowing_add,
+                    ),
+                    (
+                        $int::checked_add,
+                        $int::wrapping_add,
+                        $int::saturating_add,
+                        $int::overflowing_add,
+                    ),
+                );
+                test_variants(
+                    "sub",
+                    (
+                        int::checked_sub,
+                        int::wrapping_sub,
+                        int::saturating_sub,
+                        int::overflowing_sub,
+                    ),
+                    (
+                        $int::checked_sub,
+                        $int::wrapping_sub,
+                        $int::saturating_sub,
+                        $int::overflowing_sub,
+                    ),
+                );
+                test_variants(
+                    "mul",
+                    (
+                        int::checked_mul,
+                        int::wrapping_mul,
+                        int::saturating_mul,
+                        int::overflowing_mul,
+                    ),
+                    (
+                        $int::checked_mul,
+                        $int::wrapping_mul,
+                        $int::saturating_mul,
+                        $int::overflowing_mul,
+                    ),
+                );
+            }
+        }
+    };
+}
+
+generate_tests!(i8, I8);
+generate_tests!(u8, U8);
+
+#[test]
+fn test_div_overflow_panics() {
+    let outcome = int::div(&FnArgInt::I8(exact(i8::MIN)), &FnArgInt::I8(exact(-1i8)));
+
+    assert_eq!(outcome.panic, Possible::Yes);
+    assert_eq!(outcome.overflow, Possible::No);
+}
+
+#[test]
+#[should_panic(expected = "Different types")]
+fn test_different_types() {
+    int::add(&FnArgInt::I8(exact(1i8)), &FnArgInt::U8(exact(1u8)));
+}
+
+#[test]
+fn test_add_variants() {
+    let positive = exact(100i8);
+
+    // Always saturates at MAX
+    match int::saturating_add(&FnArgInt::I8(positive), &FnArgInt::I8(positive)) {
+        FnArgInt::I8(ip) => {
+            assert_eq!(ip.max_value, Possible::Yes);
+            assert_eq!(ip.negative, Possible::No);
+        }
+        _ => unreachable!(),
+    }
+
+    // Always overflows, and wraps around to a negative value
+    let outcome = int::overflowing_add(&FnArgInt::I8(positive), &FnArgInt::I8(positive));
+    assert_eq!(outcome.overflow, Possible::Yes);
+    match outcome.value {
+        FnArgInt::I8(ip) => assert_eq!(ip.negative, Possible::Yes),
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,314 @@
+#![cfg(all(feature = "std", feature = "full"))]
+
+use fn_num_types::interval::{Interval, RangedFloat};
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+
+const ANY: FloatPossibilities = FloatPossibilities {
+    nan: Possible::Yes,
+    pos_zero: Possible::Yes,
+    neg_zero: Possible::Yes,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
+    infinite: Possible::Yes,
+    positive: Possible::Yes,
+    negative: Possible::Yes,
+};
+
+fn get_possibilities() -> Vec<FloatPossibilities> {
+    vec![
+        ANY,
+        FloatPossibilities {
+            nan: Possible::No,
+            ..ANY
+        },
+        FloatPossibilities {
+            fractional: Possible::No,
+            ..ANY
+        },
+        FloatPossibilities {
+            negative: Possible::No,
+            ..ANY
+        },
+    ]
+}
+
+fn get_intervals() -> Vec<Interval> {
+    vec![
+        Interval::ALL,
+        Interval::new(-1.0, 1.0),
+        Interval::new(0.5, 2.0),
+        Interval::new(-3.0, -1.0),
+        Interval::new(-0.75, 0.5),
+        Interval::new(0.0, 0.0),
+        Interval::new(2.0, 2.0),
+        Interval::new(-1.0e10, 1.0e10),
+        Interval::new(1.0e10, f64::INFINITY),
+        Interval::new(f64::NEG_INFINITY, -1.0),
+        Interval::new(0.0, 1.0e-300),
+    ]
+}
+
+#[test]
+fn test_interval() {
+    assert!(Interval::EMPTY.is_empty());
+    assert!(!Interval::ALL.is_empty());
+    assert!(Interval::ALL.contains(f64::INFINITY));
+    assert!(!Interval::ALL.contains(f64::NAN));
+    assert!(Interval::new(0.0, 0.0).contains(-0.0));
+
+    let a = Interval::new(0.0, 2.0);
+    let b = Interval::new(1.0, 3.0);
+    assert_eq!(a.hull(&b), Interval::new(0.0, 3.0));
+    assert_eq!(a.intersection(&b), Interval::new(1.0, 2.0));
+    assert_eq!(a.intersection(&Interval::new(5.0, 6.0)), Interval::EMPTY);
+}
+
+#[test]
+fn test_narrowing() {
+    let x = RangedFloat::new(FnArgFloat::F64(ANY), Interval::new(0.25, 0.5));
+    let fp = x.arg().possibilities();
+
+    assert_eq!(fp.nan, Possible::Yes);
+    assert_eq!(fp.zero(), Possible::No);
+    assert_eq!(fp.negative, Possible::No);
+    assert_eq!(fp.large, Possible::No);
+    assert_eq!(fp.infinite, Possible::No);
+    assert!(x.accept(0.3));
+    assert!(!x.accept(0.6));
+
+    // And the possibilities narrow the range
+    let positive = FloatPossibilities {
+        negative: Possible::No,
+        large: Possible::No,
+        infinite: Possible::No,
+        ..ANY
+    };
+    let x = RangedFloat::from(FnArgFloat::F64(positive));
+    assert_eq!(*x.range(), Interval::new(0.0, 1.0));
+
+    // No `f32` value is in the range
+    let x = RangedFloat::new(FnArgFloat::F32(ANY), Interval::point(0.1));
+    assert!(x.range().is_empty());
+    assert_eq!(
+        x.arg().possibilities().positive | x.arg().possibilities().negative,
+        Possible::No
+    );
+}
+
+#[test]
+fn test_ops() {
+    let any = FnArgFloat::F64(ANY);
+    let x = RangedFloat::new(any, Interval::new(1.0, 4.0));
+    let y = RangedFloat::new(any, Interval::new(-2.0, 0.5));
+
+    assert_eq!(*x.sqrt().range(), Interval::new(1.0, 2.0));
+    assert_eq!(*x.mul(&y).range(), Interval::new(-8.0, 2.0));
+    assert_eq!(*y.abs().range(), Interval::new(0.0, 2.0));
+    assert_eq!(*x.recip().range(), Interval::new(0.25, 1.0));
+
+    // The square root of a negative value is NaN
+    let negative = RangedFloat::new(any, Interval::new(-4.0, -1.0));
+    let sqrt = negative.sqrt();
+    assert!(sqrt.range().is_empty());
+    assert!(sqrt.accept(f64::NAN));
+    assert!(!sqrt.accept(1.0));
+
+    // No overflow
+    let sum = x.add(&x);
+    assert_eq!(sum.arg().possibilities().infinite, Possible::No);
+    assert!(sum.accept(8.0));
+    assert!(!sum.accept(9.0));
+
+    // Which the flags alone may not tell
+    let exp = x.exp();
+    assert_eq!(exp.arg().possibilities().infinite, Possible::No);
+    assert!(exp.accept(4.0f64.exp()));
+    assert!(!exp.accept(100.0));
+
+    // Without a specific rule, the bounds of the possibilities
+    let sin = x.sin();
+    assert_eq!(*sin.range(), Interval::new(-1.0, 1.0));
+
+    // The `f32` overflow
+    let big = RangedFloat::new(
+        FnArgFloat::F32(ANY),
+        Interval::new(1.0e38, f64::from(f32::MAX)),
+    );
+    assert!(big.add(&big).accept(f64::INFINITY));
+}
+
+macro_rules! generate_tests {
+    ($float:ident, $variant:ident) => {
+        use super::*;
+
+        fn values() -> Vec<$float> {
+            vec![
+                $float::NAN,
+                $float::NEG_INFINITY,
+                $float::MIN,
+                -1.0e10,
+                -3.0,
+                -1.5,
+                -1.0,
+                -0.75,
+                -0.5,
+                -$float::MIN_POSITIVE,
+                -$float::from_bits(1),
+                -0.0,
+                0.0,
+                $float::from_bits(1),
+                $float::MIN_POSITIVE,
+                0.5,
+                0.75,
+                1.0,
+                1.5,
+                2.0,
+                3.0,
+                1.0e10,
+                $float::MAX,
+                $float::INFINITY,
+            ]
+        }
+
+        fn get_args() -> Vec<RangedFloat> {
+            let mut args = vec![];
+
+            for p in get_possibilities() {
+                for range in get_intervals() {
+                    args.push(RangedFloat::new(FnArgFloat::$variant(p), range));
+                }
+            }
+
+            args
+        }
+
+        fn accepted(x: &RangedFloat) -> Vec<$float> {
+            values()
+                .into_iter()
+                .filter(|v| x.accept(f64::from(*v)))
+                .collect()
+        }
+
+        fn test_unary(name: &str, op: fn(&RangedFloat) -> RangedFloat, f: fn($float) -> $float) {
+            for x in get_args() {
+                let res = op(&x);
+
+                for v in accepted(&x) {
+                    let result = f(v);
+                    assert!(
+                        res.accept(f64::from(result)),
+                        "{name}({v:?}) = {result:?} with {x:?} => {res:?}"
+                    );
+                }
+            }
+        }
+
+        fn test_binary(
+            name: &str,
+            op: fn(&RangedFloat, &RangedFloat) -> RangedFloat,
+            f: fn($float, $float) -> $float,
+        ) {
+            let args = get_args();
+
+            for x in args.iter() {
+                let values1 = accepted(x);
+
+                for y in args.iter() {
+                    let res = op(x, y);
+
+                    for v1 in values1.iter() {
+                        for v2 in accepted(y) {
+                            let result = f(*v1, v2);
+                            assert!(
+                                res.accept(f64::from(result)),
+                                "{name}({v1:?}, {v2:?}) = {result:?} with {x:?} {y:?} => {res:?}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_unary_ops() {
+            test_unary("neg", RangedFloat::neg, |x| -x);
+            test_unary("abs", RangedFloat::abs, $float::abs);
+            test_unary("ceil", RangedFloat::ceil, $float::ceil);
+            test_unary("floor", RangedFloat::floor, $float::floor);
+            test_unary("round", RangedFloat::round, $float::round);
+            test_unary(
+                "round_ties_even",
+                RangedFloat::round_ties_even,
+                libm::Libm::<$float>::roundeven,
+            );
+            test_unary("trunc", RangedFloat::trunc, $float::trunc);
+            test_unary("fract", RangedFloat::fract, $float::fract);
+            test_unary("sqrt", RangedFloat::sqrt, $float::sqrt);
+            test_unary("exp", RangedFloat::exp, $float::exp);
+            test_unary("exp2", RangedFloat::exp2, $float::exp2);
+            test_unary("ln", RangedFloat::ln, $float::ln);
+            test_unary("log2", RangedFloat::log2, $float::log2);
+            test_unary("log10", RangedFloat::log10, $float::log10);
+            test_unary("to_degrees", RangedFloat::to_degrees, $float::to_degrees);
+            test_unary("to_radians", RangedFloat::to_radians, $float::to_radians);
+            test_unary("cbrt", RangedFloat::cbrt, $float::cbrt);
+            test_unary("sin", RangedFloat::sin, $float::sin);
+            test_unary("asin", RangedFloat::asin, $float::asin);
+            test_unary("acos", RangedFloat::acos, $float::acos);
+            test_unary("atan", RangedFloat::atan, $float::atan);
+            test_unary("exp_m1", RangedFloat::exp_m1, $float::exp_m1);
+            test_unary("ln_1p", RangedFloat::ln_1p, $float::ln_1p);
+            test_unary("sinh", RangedFloat::sinh, $float::sinh);
+            test_unary("tanh", RangedFloat::tanh, $float::tanh);
+            test_unary("asinh", RangedFloat::asinh, $float::asinh);
+            test_unary("acosh", RangedFloat::acosh, $float::acosh);
+            test_unary("atanh", RangedFloat::atanh, $float::atanh);
+            test_unary("recip", RangedFloat::recip, $float::recip);
+        }
+
+        #[test]
+        fn test_binary_ops() {
+            test_binary("add", RangedFloat::add, |x, y| x + y);
+            test_binary("mul", RangedFloat::mul, |x, y| x * y);
+            test_binary("div", RangedFloat::div, |x, y| x / y);
+            test_binary("rem", RangedFloat::rem, |x, y| x % y);
+            test_binary("max", RangedFloat::max, $float::max);
+            test_binary("min", RangedFloat::min, $float::min);
+            test_binary("hypot", RangedFloat::hypot, $float::hypot);
+        }
+
+        #[test]
+        fn test_mul_add() {
+            let args = get_args();
+
+            for a in args.iter().step_by(3) {
+                for b in args.iter().step_by(3) {
+                    for c in args.iter().step_by(3) {
+                        let res = a.mul_add(b, c);
+
+                        for x in accepted(a) {
+                            for y in accepted(b) {
+                                for z in accepted(c) {
+                                    let result = x.mul_add(y, z);
+                                    assert!(
+                                        res.accept(f64::from(result)),
+                                        "mul_add({x:?}, {y:?}, {z:?}) = {result:?} => {res:?}"
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+mod f32 {
+    generate_tests!(f32, F32);
+}
+
+mod f64 {
+    generate_tests!(f64, F64);
+}
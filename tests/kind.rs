@@ -0,0 +1,142 @@
+#![cfg(feature = "full")]
+
+use std::num::FpCategory;
+
+use fn_num_types::kind::{TypedFloat, F32, F64};
+use fn_num_types::{FloatKind, FloatPossibilities, Possible};
+
+const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+
+fn get_possibilities() -> Vec<FloatPossibilities> {
+    FloatPossibilities::enumerate(&YESNO).collect()
+}
+
+fn test_values<K: FloatKind>() {
+    let values = K::test_values();
+
+    assert!(values.iter().any(|v| K::classify(*v).0 == FpCategory::Nan));
+
+    for category in [
+        FpCategory::Infinite,
+        FpCategory::Zero,
+        FpCategory::Subnormal,
+        FpCategory::Normal,
+    ] {
+        for sign_negative in [false, true] {
+            assert!(
+                values
+                    .iter()
+                    .any(|v| K::classify(*v) == (category, sign_negative)),
+                "No {category:?} {sign_negative} {}",
+                K::NAME
+            );
+        }
+    }
+}
+
+fn test_unary<K: FloatKind>(op: fn(&TypedFloat<K>) -> TypedFloat<K>, f: fn(K::Value) -> K::Value) {
+    for p in get_possibilities() {
+        let x = TypedFloat::<K>::new(p);
+        let res = op(&x);
+
+        for v in K::test_values().into_iter().filter(|v| x.accept(*v)) {
+            let result = f(v);
+            assert!(
+                res.accept(result),
+                "{} {v:?} => {result:?}: {res:?}",
+                K::NAME
+            );
+        }
+    }
+}
+
+fn test_binary<K: FloatKind>(
+    op: fn(&TypedFloat<K>, &TypedFloat<K>) -> TypedFloat<K>,
+    f: fn(K::Value, K::Value) -> K::Value,
+) {
+    for p1 in get_possibilities() {
+        for p2 in get_possibilities() {
+            let (x, y) = (TypedFloat::<K>::new(p1), TypedFloat::<K>::new(p2));
+            let res = op(&x, &y);
+
+            for v1 in K::test_values().into_iter().filter(|v| x.accept(*v)) {
+                for v2 in K::test_values().into_iter().filter(|v| y.accept(*v)) {
+                    let result = f(v1, v2);
+                    assert!(
+                        res.accept(result),
+                        "{} {v1:?}, {v2:?} => {result:?}: {res:?}",
+                        K::NAME
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_provided_kinds() {
+    test_values::<F32>();
+    test_values::<F64>();
+
+    assert_eq!((F32::NAME, F32::BITS), ("f32", 32));
+    assert_eq!((F64::NAME, F64::BITS), ("f64", 64));
+}
+
+#[test]
+fn test_ops() {
+    test_unary::<F32>(TypedFloat::sqrt, f32::sqrt);
+    test_unary::<F64>(TypedFloat::sqrt, f64::sqrt);
+    test_unary::<F32>(TypedFloat::sin, f32::sin);
+    test_unary::<F64>(TypedFloat::exp, f64::exp);
+
+    test_binary::<F32>(TypedFloat::add, |a, b| a + b);
+    test_binary::<F64>(TypedFloat::mul, |a, b| a * b);
+    test_binary::<F32>(TypedFloat::div, |a, b| a / b);
+}
+
+/// A type defined by another crate: a 64 bits decimal float, with its values stored as `f64`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Decimal64;
+
+impl FloatKind for Decimal64 {
+    type Value = f64;
+
+    const NAME: &'static str = "decimal64";
+    const BITS: u32 = 64;
+
+    fn classify(value: f64) -> (FpCategory, bool) {
+        (value.classify(), value.is_sign_negative())
+    }
+
+    fn test_values() -> Vec<f64> {
+        vec![
+            f64::NAN,
+            f64::NEG_INFINITY,
+            -1.0,
+            -0.0,
+            0.0,
+            1.0,
+            f64::INFINITY,
+        ]
+    }
+}
+
+#[test]
+fn test_custom_kind() {
+    let x = TypedFloat::<Decimal64>::new(FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::No,
+        positive: Possible::Yes,
+        negative: Possible::No,
+    });
+
+    assert!(x.accept(0.0));
+    assert!(!x.accept(-1.0));
+
+    assert_eq!(x.sqrt().possibilities().nan, Possible::No);
+}
@@ -0,0 +1,47 @@
+use fn_num_types::{FloatPossibilities, PossibilityLattice, Possible};
+
+#[test]
+fn test_possible() {
+    for a in Possible::ALL {
+        assert!(Possible::bottom().is_below(a));
+        assert!(a.is_below(Possible::top()));
+
+        for b in Possible::ALL {
+            assert_eq!(a.join(b), a | b);
+            assert_eq!(a.meet(b), a & b);
+            assert_eq!(a.is_below(b), a <= b);
+        }
+    }
+}
+
+#[test]
+fn test_float_possibilities() {
+    let possibilities: Vec<FloatPossibilities> =
+        FloatPossibilities::enumerate(&[Possible::No, Possible::Should, Possible::Yes]).collect();
+
+    for a in possibilities.iter().step_by(7) {
+        for b in possibilities.iter().step_by(11) {
+            assert_eq!(a.join(*b), a.union(b));
+            assert_eq!(a.meet(*b), a.intersection(b));
+        }
+    }
+
+    assert_eq!(FloatPossibilities::top(), FloatPossibilities::any());
+    assert_eq!(
+        FloatPossibilities::bottom(),
+        FloatPossibilities::any().map(|_| Possible::No)
+    );
+}
+
+#[test]
+fn test_map() {
+    let fp = FloatPossibilities::finite();
+    let may: FloatPossibilities<bool> = fp.map(|p| p != Possible::No);
+
+    assert!(!may.infinite && !may.nan);
+    assert!(may.join(FloatPossibilities::top()) == FloatPossibilities::top());
+    assert_eq!(
+        may.map(|b| if b { Possible::Yes } else { Possible::No }),
+        fp
+    );
+}
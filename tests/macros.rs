@@ -0,0 +1,64 @@
+#![cfg(feature = "macros")]
+
+use fn_num_types::core::ops;
+use fn_num_types::{analyze, FloatPossibilities, FnArgFloat};
+
+#[analyze]
+fn norm(x: f64, y: f64) -> f64 {
+    let sum = x * x + y * y;
+    sum.sqrt()
+}
+
+#[analyze]
+fn log_one_plus(x: f32) -> f32 {
+    (x.abs() + 1.0).ln()
+}
+
+#[analyze]
+fn ratio(x: f64, y: f64) -> f64 {
+    -x.exp_m1() / y.powf(2.0)
+}
+
+#[analyze]
+fn distance(x: f64, y: f64) -> f64 {
+    (x - y).abs()
+}
+
+/// The possibilities are a `const`, usable in other constants
+const RESULTS: [FnArgFloat; 2] = [NORM_POSSIBILITIES, DISTANCE_POSSIBILITIES];
+
+#[test]
+fn test_analyze() {
+    assert_eq!(norm(3.0, 4.0), 5.0);
+
+    let any = FnArgFloat::F64(FloatPossibilities::any());
+    let signature = norm_signature();
+    assert_eq!(signature.args, vec![any, any]);
+    let sum = ops::add(&ops::mul(&any, &any), &ops::mul(&any, &any));
+    assert_eq!(signature.ret, ops::sqrt(&sum));
+
+    let signature = log_one_plus_signature();
+    assert_eq!(
+        signature.args,
+        vec![FnArgFloat::F32(FloatPossibilities::any())]
+    );
+    let x = signature.args[0];
+    let one = FnArgFloat::F32(FloatPossibilities::from(1.0f32));
+    assert_eq!(signature.ret, ops::ln(&ops::add(&ops::abs(&x), &one)));
+    assert_eq!(log_one_plus(-1.0), 2f32.ln());
+
+    assert_eq!(ratio(0.0, 1.0), -0.0);
+    let two = FnArgFloat::F64(FloatPossibilities::from(2.0));
+    let expected = ops::div(&ops::neg(&ops::exp_m1(&any)), &ops::powf(&any, &two));
+    assert_eq!(ratio_signature().ret, expected);
+}
+
+#[test]
+fn test_possibilities() {
+    assert_eq!(NORM_POSSIBILITIES, norm_signature().ret);
+    assert_eq!(LOG_ONE_PLUS_POSSIBILITIES, log_one_plus_signature().ret);
+    assert_eq!(RATIO_POSSIBILITIES, ratio_signature().ret);
+    assert_eq!(distance(1.0, 3.0), 2.0);
+    assert_eq!(DISTANCE_POSSIBILITIES, distance_signature().ret);
+    assert_eq!(RESULTS[1], distance_signature().ret);
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "num-traits")]
+
+use fn_num_types::num_float::{analyze, FloatAnalysis, METHODS};
+use fn_num_types::verify::Float;
+use fn_num_types::{Error, FloatPossibilities, FloatValue};
+
+/// The concrete method, as written in a generic code
+fn call<T: num_traits::Float>(method: &str, args: &[T]) -> Result<T, bool> {
+    Ok(match (method, args) {
+        ("nan", []) => T::nan(),
+        ("infinity", []) => T::infinity(),
+        ("neg_infinity", []) => T::neg_infinity(),
+        ("neg_zero", []) => T::neg_zero(),
+        ("min_value", []) => T::min_value(),
+        ("min_positive_value", []) => T::min_positive_value(),
+        ("max_value", []) => T::max_value(),
+        ("epsilon", []) => T::epsilon(),
+        ("is_nan", [x]) => return Err(x.is_nan()),
+        ("is_infinite", [x]) => return Err(x.is_infinite()),
+        ("is_finite", [x]) => return Err(x.is_finite()),
+        ("is_normal", [x]) => return Err(x.is_normal()),
+        ("is_subnormal", [x]) => return Err(x.is_subnormal()),
+        ("is_sign_positive", [x]) => return Err(x.is_sign_positive()),
+        ("is_sign_negative", [x]) => return Err(x.is_sign_negative()),
+        ("neg", [x]) => -*x,
+        ("floor", [x]) => x.floor(),
+        ("ceil", [x]) => x.ceil(),
+        ("round", [x]) => x.round(),
+        ("trunc", [x]) => x.trunc(),
+        ("fract", [x]) => x.fract(),
+        ("abs", [x]) => x.abs(),
+        ("signum", [x]) => x.signum(),
+        ("recip", [x]) => x.recip(),
+        ("powi", [x]) => x.powi(3),
+        ("sqrt", [x]) => x.sqrt(),
+        ("exp", [x]) => x.exp(),
+        ("exp2", [x]) => x.exp2(),
+        ("ln", [x]) => x.ln(),
+        ("log2", [x]) => x.log2(),
+        ("log10", [x]) => x.log10(),
+        ("to_degrees", [x]) => x.to_degrees(),
+        ("to_radians", [x]) => x.to_radians(),
+        ("cbrt", [x]) => x.cbrt(),
+        ("sin", [x]) => x.sin(),
+        ("cos", [x]) => x.cos(),
+        ("tan", [x]) => x.tan(),
+        ("asin", [x]) => x.asin(),
+        ("acos", [x]) => x.acos(),
+        ("atan", [x]) => x.atan(),
+        ("exp_m1", [x]) => x.exp_m1(),
+        ("ln_1p", [x]) => x.ln_1p(),
+        ("sinh", [x]) => x.sinh(),
+        ("cosh", [x]) => x.cosh(),
+        ("tanh", [x]) => x.tanh(),
+        ("asinh", [x]) => x.asinh(),
+        ("acosh", [x]) => x.acosh(),
+        ("atanh", [x]) => x.atanh(),
+        ("powf", [x, y]) => x.powf(*y),
+        ("log", [x, y]) => x.log(*y),
+        ("max", [x, y]) => x.max(*y),
+        ("min", [x, y]) => x.min(*y),
+        #[allow(deprecated)]
+        ("abs_sub", [x, y]) => x.abs_sub(*y),
+        ("hypot", [x, y]) => x.hypot(*y),
+        ("atan2", [x, y]) => x.atan2(*y),
+        ("copysign", [x, y]) => x.copysign(*y),
+        ("mul_add", [x, y, z]) => x.mul_add(*y, *z),
+        _ => unreachable!("{method}"),
+    })
+}
+
+fn test_methods<T: num_traits::Float + Float + FloatValue>() {
+    let values = T::VALUES;
+
+    for (method, arity) in METHODS {
+        // Every combination of the values, a few for the ternary method
+        let mut combinations: Vec<Vec<T>> = vec![vec![]];
+        for _ in 0..*arity {
+            let step = if *arity == 3 { 5 } else { 1 };
+            combinations = combinations
+                .into_iter()
+                .flat_map(|args| {
+                    values.iter().step_by(step).map(move |x| {
+                        let mut args = args.clone();
+                        args.push(*x);
+                        args
+                    })
+                })
+                .collect();
+        }
+
+        for args in combinations {
+            let fps: Vec<FloatPossibilities> = args.iter().map(|x| (*x).into()).collect();
+            let analysis = analyze::<T>(method, &fps).unwrap();
+
+            match (analysis, call(method, &args)) {
+                (FloatAnalysis::Float(res), Ok(value)) => {
+                    assert!(res.accept(value), "{method}{args:?}: {value:?}");
+                }
+                (FloatAnalysis::Bool(res), Err(value)) => {
+                    assert!(res.accept(value), "{method}{args:?}: {value}");
+                }
+                (analysis, value) => panic!("{method}: {analysis:?}, {value:?}"),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_f32() {
+    test_methods::<f32>();
+}
+
+#[test]
+fn test_f64() {
+    test_methods::<f64>();
+}
+
+#[test]
+fn test_errors() {
+    let x = FloatPossibilities::any();
+
+    assert_eq!(
+        analyze::<f64>("sin_cos", &[x]),
+        Err(Error::UnknownOp("sin_cos".to_owned()))
+    );
+    assert_eq!(
+        analyze::<f32>("hypot", &[x]),
+        Err(Error::Arity {
+            op: "hypot".to_owned(),
+            found: 1
+        })
+    );
+    assert_eq!(
+        analyze::<f32>("nan", &[x]),
+        Err(Error::Arity {
+            op: "nan".to_owned(),
+            found: 1
+        })
+    );
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "arith")]
+
+use fn_num_types::overflow::OverflowFloat;
+use fn_num_types::verify::{possibilities, VALUES};
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+
+fn finite() -> OverflowFloat {
+    OverflowFloat::from(FnArgFloat::F64(FloatPossibilities::finite()))
+}
+
+fn any() -> OverflowFloat {
+    OverflowFloat::from(FnArgFloat::F64(FloatPossibilities::any()))
+}
+
+#[test]
+fn test_provenance() {
+    let one = OverflowFloat::from(FnArgFloat::from(1.0));
+    let zero = OverflowFloat::from(FnArgFloat::from(0.0));
+
+    assert_eq!(
+        finite().add(&finite()).infinite_from_overflow(),
+        Possible::ShouldNot
+    );
+    assert_eq!(
+        finite().mul(&finite()).infinite_from_overflow(),
+        Possible::ShouldNot
+    );
+    assert_eq!(
+        finite().powi(3).infinite_from_overflow(),
+        Possible::ShouldNot
+    );
+    assert_eq!(
+        finite().hypot(&finite()).infinite_from_overflow(),
+        Possible::ShouldNot
+    );
+    assert_eq!(
+        finite().powf(&finite()).infinite_from_overflow(),
+        Possible::ShouldNot
+    );
+
+    // A division by zero is exact
+    let inf = one.div(&zero);
+    assert_eq!(inf.arg().possibilities().infinite, Possible::Yes);
+    assert_eq!(inf.infinite_from_overflow(), Possible::No);
+
+    // The infinities of the arguments
+    let sum = any().add(&one);
+    assert_eq!(sum.arg().possibilities().infinite, Possible::Yes);
+    assert_eq!(sum.infinite_from_overflow(), Possible::No);
+
+    // No infinity at all
+    let bounded = finite().signum();
+    assert_eq!(bounded.infinite_from_overflow(), Possible::No);
+    assert_eq!(
+        finite().midpoint(&finite()).infinite_from_overflow(),
+        Possible::No
+    );
+    assert_eq!(bounded.add(&bounded).infinite_from_overflow(), Possible::No);
+
+    // Propagated, until there is no infinity left
+    let overflowed = finite().mul(&finite());
+    assert_eq!(
+        overflowed.neg().infinite_from_overflow(),
+        Possible::ShouldNot
+    );
+    assert_eq!(
+        overflowed.sub(&one).infinite_from_overflow(),
+        Possible::ShouldNot
+    );
+    assert_eq!(overflowed.signum().infinite_from_overflow(), Possible::No);
+    assert_eq!(
+        one.mul_add(&one, &overflowed).infinite_from_overflow(),
+        Possible::ShouldNot
+    );
+
+    // Bounded by the infinities
+    let x = OverflowFloat::new(FnArgFloat::from(2.0), Possible::Yes);
+    assert_eq!(x.infinite_from_overflow(), Possible::No);
+}
+
+/// Finite arguments reaching an infinity overflow
+fn test_binary(
+    name: &str,
+    op: fn(&OverflowFloat, &OverflowFloat) -> OverflowFloat,
+    f: fn(f64, f64) -> f64,
+) {
+    let finite: Vec<FloatPossibilities> = possibilities()
+        .into_iter()
+        .filter(|p| p.infinite == Possible::No)
+        .collect();
+
+    for p1 in finite.iter() {
+        for p2 in finite.iter() {
+            let x = OverflowFloat::from(FnArgFloat::F64(*p1));
+            let y = OverflowFloat::from(FnArgFloat::F64(*p2));
+            let res = op(&x, &y);
+
+            for v1 in VALUES.iter().filter(|v| p1.accept(**v)) {
+                for v2 in VALUES.iter().filter(|v| p2.accept(**v)) {
+                    let result = f(*v1, *v2);
+                    // Not a pole, like `1.0 / 0.0`
+                    if result.is_infinite() && *v1 != 0.0 && *v2 != 0.0 {
+                        assert_ne!(
+                            res.infinite_from_overflow(),
+                            Possible::No,
+                            "{name}({v1:?}, {v2:?}) with {p1} {p2}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_finite_overflows() {
+    test_binary("add", OverflowFloat::add, |x, y| x + y);
+    test_binary("sub", OverflowFloat::sub, |x, y| x - y);
+    test_binary("mul", OverflowFloat::mul, |x, y| x * y);
+    test_binary("div", OverflowFloat::div, |x, y| x / y);
+    test_binary("hypot", OverflowFloat::hypot, f64::hypot);
+    test_binary("powf", OverflowFloat::powf, f64::powf);
+}
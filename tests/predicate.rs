@@ -0,0 +1,147 @@
+use fn_num_types::core::ops;
+use fn_num_types::{BoolPossibility, FloatPossibilities, FnArgFloat, Possible};
+
+const YESNO: [Possible; 2] = [Possible::Yes, Possible::No];
+
+fn get_possibilities() -> Vec<FloatPossibilities> {
+    FloatPossibilities::enumerate(&YESNO).collect()
+}
+
+const VALUES: [f64; 11] = [
+    f64::NAN,
+    f64::NEG_INFINITY,
+    f64::MIN,
+    -1.0,
+    -1.0e-310,
+    -0.0,
+    0.0,
+    1.0e-310,
+    1.0,
+    f64::MAX,
+    f64::INFINITY,
+];
+
+type Predicate = (
+    &'static str,
+    fn(&FnArgFloat) -> BoolPossibility,
+    fn(f64) -> bool,
+);
+
+#[test]
+fn test_predicates() {
+    let predicates: [Predicate; 7] = [
+        ("is_nan", ops::is_nan, f64::is_nan),
+        ("is_finite", ops::is_finite, f64::is_finite),
+        ("is_infinite", ops::is_infinite, f64::is_infinite),
+        (
+            "is_sign_positive",
+            ops::is_sign_positive,
+            f64::is_sign_positive,
+        ),
+        (
+            "is_sign_negative",
+            ops::is_sign_negative,
+            f64::is_sign_negative,
+        ),
+        ("is_normal", ops::is_normal, f64::is_normal),
+        ("is_subnormal", ops::is_subnormal, f64::is_subnormal),
+    ];
+
+    for p in get_possibilities() {
+        for (name, predicate, f) in predicates {
+            let res = predicate(&FnArgFloat::F64(p));
+
+            for v in VALUES.iter().filter(|v| p.accept(**v)) {
+                assert!(res.accept(f(*v)), "{name}({v:?}): {p} => {res:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_precision() {
+    let finite = FnArgFloat::F64(FloatPossibilities {
+        nan: Possible::No,
+        pos_zero: Possible::Yes,
+        neg_zero: Possible::Yes,
+        subnormal: Possible::Yes,
+        fractional: Possible::Yes,
+        large: Possible::Yes,
+        infinite: Possible::No,
+        positive: Possible::Yes,
+        negative: Possible::No,
+    });
+
+    assert_eq!(ops::is_nan(&finite), BoolPossibility::False);
+    assert_eq!(ops::is_finite(&finite), BoolPossibility::True);
+    assert_eq!(ops::is_infinite(&finite), BoolPossibility::False);
+    assert_eq!(ops::is_sign_positive(&finite), BoolPossibility::True);
+    assert_eq!(ops::is_sign_negative(&finite), BoolPossibility::False);
+    assert_eq!(ops::is_normal(&finite), BoolPossibility::Maybe);
+
+    let nan = FnArgFloat::F32(FloatPossibilities {
+        nan: Possible::Yes,
+        pos_zero: Possible::No,
+        neg_zero: Possible::No,
+        subnormal: Possible::No,
+        fractional: Possible::No,
+        large: Possible::No,
+        infinite: Possible::No,
+        positive: Possible::No,
+        negative: Possible::No,
+    });
+
+    assert_eq!(ops::is_nan(&nan), BoolPossibility::True);
+    assert_eq!(ops::is_sign_positive(&nan), BoolPossibility::Maybe);
+}
+
+#[test]
+fn test_kleene() {
+    let all = [
+        BoolPossibility::True,
+        BoolPossibility::False,
+        BoolPossibility::Maybe,
+    ];
+
+    for a in all {
+        for b in all {
+            for x in [true, false] {
+                for y in [true, false] {
+                    if a.accept(x) && b.accept(y) {
+                        assert!((a & b).accept(x && y));
+                        assert!((a | b).accept(x || y));
+                    }
+                }
+            }
+        }
+
+        assert_eq!(!!a, a);
+    }
+}
+
+#[test]
+fn test_select() {
+    let values = [f64::NAN, -1.0, -0.0, 0.0, 1.0, f64::INFINITY];
+    let conds = [
+        BoolPossibility::True,
+        BoolPossibility::False,
+        BoolPossibility::Maybe,
+    ];
+
+    for p1 in get_possibilities() {
+        for p2 in get_possibilities() {
+            for cond in conds {
+                let res = ops::select(cond, &FnArgFloat::F64(p1), &FnArgFloat::F64(p2));
+                let res = res.possibilities();
+
+                for c in [true, false].into_iter().filter(|c| cond.accept(*c)) {
+                    let p = if c { p1 } else { p2 };
+
+                    for v in values.iter().filter(|v| p.accept(**v)) {
+                        assert!(res.accept(*v), "{cond:?} {c} {v:?}: {res:?}");
+                    }
+                }
+            }
+        }
+    }
+}
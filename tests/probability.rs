@@ -0,0 +1,139 @@
+#![cfg(feature = "probabilistic")]
+
+use fn_num_types::expr::{BinaryOp, UnaryOp};
+use fn_num_types::probability::{Likelihoods, ProbableFloat};
+use fn_num_types::{Field, FloatPossibilities, FnArgFloat, Possible};
+
+const VALUES: &[f64] = &[
+    f64::NAN,
+    f64::NEG_INFINITY,
+    -f64::MAX,
+    -2.5,
+    -1.0,
+    -0.0,
+    0.0,
+    1e-310,
+    0.5,
+    1.0,
+    3.0,
+    f64::MAX,
+    f64::INFINITY,
+];
+
+fn likelihood(likelihoods: &Likelihoods, field: Field) -> f64 {
+    let fields = [
+        likelihoods.nan,
+        likelihoods.pos_zero,
+        likelihoods.neg_zero,
+        likelihoods.subnormal,
+        likelihoods.fractional,
+        likelihoods.large,
+        likelihoods.infinite,
+        likelihoods.positive,
+        likelihoods.negative,
+    ];
+    let index = Field::ALL.iter().position(|f| *f == field).unwrap();
+    fields[index]
+}
+
+/// The likelihoods are probabilities, only of the possible flags
+fn assert_consistent(x: &ProbableFloat) {
+    let likelihoods = x.likelihoods();
+    for field in Field::ALL {
+        let p = likelihood(&likelihoods, field);
+        assert!((0.0..=1.0 + 1e-9).contains(&p), "{field:?} {p}");
+        if x.arg().possibilities().get(field) == Possible::No {
+            assert_eq!(p, 0.0, "{field:?}");
+        }
+    }
+
+    // A value is either NaN, positive or negative
+    let total = likelihoods.nan + likelihoods.positive + likelihoods.negative;
+    assert!((total - 1.0).abs() < 1e-9, "{total}");
+}
+
+#[test]
+fn test_from_values() {
+    let any = FnArgFloat::F64(FloatPossibilities::any());
+    let x = ProbableFloat::from_values(any, VALUES);
+    assert_consistent(&x);
+
+    for field in Field::ALL {
+        let count = VALUES
+            .iter()
+            .filter(|value| FloatPossibilities::from(**value).get(field) == Possible::Yes)
+            .count();
+        let expected = count as f64 / VALUES.len() as f64;
+        assert!((likelihood(&x.likelihoods(), field) - expected).abs() < 1e-12);
+    }
+
+    // The values not accepted are ignored
+    let finite = FnArgFloat::F64(FloatPossibilities::finite());
+    let x = ProbableFloat::from_values(finite, &[f64::NAN, 1.0, f64::INFINITY]);
+    assert_eq!(x.likelihoods().positive, 1.0);
+    assert_eq!(x.likelihoods().nan, 0.0);
+
+    // Without values, all the kinds are as likely
+    assert_eq!(
+        ProbableFloat::from_values(finite, &[]),
+        ProbableFloat::from(finite)
+    );
+}
+
+#[test]
+fn test_new() {
+    let any = FnArgFloat::F64(FloatPossibilities::any());
+    let seen = ProbableFloat::from_values(any, VALUES).likelihoods();
+    let fitted = ProbableFloat::new(any, &seen).likelihoods();
+
+    for field in Field::ALL {
+        let (a, b) = (likelihood(&seen, field), likelihood(&fitted, field));
+        assert!((a - b).abs() < 1e-6, "{field:?} {a} {b}");
+    }
+
+    // Restricted to the possibilities
+    let positive = FnArgFloat::F64(FloatPossibilities::positive());
+    let x = ProbableFloat::new(positive, &seen);
+    assert_consistent(&x);
+    assert_eq!(x.likelihoods().negative, 0.0);
+}
+
+#[test]
+fn test_ops() {
+    let any = FnArgFloat::F64(FloatPossibilities::any());
+    let x = ProbableFloat::from_values(any, VALUES);
+    let y = ProbableFloat::from(FnArgFloat::F64(FloatPossibilities::finite()));
+
+    for op in UnaryOp::ALL {
+        let res = x.apply_unary(*op);
+        assert_eq!(res.arg(), &op.model()(x.arg()), "{}", op.name());
+        assert_consistent(&res);
+    }
+    for op in BinaryOp::ALL {
+        let res = x.apply_binary(*op, &y);
+        assert_eq!(res.arg(), &op.model()(x.arg(), y.arg()), "{}", op.name());
+        assert_consistent(&res);
+    }
+}
+
+#[test]
+fn test_estimates() {
+    let finite = FnArgFloat::F64(FloatPossibilities::finite());
+
+    // A quarter of negative values, only their roots may be NaN
+    let x = ProbableFloat::from_values(finite, &[-2.0, 2.0, 0.5, 3.0]);
+    let nan = x.sqrt().likelihoods().nan;
+    assert!(nan > 0.0 && nan <= 0.25, "{nan}");
+    assert_eq!(x.abs().likelihoods().negative, 0.0);
+    assert!((x.neg().likelihoods().negative - 0.75).abs() < 1e-12);
+
+    // Independent: both negative a sixteenth of the time
+    let product = x.mul(&x);
+    assert!((product.likelihoods().negative - 0.375).abs() < 1e-12);
+
+    // Only a rounding error makes it overflow
+    let sum = x.add(&x);
+    assert_eq!(sum.arg().possibilities().infinite, Possible::ShouldNot);
+    assert!(sum.likelihoods().infinite > 0.0);
+    assert!(sum.likelihoods().infinite < 0.01);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "proptest")]
+
+use fn_num_types::core::ops;
+use fn_num_types::{FloatPossibilities, FnArgFloat};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_union(a: FloatPossibilities, b: FloatPossibilities) {
+        prop_assert_eq!(a.union(&b), b.union(&a));
+        prop_assert!(a.is_subset_of(&a.union(&b)));
+    }
+
+    #[test]
+    fn test_monotone(a: FloatPossibilities, b: FloatPossibilities) {
+        let x = FnArgFloat::F64(a);
+        let y = FnArgFloat::F64(a.union(&b));
+
+        for op in [ops::sqrt, ops::ln, ops::exp, ops::sin, ops::floor] {
+            prop_assert!(op(&x).possibilities().is_subset_of(op(&y).possibilities()));
+        }
+        prop_assert!(ops::mul(&x, &x)
+            .possibilities()
+            .is_subset_of(ops::mul(&y, &x).possibilities()));
+    }
+
+    #[test]
+    fn test_fn_arg_float(x: FnArgFloat) {
+        prop_assert!(ops::abs(&x).is_same_type(&x));
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary() {
+    use arbitrary::Unstructured;
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&bytes);
+
+    let fp: FloatPossibilities = u.arbitrary().unwrap();
+    let x: FnArgFloat = u.arbitrary().unwrap();
+    assert_eq!(fp.union(&fp), fp);
+    assert!(x.is_same_type(&x));
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "full")]
+
+use fn_num_types::core::ops::{self, apply, op_metadata, op_names};
+use fn_num_types::{Error, FloatPossibilities, FnArgFloat};
+
+#[test]
+fn test_apply() {
+    let x = FnArgFloat::F32(FloatPossibilities::any());
+    let y = FnArgFloat::F32(FloatPossibilities::positive_finite());
+
+    for (name, arity) in op_names() {
+        let args = vec![y; arity.unwrap_or(4)];
+        assert!(apply(name, &args).is_ok(), "{name}");
+
+        if arity.is_some() {
+            assert_eq!(
+                apply(name, &[y; 5]),
+                Err(Error::Arity {
+                    op: name.to_owned(),
+                    found: 5
+                })
+            );
+        }
+    }
+
+    assert_eq!(apply("hypot", &[x, y]), Ok(ops::hypot(&x, &y)));
+    assert_eq!(apply("clamp", &[x, y, y]), Ok(ops::clamp(&x, &y, &y)));
+    assert_eq!(apply("sum", &[x, y, y]), Ok(ops::sum(&[x, y, y])));
+    assert_eq!(
+        apply("sum", &[]),
+        Err(Error::Arity {
+            op: "sum".to_owned(),
+            found: 0
+        })
+    );
+    assert_eq!(
+        apply("add", &[x, FnArgFloat::F64(FloatPossibilities::any())]),
+        Err(Error::DifferentTypes)
+    );
+}
+
+#[test]
+fn test_op_metadata() {
+    for (name, arity) in op_names() {
+        assert_eq!(op_metadata(name).is_some(), arity == Some(1), "{name}");
+    }
+}
+
+/// The metadata against the concrete operations, on a few values
+#[cfg(feature = "std")]
+#[test]
+fn test_op_metadata_values() {
+    use fn_num_types::core::ops::{Monotonicity, Parity};
+    use fn_num_types::float_op::unary_op;
+
+    let mut values = vec![
+        f64::NEG_INFINITY,
+        f64::MIN,
+        -1.0e300,
+        -1.0,
+        -0.75,
+        -0.5,
+        -f64::MIN_POSITIVE,
+        -1.0e-310,
+        -0.0,
+        0.0,
+        1.0e-310,
+        f64::MIN_POSITIVE,
+        0.5,
+        0.75,
+        1.0,
+        1.0e300,
+        f64::MAX,
+        f64::INFINITY,
+    ];
+    values.extend((-40..=40).map(|i| f64::from(i) * 0.1 + 0.01));
+    values.sort_by(f64::total_cmp);
+
+    for (name, _) in op_names() {
+        let (Some(metadata), Some(op)) = (op_metadata(name), unary_op(name)) else {
+            continue;
+        };
+        let f = |x: f64| op.apply_concrete(x);
+        let (low, high) = metadata.domain;
+
+        for x in values.iter().copied() {
+            let in_domain = low <= x && x <= high;
+            assert_eq!(!f(x).is_nan(), in_domain, "{name}({x})");
+
+            // Up to the rounding errors of the implementations
+            let close = |a: f64, b: f64| a == b || (a - b).abs() <= 1e-12 * a.abs();
+            match metadata.parity {
+                Parity::Odd if in_domain => assert!(close(f(-x), -f(x)), "{name}({x})"),
+                Parity::Even if in_domain => assert!(close(f(-x), f(x)), "{name}({x})"),
+                _ => {}
+            }
+
+            if metadata.exact_for_integers && x.is_finite() {
+                let n = x.trunc();
+                assert_eq!(f(n).fract(), 0.0, "{name}({n})");
+            }
+        }
+
+        let results: Vec<f64> = values
+            .iter()
+            .copied()
+            .filter(|x| low <= *x && *x <= high)
+            .map(f)
+            .collect();
+        let ordered = |(a, b): (&f64, &f64)| match metadata.monotonicity {
+            Monotonicity::Increasing => a <= b,
+            Monotonicity::Decreasing => a >= b,
+            Monotonicity::Neither => true,
+        };
+        assert!(results.iter().zip(&results[1..]).all(ordered), "{name}");
+    }
+
+    assert_eq!(op_metadata("sqrt").unwrap().domain, (0.0, f64::INFINITY));
+}
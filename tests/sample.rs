@@ -0,0 +1,71 @@
+#![cfg(feature = "rand")]
+
+use fn_num_types::{Field, FloatPossibilities, Possible};
+use rand::RngCore;
+
+/// SplitMix64, to be reproducible
+struct Rng(u64);
+
+impl RngCore for Rng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+#[test]
+fn test_sample() {
+    let mut rng = Rng(0);
+
+    for fp in [
+        FloatPossibilities::any(),
+        FloatPossibilities::positive(),
+        FloatPossibilities::strictly_negative_finite(),
+        FloatPossibilities::finite().with_fractional(Possible::No),
+        FloatPossibilities::nan(),
+    ] {
+        for _ in 0..1000 {
+            assert!(fp.accept(fp.sample(&mut rng)));
+        }
+    }
+
+    // All the kinds of values are sampled
+    let any = FloatPossibilities::any();
+    let samples: Vec<f64> = (0..1000).map(|_| any.sample(&mut rng)).collect();
+    for field in Field::ALL {
+        assert!(
+            samples
+                .iter()
+                .any(|value| FloatPossibilities::from(*value).get(field) == Possible::Yes),
+            "{field:?}"
+        );
+    }
+
+    // Only `1.0`
+    let one = FloatPossibilities::strictly_positive_finite()
+        .with_fractional(Possible::No)
+        .with_large(Possible::No);
+    assert_eq!(one.sample(&mut rng), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "No value accepted")]
+fn test_sample_unsatisfiable() {
+    FloatPossibilities::nan()
+        .with_nan(Possible::No)
+        .sample(&mut Rng(0));
+}
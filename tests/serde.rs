@@ -0,0 +1,31 @@
+#![cfg(all(feature = "serde", feature = "integer"))]
+
+use fn_num_types::{fp, FloatPossibilities, FnArgFloat, FnArgInt, IntPossibilities, Possible};
+
+#[test]
+fn test_round_trip() {
+    let fp = fp! { nan: no, neg_zero: should_not, infinite: should };
+
+    for arg in [FnArgFloat::F32(fp), FnArgFloat::F64(fp)] {
+        let json = serde_json::to_string(&arg).unwrap();
+        let parsed: FnArgFloat = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, arg);
+    }
+
+    let arg = FnArgInt::U8(IntPossibilities::from_value(0u8));
+    let json = serde_json::to_string(&arg).unwrap();
+    assert_eq!(serde_json::from_str::<FnArgInt>(&json).unwrap(), arg);
+}
+
+#[test]
+fn test_format() {
+    assert_eq!(
+        serde_json::to_string(&Possible::ShouldNot).unwrap(),
+        "\"ShouldNot\""
+    );
+
+    let json = serde_json::to_value(FnArgFloat::F64(FloatPossibilities::nan())).unwrap();
+    assert_eq!(json["F64"]["nan"], "Yes");
+    assert_eq!(json["F64"]["positive"], "No");
+}
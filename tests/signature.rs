@@ -0,0 +1,197 @@
+use fn_num_types::{
+    checked, Error, Field, FloatPossibilities, FnArgFloat, FnSignature, PossibilityLattice,
+    Possible,
+};
+
+fn signature() -> FnSignature {
+    FnSignature::new(
+        vec![
+            FnArgFloat::F64(FloatPossibilities::non_nan()),
+            FnArgFloat::F64(FloatPossibilities::positive()),
+        ],
+        FnArgFloat::F64(FloatPossibilities::finite()),
+    )
+}
+
+#[test]
+fn test_check() {
+    let signature = signature();
+    let finite = FnArgFloat::F64(FloatPossibilities::finite());
+
+    assert_eq!(signature.check(&signature.args), Ok(()));
+    assert_eq!(
+        signature.call(&[finite, finite]),
+        Err(Error::NotAccepted { index: 1 })
+    );
+    assert_eq!(
+        signature.check(&[finite]),
+        Err(Error::ArgumentCount {
+            expected: 2,
+            found: 1
+        })
+    );
+    assert_eq!(
+        signature.check(&[FnArgFloat::F32(FloatPossibilities::finite()), finite]),
+        Err(Error::DifferentTypes)
+    );
+    assert_eq!(
+        signature.call(&[
+            finite,
+            FnArgFloat::F64(FloatPossibilities::positive_finite())
+        ]),
+        Ok(signature.ret)
+    );
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_is_implemented_by() {
+    use fn_num_types::core::ops;
+
+    let signature = signature();
+
+    // `NaN` for `-inf + inf`
+    assert!(!signature.is_implemented_by(|args| ops::add(&args[0], &args[1])));
+    assert!(signature.is_implemented_by(|args| ops::signum(&args[0])));
+    assert!(!signature.is_implemented_by(|args| ops::to_f32(&ops::signum(&args[0]))));
+}
+
+#[test]
+fn test_display() {
+    let signature = FnSignature::new(
+        vec![FnArgFloat::F32(FloatPossibilities::nan())],
+        FnArgFloat::F32(FloatPossibilities::nan()),
+    );
+
+    assert_eq!(
+        signature.to_string(),
+        "fn(f32 nan:Y zero:N sub:N frac:N large:N inf:N +:N -:N) \
+         -> f32 nan:Y zero:N sub:N frac:N large:N inf:N +:N -:N"
+    );
+}
+
+#[test]
+fn test_checked() {
+    let contract = FloatPossibilities::finite().with_large(Possible::No);
+    assert_eq!(contract.violation(0.5), None);
+    assert_eq!(contract.violation(1.0e-40f32), None);
+    assert_eq!(contract.violation(f64::INFINITY), Some(Field::Infinite));
+    assert_eq!(contract.violation(2.0), Some(Field::Large));
+    assert_eq!(
+        FloatPossibilities::non_nan()
+            .with_subnormal(Possible::No)
+            .violation(1.0e-40f32),
+        Some(Field::Subnormal)
+    );
+
+    let sin = checked(contract, f64::sin);
+    assert_eq!(sin(0.0), 0.0);
+    let hypot = checked(FloatPossibilities::positive(), |(x, y): (f32, f32)| {
+        x.hypot(y)
+    });
+    assert_eq!(hypot((3.0, 4.0)), 5.0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Result -1.0 not accepted: `negative` is `No`")]
+fn test_checked_panics() {
+    let abs = checked(FloatPossibilities::positive(), |x: f64| -x);
+    abs(1.0);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_multiple_outputs() {
+    use fn_num_types::core::ops;
+    use fn_num_types::FnRet;
+
+    let any = FnArgFloat::F64(FloatPossibilities::any());
+    let finite = FnArgFloat::F64(FloatPossibilities::finite());
+    let signature = FnSignature::new(vec![finite, finite], FnRet::from([finite, any]));
+
+    // `(x + y, x / y)`
+    assert!(!signature.is_implemented_by(|args| FnRet::from((
+        ops::add(&args[0], &args[1]),
+        ops::div(&args[0], &args[1]),
+    ))));
+    assert!(signature
+        .is_implemented_by(|args| FnRet::from(
+            (ops::sin(&args[0]), ops::div(&args[0], &args[1]),)
+        )));
+    assert_eq!(signature.call(&[finite, finite]), Ok(signature.ret.clone()));
+    assert_eq!(
+        signature.to_string(),
+        format!("fn({finite}, {finite}) -> ({finite}, {any})")
+    );
+}
+
+#[test]
+fn test_ret_lattice() {
+    use fn_num_types::FnRet;
+
+    let nan = FnArgFloat::F64(FloatPossibilities::nan());
+    let finite = FnArgFloat::F64(FloatPossibilities::finite());
+    let a = FnRet::from((nan, finite));
+    let b = FnRet::from((finite, finite));
+
+    let union = a.union(&b).unwrap();
+    assert!(a.is_subset_of(&union) && b.is_subset_of(&union));
+    assert!(union.accept(&[f64::NAN, 1.0]));
+    assert!(union.accept(&[1.0, 1.0]));
+    assert!(!union.accept(&[1.0]));
+    assert!(!union.accept(&[1.0f32, 1.0]));
+
+    let intersection = a.intersection(&b).unwrap();
+    assert_eq!(intersection.elements[1], finite);
+    assert!(!intersection.elements[0].possibilities().is_satisfiable());
+
+    // Of different types
+    let single = FnRet::from(finite);
+    let f32 = FnRet::from((nan, FnArgFloat::F32(FloatPossibilities::finite())));
+    assert_eq!(a.union(&single), Err(Error::DifferentTypes));
+    assert_eq!(a.intersection(&f32), Err(Error::DifferentTypes));
+    assert!(!single.is_subset_of(&a));
+}
+
+#[test]
+fn test_sound_approximation() {
+    let levels = [
+        Possible::No,
+        Possible::ShouldNot,
+        Possible::Should,
+        Possible::Yes,
+    ];
+
+    let fps: Vec<FloatPossibilities> = FloatPossibilities::enumerate(&levels).step_by(97).collect();
+
+    for &fp in &fps {
+        for &other in fps.iter().step_by(11) {
+            // Comparing the levels is stricter
+            if other.is_subset_of(&fp) {
+                assert!(fp.is_sound_approximation_of(&other), "{fp} {other}");
+            }
+            // Only `No` matters
+            let possible = |fp: FloatPossibilities| fp.map(|level| level != Possible::No);
+            assert_eq!(
+                fp.is_sound_approximation_of(&other),
+                possible(other).is_below(possible(fp)),
+                "{fp} {other}"
+            );
+        }
+    }
+
+    let declared =
+        FnArgFloat::F64(FloatPossibilities::finite().with(Field::Infinite, Possible::ShouldNot));
+    let computed =
+        FnArgFloat::F64(FloatPossibilities::finite().with(Field::Infinite, Possible::Yes));
+    assert!(declared.is_sound_approximation_of(&computed));
+    assert!(!computed
+        .possibilities()
+        .is_subset_of(declared.possibilities()));
+    assert!(!FnArgFloat::F32(*declared.possibilities()).is_sound_approximation_of(&computed));
+
+    // The contract is of the possible values only
+    let signature = FnSignature::new(vec![computed], declared);
+    assert!(signature.is_implemented_by(|args| args[0]));
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "full")]
+
+use fn_num_types::simd::SimdPossibilities;
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+
+const VALUES: &[f64] = &[
+    f64::NAN,
+    f64::NEG_INFINITY,
+    -f64::MAX,
+    -1.5,
+    -0.0,
+    0.0,
+    1e-310,
+    1.0,
+    f64::MAX,
+    f64::INFINITY,
+];
+
+/// All the vectors of 4 lanes of `VALUES`
+fn vectors() -> impl Iterator<Item = [f64; 4]> {
+    let n = VALUES.len();
+    (0..n.pow(4)).map(move |i| core::array::from_fn(|lane| VALUES[i / n.pow(lane as u32) % n]))
+}
+
+fn simd(values: [f64; 4]) -> SimdPossibilities<4> {
+    SimdPossibilities::new(values.map(FnArgFloat::from))
+}
+
+type Op = fn(f64, f64) -> f64;
+
+/// The reductions by `op` in some of the orders
+fn reductions(values: [f64; 4], op: Op) -> [f64; 3] {
+    let [a, b, c, d] = values;
+    [
+        op(op(op(a, b), c), d),
+        op(a, op(b, op(c, d))),
+        op(op(a, b), op(c, d)),
+    ]
+}
+
+#[test]
+fn test_lane_wise() {
+    for values in vectors().step_by(7) {
+        let x = simd(values);
+        let y = simd([values[3], values[0], values[1], values[2]]);
+        let z: [f64; 4] = core::array::from_fn(|i| values[(i + 3) % 4]);
+
+        assert!(x.accept(values));
+        assert!(x.sqrt().accept(values.map(f64::sqrt)), "{values:?}");
+        assert!(x.neg().accept(values.map(|v| -v)), "{values:?}");
+        assert!(x.add(&y).accept(core::array::from_fn(|i| values[i] + z[i])));
+        assert!(x.sub(&y).accept(core::array::from_fn(|i| values[i] - z[i])));
+        assert!(x.mul(&y).accept(core::array::from_fn(|i| values[i] * z[i])));
+        assert!(x
+            .max(&y)
+            .accept(core::array::from_fn(|i| values[i].max(z[i]))));
+    }
+}
+
+#[test]
+fn test_reductions() {
+    for values in vectors() {
+        let x = simd(values);
+        let shared = SimdPossibilities::<4>::splat(x.shared());
+
+        let ops: [(Op, FnArgFloat); 4] = [
+            (f64::max, x.reduce_max()),
+            (f64::min, x.reduce_min()),
+            (|a, b| a + b, x.reduce_sum()),
+            (|a, b| a * b, x.reduce_product()),
+        ];
+        for (op, sum) in ops {
+            for value in reductions(values, op) {
+                assert!(sum.accept(value), "{values:?} {value}");
+            }
+        }
+        for value in reductions(values, |a, b| a + b) {
+            assert!(shared.reduce_sum().accept(value), "{values:?} {value}");
+        }
+    }
+}
+
+#[test]
+fn test_overflow() {
+    let finite = FnArgFloat::F64(FloatPossibilities::finite());
+
+    // A single addition is never NaN, a sum of 4 lanes may be
+    let two = SimdPossibilities::<2>::splat(finite);
+    let four = SimdPossibilities::<4>::splat(finite);
+    assert_eq!(two.reduce_sum().possibilities().nan, Possible::No);
+    assert_ne!(four.reduce_sum().possibilities().nan, Possible::No);
+    assert!(!(f64::MAX + f64::MAX + (-f64::MAX - f64::MAX)).is_finite());
+
+    // One lane is itself
+    let one = SimdPossibilities::<1>::splat(finite);
+    assert_eq!(one.reduce_sum(), finite);
+}
+
+#[test]
+#[should_panic(expected = "Different types")]
+fn test_different_types() {
+    SimdPossibilities::new([FnArgFloat::from(1.0f32), FnArgFloat::from(1.0f64)]);
+}
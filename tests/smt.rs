@@ -0,0 +1,73 @@
+#![cfg(feature = "smt")]
+
+use fn_num_types::smt::{script, OPS};
+use fn_num_types::{Error, FnArgFloat, Possible, FP};
+
+const POSITIVE: FP = FP {
+    nan: Possible::No,
+    pos_zero: Possible::Yes,
+    neg_zero: Possible::Yes,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
+    infinite: Possible::No,
+    positive: Possible::Yes,
+    negative: Possible::No,
+};
+
+#[test]
+fn test_script() {
+    let x = FnArgFloat::F64(POSITIVE);
+
+    assert_eq!(
+        script("add", &[x, x]).unwrap(),
+        "; add(f64 nan:N zero:Y sub:Y frac:Y large:Y inf:N +:Y -:N, f64 nan:N zero:Y sub:Y frac:Y large:Y inf:N +:Y -:N) = f64 nan:N zero:Y sub:Y frac:Y large:Y inf:SN +:Y -:N
+(set-logic QF_FP)
+(declare-const x0 (_ FloatingPoint 11 53))
+(assert (and (=> (fp.isNaN x0) false) (=> (fp.isSubnormal x0) true) (=> (not (or (fp.isNaN x0) (fp.isInfinite x0) (fp.eq (fp.roundToIntegral RNE x0) x0))) true) (=> (and (not (fp.isInfinite x0)) (fp.gt (fp.abs x0) (fp #b0 #b01111111111 #b0000000000000000000000000000000000000000000000000000))) true) (=> (fp.isInfinite x0) false) (=> (and (fp.isZero x0) (fp.isPositive x0)) true) (=> (and (fp.isZero x0) (fp.isNegative x0)) true) (=> (fp.isPositive x0) true) (=> (fp.isNegative x0) false)))
+(declare-const x1 (_ FloatingPoint 11 53))
+(assert (and (=> (fp.isNaN x1) false) (=> (fp.isSubnormal x1) true) (=> (not (or (fp.isNaN x1) (fp.isInfinite x1) (fp.eq (fp.roundToIntegral RNE x1) x1))) true) (=> (and (not (fp.isInfinite x1)) (fp.gt (fp.abs x1) (fp #b0 #b01111111111 #b0000000000000000000000000000000000000000000000000000))) true) (=> (fp.isInfinite x1) false) (=> (and (fp.isZero x1) (fp.isPositive x1)) true) (=> (and (fp.isZero x1) (fp.isNegative x1)) true) (=> (fp.isPositive x1) true) (=> (fp.isNegative x1) false)))
+(define-fun r () (_ FloatingPoint 11 53) (fp.add RNE x0 x1))
+(assert (not (and (=> (fp.isNaN r) false) (=> (fp.isSubnormal r) true) (=> (not (or (fp.isNaN r) (fp.isInfinite r) (fp.eq (fp.roundToIntegral RNE r) r))) true) (=> (and (not (fp.isInfinite r)) (fp.gt (fp.abs r) (fp #b0 #b01111111111 #b0000000000000000000000000000000000000000000000000000))) true) (=> (fp.isInfinite r) true) (=> (and (fp.isZero r) (fp.isPositive r)) true) (=> (and (fp.isZero r) (fp.isNegative r)) true) (=> (fp.isPositive r) true) (=> (fp.isNegative r) false))))
+(check-sat)
+"
+    );
+}
+
+#[test]
+fn test_literals() {
+    let x = FnArgFloat::F32(POSITIVE);
+
+    let recip = script("recip", &[x]).unwrap();
+    assert!(recip.contains("(fp.div RNE (fp #b0 #b01111111 #b00000000000000000000000) x0)"));
+
+    let x = FnArgFloat::F64(POSITIVE);
+
+    let signum = script("signum", &[x]).unwrap();
+    assert!(signum.contains(
+        "(fp.neg (fp #b0 #b01111111111 #b0000000000000000000000000000000000000000000000000000))"
+    ));
+}
+
+#[test]
+fn test_ops() {
+    let x = FnArgFloat::F64(POSITIVE);
+
+    for op in OPS {
+        // Any arity
+        assert!((1..=3).any(|n| script(op, &vec![x; n]).is_ok()), "{op}");
+    }
+
+    assert_eq!(script("sin", &[x]), Err(Error::UnknownOp("sin".to_owned())));
+    assert_eq!(
+        script("add", &[x]),
+        Err(Error::Arity {
+            op: "add".to_owned(),
+            found: 1
+        })
+    );
+    assert_eq!(
+        script("add", &[x, FnArgFloat::F32(POSITIVE)]),
+        Err(Error::DifferentTypes)
+    );
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "snan")]
+
+use std::hint::black_box;
+
+use fn_num_types::snan::{NanBits, SignalingFloat};
+use fn_num_types::{BoolPossibility, FloatPossibilities, FnArgFloat, Possible};
+
+fn snan_f64() -> f64 {
+    f64::from_bits(0x7FF0_0000_0000_0001)
+}
+
+fn snan_f32() -> f32 {
+    f32::from_bits(0x7F80_0001)
+}
+
+fn any() -> SignalingFloat {
+    SignalingFloat::from(FnArgFloat::F64(FloatPossibilities::any()))
+}
+
+#[test]
+fn test_is_signaling() {
+    assert!(snan_f64().is_signaling());
+    assert!((-snan_f64()).is_signaling());
+    assert!(snan_f32().is_signaling());
+    assert!(!f64::NAN.is_signaling());
+    assert!(!f32::NAN.is_signaling());
+    assert!(!f64::INFINITY.is_signaling());
+    assert!(!1.0f32.is_signaling());
+}
+
+#[test]
+fn test_bits() {
+    // The operations on the bits keep the payload
+    let x = black_box(snan_f64());
+    assert!((-x).is_signaling());
+    assert!(x.abs().is_signaling());
+    assert!(x.copysign(-1.0).is_signaling());
+    let x = black_box(snan_f32());
+    assert!((-x).is_signaling());
+    assert!(x.abs().is_signaling());
+    assert!(x.copysign(-1.0).is_signaling());
+
+    // The arithmetic operations quiet it
+    let x = black_box(snan_f64());
+    assert!(!(x + 1.0).is_signaling());
+    assert!(!(x * 1.0).is_signaling());
+    assert!(!x.sqrt().is_signaling());
+    assert!(!black_box(snan_f32()).sqrt().is_signaling());
+
+    let x = any();
+    assert!(x.neg().accept(-snan_f64()));
+    assert!(x.abs().accept(snan_f64().abs()));
+    assert!(x.copysign(&x).accept(snan_f64()));
+    assert!(!x.add(&x).accept(snan_f64()));
+    assert!(x.add(&x).accept(f64::NAN));
+    assert!(!x.mul_add(&x, &x).accept(snan_f64()));
+    assert!(!x.sin().accept(snan_f64()));
+}
+
+#[test]
+fn test_new() {
+    let x = SignalingFloat::quiet(FnArgFloat::F64(FloatPossibilities::any()));
+    assert_eq!(x.snan(), Possible::No);
+    assert!(x.accept(f64::NAN));
+    assert!(!x.accept(snan_f64()));
+    assert!(x.accept(1.0));
+
+    // Bounded by the possibility of NaN
+    let x = SignalingFloat::from(FnArgFloat::from(1.0));
+    assert_eq!(x.snan(), Possible::No);
+    let x = SignalingFloat::new(
+        FnArgFloat::F64(FloatPossibilities {
+            nan: Possible::ShouldNot,
+            ..FloatPossibilities::any()
+        }),
+        Possible::Yes,
+    );
+    assert_eq!(x.snan(), Possible::ShouldNot);
+}
+
+#[test]
+fn test_max_min() {
+    let one = SignalingFloat::from(FnArgFloat::from(1.0));
+    let quiet = SignalingFloat::quiet(FnArgFloat::F64(FloatPossibilities::any()));
+
+    // A NaN is ignored
+    assert_eq!(any().max(&one).snan(), Possible::No);
+    assert_eq!(one.min(&any()).snan(), Possible::No);
+
+    // Of two NaNs, either is returned
+    assert_eq!(any().max(&quiet).snan(), Possible::Yes);
+    assert_eq!(quiet.min(&any()).snan(), Possible::Yes);
+    assert_eq!(quiet.max(&quiet).snan(), Possible::No);
+
+    // `maximum` is arithmetic
+    assert_eq!(any().maximum(&any()).snan(), Possible::No);
+
+    let select = SignalingFloat::select(BoolPossibility::Maybe, &one, &any());
+    assert_eq!(select.snan(), Possible::Yes);
+    let select = SignalingFloat::select(BoolPossibility::True, &one, &any());
+    assert_eq!(select.snan(), Possible::No);
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "syn")]
+
+use fn_num_types::core::ops;
+use fn_num_types::expr::{eval, parse};
+use fn_num_types::source::analyze_source;
+use fn_num_types::{Error, FloatPossibilities, FnArgFloat, ParseError};
+
+fn x() -> FnArgFloat {
+    FnArgFloat::F64(FloatPossibilities::any())
+}
+
+fn y() -> FnArgFloat {
+    FnArgFloat::F64(FloatPossibilities::positive_finite())
+}
+
+#[test]
+fn test_like_parse() {
+    let bindings = [("x", x()), ("y", y())];
+
+    for source in [
+        "x",
+        "-x.abs()",
+        "(x + y) * 2.0 - y / 3.5 % x",
+        "x.powf(y).max(1.0)",
+        "y.sqrt().ln_1p().atan2(x)",
+        "1e-3 * x.hypot(y)",
+    ] {
+        let expr = parse(source, &["x", "y"]).unwrap();
+        assert_eq!(
+            analyze_source(source, &bindings),
+            Ok(eval(&expr, &[x(), y()])),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn test_rust_only() {
+    let (x, y) = (x(), y());
+    let bindings = [("x", x), ("y", y)];
+    let one = FnArgFloat::from(1.0);
+
+    assert_eq!(
+        analyze_source("x.abs().mul_add(y, 1.0)", &bindings),
+        Ok(ops::mul_add(&ops::abs(&x), &y, &one))
+    );
+    assert_eq!(
+        analyze_source("f64::sqrt(y.clamp(0.0, 1.0))", &bindings),
+        Ok(ops::sqrt(&ops::clamp(&y, &FnArgFloat::from(0.0), &one)))
+    );
+    assert_eq!(
+        analyze_source("x.powi(2)", &bindings),
+        Ok(ops::powi_n(&x, 2))
+    );
+    assert_eq!(analyze_source("x.powi(n)", &bindings), Ok(ops::powi(&x)));
+    assert_eq!(
+        analyze_source("(x as f32).sin() as f64", &bindings),
+        Ok(ops::to_f64(&ops::sin(&ops::to_f32(&x))))
+    );
+    assert_eq!(
+        analyze_source("y * std::f64::consts::PI", &bindings),
+        Ok(ops::mul(&y, &FnArgFloat::from(core::f64::consts::PI)))
+    );
+    assert_eq!(
+        analyze_source("f64::MAX + y", &bindings),
+        Ok(ops::add(&FnArgFloat::from(f64::MAX), &y))
+    );
+    assert_eq!(
+        analyze_source("1.5f32 * 2.0f32", &[]),
+        Ok(ops::mul(
+            &FnArgFloat::from(1.5f32),
+            &FnArgFloat::from(2.0f32)
+        ))
+    );
+}
+
+#[test]
+fn test_errors() {
+    let bindings = [("x", x())];
+    let parse_error = |token: &str| {
+        Err(Error::Parse(ParseError {
+            token: token.to_owned(),
+        }))
+    };
+
+    assert_eq!(analyze_source("x +", &bindings), parse_error("x +"));
+    assert_eq!(analyze_source("z.abs()", &bindings), parse_error("z"));
+    assert_eq!(analyze_source("x << 1", &bindings), parse_error("<<"));
+    assert_eq!(analyze_source("x as i32", &bindings), parse_error("i32"));
+    assert_eq!(
+        analyze_source("x.sqrtf()", &bindings),
+        Err(Error::UnknownOp("sqrtf".to_owned()))
+    );
+    assert_eq!(
+        analyze_source("x.sqrt(x)", &bindings),
+        Err(Error::Arity {
+            op: "sqrt".to_owned(),
+            found: 2
+        })
+    );
+    assert_eq!(
+        analyze_source("x + 1.0f32", &bindings),
+        Err(Error::DifferentTypes)
+    );
+}
@@ -0,0 +1,83 @@
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+
+#[test]
+fn test_round_trip() {
+    let levels = [
+        Possible::No,
+        Possible::ShouldNot,
+        Possible::Should,
+        Possible::Yes,
+    ];
+
+    for level in levels {
+        let fp = FloatPossibilities {
+            nan: level,
+            pos_zero: level,
+            neg_zero: Possible::Yes,
+            subnormal: Possible::Yes,
+            fractional: Possible::Yes,
+            large: Possible::Yes,
+            infinite: level,
+            positive: Possible::Yes,
+            negative: level,
+        };
+
+        for arg in [FnArgFloat::F32(fp), FnArgFloat::F64(fp)] {
+            let parsed: FnArgFloat = arg.to_string().parse().unwrap();
+            assert_eq!(parsed.to_string(), arg.to_string());
+        }
+    }
+}
+
+#[test]
+fn test_parse() {
+    let arg: FnArgFloat = "f32 +:Y zero:S".parse().unwrap();
+    assert_eq!(
+        arg.to_string(),
+        "f32 nan:N zero:S sub:N frac:N large:N inf:N +:Y -:N"
+    );
+
+    let arg: FnArgFloat = "f64".parse().unwrap();
+    assert_eq!(
+        arg.to_string(),
+        "f64 nan:N zero:N sub:N frac:N large:N inf:N +:N -:N"
+    );
+
+    let arg: FnArgFloat = "f64 -0:Y -:Y".parse().unwrap();
+    assert_eq!(
+        arg.to_string(),
+        "f64 nan:N +0:N -0:Y sub:N frac:N large:N inf:N +:N -:Y"
+    );
+    assert!(!arg.possibilities().accept(0.0));
+    assert!(arg.possibilities().accept(-0.0));
+
+    let arg: FnArgFloat = "f64 +:Y sub:N".parse().unwrap();
+    assert!(arg.possibilities().accept(1.0));
+    assert!(!arg.possibilities().accept(1.0e-310));
+
+    let arg: FnArgFloat = "f64 +:Y -:Y zero:Y frac:Y".parse().unwrap();
+    assert!(arg.possibilities().accept(-0.5));
+    assert!(!arg.possibilities().accept(-2.5));
+    assert!("f64 frac:Y frac:N".parse::<FnArgFloat>().is_err());
+
+    let arg: FnArgFloat = "f64 +:Y -:Y zero:Y frac:Y large:Y".parse().unwrap();
+    assert!(arg.possibilities().accept(-2.5));
+    assert!(arg.possibilities().accept(f64::MAX));
+    assert!(!arg.possibilities().accept(f64::INFINITY));
+}
+
+#[test]
+fn test_parse_errors() {
+    for invalid in [
+        "",
+        "f8 +:Y",
+        "f64 +:Yes",
+        "f64 +",
+        "f64 +:Y +:N",
+        "f64 zero:Y +0:N",
+        "f64 pos:Y",
+    ] {
+        let err = invalid.parse::<FnArgFloat>();
+        assert!(err.is_err(), "{invalid:?} should not be valid");
+    }
+}
@@ -0,0 +1,82 @@
+#![cfg(all(feature = "tracing", feature = "full"))]
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+const POSITIVE_FINITE: FloatPossibilities = FloatPossibilities {
+    nan: Possible::No,
+    pos_zero: Possible::No,
+    neg_zero: Possible::No,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
+    infinite: Possible::No,
+    positive: Possible::Yes,
+    negative: Possible::No,
+};
+
+/// Records the fields of the spans and events as `name=value`
+#[derive(Default, Clone)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Visit for Recorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("{}={value:?}", field.name()));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("span={}", span.metadata().name()));
+        span.record(&mut self.clone());
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn test_events() {
+    let recorder = Recorder::default();
+    let x = FnArgFloat::F64(POSITIVE_FINITE);
+
+    let res = tracing::subscriber::with_default(recorder.clone(), || {
+        fn_num_types::core::ops::apply("add", &[x, x])
+    });
+
+    let records = recorder.0.lock().unwrap();
+
+    assert!(records.contains(&"span=apply".to_owned()));
+    assert!(records.contains(&"op=\"add\"".to_owned()));
+    assert!(records.contains(&format!("args={:?}", [x, x])));
+    assert!(records.contains(&"rule=\"positive overflow\"".to_owned()));
+    assert!(!records.contains(&"rule=\"negative overflow\"".to_owned()));
+    assert!(records.contains(&format!("result={res:?}")));
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "typed-floats")]
+
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+use typed_floats::{NonNaN, Positive, StrictlyNegativeFinite, StrictlyPositive};
+
+#[test]
+fn test_of() {
+    assert_eq!(
+        FloatPossibilities::of::<NonNaN<f32>>(),
+        FloatPossibilities::non_nan()
+    );
+    assert_eq!(
+        FloatPossibilities::of::<StrictlyNegativeFinite>(),
+        FloatPossibilities::strictly_negative_finite()
+    );
+}
+
+#[test]
+fn test_is_representable_by() {
+    let p = FloatPossibilities::strictly_positive();
+
+    assert!(p.is_representable_by::<StrictlyPositive>());
+    assert!(p.is_representable_by::<Positive>());
+    assert!(p.is_representable_by::<NonNaN>());
+    assert!(!p.is_representable_by::<StrictlyNegativeFinite>());
+    // Even unlikely, NaN is not representable
+    assert!(!p
+        .with_nan(Possible::ShouldNot)
+        .is_representable_by::<NonNaN>());
+}
+
+#[test]
+fn test_from_value() {
+    let x = StrictlyNegativeFinite::<f64>::try_from(-2.5).unwrap();
+
+    assert_eq!(FloatPossibilities::from(x), FloatPossibilities::from(-2.5));
+    assert_eq!(
+        FnArgFloat::from(NonNaN::<f32>::try_from(1.0e-40).unwrap()),
+        FnArgFloat::F32(FloatPossibilities::from(1.0e-40f32))
+    );
+}
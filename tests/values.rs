@@ -0,0 +1,302 @@
+#![cfg(all(feature = "std", feature = "full"))]
+
+use fn_num_types::expr::{BinaryOp, UnaryOp};
+use fn_num_types::values::{ExactFloat, MAX_VALUES};
+use fn_num_types::{BoolPossibility, FloatPossibilities, FnArgFloat, Possible};
+
+const ANY: FloatPossibilities = FloatPossibilities {
+    nan: Possible::Yes,
+    pos_zero: Possible::Yes,
+    neg_zero: Possible::Yes,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
+    infinite: Possible::Yes,
+    positive: Possible::Yes,
+    negative: Possible::Yes,
+};
+
+const NOT_NAN: FloatPossibilities = FloatPossibilities {
+    nan: Possible::No,
+    ..ANY
+};
+
+fn set(values: &[f64]) -> ExactFloat {
+    ExactFloat::new(FnArgFloat::F64(NOT_NAN), Some(values))
+}
+
+#[test]
+fn test_new() {
+    let x = ExactFloat::new(FnArgFloat::F64(ANY), Some(&[2.0, -1.0, 2.0]));
+    assert_eq!(x.values(), Some(&[-1.0, 2.0][..]));
+    assert!(x.accept(f64::NAN));
+    assert!(x.accept(2.0));
+    assert!(!x.accept(3.0));
+    assert_eq!(x.arg().possibilities().subnormal, Possible::No);
+    assert_eq!(x.arg().possibilities().infinite, Possible::No);
+
+    // The values not accepted are ignored
+    let positive = FnArgFloat::F64(FloatPossibilities {
+        negative: Possible::No,
+        ..NOT_NAN
+    });
+    let x = ExactFloat::new(positive, Some(&[-1.0, 1.0]));
+    assert_eq!(x.values(), Some(&[1.0][..]));
+
+    // Rounded to the type
+    let x = ExactFloat::new(FnArgFloat::F32(NOT_NAN), Some(&[0.1]));
+    assert_eq!(x.values(), Some(&[f64::from(0.1f32)][..]));
+
+    let x = ExactFloat::from(FnArgFloat::F64(ANY));
+    assert_eq!(x.values(), None);
+    assert!(x.accept(12.5));
+}
+
+#[test]
+fn test_tiny_ranges() {
+    let x = ExactFloat::from(FnArgFloat::F64(NOT_NAN));
+    let sign = x.signum();
+    assert_eq!(sign.values(), Some(&[-1.0, 1.0][..]));
+    assert_eq!(sign.arg().possibilities().zero(), Possible::No);
+    assert_eq!(sign.arg().possibilities().fractional, Possible::No);
+
+    // Between -1.0 and 1.0
+    let bounded = ExactFloat::from(FnArgFloat::F64(FloatPossibilities {
+        large: Possible::No,
+        infinite: Possible::No,
+        ..NOT_NAN
+    }));
+    let floor = bounded.floor();
+    assert_eq!(floor.values(), Some(&[-1.0, -0.0, 0.0, 1.0][..]));
+    assert_eq!(floor.arg().possibilities().subnormal, Possible::No);
+
+    // Not bounded
+    assert_eq!(x.floor().values(), None);
+    assert_eq!(bounded.exp().values(), None);
+}
+
+#[test]
+fn test_special_values() {
+    let one = ExactFloat::from(1.0);
+    assert_eq!(one.acos().values(), Some(&[0.0][..]));
+    assert_eq!(one.ln().values(), Some(&[0.0][..]));
+    assert_eq!(one.acos().arg().possibilities().nan, Possible::No);
+
+    let zero = ExactFloat::from(0.0);
+    assert_eq!(zero.exp().values(), Some(&[1.0][..]));
+    assert_eq!(zero.cos().values(), Some(&[1.0][..]));
+    assert_eq!(set(&[-0.0, 0.0]).sin().values(), Some(&[-0.0, 0.0][..]));
+
+    // Not a special value
+    assert_eq!(ExactFloat::from(2.0).exp().values(), None);
+    // Nor a special result
+    assert_eq!(one.exp().values(), None);
+}
+
+#[test]
+fn test_binary() {
+    let x = set(&[1.0, 2.0]);
+    let y = set(&[10.0, 20.0]);
+
+    let sum = x.add(&y);
+    assert_eq!(sum.values(), Some(&[11.0, 12.0, 21.0, 22.0][..]));
+    assert_eq!(sum.arg().possibilities().negative, Possible::No);
+    assert_eq!(sum.arg().possibilities().fractional, Possible::No);
+    assert!(!sum.accept(13.0));
+
+    assert_eq!(x.max(&y).values(), Some(&[10.0, 20.0][..]));
+    assert_eq!(x.div(&set(&[0.0])).values(), Some(&[f64::INFINITY][..]));
+
+    // Too many values
+    let many: Vec<f64> = (0..MAX_VALUES).map(|v| v as f64).collect();
+    let many = set(&many);
+    assert!(many.values().is_some());
+    let sum = many.add(&x);
+    assert_eq!(sum.values(), None);
+    assert_eq!(sum.arg().possibilities().negative, Possible::No);
+
+    // Without a set
+    let any = ExactFloat::from(FnArgFloat::F64(ANY));
+    assert_eq!(x.add(&any).values(), None);
+}
+
+#[test]
+fn test_cmp() {
+    let x = set(&[1.0, 2.0]);
+    let y = set(&[3.0]);
+
+    assert_eq!(x.lt(&y), BoolPossibility::True);
+    assert_eq!(x.ge(&y), BoolPossibility::False);
+    assert_eq!(x.eq(&set(&[2.0])), BoolPossibility::Maybe);
+    assert_eq!(set(&[0.0]).eq(&set(&[-0.0])), BoolPossibility::True);
+
+    // NaN is never equal
+    let nan = ExactFloat::new(FnArgFloat::F64(ANY), Some(&[3.0]));
+    assert_eq!(nan.eq(&y), BoolPossibility::Maybe);
+    assert_eq!(nan.ne(&x), BoolPossibility::True);
+
+    let select = ExactFloat::select(x.lt(&y), &x, &y);
+    assert_eq!(select, x);
+    let select = ExactFloat::select(BoolPossibility::Maybe, &x, &y);
+    assert_eq!(select.values(), Some(&[1.0, 2.0, 3.0][..]));
+}
+
+macro_rules! generate_tests {
+    ($float:ident, $variant:ident) => {
+        use super::*;
+
+        fn values() -> Vec<$float> {
+            vec![
+                $float::NAN,
+                $float::NEG_INFINITY,
+                $float::MIN,
+                -3.0,
+                -1.0,
+                -0.5,
+                -$float::from_bits(1),
+                -0.0,
+                0.0,
+                $float::from_bits(1),
+                0.1,
+                0.5,
+                1.0,
+                1.5,
+                3.0,
+                $float::MAX,
+                $float::INFINITY,
+            ]
+        }
+
+        fn get_args() -> Vec<ExactFloat> {
+            let values: Vec<f64> = values().into_iter().map(f64::from).collect();
+            let mut args = vec![];
+
+            for p in [ANY, NOT_NAN] {
+                let arg = FnArgFloat::$variant(p);
+                args.push(ExactFloat::from(arg));
+                for window in values.windows(3).step_by(2) {
+                    args.push(ExactFloat::new(arg, Some(window)));
+                }
+                args.push(ExactFloat::new(arg, Some(&[-1.0, -0.0, 0.0, 1.0])));
+            }
+            args.push(ExactFloat::from(FnArgFloat::$variant(FloatPossibilities {
+                large: Possible::No,
+                infinite: Possible::No,
+                ..ANY
+            })));
+
+            args
+        }
+
+        fn accepted(x: &ExactFloat) -> Vec<$float> {
+            values()
+                .into_iter()
+                .filter(|v| x.accept(f64::from(*v)))
+                .collect()
+        }
+
+        fn test_unary(op: UnaryOp, f: fn($float) -> $float) {
+            for x in get_args() {
+                let res = x.apply_unary(op);
+
+                for v in accepted(&x) {
+                    let result = f(v);
+                    assert!(
+                        res.accept(f64::from(result)),
+                        "{}({v:?}) = {result:?} with {x:?} => {res:?}",
+                        op.name()
+                    );
+                }
+            }
+        }
+
+        fn test_binary(op: BinaryOp, f: fn($float, $float) -> $float) {
+            let args = get_args();
+
+            for x in args.iter() {
+                for y in args.iter() {
+                    let res = x.apply_binary(op, y);
+
+                    for v1 in accepted(x) {
+                        for v2 in accepted(y) {
+                            let result = f(v1, v2);
+                            assert!(
+                                res.accept(f64::from(result)),
+                                "{}({v1:?}, {v2:?}) = {result:?} with {x:?} {y:?} => {res:?}",
+                                op.name()
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_unary_ops() {
+            test_unary(UnaryOp::Neg, |x| -x);
+            test_unary(UnaryOp::Abs, $float::abs);
+            test_unary(UnaryOp::Ceil, $float::ceil);
+            test_unary(UnaryOp::Floor, $float::floor);
+            test_unary(UnaryOp::Round, $float::round);
+            test_unary(UnaryOp::RoundTiesEven, libm::Libm::<$float>::roundeven);
+            test_unary(UnaryOp::Trunc, $float::trunc);
+            test_unary(UnaryOp::Fract, $float::fract);
+            test_unary(UnaryOp::Signum, $float::signum);
+            test_unary(UnaryOp::Sqrt, $float::sqrt);
+            test_unary(UnaryOp::Recip, $float::recip);
+            test_unary(UnaryOp::Exp, $float::exp);
+            test_unary(UnaryOp::Ln, $float::ln);
+            test_unary(UnaryOp::Sin, $float::sin);
+            test_unary(UnaryOp::Cos, $float::cos);
+            test_unary(UnaryOp::Acos, $float::acos);
+            test_unary(UnaryOp::Atanh, $float::atanh);
+        }
+
+        #[test]
+        fn test_binary_ops() {
+            test_binary(BinaryOp::Add, |x, y| x + y);
+            test_binary(BinaryOp::Sub, |x, y| x - y);
+            test_binary(BinaryOp::Mul, |x, y| x * y);
+            test_binary(BinaryOp::Div, |x, y| x / y);
+            test_binary(BinaryOp::Rem, |x, y| x % y);
+            test_binary(BinaryOp::Max, $float::max);
+            test_binary(BinaryOp::Min, $float::min);
+            test_binary(BinaryOp::Copysign, $float::copysign);
+            test_binary(BinaryOp::Powf, $float::powf);
+            test_binary(BinaryOp::Hypot, $float::hypot);
+        }
+
+        #[test]
+        fn test_cmp_ops() {
+            let args = get_args();
+
+            for x in args.iter() {
+                for y in args.iter() {
+                    let res = x.lt(y);
+
+                    for v1 in accepted(x) {
+                        for v2 in accepted(y) {
+                            let expected = if v1 < v2 {
+                                BoolPossibility::True
+                            } else {
+                                BoolPossibility::False
+                            };
+                            assert!(
+                                res == expected || res == BoolPossibility::Maybe,
+                                "{v1:?} < {v2:?} with {x:?} {y:?} => {res:?}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+mod f32 {
+    generate_tests!(f32, F32);
+}
+
+mod f64 {
+    generate_tests!(f64, F64);
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "extra-widths")]
+
+use fn_num_types::{FloatPossibilities, FnArgFloat, Possible};
+use std::num::FpCategory;
+
+const FINITE_POSITIVE: FloatPossibilities = FloatPossibilities {
+    nan: Possible::No,
+    pos_zero: Possible::No,
+    neg_zero: Possible::No,
+    subnormal: Possible::Yes,
+    fractional: Possible::Yes,
+    large: Possible::Yes,
+    infinite: Possible::No,
+    positive: Possible::Yes,
+    negative: Possible::No,
+};
+
+#[test]
+fn test_same_type() {
+    let f16 = FnArgFloat::F16(FINITE_POSITIVE);
+    let bf16 = FnArgFloat::BF16(FINITE_POSITIVE);
+
+    assert!(f16.is_same_type(&FnArgFloat::F16(FloatPossibilities::default())));
+    assert!(!f16.is_same_type(&bf16));
+    assert_eq!(f16.bits(), bf16.bits());
+    assert!(FnArgFloat::check_same_type(&[f16, bf16]).is_err());
+}
+
+#[cfg(feature = "arith")]
+#[test]
+fn test_ops_keep_the_type() {
+    use fn_num_types::core::ops;
+
+    for arg in [
+        FnArgFloat::F16(FINITE_POSITIVE),
+        FnArgFloat::BF16(FINITE_POSITIVE),
+        FnArgFloat::F128(FINITE_POSITIVE),
+    ] {
+        let res = ops::add(&arg, &arg);
+        assert!(res.is_same_type(&arg));
+        // May overflow
+        assert_eq!(res.possibilities().infinite, Possible::ShouldNot);
+
+        assert!(ops::neg(&arg).is_same_type(&arg));
+    }
+}
+
+#[cfg(feature = "arith")]
+#[test]
+#[should_panic(expected = "Different types")]
+fn test_different_widths() {
+    use fn_num_types::core::ops;
+
+    ops::add(
+        &FnArgFloat::F16(FINITE_POSITIVE),
+        &FnArgFloat::BF16(FINITE_POSITIVE),
+    );
+}
+
+#[test]
+fn test_accept_category() {
+    // Like `1e4000f128`, finite but not as a `f64`
+    assert!(FINITE_POSITIVE.accept_category(FpCategory::Normal, false));
+    assert!(FINITE_POSITIVE.accept_category(FpCategory::Subnormal, false));
+    assert!(!FINITE_POSITIVE.accept_category(FpCategory::Infinite, false));
+    assert!(!FINITE_POSITIVE.accept_category(FpCategory::Normal, true));
+    assert!(!FINITE_POSITIVE.accept_category(FpCategory::Nan, false));
+}
+
+#[test]
+fn test_text() {
+    for arg in [
+        FnArgFloat::F16(FINITE_POSITIVE),
+        FnArgFloat::BF16(FINITE_POSITIVE),
+        FnArgFloat::F128(FINITE_POSITIVE),
+    ] {
+        let parsed: FnArgFloat = arg.to_string().parse().unwrap();
+        assert_eq!(parsed, arg);
+    }
+
+    assert_eq!(
+        FnArgFloat::BF16(FINITE_POSITIVE).to_string(),
+        "bf16 nan:N zero:N sub:Y frac:Y large:Y inf:N +:Y -:N"
+    );
+}
+
+#[cfg(feature = "casts")]
+#[test]
+fn test_casts() {
+    use fn_num_types::core::ops;
+    use fn_num_types::IntKind;
+
+    // `f16::MAX` is 65504
+    let f16 = FnArgFloat::F16(FINITE_POSITIVE);
+    assert_eq!(ops::cast_to_int(&f16, IntKind::U16).max_value, Possible::No);
+    assert_eq!(
+        ops::cast_to_int(&f16, IntKind::I16).max_value,
+        Possible::Yes
+    );
+
+    // Widening is exact, and the subnormals of `f16` are normal in `f32`,
+    // like the ones of `f64` in `f128`
+    assert_eq!(
+        ops::to_f32(&f16),
+        FnArgFloat::F32(FloatPossibilities {
+            subnormal: Possible::No,
+            ..FINITE_POSITIVE
+        })
+    );
+    assert_eq!(
+        ops::to_f128(&FnArgFloat::F64(FINITE_POSITIVE)),
+        FnArgFloat::F128(FloatPossibilities {
+            subnormal: Possible::No,
+            ..FINITE_POSITIVE
+        })
+    );
+
+    // Narrowing may overflow and underflow
+    for (res, expected) in [
+        (ops::to_f16(&FnArgFloat::F32(FINITE_POSITIVE)), "f16"),
+        (ops::to_bf16(&FnArgFloat::F32(FINITE_POSITIVE)), "bf16"),
+        (ops::to_f64(&FnArgFloat::F128(FINITE_POSITIVE)), "f64"),
+    ] {
+        assert_eq!(res.type_name(), expected);
+        assert_eq!(res.possibilities().infinite, Possible::ShouldNot);
+        assert_eq!(res.possibilities().zero(), Possible::ShouldNot);
+    }
+
+    // A wider range of exponents
+    assert_eq!(
+        ops::to_bf16(&FnArgFloat::F16(FINITE_POSITIVE)),
+        FnArgFloat::BF16(FloatPossibilities {
+            subnormal: Possible::No,
+            ..FINITE_POSITIVE
+        })
+    );
+    // The same range of exponents
+    assert_eq!(
+        ops::to_f32(&FnArgFloat::BF16(FINITE_POSITIVE)),
+        FnArgFloat::F32(FINITE_POSITIVE)
+    );
+}
+
+#[cfg(feature = "smt")]
+#[test]
+fn test_smt_sorts() {
+    let script = fn_num_types::smt::script("neg", &[FnArgFloat::F16(FINITE_POSITIVE)]).unwrap();
+    assert!(script.contains("(_ FloatingPoint 5 11)"));
+
+    let script = fn_num_types::smt::script("recip", &[FnArgFloat::F128(FINITE_POSITIVE)]).unwrap();
+    assert!(script.contains("((_ to_fp 15 113) RNE"));
+}